@@ -142,20 +142,50 @@ pub enum ExprKind {
     /// literals (`i64::MIN`) until semantic range checking.
     IntLiteral(i128),
 
+    /// An integer literal with a type suffix (e.g., `255u8`, `-1i8`).
+    ///
+    /// Unlike [`ExprKind::IntLiteral`], whose type adapts to its context,
+    /// a suffixed literal always has the type named by its suffix.
+    SuffixedIntLiteral {
+        /// The literal value, including a folded leading minus sign.
+        value: i128,
+        /// The integer type named by the suffix.
+        ty: Type,
+    },
+
     /// A floating-point literal value.
     ///
     /// Float literals are parsed and stored as `f64`.
     FloatLiteral(f64),
 
+    /// A floating-point literal with a type suffix (e.g., `1.5f32`).
+    ///
+    /// Unlike [`ExprKind::FloatLiteral`], whose type adapts to its context,
+    /// a suffixed literal always has the type named by its suffix.
+    SuffixedFloatLiteral {
+        /// The literal value.
+        value: f64,
+        /// The float type named by the suffix.
+        ty: Type,
+    },
+
     /// A boolean literal value (`true` or `false`).
     BoolLiteral(bool),
 
+    /// A character literal value (e.g., `'a'`).
+    ///
+    /// Escape sequences have already been processed by the lexer.
+    CharLiteral(char),
+
     /// A variable reference.
     ///
     /// Refers to a variable by name. The variable must be declared before
     /// use; this is verified during semantic analysis, not parsing.
     Identifier(String),
 
+    /// A tuple expression with two or more elements (`(a, b)`).
+    Tuple(Vec<Expr>),
+
     /// A function call expression.
     Call {
         /// The name of the function being called.
@@ -230,12 +260,19 @@ pub enum ExprKind {
         /// The branch evaluated when condition is false.
         else_block: IfExprBlock,
     },
+
+    /// A block expression that yields a value.
+    ///
+    /// Represents expressions like `{ let x = f(); x + 1 }`. The block follows
+    /// the same tail-value rules as `if` expression branches and introduces its
+    /// own variable scope.
+    Block(IfExprBlock),
 }
 
-/// A branch block used by `if` expressions.
+/// A value-producing block used by `if` expressions and block expressions.
 ///
-/// Each branch can contain zero or more statements for side effects and must
-/// end with a value expression that becomes the branch result.
+/// Each block can contain zero or more statements for side effects and must
+/// end with a value expression that becomes the block result.
 #[derive(Debug, Clone)]
 pub struct IfExprBlock {
     /// Statements executed before the branch result expression.
@@ -274,6 +311,35 @@ impl Expr {
         }
     }
 
+    /// Returns true if this expression is an unsuffixed integer or float
+    /// literal, including a negated one, whose type adapts to its context.
+    pub fn is_unsuffixed_numeric_literal(&self) -> bool {
+        match &self.kind {
+            ExprKind::IntLiteral(_) | ExprKind::FloatLiteral(_) => true,
+            ExprKind::UnaryOp { op, operand } => {
+                *op == UnaryOperator::Neg && operand.is_unsuffixed_numeric_literal()
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the index of the argument whose type determines the operand
+    /// type of a numeric standard library function (e.g. `math.max`).
+    ///
+    /// This is the first argument that is not an unsuffixed numeric literal,
+    /// or the first argument if all of them are. Returns `None` if `args` is
+    /// empty.
+    pub fn numeric_operand_index(args: &[Expr]) -> Option<usize> {
+        if args.is_empty() {
+            return None;
+        }
+        Some(
+            args.iter()
+                .position(|arg| !arg.is_unsuffixed_numeric_literal())
+                .unwrap_or(0),
+        )
+    }
+
     /// Infers a common operand type for binary operations.
     ///
     /// Rules:
@@ -0,0 +1,329 @@
+//! Format string structure for the `format` builtin.
+//!
+//! Format strings are written as string literals and parsed at compile time.
+//! Semantic analysis uses the parsed form to validate placeholders against
+//! argument types, and codegen uses it to emit runtime formatting calls.
+
+/// Horizontal alignment of a padded placeholder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatAlign {
+    /// `<`: pad on the right.
+    Left,
+    /// `^`: pad on both sides.
+    Center,
+    /// `>`: pad on the left.
+    Right,
+}
+
+/// Presentation of a formatted value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatKind {
+    /// No type character: the value's default presentation.
+    Display,
+    /// `x`: lowercase hexadecimal integer.
+    LowerHex,
+    /// `X`: uppercase hexadecimal integer.
+    UpperHex,
+    /// `o`: octal integer.
+    Octal,
+    /// `b`: binary integer.
+    Binary,
+    /// `e`: float in lowercase scientific notation.
+    LowerExp,
+    /// `E`: float in uppercase scientific notation.
+    UpperExp,
+}
+
+impl FormatKind {
+    /// Returns the type character used in source, or `None` for `Display`.
+    pub fn type_char(self) -> Option<char> {
+        match self {
+            FormatKind::Display => None,
+            FormatKind::LowerHex => Some('x'),
+            FormatKind::UpperHex => Some('X'),
+            FormatKind::Octal => Some('o'),
+            FormatKind::Binary => Some('b'),
+            FormatKind::LowerExp => Some('e'),
+            FormatKind::UpperExp => Some('E'),
+        }
+    }
+
+    /// Returns true for the integer radix presentations (`x`, `X`, `o`, `b`).
+    pub fn is_radix(self) -> bool {
+        matches!(
+            self,
+            FormatKind::LowerHex | FormatKind::UpperHex | FormatKind::Octal | FormatKind::Binary
+        )
+    }
+
+    /// Returns true for the scientific notation presentations (`e`, `E`).
+    pub fn is_exponent(self) -> bool {
+        matches!(self, FormatKind::LowerExp | FormatKind::UpperExp)
+    }
+}
+
+/// The `:spec` part of a placeholder.
+///
+/// # Grammar
+///
+/// ```text
+/// spec → (fill? align)? "+"? "#"? "0"? width? ("." precision)? type?
+/// align → "<" | "^" | ">"
+/// type → "x" | "X" | "o" | "b" | "e" | "E"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatSpec {
+    /// Padding character used for alignment (defaults to a space).
+    pub fill: char,
+    /// Explicit alignment, or `None` for the type's default alignment.
+    pub align: Option<FormatAlign>,
+    /// `+`: always print the sign of numbers.
+    pub sign_plus: bool,
+    /// `#`: prefix radix output with `0x`, `0o` or `0b`.
+    pub alternate: bool,
+    /// `0`: pad numbers with zeros after the sign.
+    pub zero_pad: bool,
+    /// Minimum field width in characters.
+    pub width: Option<u32>,
+    /// Digits after the decimal point for floats, or maximum characters for strings.
+    pub precision: Option<u32>,
+    /// Presentation of the value.
+    pub kind: FormatKind,
+}
+
+impl Default for FormatSpec {
+    fn default() -> Self {
+        FormatSpec {
+            fill: ' ',
+            align: None,
+            sign_plus: false,
+            alternate: false,
+            zero_pad: false,
+            width: None,
+            precision: None,
+            kind: FormatKind::Display,
+        }
+    }
+}
+
+/// The value a placeholder refers to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormatArg {
+    /// A positional argument after the format string.
+    ///
+    /// `{}` placeholders are numbered implicitly from left to right;
+    /// `{N}` refers to argument `N` explicitly.
+    Positional(usize),
+    /// A variable in scope captured by name (`{name}`).
+    Named(String),
+}
+
+/// A `{...}` placeholder in a format string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatPlaceholder {
+    /// The value to format.
+    pub arg: FormatArg,
+    /// How to format it.
+    pub spec: FormatSpec,
+}
+
+/// A segment of a parsed format string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormatPiece {
+    /// Text copied to the output verbatim (with `{{` and `}}` unescaped).
+    Literal(String),
+    /// A value substituted at runtime.
+    Placeholder(FormatPlaceholder),
+}
+
+/// A format string parsed into literal text and placeholders.
+///
+/// # Grammar
+///
+/// ```text
+/// format_string → (text | "{{" | "}}" | placeholder)*
+/// placeholder → "{" (INTEGER | IDENTIFIER)? (":" spec)? "}"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatString {
+    /// The segments in source order.
+    pub pieces: Vec<FormatPiece>,
+}
+
+impl FormatString {
+    /// Parses the contents of a format string literal.
+    ///
+    /// Returns a description of the problem if the string is malformed.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut pieces = Vec::new();
+        let mut literal = String::new();
+        let mut next_positional = 0;
+        let mut chars = text.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    literal.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    literal.push('}');
+                }
+                '{' => {
+                    let mut body = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some('{') | None => {
+                                return Err(
+                                    "unterminated placeholder; use '{{' for a literal brace"
+                                        .to_string(),
+                                );
+                            }
+                            Some(ch) => body.push(ch),
+                        }
+                    }
+                    if !literal.is_empty() {
+                        pieces.push(FormatPiece::Literal(std::mem::take(&mut literal)));
+                    }
+                    pieces.push(FormatPiece::Placeholder(parse_placeholder(
+                        &body,
+                        &mut next_positional,
+                    )?));
+                }
+                '}' => {
+                    return Err("unmatched '}'; use '}}' for a literal brace".to_string());
+                }
+                _ => literal.push(c),
+            }
+        }
+
+        if !literal.is_empty() {
+            pieces.push(FormatPiece::Literal(literal));
+        }
+        Ok(FormatString { pieces })
+    }
+
+    /// Returns the placeholders in source order.
+    pub fn placeholders(&self) -> impl Iterator<Item = &FormatPlaceholder> {
+        self.pieces.iter().filter_map(|piece| match piece {
+            FormatPiece::Placeholder(placeholder) => Some(placeholder),
+            FormatPiece::Literal(_) => None,
+        })
+    }
+}
+
+fn parse_placeholder(body: &str, next_positional: &mut usize) -> Result<FormatPlaceholder, String> {
+    let (arg_text, spec_text) = match body.split_once(':') {
+        Some((arg, spec)) => (arg.trim(), Some(spec)),
+        None => (body.trim(), None),
+    };
+
+    let arg = if arg_text.is_empty() {
+        let index = *next_positional;
+        *next_positional += 1;
+        FormatArg::Positional(index)
+    } else if arg_text.chars().all(|c| c.is_ascii_digit()) {
+        let index = arg_text
+            .parse()
+            .map_err(|_| format!("argument index '{}' is too large", arg_text))?;
+        FormatArg::Positional(index)
+    } else if is_identifier(arg_text) {
+        FormatArg::Named(arg_text.to_string())
+    } else {
+        return Err(format!("invalid placeholder argument '{}'", arg_text));
+    };
+
+    let spec = match spec_text {
+        Some(text) => parse_spec(text)?,
+        None => FormatSpec::default(),
+    };
+
+    Ok(FormatPlaceholder { arg, spec })
+}
+
+fn is_identifier(text: &str) -> bool {
+    let mut chars = text.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn parse_align(c: char) -> Option<FormatAlign> {
+    match c {
+        '<' => Some(FormatAlign::Left),
+        '^' => Some(FormatAlign::Center),
+        '>' => Some(FormatAlign::Right),
+        _ => None,
+    }
+}
+
+fn parse_spec(text: &str) -> Result<FormatSpec, String> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut spec = FormatSpec::default();
+    let mut pos = 0;
+
+    if let Some(align) = chars.get(1).copied().and_then(parse_align) {
+        spec.fill = chars[0];
+        spec.align = Some(align);
+        pos = 2;
+    } else if let Some(align) = chars.first().copied().and_then(parse_align) {
+        spec.align = Some(align);
+        pos = 1;
+    }
+
+    if chars.get(pos) == Some(&'+') {
+        spec.sign_plus = true;
+        pos += 1;
+    }
+    if chars.get(pos) == Some(&'#') {
+        spec.alternate = true;
+        pos += 1;
+    }
+    if chars.get(pos) == Some(&'0') {
+        spec.zero_pad = true;
+        pos += 1;
+    }
+
+    spec.width = parse_count(&chars, &mut pos, "width")?;
+    if chars.get(pos) == Some(&'.') {
+        pos += 1;
+        spec.precision = parse_count(&chars, &mut pos, "precision")?;
+        if spec.precision.is_none() {
+            return Err(format!("missing precision after '.' in spec '{}'", text));
+        }
+    }
+
+    if let Some(&c) = chars.get(pos) {
+        spec.kind = match c {
+            'x' => FormatKind::LowerHex,
+            'X' => FormatKind::UpperHex,
+            'o' => FormatKind::Octal,
+            'b' => FormatKind::Binary,
+            'e' => FormatKind::LowerExp,
+            'E' => FormatKind::UpperExp,
+            _ => return Err(format!("unknown format type '{}' in spec '{}'", c, text)),
+        };
+        pos += 1;
+    }
+
+    if pos != chars.len() {
+        return Err(format!("invalid format spec '{}'", text));
+    }
+    Ok(spec)
+}
+
+fn parse_count(chars: &[char], pos: &mut usize, what: &str) -> Result<Option<u32>, String> {
+    let start = *pos;
+    while chars.get(*pos).is_some_and(|c| c.is_ascii_digit()) {
+        *pos += 1;
+    }
+    if start == *pos {
+        return Ok(None);
+    }
+    let digits: String = chars[start..*pos].iter().collect();
+    digits
+        .parse()
+        .map(Some)
+        .map_err(|_| format!("{} '{}' is too large", what, digits))
+}
@@ -10,8 +10,10 @@
 //! - [`Program`] - The root node containing all function definitions
 //! - [`FnDef`] - A function definition with name, return type, and body
 //! - [`Stmt`] - Individual statements (expression statements and let declarations)
+//! - [`Pattern`] - Destructuring patterns for `let` statements and parameters
 //! - [`Expr`] - Expressions (string literals, integer literals, identifiers, and function calls)
 //! - [`Type`] - Type annotations for variable declarations
+//! - [`FormatString`] - Parsed format strings for the `format` builtin
 //!
 //! Each AST node includes source location information ([`Span`](crate::token::Span)) for error reporting.
 //!
@@ -20,6 +22,8 @@
 //! - [`types`] - Type annotations (integer primitives, string, bool)
 //! - [`expr`] - Expression nodes and kinds
 //! - [`stmt`] - Statement nodes and kinds
+//! - [`pattern`] - Destructuring pattern nodes
+//! - [`format`] - Format string pieces and placeholder specs
 //! - [`program`] - Top-level program structure (Program, FnDef)
 //!
 //! # See Also
@@ -29,6 +33,8 @@
 //! * [`crate::codegen`] - Generates LLVM IR from the AST
 
 mod expr;
+mod format;
+mod pattern;
 mod program;
 mod stmt;
 mod types;
@@ -37,6 +43,10 @@ mod types;
 mod tests;
 
 pub use expr::{BinaryOperator, Expr, ExprKind, IfExprBlock, UnaryOperator};
-pub use program::{FnDef, FnParam, ImportDecl, Program, Visibility};
+pub use format::{
+    FormatAlign, FormatArg, FormatKind, FormatPiece, FormatPlaceholder, FormatSpec, FormatString,
+};
+pub use pattern::{Pattern, PatternKind};
+pub use program::{FnDef, FnParam, ImportDecl, Program, ReturnType, Visibility};
 pub use stmt::{Stmt, StmtKind};
 pub use types::Type;
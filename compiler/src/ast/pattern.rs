@@ -0,0 +1,81 @@
+//! Destructuring pattern nodes for the Lak AST.
+
+use crate::token::Span;
+use std::fmt;
+
+/// The kind of a destructuring pattern.
+///
+/// Patterns appear on the left-hand side of `let` statements and in
+/// function parameter position. Every pattern form is irrefutable once it
+/// matches the shape of the value's type; semantic analysis rejects shapes
+/// that could fail to match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatternKind {
+    /// Binds the matched value to a new variable (`x`).
+    Binding(String),
+
+    /// Matches any value without binding it (`_`).
+    Wildcard,
+
+    /// Destructures a tuple element-wise (`(a, b)`).
+    Tuple(Vec<Pattern>),
+}
+
+/// A destructuring pattern with source location.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    /// The kind of pattern.
+    pub kind: PatternKind,
+    /// The source location of this pattern.
+    pub span: Span,
+}
+
+impl Pattern {
+    /// Creates a new pattern with the given kind and span.
+    pub fn new(kind: PatternKind, span: Span) -> Self {
+        Pattern { kind, span }
+    }
+
+    /// Returns the variable names bound by this pattern with their spans,
+    /// in source order.
+    pub fn bindings(&self) -> Vec<(&str, Span)> {
+        let mut bindings = Vec::new();
+        self.collect_bindings(&mut bindings);
+        bindings
+    }
+
+    fn collect_bindings<'a>(&'a self, bindings: &mut Vec<(&'a str, Span)>) {
+        match &self.kind {
+            PatternKind::Binding(name) => bindings.push((name, self.span)),
+            PatternKind::Wildcard => {}
+            PatternKind::Tuple(elements) => {
+                for element in elements {
+                    element.collect_bindings(bindings);
+                }
+            }
+        }
+    }
+}
+
+/// Displays the pattern in Lak source syntax.
+///
+/// Used for diagnostics and as the synthetic name of destructured
+/// function parameters.
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            PatternKind::Binding(name) => write!(f, "{}", name),
+            PatternKind::Wildcard => write!(f, "_"),
+            PatternKind::Tuple(elements) => {
+                write!(f, "(")?;
+                for (idx, element) in elements.iter().enumerate() {
+                    if idx > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", element)?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
@@ -2,7 +2,8 @@
 
 use crate::token::Span;
 
-use super::{Type, stmt::Stmt};
+use super::{Pattern, Type, stmt::Stmt};
+use std::fmt;
 
 /// Visibility of a function or other declaration.
 ///
@@ -66,6 +67,51 @@ impl ImportDecl {
     }
 }
 
+/// The declared return type of a function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReturnType {
+    /// `void`: the function returns no value.
+    Void,
+    /// The function returns a value of this type.
+    Value(Type),
+    /// A name that does not denote a concrete type.
+    ///
+    /// The parser accepts any identifier in return position and leaves
+    /// unknown names (e.g. `int`) for semantic analysis to report. Numeric
+    /// functions of native modules also use this for their generic `T`.
+    Unknown(String),
+}
+
+impl ReturnType {
+    /// Decodes a return type written as a single name, e.g. `void` or `i64`.
+    pub fn from_name(name: &str) -> Self {
+        match Type::from_function_return_name(name) {
+            Some(None) => ReturnType::Void,
+            Some(Some(ty)) => ReturnType::Value(ty),
+            None => ReturnType::Unknown(name.to_string()),
+        }
+    }
+}
+
+impl From<Option<Type>> for ReturnType {
+    /// Converts a return type in the `None`-means-`void` form used by
+    /// runtime function tables.
+    fn from(ty: Option<Type>) -> Self {
+        ty.map_or(ReturnType::Void, ReturnType::Value)
+    }
+}
+
+/// Displays the return type as written in a function signature.
+impl fmt::Display for ReturnType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReturnType::Void => write!(f, "void"),
+            ReturnType::Value(ty) => write!(f, "{}", ty),
+            ReturnType::Unknown(name) => write!(f, "{}", name),
+        }
+    }
+}
+
 /// A function definition in the Lak language.
 ///
 /// Functions are the primary organizational unit in Lak. Every program
@@ -76,7 +122,8 @@ impl ImportDecl {
 /// The following invariants should hold for a well-formed `FnDef`:
 /// - `name` should be a non-empty valid identifier
 /// - each item in `params` should have a non-empty valid identifier name
-/// - `return_type` should be a valid type name (currently only "void")
+/// - `return_type` should be `void` or a known type; [`ReturnType::Unknown`]
+///   is rejected by semantic analysis
 /// - `return_type_span` should point to the actual return type token in source
 /// - `span` should encompass the function signature from `pub` (if present) or `fn` to before `{`
 /// - `span.start <= span.end` (valid span range)
@@ -98,7 +145,12 @@ impl ImportDecl {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FnParam {
     /// The parameter name.
+    ///
+    /// For destructured parameters this is the pattern's source text (e.g.
+    /// `(a, b)`), which can never collide with an identifier.
     pub name: String,
+    /// The destructuring pattern, if the parameter is not a plain identifier.
+    pub pattern: Option<Pattern>,
     /// The parameter type.
     pub ty: Type,
     /// The source location of the parameter declaration.
@@ -107,14 +159,19 @@ pub struct FnParam {
 
 #[derive(Debug, Clone)]
 pub struct FnDef {
+    /// The text of the `///` doc comments preceding the definition, if any.
+    ///
+    /// Consecutive doc comment lines are joined with `\n`, with the `///`
+    /// marker and a single following space removed from each line.
+    pub doc: Option<String>,
     /// The visibility of the function (public or private).
     pub visibility: Visibility,
     /// The name of the function.
     pub name: String,
     /// The parameters accepted by this function.
     pub params: Vec<FnParam>,
-    /// The return type of the function. Currently only "void" is supported.
-    pub return_type: String,
+    /// The declared return type of the function.
+    pub return_type: ReturnType,
     /// The source location of the return type token (e.g., `void` or `int`).
     pub return_type_span: Span,
     /// The statements that make up the function body.
@@ -139,10 +196,11 @@ impl FnDef {
     pub fn for_testing(name: &str, return_type: &str, body: Vec<Stmt>) -> Self {
         let dummy = Span::new(0, 0, 1, 1);
         FnDef {
+            doc: None,
             visibility: Visibility::Private,
             name: name.to_string(),
             params: vec![],
-            return_type: return_type.to_string(),
+            return_type: ReturnType::from_name(return_type),
             return_type_span: dummy,
             body,
             span: dummy,
@@ -3,6 +3,7 @@
 use crate::token::Span;
 
 use super::expr::Expr;
+use super::pattern::Pattern;
 use super::types::Type;
 
 /// The kind of a statement in the Lak language.
@@ -35,6 +36,23 @@ pub enum StmtKind {
         init: Expr,
     },
 
+    /// A destructuring `let` statement.
+    ///
+    /// Binds every variable named in `pattern`, e.g. `let (a, b) = pair`.
+    /// Plain `let x = ...` bindings use [`StmtKind::Let`] instead.
+    LetPattern {
+        /// Whether every binding in the pattern is mutable (`let mut (a, b)`).
+        is_mutable: bool,
+        /// The destructuring pattern.
+        pattern: Pattern,
+        /// The type of the whole destructured value.
+        ///
+        /// `Type::Inferred` means semantic analysis must infer it from `init`.
+        ty: Type,
+        /// The initializer expression.
+        init: Expr,
+    },
+
     /// A variable reassignment statement.
     ///
     /// Assigns a new value to an existing variable.
@@ -85,6 +103,14 @@ pub enum StmtKind {
 
     /// Skips to the next iteration of the innermost enclosing loop.
     Continue,
+
+    /// A deferred statement list.
+    ///
+    /// `body` runs when the enclosing block exits, whether by falling off its
+    /// end or via `return`, `break` or `continue`. Multiple deferred bodies in
+    /// the same block run in reverse registration order. The single-statement
+    /// form `defer stmt` is stored as a one-element body.
+    Defer(Vec<Stmt>),
 }
 
 /// A statement in the Lak language with source location.
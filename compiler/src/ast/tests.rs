@@ -212,6 +212,32 @@ fn test_expr_is_integer_literal_non_literal() {
     assert!(!expr.is_integer_literal());
 }
 
+#[test]
+fn test_numeric_operand_index_skips_unsuffixed_literals() {
+    let args = vec![
+        Expr::new(ExprKind::FloatLiteral(1.5), dummy_span()),
+        Expr::new(
+            ExprKind::UnaryOp {
+                op: UnaryOperator::Neg,
+                operand: Box::new(Expr::new(ExprKind::IntLiteral(1), dummy_span())),
+            },
+            dummy_span(),
+        ),
+        Expr::new(ExprKind::Identifier("x".to_string()), dummy_span()),
+    ];
+    assert_eq!(Expr::numeric_operand_index(&args), Some(2));
+}
+
+#[test]
+fn test_numeric_operand_index_all_literals_uses_first() {
+    let args = vec![
+        Expr::new(ExprKind::IntLiteral(1), dummy_span()),
+        Expr::new(ExprKind::IntLiteral(2), dummy_span()),
+    ];
+    assert_eq!(Expr::numeric_operand_index(&args), Some(0));
+    assert_eq!(Expr::numeric_operand_index(&[]), None);
+}
+
 #[test]
 fn test_infer_common_binary_operand_type_same_type() {
     let left = Expr::new(ExprKind::Identifier("x".to_string()), dummy_span());
@@ -318,6 +344,36 @@ fn test_type_from_function_return_name_rejects_unknown() {
     assert_eq!(Type::from_function_return_name("int"), None);
 }
 
+#[test]
+fn test_return_type_from_name() {
+    assert_eq!(ReturnType::from_name("void"), ReturnType::Void);
+    assert_eq!(ReturnType::from_name("byte"), ReturnType::Value(Type::U8));
+    assert_eq!(
+        ReturnType::from_name("int"),
+        ReturnType::Unknown("int".to_string())
+    );
+}
+
+#[test]
+fn test_return_type_display() {
+    assert_eq!(ReturnType::Void.to_string(), "void");
+    assert_eq!(
+        ReturnType::Value(Type::Tuple(vec![
+            Type::U8,
+            Type::Tuple(vec![Type::F64, Type::Bool])
+        ]))
+        .to_string(),
+        "(u8, (f64, bool))"
+    );
+    assert_eq!(ReturnType::Unknown("int".to_string()).to_string(), "int");
+}
+
+#[test]
+fn test_type_tuple_with_inferred_element_is_unresolved() {
+    assert!(Type::Tuple(vec![Type::I64, Type::Bool]).is_resolved());
+    assert!(!Type::Tuple(vec![Type::I64, Type::Inferred]).is_resolved());
+}
+
 #[test]
 fn test_type_is_integer() {
     assert!(Type::I32.is_integer());
@@ -360,10 +416,11 @@ fn test_program_empty() {
 #[test]
 fn test_program_with_functions() {
     let functions = vec![FnDef {
+        doc: None,
         visibility: Visibility::Private,
         name: "main".to_string(),
         params: vec![],
-        return_type: "void".to_string(),
+        return_type: ReturnType::Void,
         return_type_span: dummy_span(),
         body: vec![Stmt::new(
             StmtKind::Expr(Expr::new(
@@ -391,26 +448,28 @@ fn test_program_with_functions() {
 #[test]
 fn test_fn_def() {
     let fn_def = FnDef {
+        doc: None,
         visibility: Visibility::Private,
         name: "test".to_string(),
         params: vec![],
-        return_type: "void".to_string(),
+        return_type: ReturnType::Void,
         return_type_span: dummy_span(),
         body: vec![],
         span: dummy_span(),
     };
     assert_eq!(fn_def.name, "test");
-    assert_eq!(fn_def.return_type, "void");
+    assert_eq!(fn_def.return_type, ReturnType::Void);
     assert!(fn_def.body.is_empty());
 }
 
 #[test]
 fn test_fn_def_with_body() {
     let fn_def = FnDef {
+        doc: None,
         visibility: Visibility::Private,
         name: "greet".to_string(),
         params: vec![],
-        return_type: "void".to_string(),
+        return_type: ReturnType::Void,
         return_type_span: dummy_span(),
         body: vec![
             Stmt::new(
@@ -448,10 +507,11 @@ fn test_fn_def_with_body() {
 #[test]
 fn test_fn_def_clone() {
     let fn_def = FnDef {
+        doc: None,
         visibility: Visibility::Private,
         name: "test".to_string(),
         params: vec![],
-        return_type: "void".to_string(),
+        return_type: ReturnType::Void,
         return_type_span: dummy_span(),
         body: vec![Stmt::new(
             StmtKind::Expr(Expr::new(
@@ -555,10 +615,11 @@ fn test_program_debug() {
     let program = Program {
         imports: vec![],
         functions: vec![FnDef {
+            doc: None,
             visibility: Visibility::Private,
             name: "main".to_string(),
             params: vec![],
-            return_type: "void".to_string(),
+            return_type: ReturnType::Void,
             return_type_span: dummy_span(),
             body: vec![Stmt::new(
                 StmtKind::Expr(Expr::new(
@@ -573,7 +634,7 @@ fn test_program_debug() {
     let debug_str = format!("{:?}", program);
     assert_eq!(
         debug_str,
-        "Program { imports: [], functions: [FnDef { visibility: Private, name: \"main\", params: [], return_type: \"void\", return_type_span: Span { start: 0, end: 0, line: 1, column: 1 }, body: [Stmt { kind: Expr(Expr { kind: StringLiteral(\"test\"), span: Span { start: 0, end: 0, line: 1, column: 1 } }), span: Span { start: 0, end: 0, line: 1, column: 1 } }], span: Span { start: 0, end: 0, line: 1, column: 1 } }] }"
+        "Program { imports: [], functions: [FnDef { doc: None, visibility: Private, name: \"main\", params: [], return_type: Void, return_type_span: Span { start: 0, end: 0, line: 1, column: 1 }, body: [Stmt { kind: Expr(Expr { kind: StringLiteral(\"test\"), span: Span { start: 0, end: 0, line: 1, column: 1 } }), span: Span { start: 0, end: 0, line: 1, column: 1 } }], span: Span { start: 0, end: 0, line: 1, column: 1 } }] }"
     );
 }
 
@@ -597,3 +658,74 @@ fn test_stmt_span() {
     assert_eq!(stmt.span.start, 0);
     assert_eq!(stmt.span.end, 15);
 }
+
+// ===================
+// Format strings
+// ===================
+
+#[test]
+fn test_format_string_literals_and_escapes() {
+    let parsed = FormatString::parse("a {{b}} c").unwrap();
+    assert_eq!(
+        parsed.pieces,
+        vec![FormatPiece::Literal("a {b} c".to_string())]
+    );
+}
+
+#[test]
+fn test_format_string_positional_and_named_args() {
+    let parsed = FormatString::parse("{} {1} {name} {}").unwrap();
+    let args: Vec<FormatArg> = parsed.placeholders().map(|p| p.arg.clone()).collect();
+    assert_eq!(
+        args,
+        vec![
+            FormatArg::Positional(0),
+            FormatArg::Positional(1),
+            FormatArg::Named("name".to_string()),
+            FormatArg::Positional(1),
+        ]
+    );
+}
+
+#[test]
+fn test_format_string_full_spec() {
+    let parsed = FormatString::parse("{x:*^+#012.3b}").unwrap();
+    let placeholder = parsed.placeholders().next().unwrap();
+    assert_eq!(
+        placeholder.spec,
+        FormatSpec {
+            fill: '*',
+            align: Some(FormatAlign::Center),
+            sign_plus: true,
+            alternate: true,
+            zero_pad: true,
+            width: Some(12),
+            precision: Some(3),
+            kind: FormatKind::Binary,
+        }
+    );
+}
+
+#[test]
+fn test_format_string_width_precision_and_radix() {
+    let parsed = FormatString::parse("{:8.3}|{:x}|{:08b}|{:<5}").unwrap();
+    let specs: Vec<&FormatSpec> = parsed.placeholders().map(|p| &p.spec).collect();
+    assert_eq!(specs[0].width, Some(8));
+    assert_eq!(specs[0].precision, Some(3));
+    assert_eq!(specs[1].kind, FormatKind::LowerHex);
+    assert!(specs[2].zero_pad);
+    assert_eq!(specs[2].width, Some(8));
+    assert_eq!(specs[2].kind, FormatKind::Binary);
+    assert_eq!(specs[3].align, Some(FormatAlign::Left));
+    assert_eq!(specs[3].width, Some(5));
+}
+
+#[test]
+fn test_format_string_errors() {
+    assert!(FormatString::parse("{").is_err());
+    assert!(FormatString::parse("}").is_err());
+    assert!(FormatString::parse("{:q}").is_err());
+    assert!(FormatString::parse("{:8.}").is_err());
+    assert!(FormatString::parse("{1x}").is_err());
+    assert!(FormatString::parse("{:5x3}").is_err());
+}
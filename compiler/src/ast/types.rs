@@ -6,7 +6,9 @@ use std::fmt;
 ///
 /// This enum represents the types that can be specified in Lak code.
 /// Currently supports integer primitives, floating-point primitives, strings,
-/// booleans, and an internal inference placeholder (`Type::Inferred`).
+/// booleans, characters, tuples of those, hash maps and sets of primitives, optional
+/// primitives, random number generators, and an internal inference
+/// placeholder (`Type::Inferred`).
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Type {
     /// 8-bit signed integer type (`i8` in Lak source code).
@@ -33,6 +35,25 @@ pub enum Type {
     String,
     /// Boolean type (`bool` in Lak source code).
     Bool,
+    /// Unicode scalar value type (`char` in Lak source code).
+    Char,
+    /// Tuple type with two or more elements (`(i64, string)` in Lak source code).
+    Tuple(Vec<Type>),
+    /// Hash map type (`map<string, i64>` in Lak source code).
+    ///
+    /// Keys satisfy [`Type::is_collection_key`] and values
+    /// [`Type::is_map_value`].
+    Map(Box<Type>, Box<Type>),
+    /// Hash set type (`set<string>` in Lak source code).
+    ///
+    /// Items satisfy [`Type::is_collection_key`].
+    Set(Box<Type>),
+    /// Optional value type (`Option<i64>` in Lak source code).
+    ///
+    /// The payload satisfies [`Type::is_map_value`].
+    Option(Box<Type>),
+    /// Pseudo-random number generator type (`rng` in Lak source code).
+    Rng,
     /// Type to be inferred from initializer expression (`let x = ...`).
     ///
     /// This variant is an AST-level placeholder created by the parser.
@@ -63,6 +84,8 @@ impl Type {
             "f64" => Some(Self::F64),
             "string" => Some(Self::String),
             "bool" => Some(Self::Bool),
+            "char" => Some(Self::Char),
+            "rng" => Some(Self::Rng),
             _ => None,
         }
     }
@@ -115,7 +138,37 @@ impl Type {
 
     /// Returns true when this type is concrete and safe for backend mapping.
     pub fn is_resolved(&self) -> bool {
-        !matches!(self, Type::Inferred)
+        match self {
+            Type::Inferred => false,
+            Type::Tuple(elements) => elements.iter().all(Type::is_resolved),
+            _ => true,
+        }
+    }
+
+    /// Returns true when this type is a tuple type.
+    pub fn is_tuple(&self) -> bool {
+        matches!(self, Type::Tuple(_))
+    }
+
+    /// Returns true when this type is an `Option` type.
+    pub fn is_option(&self) -> bool {
+        matches!(self, Type::Option(_))
+    }
+
+    /// Returns true when this type is a map or set type.
+    pub fn is_collection(&self) -> bool {
+        matches!(self, Type::Map(..) | Type::Set(_))
+    }
+
+    /// Returns true when this type can be a map key or set item: an integer,
+    /// `string`, `bool` or `char`.
+    pub fn is_collection_key(&self) -> bool {
+        self.is_integer() || matches!(self, Type::String | Type::Bool | Type::Char)
+    }
+
+    /// Returns true when this type can be a map value: a key type or a float.
+    pub fn is_map_value(&self) -> bool {
+        self.is_collection_key() || self.is_float()
     }
 }
 
@@ -138,6 +191,21 @@ impl fmt::Display for Type {
             Type::F64 => write!(f, "f64"),
             Type::String => write!(f, "string"),
             Type::Bool => write!(f, "bool"),
+            Type::Char => write!(f, "char"),
+            Type::Tuple(elements) => {
+                write!(f, "(")?;
+                for (idx, element) in elements.iter().enumerate() {
+                    if idx > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", element)?;
+                }
+                write!(f, ")")
+            }
+            Type::Map(key, value) => write!(f, "map<{}, {}>", key, value),
+            Type::Set(item) => write!(f, "set<{}>", item),
+            Type::Option(payload) => write!(f, "Option<{}>", payload),
+            Type::Rng => write!(f, "rng"),
             // Keep internal placeholders visually explicit in diagnostics.
             Type::Inferred => write!(f, "<inferred>"),
         }
@@ -0,0 +1,123 @@
+//! Shadow call stack code generation for panic backtraces.
+//!
+//! When enabled with [`Codegen::set_backtrace`], generated code records the
+//! active Lak calls in the runtime's shadow call stack so that `lak_panic_at`
+//! can print a backtrace:
+//!
+//! - `main` pushes a root frame on entry
+//! - every user function call is surrounded by `lak_backtrace_push` (callee
+//!   name and call-site location) and `lak_backtrace_pop`
+//!
+//! Panics terminate the program, so frames only need to be popped when a call
+//! returns normally.
+
+use super::error::CodegenError;
+use super::{Codegen, user_facing_function_name};
+use crate::token::Span;
+use inkwell::AddressSpace;
+use inkwell::module::Linkage;
+use inkwell::values::BasicMetadataValueEnum;
+
+impl<'ctx> Codegen<'ctx> {
+    /// Declares the Lak runtime shadow call stack functions for use in generated code.
+    ///
+    /// This creates external function declarations with the signatures:
+    /// - `void lak_backtrace_push(const char* function, const char* file, i32 line, i32 column)`
+    /// - `void lak_backtrace_pop()`
+    pub(super) fn declare_lak_backtrace_builtins(&self) {
+        let void_type = self.context.void_type();
+        let ptr_type = self.context.ptr_type(AddressSpace::default());
+        let i32_type = self.context.i32_type();
+
+        let push_type = void_type.fn_type(
+            &[
+                ptr_type.into(),
+                ptr_type.into(),
+                i32_type.into(),
+                i32_type.into(),
+            ],
+            false,
+        );
+        self.module
+            .add_function("lak_backtrace_push", push_type, Some(Linkage::External));
+
+        let pop_type = void_type.fn_type(&[], false);
+        self.module
+            .add_function("lak_backtrace_pop", pop_type, Some(Linkage::External));
+    }
+
+    /// Pushes the root frame of `main` if backtraces are enabled.
+    pub(super) fn build_backtrace_root(&self, span: Span) -> Result<(), CodegenError> {
+        if !self.backtrace {
+            return Ok(());
+        }
+        let function = self.backtrace_string("main", span)?;
+        let null = self.context.ptr_type(AddressSpace::default()).const_null();
+        let zero = self.context.i32_type().const_zero();
+        self.call_backtrace_runtime(
+            "lak_backtrace_push",
+            &[function.into(), null.into(), zero.into(), zero.into()],
+            span,
+        )
+    }
+
+    /// Pushes a frame for a call to `llvm_name` at `span` if backtraces are enabled.
+    ///
+    /// The frame is named after the source-level function (the mangled module
+    /// prefix is stripped).
+    pub(super) fn build_backtrace_push(
+        &self,
+        llvm_name: &str,
+        span: Span,
+    ) -> Result<(), CodegenError> {
+        if !self.backtrace {
+            return Ok(());
+        }
+        let function = self.backtrace_string(user_facing_function_name(llvm_name), span)?;
+        let file = self.backtrace_string(&self.source_file, span)?;
+        let i32_type = self.context.i32_type();
+        let line = i32_type.const_int(span.line as u64, false);
+        let column = i32_type.const_int(span.column as u64, false);
+        self.call_backtrace_runtime(
+            "lak_backtrace_push",
+            &[function.into(), file.into(), line.into(), column.into()],
+            span,
+        )
+    }
+
+    /// Pops the frame pushed by [`build_backtrace_push`](Self::build_backtrace_push)
+    /// if backtraces are enabled.
+    pub(super) fn build_backtrace_pop(&self, span: Span) -> Result<(), CodegenError> {
+        if !self.backtrace {
+            return Ok(());
+        }
+        self.call_backtrace_runtime("lak_backtrace_pop", &[], span)
+    }
+
+    fn backtrace_string(
+        &self,
+        text: &str,
+        span: Span,
+    ) -> Result<inkwell::values::PointerValue<'ctx>, CodegenError> {
+        self.builder
+            .build_global_string_ptr(text, "bt_str")
+            .map(|global| global.as_pointer_value())
+            .map_err(|e| CodegenError::internal_string_ptr_failed(&e.to_string(), span))
+    }
+
+    fn call_backtrace_runtime(
+        &self,
+        name: &str,
+        args: &[BasicMetadataValueEnum<'ctx>],
+        span: Span,
+    ) -> Result<(), CodegenError> {
+        let function = self
+            .module
+            .get_function(name)
+            .ok_or_else(|| CodegenError::internal_builtin_not_found(name))?;
+        self.builder
+            .build_call(function, args, "")
+            .map_err(|e| CodegenError::internal_call_failed(name, &e.to_string(), span))?;
+        Ok(())
+    }
+}
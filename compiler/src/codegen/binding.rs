@@ -29,6 +29,9 @@ use inkwell::values::PointerValue;
 /// - `Type::U64` → `alloca` points to an LLVM `i64`
 /// - `Type::Bool` → `alloca` points to an LLVM `i1`
 /// - `Type::String` → `alloca` points to an LLVM `ptr` (pointer to string data)
+/// - `Type::Tuple` → `alloca` points to an LLVM struct of the element types
+/// - `Type::Option` → `alloca` points to an LLVM struct of the payload type and `i1`
+/// - `Type::Map` / `Type::Set` → `alloca` points to an LLVM `ptr` (pointer to the runtime table)
 ///
 /// This invariant is enforced by creating bindings only through
 /// [`VarBinding::new`], which allocates the correct LLVM type.
@@ -65,26 +68,12 @@ impl<'ctx> VarBinding<'ctx> {
         name: &str,
         span: Span,
     ) -> Result<Self, CodegenError> {
-        let llvm_type: BasicTypeEnum = match ty {
-            Type::I8 => context.i8_type().into(),
-            Type::I16 => context.i16_type().into(),
-            Type::I32 => context.i32_type().into(),
-            Type::I64 => context.i64_type().into(),
-            Type::U8 => context.i8_type().into(),
-            Type::U16 => context.i16_type().into(),
-            Type::U32 => context.i32_type().into(),
-            Type::U64 => context.i64_type().into(),
-            Type::F32 => context.f32_type().into(),
-            Type::F64 => context.f64_type().into(),
-            Type::String => context.ptr_type(AddressSpace::default()).into(),
-            Type::Bool => context.bool_type().into(),
-            Type::Inferred => {
-                let message_context = format!("variable binding allocation for '{}'", name);
-                return Err(CodegenError::internal_unresolved_inferred_type(
-                    &message_context,
-                    span,
-                ));
-            }
+        let Some(llvm_type) = basic_type_for(context, ty) else {
+            let message_context = format!("variable binding allocation for '{}'", name);
+            return Err(CodegenError::internal_unresolved_inferred_type(
+                &message_context,
+                span,
+            ));
         };
         let alloca = builder.build_alloca(llvm_type, name).map_err(|e| {
             CodegenError::internal_variable_alloca_failed(name, &e.to_string(), span)
@@ -105,3 +94,42 @@ impl<'ctx> VarBinding<'ctx> {
         &self.ty
     }
 }
+
+/// Maps a Lak type to the LLVM type stored in a binding's stack slot.
+///
+/// Returns `None` when `ty` (or one of its tuple elements) is still
+/// `Type::Inferred`.
+fn basic_type_for<'ctx>(context: &'ctx Context, ty: &Type) -> Option<BasicTypeEnum<'ctx>> {
+    let llvm_type = match ty {
+        Type::I8 => context.i8_type().into(),
+        Type::I16 => context.i16_type().into(),
+        Type::I32 => context.i32_type().into(),
+        Type::I64 => context.i64_type().into(),
+        Type::U8 => context.i8_type().into(),
+        Type::U16 => context.i16_type().into(),
+        Type::U32 => context.i32_type().into(),
+        Type::U64 => context.i64_type().into(),
+        Type::F32 => context.f32_type().into(),
+        Type::F64 => context.f64_type().into(),
+        Type::String | Type::Map(..) | Type::Set(_) | Type::Rng => {
+            context.ptr_type(AddressSpace::default()).into()
+        }
+        Type::Bool => context.bool_type().into(),
+        Type::Char => context.i32_type().into(),
+        Type::Tuple(elements) => {
+            let element_types = elements
+                .iter()
+                .map(|element| basic_type_for(context, element))
+                .collect::<Option<Vec<_>>>()?;
+            context.struct_type(&element_types, false).into()
+        }
+        Type::Option(payload) => {
+            let payload_type = basic_type_for(context, payload)?;
+            context
+                .struct_type(&[payload_type, context.bool_type().into()], false)
+                .into()
+        }
+        Type::Inferred => return None,
+    };
+    Some(llvm_type)
+}
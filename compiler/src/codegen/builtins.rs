@@ -1,18 +1,33 @@
 //! Built-in function code generation.
 //!
 //! This module implements code generation for Lak's built-in functions:
-//! println (string, integer, bool variants), panic, and string comparison helpers.
+//! print, println, eprint and eprintln (string, numeric, bool variants), panic,
+//! exit, assert, assert_eq and assert_ne, the prelude functions implemented
+//! directly by the runtime (see [`crate::prelude`]), and string comparison
+//! helpers. `format` lives in its own module.
 
 use super::Codegen;
 use super::error::CodegenError;
-use crate::ast::{Expr, ExprKind, StmtKind, Type};
+use crate::ast::{
+    BinaryOperator, Expr, ExprKind, FormatSpec, IfExprBlock, Pattern, PatternKind, StmtKind, Type,
+};
+use crate::collections;
+use crate::prelude::{RUNTIME_FUNCTIONS, RuntimeFunction, RuntimeReturn, runtime_function};
 use crate::token::Span;
 use inkwell::AddressSpace;
 use inkwell::module::Linkage;
-use inkwell::types::IntType;
-use inkwell::values::{BasicMetadataValueEnum, BasicValueEnum};
+use inkwell::types::{BasicMetadataTypeEnum, BasicType, BasicTypeEnum, IntType};
+use inkwell::values::{
+    BasicMetadataValueEnum, BasicValueEnum, FunctionValue, IntValue, PointerValue,
+};
 use std::collections::HashMap;
 
+/// Lak print builtins, each backed by a `lak_{name}` runtime function family.
+pub(super) const PRINT_BUILTINS: &[&str] = &["print", "println", "eprint", "eprintln"];
+
+/// Lak assertion builtins, which panic with the source location on failure.
+pub(super) const ASSERT_BUILTINS: &[&str] = &["assert", "assert_eq", "assert_ne"];
+
 /// Names of all builtin runtime functions declared by `declare_builtins()`.
 ///
 /// This list is used by `generate_user_function_call()` in `expr.rs` to
@@ -22,6 +37,19 @@ use std::collections::HashMap;
 /// This list must be kept in sync with the functions declared in `declare_builtins()`.
 /// Enforced by `test_builtin_names_matches_declare_builtins` in `tests.rs`.
 pub(super) const BUILTIN_NAMES: &[&str] = &[
+    "lak_print",
+    "lak_print_i8",
+    "lak_print_i16",
+    "lak_print_i32",
+    "lak_print_i64",
+    "lak_print_u8",
+    "lak_print_u16",
+    "lak_print_u32",
+    "lak_print_u64",
+    "lak_print_f32",
+    "lak_print_f64",
+    "lak_print_char",
+    "lak_print_bool",
     "lak_println",
     "lak_println_i8",
     "lak_println_i16",
@@ -33,10 +61,100 @@ pub(super) const BUILTIN_NAMES: &[&str] = &[
     "lak_println_u64",
     "lak_println_f32",
     "lak_println_f64",
+    "lak_println_char",
     "lak_println_bool",
-    "lak_panic",
+    "lak_eprint",
+    "lak_eprint_i8",
+    "lak_eprint_i16",
+    "lak_eprint_i32",
+    "lak_eprint_i64",
+    "lak_eprint_u8",
+    "lak_eprint_u16",
+    "lak_eprint_u32",
+    "lak_eprint_u64",
+    "lak_eprint_f32",
+    "lak_eprint_f64",
+    "lak_eprint_char",
+    "lak_eprint_bool",
+    "lak_eprintln",
+    "lak_eprintln_i8",
+    "lak_eprintln_i16",
+    "lak_eprintln_i32",
+    "lak_eprintln_i64",
+    "lak_eprintln_u8",
+    "lak_eprintln_u16",
+    "lak_eprintln_u32",
+    "lak_eprintln_u64",
+    "lak_eprintln_f32",
+    "lak_eprintln_f64",
+    "lak_eprintln_char",
+    "lak_eprintln_bool",
+    "lak_fmt_new",
+    "lak_fmt_push_literal",
+    "lak_fmt_push_str",
+    "lak_fmt_push_bool",
+    "lak_fmt_push_i64",
+    "lak_fmt_push_u64",
+    "lak_fmt_push_f32",
+    "lak_fmt_push_f64",
+    "lak_fmt_push_char",
+    "lak_fmt_finish",
+    "lak_panic_at",
+    "lak_exit",
     "lak_streq",
     "lak_strcmp",
+    "lak_rc_init",
+    "lak_retain",
+    "lak_release",
+    "lak_backtrace_push",
+    "lak_backtrace_pop",
+    "lak_args_init",
+    "lak_arg_count",
+    "lak_arg",
+    "lak_read_line",
+    "lak_read_all",
+    "lak_parse_i8",
+    "lak_parse_i16",
+    "lak_parse_i32",
+    "lak_parse_i64",
+    "lak_parse_u8",
+    "lak_parse_u16",
+    "lak_parse_u32",
+    "lak_parse_u64",
+    "lak_parse_f32",
+    "lak_parse_f64",
+    "lak_map_new",
+    "lak_map_insert_int",
+    "lak_map_insert_string",
+    "lak_map_get_int",
+    "lak_map_get_string",
+    "lak_map_remove_int",
+    "lak_map_remove_string",
+    "lak_map_contains_int",
+    "lak_map_contains_string",
+    "lak_map_len",
+    "lak_map_key_at",
+    "lak_map_value_at",
+    "lak_map_shuffle",
+    "lak_map_unique",
+    "lak_map_release",
+    "lak_set_new",
+    "lak_set_insert_int",
+    "lak_set_insert_string",
+    "lak_set_remove_int",
+    "lak_set_remove_string",
+    "lak_set_contains_int",
+    "lak_set_contains_string",
+    "lak_set_len",
+    "lak_set_item_at",
+    "lak_set_shuffle",
+    "lak_set_unique",
+    "lak_set_release",
+    "lak_rng_unique",
+    "lak_rng_next_u64",
+    "lak_rng_int_range",
+    "lak_rng_float",
+    "lak_rng_float_range",
 ];
 
 impl<'ctx> Codegen<'ctx> {
@@ -88,127 +206,461 @@ impl<'ctx> Codegen<'ctx> {
         }
     }
 
-    /// Declares the Lak runtime `lak_println` function for use in generated code.
+    /// Declares one family of Lak runtime print functions for use in generated code.
     ///
-    /// This creates an external function declaration with the signature:
-    /// `void lak_println(const char* s)`
-    pub(super) fn declare_lak_println(&self) {
+    /// For a runtime prefix such as `lak_println`, this creates external
+    /// declarations for the string printer `void lak_println(const char* s)`,
+    /// one `void {prefix}_{ty}(ty value)` function per numeric type,
+    /// `void {prefix}_char(u32 value)` and `void {prefix}_bool(bool value)`.
+    pub(super) fn declare_lak_print_family(&self, prefix: &str) {
         let void_type = self.context.void_type();
         let i8_ptr_type = self.context.ptr_type(AddressSpace::default());
 
-        let println_type = void_type.fn_type(&[i8_ptr_type.into()], false);
+        let string_type = void_type.fn_type(&[i8_ptr_type.into()], false);
         self.module
-            .add_function("lak_println", println_type, Some(Linkage::External));
-    }
+            .add_function(prefix, string_type, Some(Linkage::External));
+
+        let integer_types: [(&str, IntType<'ctx>); 8] = [
+            ("i8", self.context.i8_type()),
+            ("i16", self.context.i16_type()),
+            ("i32", self.context.i32_type()),
+            ("i64", self.context.i64_type()),
+            ("u8", self.context.i8_type()),
+            ("u16", self.context.i16_type()),
+            ("u32", self.context.i32_type()),
+            ("u64", self.context.i64_type()),
+        ];
+        for (suffix, int_type) in integer_types {
+            let fn_type = void_type.fn_type(&[int_type.into()], false);
+            self.module.add_function(
+                &format!("{}_{}", prefix, suffix),
+                fn_type,
+                Some(Linkage::External),
+            );
+        }
 
-    fn declare_lak_println_integer(&self, function_name: &str, int_type: IntType<'ctx>) {
-        let void_type = self.context.void_type();
-        let println_type = void_type.fn_type(&[int_type.into()], false);
-        self.module
-            .add_function(function_name, println_type, Some(Linkage::External));
+        let float_types = [
+            ("f32", self.context.f32_type()),
+            ("f64", self.context.f64_type()),
+        ];
+        for (suffix, float_type) in float_types {
+            let fn_type = void_type.fn_type(&[float_type.into()], false);
+            self.module.add_function(
+                &format!("{}_{}", prefix, suffix),
+                fn_type,
+                Some(Linkage::External),
+            );
+        }
+
+        let char_type = void_type.fn_type(&[self.context.i32_type().into()], false);
+        self.module.add_function(
+            &format!("{}_char", prefix),
+            char_type,
+            Some(Linkage::External),
+        );
+
+        let bool_type = void_type.fn_type(&[self.context.bool_type().into()], false);
+        self.module.add_function(
+            &format!("{}_bool", prefix),
+            bool_type,
+            Some(Linkage::External),
+        );
     }
 
-    fn declare_lak_println_float(
-        &self,
-        function_name: &str,
-        float_type: inkwell::types::FloatType<'ctx>,
-    ) {
+    /// Declares the Lak runtime `lak_panic_at` function for use in generated code.
+    ///
+    /// This creates an external function declaration with the signature:
+    /// `void lak_panic_at(const char* message, const char* file, i32 line, i32 column)`
+    /// with noreturn attribute.
+    ///
+    /// The noreturn attribute tells LLVM that this function never returns,
+    /// allowing for proper control flow analysis and optimization.
+    pub(super) fn declare_lak_panic_at(&self) {
         let void_type = self.context.void_type();
-        let println_type = void_type.fn_type(&[float_type.into()], false);
-        self.module
-            .add_function(function_name, println_type, Some(Linkage::External));
-    }
+        let i8_ptr_type = self.context.ptr_type(AddressSpace::default());
+        let i32_type = self.context.i32_type();
 
-    /// Declares the Lak runtime `lak_println_i8` function for use in generated code.
-    pub(super) fn declare_lak_println_i8(&self) {
-        self.declare_lak_println_integer("lak_println_i8", self.context.i8_type());
-    }
+        let panic_type = void_type.fn_type(
+            &[
+                i8_ptr_type.into(),
+                i8_ptr_type.into(),
+                i32_type.into(),
+                i32_type.into(),
+            ],
+            false,
+        );
+        let panic_fn =
+            self.module
+                .add_function("lak_panic_at", panic_type, Some(Linkage::External));
 
-    /// Declares the Lak runtime `lak_println_i16` function for use in generated code.
-    pub(super) fn declare_lak_println_i16(&self) {
-        self.declare_lak_println_integer("lak_println_i16", self.context.i16_type());
+        // Add noreturn attribute to the function
+        let noreturn_kind_id = inkwell::attributes::Attribute::get_named_enum_kind_id("noreturn");
+        // create_enum_attribute(kind_id, value): value is 0 for boolean attributes like noreturn
+        let noreturn_attr = self.context.create_enum_attribute(noreturn_kind_id, 0);
+        panic_fn.add_attribute(inkwell::attributes::AttributeLoc::Function, noreturn_attr);
     }
 
-    /// Declares the Lak runtime `lak_println_i32` function for use in generated code.
+    /// Declares the Lak runtime `lak_exit` function for use in generated code.
     ///
     /// This creates an external function declaration with the signature:
-    /// `void lak_println_i32(i32 value)`
-    pub(super) fn declare_lak_println_i32(&self) {
-        self.declare_lak_println_integer("lak_println_i32", self.context.i32_type());
+    /// `void lak_exit(i32 code)` with noreturn attribute.
+    pub(super) fn declare_lak_exit(&self) {
+        let void_type = self.context.void_type();
+        let i32_type = self.context.i32_type();
+
+        let exit_type = void_type.fn_type(&[i32_type.into()], false);
+        let exit_fn = self
+            .module
+            .add_function("lak_exit", exit_type, Some(Linkage::External));
+
+        let noreturn_kind_id = inkwell::attributes::Attribute::get_named_enum_kind_id("noreturn");
+        let noreturn_attr = self.context.create_enum_attribute(noreturn_kind_id, 0);
+        exit_fn.add_attribute(inkwell::attributes::AttributeLoc::Function, noreturn_attr);
     }
 
-    /// Declares the Lak runtime `lak_println_i64` function for use in generated code.
+    /// Declares the Lak runtime `lak_args_init` function for use in generated code.
     ///
     /// This creates an external function declaration with the signature:
-    /// `void lak_println_i64(i64 value)`
-    pub(super) fn declare_lak_println_i64(&self) {
-        self.declare_lak_println_integer("lak_println_i64", self.context.i64_type());
+    /// `void lak_args_init(i32 argc, const char** argv)`, called on entry to
+    /// `main` so the runtime can serve `arg_count()` and `arg(index)`.
+    pub(super) fn declare_lak_args_init(&self) {
+        let void_type = self.context.void_type();
+        let i32_type = self.context.i32_type();
+        let ptr_type = self.context.ptr_type(AddressSpace::default());
+
+        let args_init_type = void_type.fn_type(&[i32_type.into(), ptr_type.into()], false);
+        self.module
+            .add_function("lak_args_init", args_init_type, Some(Linkage::External));
     }
 
-    /// Declares the Lak runtime `lak_println_u8` function for use in generated code.
-    pub(super) fn declare_lak_println_u8(&self) {
-        self.declare_lak_println_integer("lak_println_u8", self.context.i8_type());
+    /// Declares the runtime functions behind the prelude functions in
+    /// [`RUNTIME_FUNCTIONS`].
+    ///
+    /// Functions of native standard library modules are declared on first
+    /// use instead (see [`Self::runtime_function_value`]).
+    pub(super) fn declare_lak_runtime_functions(&self) -> Result<(), CodegenError> {
+        for function in RUNTIME_FUNCTIONS {
+            self.declare_runtime_function(function)?;
+        }
+        Ok(())
     }
 
-    /// Declares the Lak runtime `lak_println_u16` function for use in generated code.
-    pub(super) fn declare_lak_println_u16(&self) {
-        self.declare_lak_println_integer("lak_println_u16", self.context.i16_type());
+    /// Declares the runtime function implementing `function`.
+    ///
+    /// Parameters and the return type map to LLVM types as for user
+    /// functions; optional, error and string result values are returned as
+    /// pointers.
+    /// Functions that report panics take the call site as three trailing
+    /// parameters: `const char* file, i32 line, i32 column`. Functions
+    /// returning a result take a trailing `const char** error` parameter,
+    /// and functions returning outputs two trailing `const char**`
+    /// parameters.
+    fn declare_runtime_function(
+        &self,
+        function: &RuntimeFunction,
+    ) -> Result<FunctionValue<'ctx>, CodegenError> {
+        let span = Span::new(0, 0, 0, 0);
+        let ptr_type = self.context.ptr_type(AddressSpace::default());
+        let i32_type = self.context.i32_type();
+
+        let mut param_types = function
+            .params
+            .iter()
+            .map(|(_, ty)| {
+                self.get_llvm_type(ty, span)
+                    .map(BasicMetadataTypeEnum::from)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if function.takes_location {
+            param_types.extend::<[BasicMetadataTypeEnum; 3]>([
+                ptr_type.into(),
+                i32_type.into(),
+                i32_type.into(),
+            ]);
+        }
+        for _ in 0..Self::runtime_out_param_count(&function.returns) {
+            param_types.push(ptr_type.into());
+        }
+        let fn_type = match &function.returns {
+            RuntimeReturn::Void => self.context.void_type().fn_type(&param_types, false),
+            RuntimeReturn::Value(ty) | RuntimeReturn::Outputs(ty) => {
+                self.get_llvm_type(ty, span)?.fn_type(&param_types, false)
+            }
+            RuntimeReturn::Result(ty) if *ty != Type::String => {
+                self.get_llvm_type(ty, span)?.fn_type(&param_types, false)
+            }
+            RuntimeReturn::Optional(_) | RuntimeReturn::Error | RuntimeReturn::Result(_) => {
+                ptr_type.fn_type(&param_types, false)
+            }
+        };
+        Ok(self
+            .module
+            .add_function(function.symbol, fn_type, Some(Linkage::External)))
     }
 
-    /// Declares the Lak runtime `lak_println_u32` function for use in generated code.
-    pub(super) fn declare_lak_println_u32(&self) {
-        self.declare_lak_println_integer("lak_println_u32", self.context.i32_type());
+    /// Returns the number of trailing `const char**` out-parameters of a
+    /// runtime function returning `returns`.
+    fn runtime_out_param_count(returns: &RuntimeReturn) -> usize {
+        match returns {
+            RuntimeReturn::Result(_) => 1,
+            RuntimeReturn::Outputs(_) => 2,
+            RuntimeReturn::Void
+            | RuntimeReturn::Value(_)
+            | RuntimeReturn::Optional(_)
+            | RuntimeReturn::Error => 0,
+        }
     }
 
-    /// Declares the Lak runtime `lak_println_u64` function for use in generated code.
-    pub(super) fn declare_lak_println_u64(&self) {
-        self.declare_lak_println_integer("lak_println_u64", self.context.i64_type());
+    /// Returns the declaration of the runtime function implementing
+    /// `function`, declaring it if needed.
+    fn runtime_function_value(
+        &self,
+        function: &RuntimeFunction,
+    ) -> Result<FunctionValue<'ctx>, CodegenError> {
+        match self.module.get_function(function.symbol) {
+            Some(value) => Ok(value),
+            None => self.declare_runtime_function(function),
+        }
     }
 
-    /// Declares the Lak runtime `lak_println_f32` function for use in generated code.
-    pub(super) fn declare_lak_println_f32(&self) {
-        self.declare_lak_println_float("lak_println_f32", self.context.f32_type());
+    /// Generates a call to a prelude function implemented by the runtime and
+    /// returns its result, if any.
+    ///
+    /// Arguments are evaluated left to right against the declared parameter
+    /// types. A string result is an owned reference and is registered as a
+    /// temporary of the enclosing statement.
+    pub(super) fn generate_runtime_function_call(
+        &mut self,
+        function: &RuntimeFunction,
+        args: &[Expr],
+        span: Span,
+    ) -> Result<Option<BasicValueEnum<'ctx>>, CodegenError> {
+        if args.len() != function.params.len() {
+            return Err(CodegenError::internal_call_arg_count_mismatch(
+                function.name,
+                function.params.len(),
+                args.len(),
+                span,
+            ));
+        }
+
+        let mut call_args: Vec<BasicMetadataValueEnum<'ctx>> = Vec::with_capacity(args.len() + 3);
+        for (arg, (_, ty)) in args.iter().zip(function.params) {
+            call_args.push(self.generate_expr_value(arg, ty)?.into());
+        }
+        if function.takes_location {
+            call_args.extend(self.build_call_location(span)?);
+        }
+
+        let out_slots = (0..Self::runtime_out_param_count(&function.returns))
+            .map(|_| {
+                self.build_entry_block_alloca(
+                    self.context.ptr_type(AddressSpace::default()).into(),
+                    &format!("{}_out", function.name),
+                    span,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        call_args.extend(
+            out_slots
+                .iter()
+                .map(|&slot| BasicMetadataValueEnum::from(slot)),
+        );
+
+        let runtime_fn = self.runtime_function_value(function)?;
+        let call = self
+            .builder
+            .build_call(runtime_fn, &call_args, &format!("{}_result", function.name))
+            .map_err(|e| CodegenError::internal_call_failed(function.name, &e.to_string(), span))?;
+
+        let Some(return_ty) = function.return_type() else {
+            return Ok(None);
+        };
+        let value = call
+            .try_as_basic_value()
+            .basic()
+            .ok_or_else(|| CodegenError::internal_call_returned_void(function.name, span))?;
+        let outputs = out_slots
+            .into_iter()
+            .map(|slot| self.load_out_string(function.name, slot, span))
+            .collect::<Result<Vec<_>, _>>()?;
+        let value = match &function.returns {
+            RuntimeReturn::Optional(_) => {
+                let (text, present) =
+                    self.build_string_or_empty(function.name, value.into_pointer_value(), span)?;
+                self.build_tuple(function.name, &[text, present.into()], span)?
+            }
+            RuntimeReturn::Error => {
+                let (error, _) =
+                    self.build_string_or_empty(function.name, value.into_pointer_value(), span)?;
+                error
+            }
+            RuntimeReturn::Result(ty) => {
+                let value = if *ty == Type::String {
+                    self.build_string_or_empty(function.name, value.into_pointer_value(), span)?
+                        .0
+                } else {
+                    value
+                };
+                self.build_tuple(function.name, &[value, outputs[0]], span)?
+            }
+            RuntimeReturn::Outputs(_) => {
+                self.build_tuple(function.name, &[value, outputs[0], outputs[1]], span)?
+            }
+            RuntimeReturn::Void | RuntimeReturn::Value(_) => value,
+        };
+        self.register_temporary(value, &return_ty, span)?;
+        Ok(Some(value))
     }
 
-    /// Declares the Lak runtime `lak_println_f64` function for use in generated code.
-    pub(super) fn declare_lak_println_f64(&self) {
-        self.declare_lak_println_float("lak_println_f64", self.context.f64_type());
+    /// Returns the `const char* file, i32 line, i32 column` arguments that
+    /// locate `span` for runtime functions that can panic.
+    pub(super) fn build_call_location(
+        &self,
+        span: Span,
+    ) -> Result<[BasicMetadataValueEnum<'ctx>; 3], CodegenError> {
+        let file = self
+            .builder
+            .build_global_string_ptr(&self.source_file, "call_file")
+            .map_err(|e| CodegenError::internal_string_ptr_failed(&e.to_string(), span))?
+            .as_pointer_value();
+        let i32_type = self.context.i32_type();
+        let line = i32_type.const_int(span.line as u64, false);
+        let column = i32_type.const_int(span.column as u64, false);
+        Ok([file.into(), line.into(), column.into()])
     }
 
-    /// Declares the Lak runtime `lak_println_bool` function for use in generated code.
+    /// Replaces a null string returned by the runtime with the static empty
+    /// string. Returns the string and whether the pointer was non-null.
     ///
-    /// This creates an external function declaration with the signature:
-    /// `void lak_println_bool(bool value)`
-    pub(super) fn declare_lak_println_bool(&self) {
-        let void_type = self.context.void_type();
-        let bool_type = self.context.bool_type();
+    /// Releasing the static empty string is a no-op, so the result can be
+    /// treated as owned either way.
+    pub(super) fn build_string_or_empty(
+        &self,
+        callee: &str,
+        ptr: PointerValue<'ctx>,
+        span: Span,
+    ) -> Result<(BasicValueEnum<'ctx>, IntValue<'ctx>), CodegenError> {
+        let map_err = |e: inkwell::builder::BuilderError| {
+            CodegenError::internal_runtime_result_failed(callee, &e.to_string(), span)
+        };
+        let present = self
+            .builder
+            .build_is_not_null(ptr, &format!("{}_present", callee))
+            .map_err(map_err)?;
+        let empty = self.build_string_object("", span)?;
+        let text = self
+            .builder
+            .build_select(present, ptr, empty, &format!("{}_value", callee))
+            .map_err(map_err)?;
+        Ok((text, present))
+    }
 
-        let println_type = void_type.fn_type(&[bool_type.into()], false);
-        self.module
-            .add_function("lak_println_bool", println_type, Some(Linkage::External));
+    /// Loads a string stored by the runtime through an out-parameter,
+    /// replacing null with the empty string.
+    fn load_out_string(
+        &self,
+        callee: &str,
+        slot: PointerValue<'ctx>,
+        span: Span,
+    ) -> Result<BasicValueEnum<'ctx>, CodegenError> {
+        let ptr = self
+            .builder
+            .build_load(
+                self.context.ptr_type(AddressSpace::default()),
+                slot,
+                &format!("{}_out", callee),
+            )
+            .map_err(|e| {
+                CodegenError::internal_runtime_result_failed(callee, &e.to_string(), span)
+            })?;
+        let (text, _) = self.build_string_or_empty(callee, ptr.into_pointer_value(), span)?;
+        Ok(text)
     }
 
-    /// Declares the Lak runtime `lak_panic` function for use in generated code.
-    ///
-    /// This creates an external function declaration with the signature:
-    /// `void lak_panic(const char* message)` with noreturn attribute.
+    /// Allocates a stack slot in the entry block of the current function, so
+    /// that calls inside loops reuse one slot.
+    pub(super) fn build_entry_block_alloca(
+        &self,
+        ty: BasicTypeEnum<'ctx>,
+        name: &str,
+        span: Span,
+    ) -> Result<PointerValue<'ctx>, CodegenError> {
+        let entry = self
+            .builder
+            .get_insert_block()
+            .and_then(|block| block.get_parent())
+            .and_then(|function| function.get_first_basic_block())
+            .ok_or_else(|| CodegenError::internal_no_current_function(span))?;
+        let entry_builder = self.context.create_builder();
+        match entry.get_first_instruction() {
+            Some(instruction) => entry_builder.position_before(&instruction),
+            None => entry_builder.position_at_end(entry),
+        }
+        entry_builder
+            .build_alloca(ty, name)
+            .map_err(|e| CodegenError::internal_variable_alloca_failed(name, &e.to_string(), span))
+    }
+
+    /// Builds a tuple value from the parts of a runtime result.
+    pub(super) fn build_tuple(
+        &self,
+        callee: &str,
+        elements: &[BasicValueEnum<'ctx>],
+        span: Span,
+    ) -> Result<BasicValueEnum<'ctx>, CodegenError> {
+        let map_err = |e: inkwell::builder::BuilderError| {
+            CodegenError::internal_runtime_result_failed(callee, &e.to_string(), span)
+        };
+        let element_types: Vec<BasicTypeEnum<'ctx>> =
+            elements.iter().map(|element| element.get_type()).collect();
+        let struct_type = self.context.struct_type(&element_types, false);
+        let mut tuple = struct_type.get_undef().into();
+        for (index, &element) in elements.iter().enumerate() {
+            tuple = self
+                .builder
+                .build_insert_value(tuple, element, index as u32, "tuple")
+                .map_err(map_err)?;
+        }
+        Ok(tuple.into_struct_value().into())
+    }
+
+    /// Calls `lak_panic_at` with `message` and the source location of `span`,
+    /// followed by an `unreachable` terminator.
     ///
-    /// The noreturn attribute tells LLVM that this function never returns,
-    /// allowing for proper control flow analysis and optimization.
-    pub(super) fn declare_lak_panic(&self) {
-        let void_type = self.context.void_type();
-        let i8_ptr_type = self.context.ptr_type(AddressSpace::default());
+    /// The file name is the module currently being generated (see
+    /// `Codegen::source_file`).
+    pub(super) fn build_panic_at(
+        &self,
+        message: inkwell::values::PointerValue<'ctx>,
+        span: Span,
+    ) -> Result<(), CodegenError> {
+        let file = self
+            .builder
+            .build_global_string_ptr(&self.source_file, "panic_file")
+            .map_err(|e| CodegenError::internal_string_ptr_failed(&e.to_string(), span))?
+            .as_pointer_value();
+        let i32_type = self.context.i32_type();
+        let line = i32_type.const_int(span.line as u64, false);
+        let column = i32_type.const_int(span.column as u64, false);
 
-        let panic_type = void_type.fn_type(&[i8_ptr_type.into()], false);
-        let panic_fn = self
-            .module
-            .add_function("lak_panic", panic_type, Some(Linkage::External));
+        let lak_panic_at = self.module.get_function("lak_panic_at").ok_or_else(|| {
+            CodegenError::internal_builtin_not_found_with_span("lak_panic_at", span)
+        })?;
+        self.builder
+            .build_call(
+                lak_panic_at,
+                &[message.into(), file.into(), line.into(), column.into()],
+                "",
+            )
+            .map_err(|e| CodegenError::internal_panic_call_failed(&e.to_string(), span))?;
 
-        // Add noreturn attribute to the function
-        let noreturn_kind_id = inkwell::attributes::Attribute::get_named_enum_kind_id("noreturn");
-        // create_enum_attribute(kind_id, value): value is 0 for boolean attributes like noreturn
-        let noreturn_attr = self.context.create_enum_attribute(noreturn_kind_id, 0);
-        panic_fn.add_attribute(inkwell::attributes::AttributeLoc::Function, noreturn_attr);
+        // This tells LLVM that execution never reaches past this point
+        self.builder
+            .build_unreachable()
+            .map_err(|e| CodegenError::internal_unreachable_failed(&e.to_string(), span))?;
+
+        Ok(())
     }
 
     /// Declares the Lak runtime `lak_streq` function for use in generated code.
@@ -218,7 +670,7 @@ impl<'ctx> Codegen<'ctx> {
     ///
     /// The return type is declared as `bool_type()` (LLVM `i1`), matching the Rust
     /// `bool` return type of the runtime function. This is consistent with how
-    /// `declare_lak_println_bool` uses `bool_type()` for its bool parameter.
+    /// `declare_lak_print_family` uses `bool_type()` for its bool parameters.
     /// The `i1` return type is required by `generate_comparison_op` in `expr.rs`,
     /// which uses `build_not` (bitwise NOT) for `!=` — this is only equivalent
     /// to logical NOT for `i1` values.
@@ -244,6 +696,134 @@ impl<'ctx> Codegen<'ctx> {
             .add_function("lak_strcmp", strcmp_type, Some(Linkage::External));
     }
 
+    /// Declares the Lak runtime functions behind `map<K, V>` and `set<T>`
+    /// for use in generated code.
+    ///
+    /// Collections are passed as `ptr` and keys, items and values as `i64`
+    /// slots (see [`super::collections`]). Each operation taking a key has an
+    /// `_int` variant, which hashes and compares the slot itself, and a
+    /// `_string` variant, which takes a `ptr` key and hashes and compares the
+    /// string contents:
+    /// - `ptr lak_map_new(i1 key_is_string, i1 value_is_string)`
+    /// - `void lak_map_insert_{int,string}(ptr map, key, i64 value)`
+    /// - `i1 lak_map_get_{int,string}(ptr map, key, ptr value_out)`
+    /// - `i1 lak_map_remove_{int,string}(ptr map, key)`
+    /// - `i1 lak_map_contains_{int,string}(ptr map, key)`
+    /// - `i64 lak_map_len(ptr map)`
+    /// - `i64 lak_map_{key,value}_at(ptr map, i64 index, ptr file, i32 line, i32 column)`
+    /// - `ptr lak_map_shuffle(ptr map, ptr rng)`
+    /// - `ptr lak_map_unique(ptr map)`
+    /// - `void lak_map_release(ptr map)`
+    /// - `ptr lak_set_new(i1 item_is_string)`
+    /// - `void lak_set_insert_{int,string}(ptr set, item)`
+    /// - `i1 lak_set_remove_{int,string}(ptr set, item)`
+    /// - `i1 lak_set_contains_{int,string}(ptr set, item)`
+    /// - `i64 lak_set_len(ptr set)`
+    /// - `i64 lak_set_item_at(ptr set, i64 index, ptr file, i32 line, i32 column)`
+    /// - `ptr lak_set_shuffle(ptr set, ptr rng)`
+    /// - `ptr lak_set_unique(ptr set)`
+    /// - `void lak_set_release(ptr set)`
+    pub(super) fn declare_lak_collection_builtins(&self) {
+        let ptr: BasicMetadataTypeEnum = self.context.ptr_type(AddressSpace::default()).into();
+        let slot: BasicMetadataTypeEnum = self.context.i64_type().into();
+        let flag: BasicMetadataTypeEnum = self.context.bool_type().into();
+        let int: BasicMetadataTypeEnum = self.context.i32_type().into();
+        let ptr_ret: BasicTypeEnum = self.context.ptr_type(AddressSpace::default()).into();
+        let slot_ret: BasicTypeEnum = self.context.i64_type().into();
+        let flag_ret: BasicTypeEnum = self.context.bool_type().into();
+
+        let declarations: [(&str, Option<BasicTypeEnum>, &[BasicMetadataTypeEnum]); 27] = [
+            ("lak_map_new", Some(ptr_ret), &[flag, flag]),
+            ("lak_map_insert_int", None, &[ptr, slot, slot]),
+            ("lak_map_insert_string", None, &[ptr, ptr, slot]),
+            ("lak_map_get_int", Some(flag_ret), &[ptr, slot, ptr]),
+            ("lak_map_get_string", Some(flag_ret), &[ptr, ptr, ptr]),
+            ("lak_map_remove_int", Some(flag_ret), &[ptr, slot]),
+            ("lak_map_remove_string", Some(flag_ret), &[ptr, ptr]),
+            ("lak_map_contains_int", Some(flag_ret), &[ptr, slot]),
+            ("lak_map_contains_string", Some(flag_ret), &[ptr, ptr]),
+            ("lak_map_len", Some(slot_ret), &[ptr]),
+            (
+                "lak_map_key_at",
+                Some(slot_ret),
+                &[ptr, slot, ptr, int, int],
+            ),
+            (
+                "lak_map_value_at",
+                Some(slot_ret),
+                &[ptr, slot, ptr, int, int],
+            ),
+            ("lak_map_shuffle", Some(ptr_ret), &[ptr, ptr]),
+            ("lak_map_unique", Some(ptr_ret), &[ptr]),
+            ("lak_map_release", None, &[ptr]),
+            ("lak_set_new", Some(ptr_ret), &[flag]),
+            ("lak_set_insert_int", None, &[ptr, slot]),
+            ("lak_set_insert_string", None, &[ptr, ptr]),
+            ("lak_set_remove_int", Some(flag_ret), &[ptr, slot]),
+            ("lak_set_remove_string", Some(flag_ret), &[ptr, ptr]),
+            ("lak_set_contains_int", Some(flag_ret), &[ptr, slot]),
+            ("lak_set_contains_string", Some(flag_ret), &[ptr, ptr]),
+            ("lak_set_len", Some(slot_ret), &[ptr]),
+            (
+                "lak_set_item_at",
+                Some(slot_ret),
+                &[ptr, slot, ptr, int, int],
+            ),
+            ("lak_set_shuffle", Some(ptr_ret), &[ptr, ptr]),
+            ("lak_set_unique", Some(ptr_ret), &[ptr]),
+            ("lak_set_release", None, &[ptr]),
+        ];
+        for (name, return_type, param_types) in declarations {
+            let fn_type = match return_type {
+                Some(return_type) => return_type.fn_type(param_types, false),
+                None => self.context.void_type().fn_type(param_types, false),
+            };
+            self.module
+                .add_function(name, fn_type, Some(Linkage::External));
+        }
+    }
+
+    /// Declares the Lak runtime functions behind `rng` methods for use in
+    /// generated code.
+    ///
+    /// Generators are passed as `ptr` (see [`super::rng`]); `shuffle` calls
+    /// `lak_map_shuffle` or `lak_set_shuffle`:
+    /// - `ptr lak_rng_unique(ptr rng)`
+    /// - `i64 lak_rng_next_u64(ptr rng)`
+    /// - `i64 lak_rng_int_range(ptr rng, i64 low, i64 high, ptr file, i32 line, i32 column)`
+    /// - `double lak_rng_float(ptr rng)`
+    /// - `double lak_rng_float_range(ptr rng, double low, double high, ptr file, i32 line, i32 column)`
+    pub(super) fn declare_lak_rng_builtins(&self) {
+        let ptr: BasicMetadataTypeEnum = self.context.ptr_type(AddressSpace::default()).into();
+        let int: BasicMetadataTypeEnum = self.context.i32_type().into();
+        let i64_param: BasicMetadataTypeEnum = self.context.i64_type().into();
+        let f64_param: BasicMetadataTypeEnum = self.context.f64_type().into();
+        let ptr_ret: BasicTypeEnum = self.context.ptr_type(AddressSpace::default()).into();
+        let i64_ret: BasicTypeEnum = self.context.i64_type().into();
+        let f64_ret: BasicTypeEnum = self.context.f64_type().into();
+
+        let declarations: [(&str, BasicTypeEnum, &[BasicMetadataTypeEnum]); 5] = [
+            ("lak_rng_unique", ptr_ret, &[ptr]),
+            ("lak_rng_next_u64", i64_ret, &[ptr]),
+            (
+                "lak_rng_int_range",
+                i64_ret,
+                &[ptr, i64_param, i64_param, ptr, int, int],
+            ),
+            ("lak_rng_float", f64_ret, &[ptr]),
+            (
+                "lak_rng_float_range",
+                f64_ret,
+                &[ptr, f64_param, f64_param, ptr, int, int],
+            ),
+        ];
+        for (name, return_type, param_types) in declarations {
+            let fn_type = return_type.fn_type(param_types, false);
+            self.module
+                .add_function(name, fn_type, Some(Linkage::External));
+        }
+    }
+
     /// Infers a common binary operand type with integer-literal adaptation.
     ///
     /// This mirrors semantic analysis rules used by `infer_expr_type`:
@@ -273,9 +853,9 @@ impl<'ctx> Codegen<'ctx> {
         ))
     }
 
-    /// Returns the type of an expression for println dispatch.
+    /// Returns the type of an expression for print builtin dispatch.
     ///
-    /// This is used to determine which println runtime function to call.
+    /// This is used to determine which print runtime function to call.
     /// The type dispatch is compile-time: each supported type maps to a dedicated
     /// runtime function (`lak_println`, `lak_println_i8`, `lak_println_i16`,
     /// `lak_println_i32`, `lak_println_i64`, `lak_println_u8`, `lak_println_u16`,
@@ -283,6 +863,7 @@ impl<'ctx> Codegen<'ctx> {
     ///
     /// Type mapping:
     /// - `IntLiteral` → `Type::I64` (standalone integer literals default to i64)
+    /// - `SuffixedIntLiteral` / `SuffixedFloatLiteral` → the suffix type
     /// - `StringLiteral` → `Type::String`
     /// - `BoolLiteral` → `Type::Bool`
     /// - `Identifier` → the variable's declared type
@@ -305,9 +886,12 @@ impl<'ctx> Codegen<'ctx> {
     ) -> Result<Type, CodegenError> {
         match &expr.kind {
             ExprKind::IntLiteral(_) => Ok(Type::I64),
+            ExprKind::SuffixedIntLiteral { ty, .. } => Ok(ty.clone()),
             ExprKind::FloatLiteral(_) => Ok(Type::F64),
+            ExprKind::SuffixedFloatLiteral { ty, .. } => Ok(ty.clone()),
             ExprKind::StringLiteral(_) => Ok(Type::String),
             ExprKind::BoolLiteral(_) => Ok(Type::Bool),
+            ExprKind::CharLiteral(_) => Ok(Type::Char),
             ExprKind::Identifier(name) => {
                 if let Some(ty) = local_types.get(name) {
                     if !ty.is_resolved() {
@@ -332,6 +916,19 @@ impl<'ctx> Codegen<'ctx> {
                 }
                 Ok(binding.ty().clone())
             }
+            ExprKind::Call { callee, .. } if callee == "format" => Ok(Type::String),
+            ExprKind::Call { callee, .. } if collections::is_constructor(callee) => {
+                Err(CodegenError::internal_collection_constructor_type(
+                    callee,
+                    &Type::Inferred,
+                    expr.span,
+                ))
+            }
+            ExprKind::Call { callee, .. } if runtime_function(callee).is_some() => {
+                runtime_function(callee)
+                    .and_then(RuntimeFunction::return_type)
+                    .ok_or_else(|| CodegenError::internal_call_returned_void(callee, expr.span))
+            }
             ExprKind::Call { callee, .. } => {
                 let (llvm_name, _) = self.resolve_user_function_target(callee, expr.span)?;
                 let return_ty = self
@@ -373,35 +970,8 @@ impl<'ctx> Codegen<'ctx> {
                 then_block,
                 else_block,
             } => {
-                let mut then_locals = local_types.clone();
-                for stmt in &then_block.stmts {
-                    if let StmtKind::Let { name, ty, init, .. } = &stmt.kind {
-                        let binding_ty = self.resolve_let_type_with_locals(
-                            name,
-                            ty,
-                            init,
-                            &then_locals,
-                            stmt.span,
-                        )?;
-                        then_locals.insert(name.clone(), binding_ty);
-                    }
-                }
-                let then_ty = self.get_expr_type_with_locals(&then_block.value, &then_locals)?;
-
-                let mut else_locals = local_types.clone();
-                for stmt in &else_block.stmts {
-                    if let StmtKind::Let { name, ty, init, .. } = &stmt.kind {
-                        let binding_ty = self.resolve_let_type_with_locals(
-                            name,
-                            ty,
-                            init,
-                            &else_locals,
-                            stmt.span,
-                        )?;
-                        else_locals.insert(name.clone(), binding_ty);
-                    }
-                }
-                let else_ty = self.get_expr_type_with_locals(&else_block.value, &else_locals)?;
+                let then_ty = self.get_value_block_type_with_locals(then_block, local_types)?;
+                let else_ty = self.get_value_block_type_with_locals(else_block, local_types)?;
 
                 if then_ty != else_ty {
                     return Err(CodegenError::internal_if_expr_branch_type_mismatch(
@@ -413,14 +983,55 @@ impl<'ctx> Codegen<'ctx> {
 
                 Ok(then_ty)
             }
+            ExprKind::Block(block) => self.get_value_block_type_with_locals(block, local_types),
+            ExprKind::Tuple(elements) => Ok(Type::Tuple(
+                elements
+                    .iter()
+                    .map(|element| self.get_expr_type_with_locals(element, local_types))
+                    .collect::<Result<Vec<_>, _>>()?,
+            )),
             ExprKind::MemberAccess { .. } => Err(
                 CodegenError::internal_member_access_not_implemented(expr.span),
             ),
             ExprKind::ModuleCall {
                 module,
                 function,
-                args: _,
+                args,
             } => {
+                // Block-local variables shadow the variables in scope.
+                let collection_ty = match local_types.get(module) {
+                    Some(ty) => ty.is_collection().then(|| ty.clone()),
+                    None => self.collection_variable_type(module),
+                };
+                if let Some(ty) = collection_ty {
+                    return Self::collection_method_value_type(module, function, &ty, expr.span);
+                }
+                let option_ty = match local_types.get(module) {
+                    Some(ty) => ty.is_option().then(|| ty.clone()),
+                    None => self.option_variable_type(module),
+                };
+                if let Some(ty) = option_ty {
+                    return Self::option_method_value_type(module, function, &ty, expr.span);
+                }
+                let is_rng = match local_types.get(module) {
+                    Some(ty) => *ty == Type::Rng,
+                    None => self.is_rng_variable(module),
+                };
+                if is_rng {
+                    return Self::rng_method_value_type(module, function, args, expr.span, |arg| {
+                        self.get_expr_type_with_locals(arg, local_types)
+                    });
+                }
+                if let Some(numeric) = self.numeric_module_function(module, function) {
+                    let callee = format!("{}.{}", module, function);
+                    let operand = Self::numeric_operand(&callee, numeric, args, expr.span)?;
+                    return self.get_expr_type_with_locals(operand, local_types);
+                }
+                if let Some(native) = self.native_module_function(module, function, expr.span)? {
+                    return native.return_type().ok_or_else(|| {
+                        CodegenError::internal_module_call_as_value(module, function, expr.span)
+                    });
+                }
                 let mangle_prefix = self.resolve_module_alias(module, expr.span)?;
                 let mangled_name = super::mangle_name(&mangle_prefix, function);
                 let source_callee = format!("{}.{}", module, function);
@@ -451,6 +1062,75 @@ impl<'ctx> Codegen<'ctx> {
         }
     }
 
+    /// Determines the result type of an `if`-expression branch or block expression.
+    ///
+    /// `let` bindings inside the block are tracked as block-local variables so
+    /// the trailing value expression can refer to them.
+    fn get_value_block_type_with_locals(
+        &self,
+        block: &IfExprBlock,
+        local_types: &HashMap<String, Type>,
+    ) -> Result<Type, CodegenError> {
+        let mut block_locals = local_types.clone();
+        for stmt in &block.stmts {
+            match &stmt.kind {
+                StmtKind::Let { name, ty, init, .. } => {
+                    let binding_ty = self.resolve_let_type_with_locals(
+                        name,
+                        ty,
+                        init,
+                        &block_locals,
+                        stmt.span,
+                    )?;
+                    block_locals.insert(name.clone(), binding_ty);
+                }
+                StmtKind::LetPattern {
+                    pattern, ty, init, ..
+                } => {
+                    let value_ty = self.resolve_let_type_with_locals(
+                        &pattern.to_string(),
+                        ty,
+                        init,
+                        &block_locals,
+                        stmt.span,
+                    )?;
+                    Self::collect_pattern_types(pattern, &value_ty, &mut block_locals)?;
+                }
+                _ => {}
+            }
+        }
+        self.get_expr_type_with_locals(&block.value, &block_locals)
+    }
+
+    /// Records the type of every binding introduced by `pattern` when it
+    /// destructures a value of type `ty`.
+    fn collect_pattern_types(
+        pattern: &Pattern,
+        ty: &Type,
+        local_types: &mut HashMap<String, Type>,
+    ) -> Result<(), CodegenError> {
+        match (&pattern.kind, ty) {
+            (PatternKind::Binding(name), _) => {
+                local_types.insert(name.clone(), ty.clone());
+                Ok(())
+            }
+            (PatternKind::Wildcard, _) => Ok(()),
+            (PatternKind::Tuple(elements), Type::Tuple(element_types))
+                if elements.len() == element_types.len() =>
+            {
+                for (element, element_ty) in elements.iter().zip(element_types) {
+                    Self::collect_pattern_types(element, element_ty, local_types)?;
+                }
+                Ok(())
+            }
+            _ => Err(CodegenError::internal_pattern_type_mismatch(
+                &pattern.to_string(),
+                &ty.to_string(),
+                pattern.span,
+            )),
+        }
+    }
+
     pub(super) fn get_expr_type(&self, expr: &Expr) -> Result<Type, CodegenError> {
         self.get_expr_type_with_locals(expr, &HashMap::new())
     }
@@ -486,23 +1166,19 @@ impl<'ctx> Codegen<'ctx> {
         Ok(ty.clone())
     }
 
-    /// Generates LLVM IR for a `println` call.
+    /// Generates LLVM IR for a `print`, `println`, `eprint` or `eprintln` call.
     ///
-    /// Implements `println(value)` by calling the appropriate Lak runtime function
+    /// Implements `callee(value)` by calling the appropriate Lak runtime function
     /// based on the argument type. Type dispatch is performed via `get_expr_type()`,
     /// which determines the type from the expression kind or variable declaration.
     ///
-    /// Type dispatch:
-    /// - `string` → `lak_println` (any expression producing `string`)
-    /// - `i8` → `lak_println_i8` (any expression producing `i8`)
-    /// - `i16` → `lak_println_i16` (any expression producing `i16`)
-    /// - `i32` → `lak_println_i32` (any expression producing `i32`)
-    /// - `i64` → `lak_println_i64` (any expression producing `i64`)
-    /// - `u8` → `lak_println_u8` (any expression producing `u8`)
-    /// - `u16` → `lak_println_u16` (any expression producing `u16`)
-    /// - `u32` → `lak_println_u32` (any expression producing `u32`)
-    /// - `u64` → `lak_println_u64` (any expression producing `u64`)
-    /// - `bool` → `lak_println_bool` (any expression producing `bool`)
+    /// Each builtin has its own runtime family named after it (`lak_print`,
+    /// `lak_println`, `lak_eprint`, `lak_eprintln`). Type dispatch selects the
+    /// member of that family:
+    /// - `string` → `lak_{callee}` (any expression producing `string`)
+    /// - integer and float types → `lak_{callee}_{ty}` (e.g. `lak_println_i64`)
+    /// - `bool` → `lak_{callee}_bool` (any expression producing `bool`)
+    /// - `char` → `lak_{callee}_char` (the Unicode scalar value as `u32`)
     ///
     /// # Validation responsibilities
     ///
@@ -513,102 +1189,75 @@ impl<'ctx> Codegen<'ctx> {
     ///
     /// # Arguments
     ///
-    /// * `args` - The arguments passed to `println`
-    /// * `span` - The source location of the println call
+    /// * `callee` - The print builtin being called (one of `PRINT_BUILTINS`)
+    /// * `args` - The arguments passed to the builtin
+    /// * `span` - The source location of the call
     ///
     /// # Errors
     ///
     /// Returns an internal error if:
     /// - Argument count is not 1 (semantic analysis should have caught this)
     /// - Expression type cannot be determined (e.g., unsupported expression, undefined variable)
-    pub(super) fn generate_println(
+    pub(super) fn generate_print(
         &mut self,
+        callee: &str,
         args: &[Expr],
         span: Span,
     ) -> Result<(), CodegenError> {
         // Semantic analysis guarantees exactly one argument
         if args.len() != 1 {
-            return Err(CodegenError::internal_println_arg_count(args.len(), span));
+            return Err(CodegenError::internal_print_arg_count(
+                callee,
+                args.len(),
+                span,
+            ));
         }
 
         let arg = &args[0];
+        let runtime_prefix = format!("lak_{}", callee);
 
         // Determine the type of the argument and call the appropriate runtime function
         let arg_type = self.get_expr_type(arg)?;
 
         match arg_type {
-            Type::String => self.generate_println_string(arg, span),
-            Type::I8 => self.generate_println_integer(
-                arg,
-                span,
-                &Type::I8,
-                "lak_println_i8",
-                "println_i8 expr",
-            ),
-            Type::I16 => self.generate_println_integer(
-                arg,
-                span,
-                &Type::I16,
-                "lak_println_i16",
-                "println_i16 expr",
-            ),
-            Type::I32 => self.generate_println_integer(
-                arg,
-                span,
-                &Type::I32,
-                "lak_println_i32",
-                "println_i32 expr",
-            ),
-            Type::I64 => self.generate_println_integer(
+            Type::String => self.generate_print_string(arg, span, &runtime_prefix),
+            Type::I8
+            | Type::I16
+            | Type::I32
+            | Type::I64
+            | Type::U8
+            | Type::U16
+            | Type::U32
+            | Type::U64 => self.generate_print_integer(
                 arg,
                 span,
-                &Type::I64,
-                "lak_println_i64",
-                "println_i64 expr",
+                &arg_type,
+                &format!("{}_{}", runtime_prefix, arg_type),
+                &format!("{}_{} expr", callee, arg_type),
             ),
-            Type::U8 => self.generate_println_integer(
+            Type::F32 | Type::F64 => self.generate_print_float(
                 arg,
                 span,
-                &Type::U8,
-                "lak_println_u8",
-                "println_u8 expr",
+                &arg_type,
+                &format!("{}_{}", runtime_prefix, arg_type),
+                &format!("{}_{} expr", callee, arg_type),
             ),
-            Type::U16 => self.generate_println_integer(
+            Type::Bool => self.generate_print_bool(arg, span, &format!("{}_bool", runtime_prefix)),
+            Type::Char => self.generate_print_integer(
                 arg,
                 span,
-                &Type::U16,
-                "lak_println_u16",
-                "println_u16 expr",
+                &arg_type,
+                &format!("{}_char", runtime_prefix),
+                &format!("{}_char expr", callee),
             ),
-            Type::U32 => self.generate_println_integer(
-                arg,
-                span,
-                &Type::U32,
-                "lak_println_u32",
-                "println_u32 expr",
-            ),
-            Type::U64 => self.generate_println_integer(
-                arg,
-                span,
-                &Type::U64,
-                "lak_println_u64",
-                "println_u64 expr",
-            ),
-            Type::F32 => self.generate_println_float(
-                arg,
-                span,
-                &Type::F32,
-                "lak_println_f32",
-                "println_f32 expr",
-            ),
-            Type::F64 => self.generate_println_float(
-                arg,
-                span,
-                &Type::F64,
-                "lak_println_f64",
-                "println_f64 expr",
-            ),
-            Type::Bool => self.generate_println_bool(arg, span),
+            Type::Tuple(_) | Type::Map(..) | Type::Set(_) | Type::Option(_) | Type::Rng => {
+                Err(CodegenError::internal_println_type_mismatch(
+                    "<expr>",
+                    "printable type",
+                    &arg_type.to_string(),
+                    span,
+                ))
+            }
             Type::Inferred => Err(CodegenError::internal_println_type_mismatch(
                 "<expr>",
                 "concrete type",
@@ -618,8 +1267,13 @@ impl<'ctx> Codegen<'ctx> {
         }
     }
 
-    /// Generates LLVM IR for `println` with a string argument.
-    fn generate_println_string(&mut self, arg: &Expr, span: Span) -> Result<(), CodegenError> {
+    /// Generates LLVM IR for a print builtin with a string argument.
+    fn generate_print_string(
+        &mut self,
+        arg: &Expr,
+        span: Span,
+        runtime_name: &str,
+    ) -> Result<(), CodegenError> {
         let string_ptr = match &arg.kind {
             ExprKind::StringLiteral(s) => self
                 .builder
@@ -638,27 +1292,28 @@ impl<'ctx> Codegen<'ctx> {
                     arg.span,
                 )?
             }
-            ExprKind::IfExpr { .. } | ExprKind::Call { .. } | ExprKind::ModuleCall { .. } => {
-                match self.generate_expr_value(arg, &Type::String)? {
-                    BasicValueEnum::PointerValue(v) => v,
-                    _ => {
-                        return Err(CodegenError::internal_println_invalid_string_arg(arg.span));
-                    }
+            ExprKind::IfExpr { .. }
+            | ExprKind::Block(_)
+            | ExprKind::Call { .. }
+            | ExprKind::ModuleCall { .. } => match self.generate_expr_value(arg, &Type::String)? {
+                BasicValueEnum::PointerValue(v) => v,
+                _ => {
+                    return Err(CodegenError::internal_println_invalid_string_arg(arg.span));
                 }
-            }
+            },
             _ => {
                 return Err(CodegenError::internal_println_invalid_string_arg(arg.span));
             }
         };
 
-        let lak_println = self
+        let runtime_fn = self
             .module
-            .get_function("lak_println")
-            .ok_or_else(|| CodegenError::internal_builtin_not_found("lak_println"))?;
+            .get_function(runtime_name)
+            .ok_or_else(|| CodegenError::internal_builtin_not_found(runtime_name))?;
 
         self.builder
             .build_call(
-                lak_println,
+                runtime_fn,
                 &[BasicMetadataValueEnum::PointerValue(string_ptr)],
                 "",
             )
@@ -667,7 +1322,7 @@ impl<'ctx> Codegen<'ctx> {
         Ok(())
     }
 
-    fn generate_println_integer(
+    fn generate_print_integer(
         &mut self,
         arg: &Expr,
         span: Span,
@@ -701,7 +1356,7 @@ impl<'ctx> Codegen<'ctx> {
         Ok(())
     }
 
-    fn generate_println_float(
+    fn generate_print_float(
         &mut self,
         arg: &Expr,
         span: Span,
@@ -735,11 +1390,16 @@ impl<'ctx> Codegen<'ctx> {
         Ok(())
     }
 
-    /// Generates LLVM IR for `println` with a bool argument.
+    /// Generates LLVM IR for a print builtin with a bool argument.
     ///
     /// This handles boolean literals, bool variables, and bool-producing expressions
     /// (such as comparison operations).
-    fn generate_println_bool(&mut self, arg: &Expr, span: Span) -> Result<(), CodegenError> {
+    fn generate_print_bool(
+        &mut self,
+        arg: &Expr,
+        span: Span,
+        runtime_name: &str,
+    ) -> Result<(), CodegenError> {
         let bool_value = match &arg.kind {
             ExprKind::BoolLiteral(value) => {
                 self.context.bool_type().const_int(*value as u64, false)
@@ -769,6 +1429,7 @@ impl<'ctx> Codegen<'ctx> {
             ExprKind::BinaryOp { .. }
             | ExprKind::UnaryOp { .. }
             | ExprKind::IfExpr { .. }
+            | ExprKind::Block(_)
             | ExprKind::Call { .. }
             | ExprKind::ModuleCall { .. } => {
                 // For expression values, delegate to generate_expr_value.
@@ -787,14 +1448,14 @@ impl<'ctx> Codegen<'ctx> {
             }
         };
 
-        let lak_println_bool = self
+        let runtime_fn = self
             .module
-            .get_function("lak_println_bool")
-            .ok_or_else(|| CodegenError::internal_builtin_not_found("lak_println_bool"))?;
+            .get_function(runtime_name)
+            .ok_or_else(|| CodegenError::internal_builtin_not_found(runtime_name))?;
 
         self.builder
             .build_call(
-                lak_println_bool,
+                runtime_fn,
                 &[BasicMetadataValueEnum::IntValue(bool_value)],
                 "",
             )
@@ -806,11 +1467,12 @@ impl<'ctx> Codegen<'ctx> {
     /// Generates LLVM IR for a `panic` call.
     ///
     /// Implements `panic(message)` by:
-    /// 1. Calling the Lak runtime `lak_panic` function with the message
+    /// 1. Calling the Lak runtime `lak_panic_at` function with the message and
+    ///    the source location of the call
     /// 2. Inserting an `unreachable` instruction after the call
     ///
     /// The `unreachable` instruction tells LLVM that execution never reaches
-    /// this point, which is guaranteed by the `noreturn` attribute on `lak_panic`.
+    /// this point, which is guaranteed by the `noreturn` attribute on `lak_panic_at`.
     ///
     /// # Arguments
     ///
@@ -845,7 +1507,10 @@ impl<'ctx> Codegen<'ctx> {
 
                 self.load_and_extract_pointer_value(binding.alloca(), name, "panic load", arg.span)?
             }
-            ExprKind::IfExpr { .. } => match self.generate_expr_value(arg, &Type::String)? {
+            ExprKind::IfExpr { .. }
+            | ExprKind::Block(_)
+            | ExprKind::Call { .. }
+            | ExprKind::ModuleCall { .. } => match self.generate_expr_value(arg, &Type::String)? {
                 BasicValueEnum::PointerValue(v) => v,
                 _ => {
                     return Err(CodegenError::internal_panic_invalid_arg(arg.span));
@@ -856,26 +1521,137 @@ impl<'ctx> Codegen<'ctx> {
             }
         };
 
-        // Call lak_panic
-        let lak_panic = self
-            .module
-            .get_function("lak_panic")
-            .ok_or_else(|| CodegenError::internal_builtin_not_found("lak_panic"))?;
+        self.build_panic_at(string_ptr, span)
+    }
 
-        self.builder
-            .build_call(
-                lak_panic,
-                &[BasicMetadataValueEnum::PointerValue(string_ptr)],
-                "",
-            )
-            .map_err(|e| CodegenError::internal_panic_call_failed(&e.to_string(), span))?;
+    /// Generates LLVM IR for an `exit` call.
+    ///
+    /// Implements `exit(code)` by calling the Lak runtime `lak_exit` function,
+    /// followed by an `unreachable` instruction. Like `panic`, `exit` terminates
+    /// the process immediately: deferred bodies do not run and live values are
+    /// not released.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - The arguments passed to `exit` (must contain exactly 1 `i32`)
+    /// * `span` - The source location of the exit call
+    pub(super) fn generate_exit(&mut self, args: &[Expr], span: Span) -> Result<(), CodegenError> {
+        let [code] = args else {
+            return Err(CodegenError::internal_exit_arg_count(args.len(), span));
+        };
+        let code_value = self.generate_expr_value(code, &Type::I32)?;
 
-        // Insert unreachable instruction
-        // This tells LLVM that execution never reaches past this point
+        let lak_exit = self
+            .module
+            .get_function("lak_exit")
+            .ok_or_else(|| CodegenError::internal_builtin_not_found_with_span("lak_exit", span))?;
+        self.builder
+            .build_call(lak_exit, &[code_value.into()], "")
+            .map_err(|e| CodegenError::internal_exit_call_failed(&e.to_string(), span))?;
         self.builder
             .build_unreachable()
             .map_err(|e| CodegenError::internal_unreachable_failed(&e.to_string(), span))?;
 
         Ok(())
     }
+
+    /// Generates code for `assert(cond)`, `assert_eq(a, b)` and `assert_ne(a, b)`.
+    ///
+    /// The operands are evaluated once. When the check fails, a message (with
+    /// both values for `assert_eq`/`assert_ne`) is built with the format
+    /// runtime and passed to `lak_panic_at` together with the source location:
+    ///
+    /// ```text
+    /// panic: assertion `left == right` failed
+    ///   left: 1
+    ///  right: 2
+    ///     at main.lak:3:5
+    /// ```
+    ///
+    /// The message is never released because `lak_panic_at` does not return.
+    pub(super) fn generate_assert(
+        &mut self,
+        callee: &str,
+        args: &[Expr],
+        span: Span,
+    ) -> Result<(), CodegenError> {
+        let (passed, header, operands) = match (callee, args) {
+            ("assert", [condition]) => {
+                let passed = self.generate_expr_value(condition, &Type::Bool)?;
+                (passed, "assertion failed".to_string(), None)
+            }
+            ("assert_eq" | "assert_ne", [left, right]) => {
+                let (op, symbol) = if callee == "assert_eq" {
+                    (BinaryOperator::Equal, "==")
+                } else {
+                    (BinaryOperator::NotEqual, "!=")
+                };
+                let (operand_ty, left_value, right_value) =
+                    self.generate_comparison_operands(left, right, span)?;
+                let passed =
+                    self.build_comparison(op, &operand_ty, left_value, right_value, span)?;
+                let header = format!("assertion `left {} right` failed", symbol);
+                (passed, header, Some((operand_ty, left_value, right_value)))
+            }
+            _ => {
+                return Err(CodegenError::internal_assert_arg_count(
+                    callee,
+                    args.len(),
+                    span,
+                ));
+            }
+        };
+        let BasicValueEnum::IntValue(passed) = passed else {
+            return Err(CodegenError::internal_non_integer_value("assert", span));
+        };
+
+        let current_fn = self.get_current_function(span)?;
+        let fail_block = self.context.append_basic_block(current_fn, "assert_fail");
+        let ok_block = self.context.append_basic_block(current_fn, "assert_ok");
+        self.builder
+            .build_conditional_branch(passed, ok_block, fail_block)
+            .map_err(|e| CodegenError::internal_branch_failed(&e.to_string(), span))?;
+
+        self.builder.position_at_end(fail_block);
+        let buffer = self
+            .call_format_runtime("lak_fmt_new", &[], span)?
+            .ok_or_else(|| CodegenError::internal_format_call_failed("lak_fmt_new", span))?
+            .into_pointer_value();
+        self.push_assert_literal(buffer, &header, span)?;
+        if let Some((operand_ty, left_value, right_value)) = operands {
+            let spec = FormatSpec::default();
+            self.push_assert_literal(buffer, "\n  left: ", span)?;
+            self.generate_format_push(buffer, &operand_ty, left_value, &spec, span)?;
+            self.push_assert_literal(buffer, "\n right: ", span)?;
+            self.generate_format_push(buffer, &operand_ty, right_value, &spec, span)?;
+        }
+        let message = self
+            .call_format_runtime("lak_fmt_finish", &[buffer.into()], span)?
+            .ok_or_else(|| CodegenError::internal_format_call_failed("lak_fmt_finish", span))?;
+
+        self.build_panic_at(message.into_pointer_value(), span)?;
+
+        self.builder.position_at_end(ok_block);
+        Ok(())
+    }
+
+    /// Appends a literal piece of an assertion failure message to `buffer`.
+    fn push_assert_literal(
+        &self,
+        buffer: inkwell::values::PointerValue<'ctx>,
+        text: &str,
+        span: Span,
+    ) -> Result<(), CodegenError> {
+        let text_ptr = self
+            .builder
+            .build_global_string_ptr(text, "assert_msg")
+            .map_err(|e| CodegenError::internal_string_ptr_failed(&e.to_string(), span))?
+            .as_pointer_value();
+        self.call_format_runtime(
+            "lak_fmt_push_literal",
+            &[buffer.into(), text_ptr.into()],
+            span,
+        )?;
+        Ok(())
+    }
 }
@@ -0,0 +1,365 @@
+//! `map<K, V>` and `set<T>` code generation.
+//!
+//! A collection value is a pointer to a runtime object, reference-counted
+//! like a string. Copies of a variable share the object until one of them
+//! is changed: `insert` and `remove` first call `lak_map_unique` or
+//! `lak_set_unique` and store the result back into the variable, so the
+//! change is made on a copy if the object has other owners. Method
+//! calls (see [`crate::collections`]) lower to the `lak_map_*` and
+//! `lak_set_*` runtime functions declared in `builtins.rs`, which take keys,
+//! items and values as `i64` slots:
+//!
+//! - integers are sign- or zero-extended by signedness, and bools are 0 or 1
+//! - floats are the bits of the value converted to `f64`
+//! - strings are the object pointer
+//!
+//! Operations taking a key call the `_string` variant for string keys,
+//! which takes the key as a `ptr`, and the `_int` variant otherwise.
+
+use super::Codegen;
+use super::error::CodegenError;
+use crate::ast::{Expr, Type};
+use crate::collections::CollectionMethod;
+use crate::token::Span;
+use inkwell::AddressSpace;
+use inkwell::values::{BasicMetadataValueEnum, BasicValueEnum, IntValue};
+
+impl<'ctx> Codegen<'ctx> {
+    /// Returns the type of `variable` if it names a map or set variable in
+    /// scope, so that `variable.f(...)` is a method call.
+    pub(super) fn collection_variable_type(&self, variable: &str) -> Option<Type> {
+        let ty = self.lookup_variable(variable)?.ty();
+        ty.is_collection().then(|| ty.clone())
+    }
+
+    /// Returns the type of the value of `variable.method(...)` on a
+    /// collection of type `ty`.
+    pub(super) fn collection_method_value_type(
+        variable: &str,
+        method: &str,
+        ty: &Type,
+        span: Span,
+    ) -> Result<Type, CodegenError> {
+        let method_kind = CollectionMethod::lookup(ty, method).ok_or_else(|| {
+            CodegenError::internal_collection_method_not_found(variable, method, ty, span)
+        })?;
+        method_kind
+            .return_type(ty)
+            .ok_or_else(|| CodegenError::internal_module_call_as_value(variable, method, span))
+    }
+
+    /// Generates `map()` or `set()`, creating an empty collection of type
+    /// `ty`.
+    ///
+    /// The new collection is owned and registered as a temporary of the
+    /// enclosing statement.
+    pub(super) fn generate_collection_constructor(
+        &mut self,
+        callee: &str,
+        ty: &Type,
+        span: Span,
+    ) -> Result<BasicValueEnum<'ctx>, CodegenError> {
+        let bool_type = self.context.bool_type();
+        let is_string = |ty: &Type| -> BasicMetadataValueEnum<'ctx> {
+            bool_type
+                .const_int(u64::from(*ty == Type::String), false)
+                .into()
+        };
+        let (runtime_name, flags) = match (callee, ty) {
+            ("map", Type::Map(key, value)) => {
+                ("lak_map_new", vec![is_string(key), is_string(value)])
+            }
+            ("set", Type::Set(item)) => ("lak_set_new", vec![is_string(item)]),
+            _ => {
+                return Err(CodegenError::internal_collection_constructor_type(
+                    callee, ty, span,
+                ));
+            }
+        };
+        let value = self
+            .build_collection_call(runtime_name, &flags, span)?
+            .ok_or_else(|| CodegenError::internal_call_returned_void(runtime_name, span))?;
+        self.register_temporary(value, ty, span)?;
+        Ok(value)
+    }
+
+    /// Generates the method call `variable.method(args)` on a map or set
+    /// variable and returns its value, or `None` for `insert` and `remove`.
+    ///
+    /// Keys and values returned by the runtime are owned and registered as
+    /// temporaries of the enclosing statement.
+    pub(super) fn generate_collection_method(
+        &mut self,
+        variable: &str,
+        method: &str,
+        args: &[Expr],
+        span: Span,
+    ) -> Result<Option<BasicValueEnum<'ctx>>, CodegenError> {
+        let binding = self
+            .lookup_variable(variable)
+            .cloned()
+            .ok_or_else(|| CodegenError::internal_variable_not_found(variable, span))?;
+        let ty = binding.ty();
+        let method_kind = CollectionMethod::lookup(ty, method).ok_or_else(|| {
+            CodegenError::internal_collection_method_not_found(variable, method, ty, span)
+        })?;
+        let (prefix, key_ty, value_ty) = match ty {
+            Type::Map(key, value) => ("lak_map", key.as_ref(), Some(value.as_ref())),
+            Type::Set(item) => ("lak_set", item.as_ref(), None),
+            _ => {
+                return Err(CodegenError::internal_collection_method_not_found(
+                    variable, method, ty, span,
+                ));
+            }
+        };
+        let takes_key = matches!(
+            method_kind,
+            CollectionMethod::Insert
+                | CollectionMethod::Get
+                | CollectionMethod::Remove
+                | CollectionMethod::Contains
+        );
+        let runtime_name = if !takes_key {
+            format!("{}_{}", prefix, method)
+        } else if *key_ty == Type::String {
+            format!("{}_{}_string", prefix, method)
+        } else {
+            format!("{}_{}_int", prefix, method)
+        };
+
+        let param_types = method_kind.param_types(ty);
+        if args.len() != param_types.len() {
+            return Err(CodegenError::internal_call_arg_count_mismatch(
+                &format!("{}.{}", variable, method),
+                param_types.len(),
+                args.len(),
+                span,
+            ));
+        }
+
+        let collection = self
+            .builder
+            .build_load(
+                self.context.ptr_type(AddressSpace::default()),
+                binding.alloca(),
+                &format!("{}_load", variable),
+            )
+            .map_err(|e| {
+                CodegenError::internal_variable_load_failed(variable, &e.to_string(), span)
+            })?;
+        let collection = if method_kind.is_mutating() {
+            let unique_name = format!("{}_unique", prefix);
+            let unique = self
+                .build_collection_call(&unique_name, &[collection.into()], span)?
+                .ok_or_else(|| CodegenError::internal_call_returned_void(&unique_name, span))?;
+            self.builder
+                .build_store(binding.alloca(), unique)
+                .map_err(|e| {
+                    CodegenError::internal_variable_store_failed(variable, &e.to_string(), span)
+                })?;
+            unique
+        } else {
+            collection
+        };
+        let mut call_args: Vec<BasicMetadataValueEnum<'ctx>> = vec![collection.into()];
+        for (index, (arg, param_ty)) in args.iter().zip(&param_types).enumerate() {
+            let value = self.generate_expr_value(arg, param_ty)?;
+            if takes_key && index == 0 && *param_ty == Type::String {
+                call_args.push(value.into());
+            } else {
+                call_args.push(
+                    self.build_to_slot(&runtime_name, value, param_ty, span)?
+                        .into(),
+                );
+            }
+        }
+
+        let out_slot = match method_kind {
+            CollectionMethod::Get => {
+                let slot = self.build_entry_block_alloca(
+                    self.context.i64_type().into(),
+                    &format!("{}_out", runtime_name),
+                    span,
+                )?;
+                call_args.push(slot.into());
+                Some(slot)
+            }
+            CollectionMethod::KeyAt | CollectionMethod::ValueAt | CollectionMethod::ItemAt => {
+                call_args.extend(self.build_call_location(span)?);
+                None
+            }
+            CollectionMethod::Insert
+            | CollectionMethod::Remove
+            | CollectionMethod::Contains
+            | CollectionMethod::Len => None,
+        };
+
+        let result = self.build_collection_call(&runtime_name, &call_args, span)?;
+        let Some(return_ty) = method_kind.return_type(ty) else {
+            return Ok(None);
+        };
+        let result =
+            result.ok_or_else(|| CodegenError::internal_call_returned_void(&runtime_name, span))?;
+        let value = match method_kind {
+            CollectionMethod::Get => {
+                let value_ty = value_ty.ok_or_else(|| {
+                    CodegenError::internal_collection_method_not_found(variable, method, ty, span)
+                })?;
+                let slot = out_slot.ok_or_else(|| {
+                    CodegenError::internal_runtime_result_failed(
+                        &runtime_name,
+                        "missing out-parameter",
+                        span,
+                    )
+                })?;
+                let stored = self
+                    .builder
+                    .build_load(
+                        self.context.i64_type(),
+                        slot,
+                        &format!("{}_out", runtime_name),
+                    )
+                    .map_err(|e| {
+                        CodegenError::internal_runtime_result_failed(
+                            &runtime_name,
+                            &e.to_string(),
+                            span,
+                        )
+                    })?;
+                let mut value =
+                    self.build_from_slot(&runtime_name, stored.into_int_value(), value_ty, span)?;
+                if *value_ty == Type::String {
+                    // A missing key stores a null string.
+                    value = self
+                        .build_string_or_empty(&runtime_name, value.into_pointer_value(), span)?
+                        .0;
+                }
+                // An option has the layout of a `(V, bool)` tuple.
+                self.build_tuple(&runtime_name, &[value, result], span)?
+            }
+            CollectionMethod::KeyAt | CollectionMethod::ItemAt | CollectionMethod::ValueAt => {
+                self.build_from_slot(&runtime_name, result.into_int_value(), &return_ty, span)?
+            }
+            CollectionMethod::Contains | CollectionMethod::Len => result,
+            CollectionMethod::Insert | CollectionMethod::Remove => {
+                return Err(CodegenError::internal_module_call_as_value(
+                    variable, method, span,
+                ));
+            }
+        };
+        self.register_temporary(value, &return_ty, span)?;
+        Ok(Some(value))
+    }
+
+    /// Calls the collection runtime function `runtime_name` and returns its
+    /// result, if any.
+    pub(super) fn build_collection_call(
+        &self,
+        runtime_name: &str,
+        args: &[BasicMetadataValueEnum<'ctx>],
+        span: Span,
+    ) -> Result<Option<BasicValueEnum<'ctx>>, CodegenError> {
+        let function = self.module.get_function(runtime_name).ok_or_else(|| {
+            CodegenError::internal_builtin_not_found_with_span(runtime_name, span)
+        })?;
+        let call = self
+            .builder
+            .build_call(function, args, &format!("{}_result", runtime_name))
+            .map_err(|e| CodegenError::internal_call_failed(runtime_name, &e.to_string(), span))?;
+        Ok(call.try_as_basic_value().basic())
+    }
+
+    /// Converts a key, item or value of type `ty` to its `i64` slot.
+    fn build_to_slot(
+        &self,
+        callee: &str,
+        value: BasicValueEnum<'ctx>,
+        ty: &Type,
+        span: Span,
+    ) -> Result<IntValue<'ctx>, CodegenError> {
+        let map_err = |e: inkwell::builder::BuilderError| {
+            CodegenError::internal_call_failed(callee, &e.to_string(), span)
+        };
+        let i64_type = self.context.i64_type();
+        match (ty, value) {
+            (Type::String, BasicValueEnum::PointerValue(ptr)) => self
+                .builder
+                .build_ptr_to_int(ptr, i64_type, "slot")
+                .map_err(map_err),
+            (Type::F32 | Type::F64, BasicValueEnum::FloatValue(float)) => {
+                let widened = self
+                    .builder
+                    .build_float_cast(float, self.context.f64_type(), "slot_f64")
+                    .map_err(map_err)?;
+                let bits = self
+                    .builder
+                    .build_bit_cast(widened, i64_type, "slot")
+                    .map_err(map_err)?;
+                Ok(bits.into_int_value())
+            }
+            (_, BasicValueEnum::IntValue(int)) if ty.is_signed_integer() => self
+                .builder
+                .build_int_s_extend_or_bit_cast(int, i64_type, "slot")
+                .map_err(map_err),
+            (_, BasicValueEnum::IntValue(int)) => self
+                .builder
+                .build_int_z_extend_or_bit_cast(int, i64_type, "slot")
+                .map_err(map_err),
+            _ => Err(CodegenError::internal_call_failed(
+                callee,
+                &format!("cannot pass a value of type '{}' as a slot", ty),
+                span,
+            )),
+        }
+    }
+
+    /// Converts an `i64` slot returned by the runtime to a value of type `ty`.
+    fn build_from_slot(
+        &self,
+        callee: &str,
+        slot: IntValue<'ctx>,
+        ty: &Type,
+        span: Span,
+    ) -> Result<BasicValueEnum<'ctx>, CodegenError> {
+        let map_err = |e: inkwell::builder::BuilderError| {
+            CodegenError::internal_runtime_result_failed(callee, &e.to_string(), span)
+        };
+        match ty {
+            Type::String => Ok(self
+                .builder
+                .build_int_to_ptr(
+                    slot,
+                    self.context.ptr_type(AddressSpace::default()),
+                    "value",
+                )
+                .map_err(map_err)?
+                .into()),
+            Type::F32 | Type::F64 => {
+                let widened = self
+                    .builder
+                    .build_bit_cast(slot, self.context.f64_type(), "value_f64")
+                    .map_err(map_err)?
+                    .into_float_value();
+                let float_type = self.get_llvm_type(ty, span)?.into_float_type();
+                Ok(self
+                    .builder
+                    .build_float_cast(widened, float_type, "value")
+                    .map_err(map_err)?
+                    .into())
+            }
+            _ if ty.is_integer() || matches!(ty, Type::Bool | Type::Char) => {
+                let int_type = self.get_llvm_type(ty, span)?.into_int_type();
+                Ok(self
+                    .builder
+                    .build_int_truncate_or_bit_cast(slot, int_type, "value")
+                    .map_err(map_err)?
+                    .into())
+            }
+            _ => Err(CodegenError::internal_runtime_result_failed(
+                callee,
+                &format!("cannot convert a slot to type '{}'", ty),
+                span,
+            )),
+        }
+    }
+}
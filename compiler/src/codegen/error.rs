@@ -358,6 +358,19 @@ impl CodegenError {
         )
     }
 
+    /// Creates an internal error for a character literal used as non-char type.
+    pub fn internal_char_as_type(expected: &str, span: Span) -> Self {
+        Self::new(
+            CodegenErrorKind::InternalError,
+            format!(
+                "Internal error: character literal used as '{}' value in codegen. \
+                 Semantic analysis should have caught this. This is a compiler bug.",
+                expected
+            ),
+            span,
+        )
+    }
+
     /// Creates an internal error for float used as non-float type.
     pub fn internal_float_as_type(expected: &str, span: Span) -> Self {
         Self::new(
@@ -442,6 +455,20 @@ impl CodegenError {
         )
     }
 
+    /// Creates an internal error for a tuple expression whose shape does not
+    /// match the expected type.
+    pub fn internal_tuple_as_type(expected: &str, span: Span) -> Self {
+        Self::new(
+            CodegenErrorKind::InternalError,
+            format!(
+                "Internal error: tuple expression used as '{}' value in codegen. \
+                 Semantic analysis should have caught this. This is a compiler bug.",
+                expected
+            ),
+            span,
+        )
+    }
+
     /// Creates an internal error for failed string pointer creation.
     pub fn internal_string_ptr_failed(error: &str, span: Span) -> Self {
         Self::new(
@@ -549,14 +576,54 @@ impl CodegenError {
         )
     }
 
-    /// Creates an internal error for println argument count mismatch.
-    pub fn internal_println_arg_count(count: usize, span: Span) -> Self {
+    /// Creates an internal error for LLVM failing to build a tuple value.
+    pub fn internal_tuple_build_failed(error: &str, span: Span) -> Self {
         Self::new(
             CodegenErrorKind::InternalError,
             format!(
-                "Internal error: println expects 1 argument, but got {} in codegen. \
+                "Internal error: failed to build tuple value. \
+                 This is a compiler bug: {}",
+                error
+            ),
+            span,
+        )
+    }
+
+    /// Creates an internal error for LLVM failing to extract a tuple element.
+    pub fn internal_tuple_extract_failed(index: usize, error: &str, span: Span) -> Self {
+        Self::new(
+            CodegenErrorKind::InternalError,
+            format!(
+                "Internal error: failed to extract tuple element {}. \
+                 This is a compiler bug: {}",
+                index, error
+            ),
+            span,
+        )
+    }
+
+    /// Creates an internal error for a pattern whose shape does not match
+    /// the destructured type.
+    pub fn internal_pattern_type_mismatch(pattern: &str, ty: &str, span: Span) -> Self {
+        Self::new(
+            CodegenErrorKind::InternalError,
+            format!(
+                "Internal error: pattern '{}' cannot destructure type '{}' in codegen. \
+                 Semantic analysis should have rejected this. This is a compiler bug.",
+                pattern, ty
+            ),
+            span,
+        )
+    }
+
+    /// Creates an internal error for print builtin argument count mismatch.
+    pub fn internal_print_arg_count(callee: &str, count: usize, span: Span) -> Self {
+        Self::new(
+            CodegenErrorKind::InternalError,
+            format!(
+                "Internal error: {} expects 1 argument, but got {} in codegen. \
                  Semantic analysis should have caught this. This is a compiler bug.",
-                count
+                callee, count
             ),
             span,
         )
@@ -611,6 +678,32 @@ impl CodegenError {
         )
     }
 
+    /// Creates an internal error for a `format` call that semantic analysis
+    /// should have rejected (missing or non-literal format string, bad placeholder).
+    pub fn internal_format_invalid(detail: &str, span: Span) -> Self {
+        Self::new(
+            CodegenErrorKind::InternalError,
+            format!(
+                "Internal error: invalid format call in codegen: {}. \
+                 Semantic analysis should have caught this. This is a compiler bug.",
+                detail
+            ),
+            span,
+        )
+    }
+
+    /// Creates an internal error for a failed format runtime call or conversion.
+    pub fn internal_format_call_failed(error: &str, span: Span) -> Self {
+        Self::new(
+            CodegenErrorKind::InternalError,
+            format!(
+                "Internal error: failed to generate format call. This is a compiler bug: {}",
+                error
+            ),
+            span,
+        )
+    }
+
     /// Creates an internal error for panic argument count mismatch.
     pub fn internal_panic_arg_count(count: usize, span: Span) -> Self {
         Self::new(
@@ -624,6 +717,19 @@ impl CodegenError {
         )
     }
 
+    /// Creates an internal error for assert argument count mismatch.
+    pub fn internal_assert_arg_count(callee: &str, count: usize, span: Span) -> Self {
+        Self::new(
+            CodegenErrorKind::InternalError,
+            format!(
+                "Internal error: {} got {} argument(s) in codegen. \
+                 Semantic analysis should have caught this. This is a compiler bug.",
+                callee, count
+            ),
+            span,
+        )
+    }
+
     /// Creates an internal error for invalid panic argument.
     pub fn internal_panic_invalid_arg(span: Span) -> Self {
         Self::new(
@@ -646,6 +752,45 @@ impl CodegenError {
         )
     }
 
+    /// Creates an internal error for exit argument count mismatch.
+    pub fn internal_exit_arg_count(count: usize, span: Span) -> Self {
+        Self::new(
+            CodegenErrorKind::InternalError,
+            format!(
+                "Internal error: exit expects 1 argument, but got {} in codegen. \
+                 Semantic analysis should have caught this. This is a compiler bug.",
+                count
+            ),
+            span,
+        )
+    }
+
+    /// Creates an internal error for failed exit call generation.
+    pub fn internal_exit_call_failed(error: &str, span: Span) -> Self {
+        Self::new(
+            CodegenErrorKind::InternalError,
+            format!(
+                "Internal error: failed to generate exit call. This is a compiler bug: {}",
+                error
+            ),
+            span,
+        )
+    }
+
+    /// Creates an internal error for a failed conversion of `main`'s return
+    /// value to the process exit status.
+    pub fn internal_main_exit_code_failed(error: &str, span: Span) -> Self {
+        Self::new(
+            CodegenErrorKind::InternalError,
+            format!(
+                "Internal error: failed to convert main's return value to an exit code. \
+                 This is a compiler bug: {}",
+                error
+            ),
+            span,
+        )
+    }
+
     /// Creates an internal error for failed unreachable instruction.
     pub fn internal_unreachable_failed(error: &str, span: Span) -> Self {
         Self::new(
@@ -738,6 +883,16 @@ impl CodegenError {
         )
     }
 
+    /// Creates an internal error for a missing temporary frame.
+    pub fn internal_no_temporary_frame(span: Span) -> Self {
+        Self::new(
+            CodegenErrorKind::InternalError,
+            "Internal error: attempted to register a temporary value outside a statement in codegen. \
+             This is a compiler bug.",
+            span,
+        )
+    }
+
     /// Creates an internal error for missing loop control scope.
     pub fn internal_no_loop_control_scope(span: Span) -> Self {
         Self::new(
@@ -748,6 +903,46 @@ impl CodegenError {
         )
     }
 
+    /// Creates an internal error for failed conversion of a runtime result
+    /// into its Lak value.
+    pub fn internal_runtime_result_failed(callee: &str, error: &str, span: Span) -> Self {
+        Self::new(
+            CodegenErrorKind::InternalError,
+            format!(
+                "Internal error: failed to build the result of '{}'. This is a compiler bug: {}",
+                callee, error
+            ),
+            span,
+        )
+    }
+
+    /// Creates an internal error for a numeric function reaching codegen
+    /// with a type it is not defined for.
+    pub fn internal_numeric_function_type(callee: &str, ty: &crate::ast::Type, span: Span) -> Self {
+        Self::new(
+            CodegenErrorKind::InternalError,
+            format!(
+                "Internal error: '{}' is not defined for type '{}'. \
+                 Semantic analysis should have caught this. This is a compiler bug.",
+                callee, ty
+            ),
+            span,
+        )
+    }
+
+    /// Creates an internal error for a failed instruction in a numeric
+    /// function call.
+    pub fn internal_numeric_call_failed(callee: &str, error: &str, span: Span) -> Self {
+        Self::new(
+            CodegenErrorKind::InternalError,
+            format!(
+                "Internal error: failed to generate call of '{}'. This is a compiler bug: {}",
+                callee, error
+            ),
+            span,
+        )
+    }
+
     /// Creates an internal error for failed comparison.
     pub fn internal_compare_failed(error: &str, span: Span) -> Self {
         Self::new(
@@ -1023,6 +1218,43 @@ impl CodegenError {
         )
     }
 
+    /// Creates an internal error for a collection method that does not
+    /// exist on the variable's type.
+    pub fn internal_collection_method_not_found(
+        variable: &str,
+        method: &str,
+        ty: &crate::ast::Type,
+        span: Span,
+    ) -> Self {
+        Self::new(
+            CodegenErrorKind::InternalError,
+            format!(
+                "Internal error: '{}' of type '{}' has no method '{}' in codegen. \
+                 Semantic analysis should have caught this. This is a compiler bug.",
+                variable, ty, method
+            ),
+            span,
+        )
+    }
+
+    /// Creates an internal error for a `map()` or `set()` call whose
+    /// expected type is not the collection it creates.
+    pub fn internal_collection_constructor_type(
+        callee: &str,
+        ty: &crate::ast::Type,
+        span: Span,
+    ) -> Self {
+        Self::new(
+            CodegenErrorKind::InternalError,
+            format!(
+                "Internal error: '{}()' cannot create a value of type '{}'. \
+                 Semantic analysis should have caught this. This is a compiler bug.",
+                callee, ty
+            ),
+            span,
+        )
+    }
+
     // =========================================================================
     // Module compilation internal errors
     // =========================================================================
@@ -5,10 +5,12 @@
 //! (arithmetic and comparison), and unary operations.
 
 use super::Codegen;
-use super::builtins::BUILTIN_NAMES;
+use super::builtins::{ASSERT_BUILTINS, BUILTIN_NAMES, PRINT_BUILTINS};
 use super::error::CodegenError;
 use super::mangle_name;
 use crate::ast::{BinaryOperator, Expr, ExprKind, IfExprBlock, Type, UnaryOperator};
+use crate::collections;
+use crate::prelude::{RuntimeFunction, runtime_function};
 use inkwell::FloatPredicate;
 use inkwell::IntPredicate;
 use inkwell::intrinsics::Intrinsic;
@@ -47,10 +49,18 @@ impl<'ctx> Codegen<'ctx> {
     pub(super) fn generate_expr(&mut self, expr: &Expr) -> Result<(), CodegenError> {
         match &expr.kind {
             ExprKind::Call { callee, args } => {
-                if callee == "println" {
-                    self.generate_println(args, expr.span)?;
+                if PRINT_BUILTINS.contains(&callee.as_str()) {
+                    self.generate_print(callee, args, expr.span)?;
                 } else if callee == "panic" {
                     self.generate_panic(args, expr.span)?;
+                } else if callee == "exit" {
+                    self.generate_exit(args, expr.span)?;
+                } else if ASSERT_BUILTINS.contains(&callee.as_str()) {
+                    self.generate_assert(callee, args, expr.span)?;
+                } else if callee == "format" {
+                    self.generate_format_value(args, expr.span)?;
+                } else if let Some(function) = runtime_function(callee) {
+                    self.generate_runtime_function_call(function, args, expr.span)?;
                 } else {
                     self.generate_user_function_call(callee, args, expr.span)?;
                 }
@@ -62,12 +72,17 @@ impl<'ctx> Codegen<'ctx> {
             } => self.generate_module_call(module, function, args, expr.span)?,
             ExprKind::StringLiteral(_)
             | ExprKind::IntLiteral(_)
+            | ExprKind::SuffixedIntLiteral { .. }
             | ExprKind::FloatLiteral(_)
+            | ExprKind::SuffixedFloatLiteral { .. }
             | ExprKind::BoolLiteral(_)
+            | ExprKind::CharLiteral(_)
             | ExprKind::Identifier(_)
             | ExprKind::BinaryOp { .. }
             | ExprKind::UnaryOp { .. }
             | ExprKind::IfExpr { .. }
+            | ExprKind::Block(_)
+            | ExprKind::Tuple(_)
             | ExprKind::MemberAccess { .. } => {
                 return Err(CodegenError::internal_invalid_expr_stmt(expr.span));
             }
@@ -194,12 +209,21 @@ impl<'ctx> Codegen<'ctx> {
             }
         }
 
+        self.build_backtrace_push(&target.llvm_name, span)?;
         let call_site = self
             .builder
             .build_call(target.function, &llvm_args, "")
             .map_err(|e| {
                 CodegenError::internal_call_failed(&target.source_callee, &e.to_string(), span)
             })?;
+        self.build_backtrace_pop(span)?;
+
+        // The callee returns an owned reference; release it after the statement.
+        if let Some(Some(return_ty)) = self.function_return_types.get(&target.llvm_name).cloned()
+            && let Some(value) = call_site.try_as_basic_value().basic()
+        {
+            self.register_temporary(value, &return_ty, span)?;
+        }
 
         match value_mode {
             CallValueMode::Ignore => Ok(None),
@@ -260,6 +284,29 @@ impl<'ctx> Codegen<'ctx> {
         args: &[Expr],
         span: crate::token::Span,
     ) -> Result<(), CodegenError> {
+        if self.collection_variable_type(module_alias).is_some() {
+            self.generate_collection_method(module_alias, function, args, span)?;
+            return Ok(());
+        }
+        if self.option_variable_type(module_alias).is_some() {
+            self.generate_option_method(module_alias, function, args, span)?;
+            return Ok(());
+        }
+        if self.is_rng_variable(module_alias) {
+            self.generate_rng_method(module_alias, function, args, span)?;
+            return Ok(());
+        }
+        if let Some(numeric) = self.numeric_module_function(module_alias, function) {
+            let callee = format!("{}.{}", module_alias, function);
+            let operand = Self::numeric_operand(&callee, numeric, args, span)?;
+            let ty = self.infer_expr_type_for_comparison(operand)?;
+            self.generate_numeric_call(&callee, numeric, args, &ty, span)?;
+            return Ok(());
+        }
+        if let Some(native) = self.native_module_function(module_alias, function, span)? {
+            self.generate_runtime_function_call(native, args, span)?;
+            return Ok(());
+        }
         let target = self.resolve_module_call_target(module_alias, function, span)?;
         self.generate_call(&target, args, span, CallValueMode::Ignore)?;
         Ok(())
@@ -288,8 +335,33 @@ impl<'ctx> Codegen<'ctx> {
         module_alias: &str,
         function: &str,
         args: &[Expr],
+        expected_ty: &Type,
         span: crate::token::Span,
     ) -> Result<BasicValueEnum<'ctx>, CodegenError> {
+        if self.collection_variable_type(module_alias).is_some() {
+            return self
+                .generate_collection_method(module_alias, function, args, span)?
+                .ok_or_else(|| {
+                    CodegenError::internal_module_call_as_value(module_alias, function, span)
+                });
+        }
+        if self.option_variable_type(module_alias).is_some() {
+            return self.generate_option_method(module_alias, function, args, span);
+        }
+        if self.is_rng_variable(module_alias) {
+            return self.generate_rng_method(module_alias, function, args, span);
+        }
+        if let Some(numeric) = self.numeric_module_function(module_alias, function) {
+            let callee = format!("{}.{}", module_alias, function);
+            return self.generate_numeric_call(&callee, numeric, args, expected_ty, span);
+        }
+        if let Some(native) = self.native_module_function(module_alias, function, span)? {
+            return self
+                .generate_runtime_function_call(native, args, span)?
+                .ok_or_else(|| {
+                    CodegenError::internal_module_call_as_value(module_alias, function, span)
+                });
+        }
         let target = self.resolve_module_call_target(module_alias, function, span)?;
         match self.generate_call(
             &target,
@@ -337,8 +409,9 @@ impl<'ctx> Codegen<'ctx> {
         }
 
         match &expr.kind {
-            ExprKind::IntLiteral(value) => {
-                // Semantic analysis guarantees the value fits in the expected type.
+            ExprKind::IntLiteral(value) | ExprKind::SuffixedIntLiteral { value, .. } => {
+                // Semantic analysis guarantees the value fits in the expected type,
+                // and that a suffixed literal's type is the expected type.
                 if expected_ty.is_integer() {
                     let llvm_type = self.get_llvm_type(expected_ty, expr.span)?.into_int_type();
                     // `const_int` receives a raw `u64` bit pattern; LLVM materializes the
@@ -360,7 +433,7 @@ impl<'ctx> Codegen<'ctx> {
                     }
                 }
             }
-            ExprKind::FloatLiteral(value) => {
+            ExprKind::FloatLiteral(value) | ExprKind::SuffixedFloatLiteral { value, .. } => {
                 if expected_ty.is_float() {
                     let llvm_type = self
                         .get_llvm_type(expected_ty, expr.span)?
@@ -384,6 +457,17 @@ impl<'ctx> Codegen<'ctx> {
                 let llvm_value = self.context.bool_type().const_int(*value as u64, false);
                 Ok(llvm_value.into())
             }
+            ExprKind::CharLiteral(value) => {
+                // Semantic analysis guarantees the expected type is Char.
+                if *expected_ty != Type::Char {
+                    return Err(CodegenError::internal_char_as_type(
+                        &expected_ty.to_string(),
+                        expr.span,
+                    ));
+                }
+                let llvm_value = self.context.i32_type().const_int(u64::from(*value), false);
+                Ok(llvm_value.into())
+            }
             ExprKind::Identifier(name) => {
                 // Semantic analysis guarantees the variable exists and has the correct type
                 let binding = self
@@ -416,15 +500,22 @@ impl<'ctx> Codegen<'ctx> {
                         expr.span,
                     ));
                 }
-                // Create a global constant string in read-only memory.
-                // The pointer remains valid for the program's lifetime.
-                let str_ptr = self
-                    .builder
-                    .build_global_string_ptr(s, "str")
-                    .map_err(|e| {
-                        CodegenError::internal_string_ptr_failed(&e.to_string(), expr.span)
-                    })?;
-                Ok(str_ptr.as_pointer_value().into())
+                // Create a static string object in read-only memory.
+                // Its immortal reference count keeps it valid for the program's lifetime.
+                let str_ptr = self.build_string_object(s, expr.span)?;
+                Ok(str_ptr.into())
+            }
+            ExprKind::Call { callee, args } if callee == "format" => {
+                self.generate_format_value(args, expr.span)
+            }
+            ExprKind::Call { callee, .. } if collections::is_constructor(callee) => {
+                self.generate_collection_constructor(callee, expected_ty, expr.span)
+            }
+            ExprKind::Call { callee, args } if runtime_function(callee).is_some() => {
+                let function = runtime_function(callee)
+                    .ok_or_else(|| CodegenError::internal_builtin_not_found(callee))?;
+                self.generate_runtime_function_call(function, args, expr.span)?
+                    .ok_or_else(|| CodegenError::internal_call_returned_void(callee, expr.span))
             }
             ExprKind::Call { callee, args } => {
                 self.generate_user_function_call_value(callee, args, expr.span)
@@ -446,6 +537,10 @@ impl<'ctx> Codegen<'ctx> {
                 expected_ty,
                 expr.span,
             ),
+            ExprKind::Block(block) => self.generate_block_expr_value(block, expected_ty, expr.span),
+            ExprKind::Tuple(elements) => {
+                self.generate_tuple_expr_value(elements, expected_ty, expr.span)
+            }
             ExprKind::MemberAccess { .. } => {
                 // Module-qualified expressions are not yet supported
                 Err(CodegenError::internal_member_access_not_implemented(
@@ -456,7 +551,7 @@ impl<'ctx> Codegen<'ctx> {
                 module,
                 function,
                 args,
-            } => self.generate_module_call_value(module, function, args, expr.span),
+            } => self.generate_module_call_value(module, function, args, expected_ty, expr.span),
         }
     }
 
@@ -529,7 +624,9 @@ impl<'ctx> Codegen<'ctx> {
     pub(super) fn infer_expr_type_for_comparison(&self, expr: &Expr) -> Result<Type, CodegenError> {
         match &expr.kind {
             ExprKind::IntLiteral(_) => Ok(Type::I64),
+            ExprKind::SuffixedIntLiteral { ty, .. } => Ok(ty.clone()),
             ExprKind::FloatLiteral(_) => Ok(Type::F64),
+            ExprKind::SuffixedFloatLiteral { ty, .. } => Ok(ty.clone()),
             ExprKind::Identifier(name) => {
                 let binding = self
                     .lookup_variable(name)
@@ -560,9 +657,25 @@ impl<'ctx> Codegen<'ctx> {
                 condition: _,
                 then_block: _,
                 else_block: _,
-            } => self.get_expr_type(expr),
+            }
+            | ExprKind::Block(_)
+            | ExprKind::Tuple(_) => self.get_expr_type(expr),
             ExprKind::BoolLiteral(_) => Ok(Type::Bool),
+            ExprKind::CharLiteral(_) => Ok(Type::Char),
             ExprKind::StringLiteral(_) => Ok(Type::String),
+            ExprKind::Call { callee, .. } if callee == "format" => Ok(Type::String),
+            ExprKind::Call { callee, .. } if collections::is_constructor(callee) => {
+                Err(CodegenError::internal_collection_constructor_type(
+                    callee,
+                    &Type::Inferred,
+                    expr.span,
+                ))
+            }
+            ExprKind::Call { callee, .. } if runtime_function(callee).is_some() => {
+                runtime_function(callee)
+                    .and_then(RuntimeFunction::return_type)
+                    .ok_or_else(|| CodegenError::internal_call_as_value(callee, expr.span))
+            }
             ExprKind::Call { callee, .. } => {
                 let (llvm_name, _) = self.resolve_user_function_target(callee, expr.span)?;
                 let return_ty = self
@@ -589,8 +702,31 @@ impl<'ctx> Codegen<'ctx> {
                 CodegenError::internal_member_access_not_implemented(expr.span),
             ),
             ExprKind::ModuleCall {
-                module, function, ..
+                module,
+                function,
+                args,
             } => {
+                if let Some(ty) = self.collection_variable_type(module) {
+                    return Self::collection_method_value_type(module, function, &ty, expr.span);
+                }
+                if let Some(ty) = self.option_variable_type(module) {
+                    return Self::option_method_value_type(module, function, &ty, expr.span);
+                }
+                if self.is_rng_variable(module) {
+                    return Self::rng_method_value_type(module, function, args, expr.span, |arg| {
+                        self.infer_expr_type_for_comparison(arg)
+                    });
+                }
+                if let Some(numeric) = self.numeric_module_function(module, function) {
+                    let callee = format!("{}.{}", module, function);
+                    let operand = Self::numeric_operand(&callee, numeric, args, expr.span)?;
+                    return self.infer_expr_type_for_comparison(operand);
+                }
+                if let Some(native) = self.native_module_function(module, function, expr.span)? {
+                    return native.return_type().ok_or_else(|| {
+                        CodegenError::internal_module_call_as_value(module, function, expr.span)
+                    });
+                }
                 let mangle_prefix = self.resolve_module_alias(module, expr.span)?;
                 let mangled_name = mangle_name(&mangle_prefix, function);
                 let source_callee = format!("{}.{}", module, function);
@@ -658,15 +794,7 @@ impl<'ctx> Codegen<'ctx> {
             .map_err(|e| CodegenError::internal_branch_failed(&e.to_string(), span))?;
 
         self.builder.position_at_end(then_bb);
-        self.enter_variable_scope();
-        let then_value_result = (|| -> Result<BasicValueEnum<'ctx>, CodegenError> {
-            for stmt in &then_block.stmts {
-                self.generate_stmt(stmt)?;
-            }
-            self.generate_expr_value(&then_block.value, expected_ty)
-        })();
-        self.exit_variable_scope(span)?;
-        let then_value = then_value_result?;
+        let then_value = self.generate_owned_block_value(then_block, expected_ty, span)?;
         let then_end_bb = self
             .builder
             .get_insert_block()
@@ -676,15 +804,7 @@ impl<'ctx> Codegen<'ctx> {
             .map_err(|e| CodegenError::internal_branch_failed(&e.to_string(), span))?;
 
         self.builder.position_at_end(else_bb);
-        self.enter_variable_scope();
-        let else_value_result = (|| -> Result<BasicValueEnum<'ctx>, CodegenError> {
-            for stmt in &else_block.stmts {
-                self.generate_stmt(stmt)?;
-            }
-            self.generate_expr_value(&else_block.value, expected_ty)
-        })();
-        self.exit_variable_scope(span)?;
-        let else_value = else_value_result?;
+        let else_value = self.generate_owned_block_value(else_block, expected_ty, span)?;
         let else_end_bb = self
             .builder
             .get_insert_block()
@@ -700,15 +820,102 @@ impl<'ctx> Codegen<'ctx> {
             .map_err(|e| CodegenError::internal_branch_failed(&e.to_string(), span))?;
         phi.add_incoming(&[(&then_value, then_end_bb), (&else_value, else_end_bb)]);
 
-        Ok(phi.as_basic_value())
+        let value = phi.as_basic_value();
+        self.register_temporary(value, expected_ty, span)?;
+        Ok(value)
+    }
+
+    /// Generates LLVM IR for a block expression value.
+    ///
+    /// The block is emitted inline in the current basic block; only a new
+    /// variable scope is introduced for its statements.
+    fn generate_block_expr_value(
+        &mut self,
+        block: &IfExprBlock,
+        expected_ty: &Type,
+        span: crate::token::Span,
+    ) -> Result<BasicValueEnum<'ctx>, CodegenError> {
+        let value = self.generate_owned_block_value(block, expected_ty, span)?;
+        self.register_temporary(value, expected_ty, span)?;
+        Ok(value)
+    }
+
+    /// Generates the statements and value of a block in a new variable scope.
+    ///
+    /// The value is retained before the block's temporaries and variables are
+    /// released, so the caller owns the returned reference.
+    fn generate_owned_block_value(
+        &mut self,
+        block: &IfExprBlock,
+        expected_ty: &Type,
+        span: crate::token::Span,
+    ) -> Result<BasicValueEnum<'ctx>, CodegenError> {
+        self.enter_variable_scope();
+        self.push_temporary_frame();
+        let value_result = (|| -> Result<BasicValueEnum<'ctx>, CodegenError> {
+            for stmt in &block.stmts {
+                self.generate_stmt(stmt)?;
+            }
+            let value = self.generate_expr_value(&block.value, expected_ty)?;
+            self.build_retain(value, expected_ty, span)?;
+            Ok(value)
+        })();
+        let release_result = self.pop_temporary_frame(span);
+        let exit_result = self.exit_variable_scope(span);
+        let value = value_result?;
+        release_result?;
+        exit_result?;
+        Ok(value)
+    }
+
+    /// Generates LLVM IR for a tuple expression value.
+    ///
+    /// Each element is evaluated left to right against its element type and
+    /// inserted into an LLVM struct value with `insertvalue`.
+    fn generate_tuple_expr_value(
+        &mut self,
+        elements: &[Expr],
+        expected_ty: &Type,
+        span: crate::token::Span,
+    ) -> Result<BasicValueEnum<'ctx>, CodegenError> {
+        let Type::Tuple(element_types) = expected_ty else {
+            return Err(CodegenError::internal_tuple_as_type(
+                &expected_ty.to_string(),
+                span,
+            ));
+        };
+        if elements.len() != element_types.len() {
+            return Err(CodegenError::internal_tuple_as_type(
+                &expected_ty.to_string(),
+                span,
+            ));
+        }
+
+        let struct_type = self.get_llvm_type(expected_ty, span)?.into_struct_type();
+        let mut aggregate = struct_type.get_undef();
+        for (index, (element, element_ty)) in elements.iter().zip(element_types).enumerate() {
+            let element_value = self.generate_expr_value(element, element_ty)?;
+            aggregate = self
+                .builder
+                .build_insert_value(aggregate, element_value, index as u32, "tuple")
+                .map_err(|e| CodegenError::internal_tuple_build_failed(&e.to_string(), span))?
+                .into_struct_value();
+        }
+        Ok(aggregate.into())
     }
 
     /// Generates LLVM IR for a comparison operation.
     ///
-    /// Supports integer, bool (== and != only), and string comparisons.
+    /// Supports integer, float, bool (== and != only), and string comparisons.
     ///
     /// String `==` and `!=` use `lak_streq`, while string ordering operators
     /// (`<`, `>`, `<=`, `>=`) use `lak_strcmp`.
+    ///
+    /// Float comparisons follow IEEE 754: every comparison involving NaN is
+    /// false except `!=`, which is true. `==` and the ordering operators lower
+    /// to ordered predicates (`oeq`, `olt`, ...) and `!=` lowers to the
+    /// unordered `une`, so `a != b` is always the negation of `a == b` and
+    /// `x != x` holds exactly when `x` is NaN.
     fn generate_comparison_op(
         &mut self,
         left: &Expr,
@@ -716,8 +923,41 @@ impl<'ctx> Codegen<'ctx> {
         right: &Expr,
         span: crate::token::Span,
     ) -> Result<BasicValueEnum<'ctx>, CodegenError> {
+        let (operand_ty, left_value, right_value) =
+            self.generate_comparison_operands(left, right, span)?;
+        self.build_comparison(op, &operand_ty, left_value, right_value, span)
+    }
+
+    /// Evaluates both operands of a comparison, left to right, and returns
+    /// their common operand type with the two values.
+    ///
+    /// Float operands are widened to the common float type.
+    pub(super) fn generate_comparison_operands(
+        &mut self,
+        left: &Expr,
+        right: &Expr,
+        span: crate::token::Span,
+    ) -> Result<(Type, BasicValueEnum<'ctx>, BasicValueEnum<'ctx>), CodegenError> {
         let operand_ty = self.infer_binary_operand_type_for_codegen(left, right, span)?;
+        if operand_ty.is_float() {
+            let left_value = self.generate_float_operand_value(left, &operand_ty, span)?;
+            let right_value = self.generate_float_operand_value(right, &operand_ty, span)?;
+            return Ok((operand_ty, left_value.into(), right_value.into()));
+        }
+        let left_value = self.generate_expr_value(left, &operand_ty)?;
+        let right_value = self.generate_expr_value(right, &operand_ty)?;
+        Ok((operand_ty, left_value, right_value))
+    }
 
+    /// Compares two already evaluated operands of `operand_ty`.
+    pub(super) fn build_comparison(
+        &self,
+        op: BinaryOperator,
+        operand_ty: &Type,
+        left: BasicValueEnum<'ctx>,
+        right: BasicValueEnum<'ctx>,
+        span: crate::token::Span,
+    ) -> Result<BasicValueEnum<'ctx>, CodegenError> {
         match operand_ty {
             Type::I8
             | Type::I16
@@ -727,9 +967,10 @@ impl<'ctx> Codegen<'ctx> {
             | Type::U16
             | Type::U32
             | Type::U64
+            | Type::Char
             | Type::Bool => {
                 // Reject ordering operators for bool
-                if operand_ty == Type::Bool && !op.is_equality() {
+                if *operand_ty == Type::Bool && !op.is_equality() {
                     return Err(CodegenError::internal_binary_op_failed(
                         op,
                         "ordering operators not supported for bool",
@@ -737,18 +978,16 @@ impl<'ctx> Codegen<'ctx> {
                     ));
                 }
 
-                let left_basic = self.generate_expr_value(left, &operand_ty)?;
-                let left_value = match left_basic {
+                let left_value = match left {
                     BasicValueEnum::IntValue(v) => v,
                     _ => return Err(CodegenError::internal_non_integer_value("comparison", span)),
                 };
-                let right_basic = self.generate_expr_value(right, &operand_ty)?;
-                let right_value = match right_basic {
+                let right_value = match right {
                     BasicValueEnum::IntValue(v) => v,
                     _ => return Err(CodegenError::internal_non_integer_value("comparison", span)),
                 };
 
-                let predicate = if operand_ty == Type::Bool || operand_ty.is_signed_integer() {
+                let predicate = if *operand_ty == Type::Bool || operand_ty.is_signed_integer() {
                     match op {
                         BinaryOperator::Equal => IntPredicate::EQ,
                         BinaryOperator::NotEqual => IntPredicate::NE,
@@ -794,7 +1033,7 @@ impl<'ctx> Codegen<'ctx> {
                     }
                 };
 
-                let label = if operand_ty == Type::Bool {
+                let label = if *operand_ty == Type::Bool {
                     "bool_cmp_tmp"
                 } else {
                     "cmp_tmp"
@@ -806,12 +1045,18 @@ impl<'ctx> Codegen<'ctx> {
                 Ok(result.into())
             }
             Type::F32 | Type::F64 => {
-                let left_value = self.generate_float_operand_value(left, &operand_ty, span)?;
-                let right_value = self.generate_float_operand_value(right, &operand_ty, span)?;
+                let left_value = match left {
+                    BasicValueEnum::FloatValue(v) => v,
+                    _ => return Err(CodegenError::internal_non_float_value("comparison", span)),
+                };
+                let right_value = match right {
+                    BasicValueEnum::FloatValue(v) => v,
+                    _ => return Err(CodegenError::internal_non_float_value("comparison", span)),
+                };
 
                 let predicate = match op {
                     BinaryOperator::Equal => FloatPredicate::OEQ,
-                    BinaryOperator::NotEqual => FloatPredicate::ONE,
+                    BinaryOperator::NotEqual => FloatPredicate::UNE,
                     BinaryOperator::LessThan => FloatPredicate::OLT,
                     BinaryOperator::GreaterThan => FloatPredicate::OGT,
                     BinaryOperator::LessEqual => FloatPredicate::OLE,
@@ -838,8 +1083,7 @@ impl<'ctx> Codegen<'ctx> {
                 Ok(result.into())
             }
             Type::String => {
-                let left_basic = self.generate_expr_value(left, &Type::String)?;
-                let left_ptr = match left_basic {
+                let left_ptr = match left {
                     BasicValueEnum::PointerValue(v) => v,
                     _ => {
                         return Err(CodegenError::internal_non_pointer_value(
@@ -848,8 +1092,7 @@ impl<'ctx> Codegen<'ctx> {
                         ));
                     }
                 };
-                let right_basic = self.generate_expr_value(right, &Type::String)?;
-                let right_ptr = match right_basic {
+                let right_ptr = match right {
                     BasicValueEnum::PointerValue(v) => v,
                     _ => {
                         return Err(CodegenError::internal_non_pointer_value(
@@ -985,6 +1228,26 @@ impl<'ctx> Codegen<'ctx> {
                     )),
                 }
             }
+            Type::Tuple(_) => Err(CodegenError::internal_binary_op_failed(
+                op,
+                "tuple operand type reached comparison codegen",
+                span,
+            )),
+            Type::Map(..) | Type::Set(_) => Err(CodegenError::internal_binary_op_failed(
+                op,
+                "collection operand type reached comparison codegen",
+                span,
+            )),
+            Type::Option(_) => Err(CodegenError::internal_binary_op_failed(
+                op,
+                "Option operand type reached comparison codegen",
+                span,
+            )),
+            Type::Rng => Err(CodegenError::internal_binary_op_failed(
+                op,
+                "rng operand type reached comparison codegen",
+                span,
+            )),
             Type::Inferred => Err(CodegenError::internal_binary_op_failed(
                 op,
                 "inferred operand type reached comparison codegen",
@@ -1058,7 +1321,13 @@ impl<'ctx> Codegen<'ctx> {
             .map_err(|e| CodegenError::internal_branch_failed(&e.to_string(), span))?;
 
         self.builder.position_at_end(rhs_block);
-        let right_basic = self.generate_expr_value(right, &Type::Bool)?;
+        // The right operand is evaluated conditionally, so its temporaries
+        // are released before the blocks merge.
+        self.push_temporary_frame();
+        let right_result = self.generate_expr_value(right, &Type::Bool);
+        let release_result = self.pop_temporary_frame(span);
+        let right_basic = right_result?;
+        release_result?;
         let right_value = match right_basic {
             BasicValueEnum::IntValue(v) => v,
             _ => return Err(CodegenError::internal_non_integer_value("logical", span)),
@@ -1407,7 +1676,7 @@ impl<'ctx> Codegen<'ctx> {
         }
     }
 
-    fn get_current_function(
+    pub(super) fn get_current_function(
         &self,
         span: crate::token::Span,
     ) -> Result<FunctionValue<'ctx>, CodegenError> {
@@ -1429,27 +1698,10 @@ impl<'ctx> Codegen<'ctx> {
             .map_err(|e| CodegenError::internal_string_ptr_failed(&e.to_string(), span))?
             .as_pointer_value();
 
-        let lak_panic = self
-            .module
-            .get_function("lak_panic")
-            .ok_or_else(|| CodegenError::internal_builtin_not_found_with_span("lak_panic", span))?;
-
-        self.builder
-            .build_call(
-                lak_panic,
-                &[BasicMetadataValueEnum::PointerValue(panic_msg)],
-                "",
-            )
-            .map_err(|e| CodegenError::internal_panic_call_failed(&e.to_string(), span))?;
-
-        self.builder
-            .build_unreachable()
-            .map_err(|e| CodegenError::internal_unreachable_failed(&e.to_string(), span))?;
-
-        Ok(())
+        self.build_panic_at(panic_msg, span)
     }
 
-    fn generate_runtime_check_branch(
+    pub(super) fn generate_runtime_check_branch(
         &mut self,
         condition: inkwell::values::IntValue<'ctx>,
         panic_block_name: &str,
@@ -1490,7 +1742,7 @@ impl<'ctx> Codegen<'ctx> {
     ///   br i1 %is_zero, label %div_zero_panic, label %div_zero_safe
     ///
     /// div_zero_panic:
-    ///   call void @lak_panic("division by zero")
+    ///   call void @lak_panic_at("division by zero", file, line, column)
     ///   unreachable
     ///
     /// div_zero_safe:
@@ -1546,7 +1798,7 @@ impl<'ctx> Codegen<'ctx> {
     ///   br i1 %is_overflow, label %div_overflow_panic, label %div_overflow_safe
     ///
     /// div_overflow_panic:
-    ///   call void @lak_panic("integer overflow")
+    ///   call void @lak_panic_at("integer overflow", file, line, column)
     ///   unreachable
     ///
     /// div_overflow_safe:
@@ -1616,7 +1868,7 @@ impl<'ctx> Codegen<'ctx> {
     ///   br i1 %overflow, label %overflow_panic, label %overflow_safe
     ///
     /// overflow_panic:
-    ///   call void @lak_panic("integer overflow")
+    ///   call void @lak_panic_at("integer overflow", file, line, column)
     ///   unreachable
     ///
     /// overflow_safe:
@@ -1629,7 +1881,7 @@ impl<'ctx> Codegen<'ctx> {
     /// * `right` - The right operand
     /// * `intrinsic_prefix` - The LLVM intrinsic prefix (e.g., "llvm.sadd.with.overflow")
     /// * `span` - The source span for error reporting
-    fn generate_overflow_checked_binop(
+    pub(super) fn generate_overflow_checked_binop(
         &mut self,
         left: inkwell::values::IntValue<'ctx>,
         right: inkwell::values::IntValue<'ctx>,
@@ -0,0 +1,280 @@
+//! Code generation for the `format` builtin.
+//!
+//! A `format(fmt, args...)` call is lowered to calls on a runtime format
+//! buffer: `lak_fmt_new`, one `lak_fmt_push_*` call per format string piece,
+//! and `lak_fmt_finish`, which returns the resulting string. Each placeholder
+//! spec is emitted as a constant `LakFormatSpec` global that the runtime reads.
+//!
+//! Semantic analysis has already validated the format string, so parse
+//! failures and type mismatches here are internal errors.
+
+use super::Codegen;
+use super::error::CodegenError;
+use crate::ast::{
+    Expr, ExprKind, FormatAlign, FormatArg, FormatKind, FormatPiece, FormatSpec, FormatString, Type,
+};
+use crate::token::Span;
+use inkwell::AddressSpace;
+use inkwell::module::Linkage;
+use inkwell::types::StructType;
+use inkwell::values::{BasicMetadataValueEnum, BasicValueEnum, PointerValue};
+
+impl<'ctx> Codegen<'ctx> {
+    /// Returns the LLVM layout of the runtime's `#[repr(C)] LakFormatSpec`:
+    /// `{ i32 fill, i32 width, i32 precision, i8 align, i8 flags, i8 kind }`.
+    fn format_spec_type(&self) -> StructType<'ctx> {
+        let i32_type = self.context.i32_type();
+        let i8_type = self.context.i8_type();
+        self.context.struct_type(
+            &[
+                i32_type.into(),
+                i32_type.into(),
+                i32_type.into(),
+                i8_type.into(),
+                i8_type.into(),
+                i8_type.into(),
+            ],
+            false,
+        )
+    }
+
+    /// Declares the Lak runtime format buffer functions for use in generated code.
+    ///
+    /// This creates external function declarations with the signatures:
+    /// - `ptr lak_fmt_new()`
+    /// - `void lak_fmt_push_literal(ptr buffer, const char* s)`
+    /// - `void lak_fmt_push_{str,bool,i64,u64,f32,f64}(ptr buffer, value, ptr spec)`
+    /// - `const char* lak_fmt_finish(ptr buffer)`
+    pub(super) fn declare_lak_format_builtins(&self) {
+        let void_type = self.context.void_type();
+        let ptr_type = self.context.ptr_type(AddressSpace::default());
+
+        self.module.add_function(
+            "lak_fmt_new",
+            ptr_type.fn_type(&[], false),
+            Some(Linkage::External),
+        );
+        self.module.add_function(
+            "lak_fmt_push_literal",
+            void_type.fn_type(&[ptr_type.into(), ptr_type.into()], false),
+            Some(Linkage::External),
+        );
+
+        let value_types = [
+            ("lak_fmt_push_str", ptr_type.into()),
+            ("lak_fmt_push_bool", self.context.bool_type().into()),
+            ("lak_fmt_push_i64", self.context.i64_type().into()),
+            ("lak_fmt_push_u64", self.context.i64_type().into()),
+            ("lak_fmt_push_f32", self.context.f32_type().into()),
+            ("lak_fmt_push_f64", self.context.f64_type().into()),
+            ("lak_fmt_push_char", self.context.i32_type().into()),
+        ];
+        for (name, value_type) in value_types {
+            let fn_type = void_type.fn_type(&[ptr_type.into(), value_type, ptr_type.into()], false);
+            self.module
+                .add_function(name, fn_type, Some(Linkage::External));
+        }
+
+        self.module.add_function(
+            "lak_fmt_finish",
+            ptr_type.fn_type(&[ptr_type.into()], false),
+            Some(Linkage::External),
+        );
+    }
+
+    /// Generates LLVM IR for a `format(fmt, args...)` call and returns the
+    /// resulting string pointer.
+    ///
+    /// Positional arguments are evaluated once, left to right, before any
+    /// output is produced, so an argument referenced by several placeholders
+    /// is not re-evaluated. Named placeholders load the captured variable.
+    pub(super) fn generate_format_value(
+        &mut self,
+        args: &[Expr],
+        span: Span,
+    ) -> Result<BasicValueEnum<'ctx>, CodegenError> {
+        let Some((format_expr, values)) = args.split_first() else {
+            return Err(CodegenError::internal_format_invalid(
+                "missing format string",
+                span,
+            ));
+        };
+        let ExprKind::StringLiteral(text) = &format_expr.kind else {
+            return Err(CodegenError::internal_format_invalid(
+                "format string is not a literal",
+                format_expr.span,
+            ));
+        };
+        let format_string = FormatString::parse(text)
+            .map_err(|detail| CodegenError::internal_format_invalid(&detail, format_expr.span))?;
+
+        let mut positional = Vec::with_capacity(values.len());
+        for value in values {
+            let ty = self.get_expr_type(value)?;
+            let llvm_value = self.generate_expr_value(value, &ty)?;
+            positional.push((ty, llvm_value));
+        }
+
+        let buffer = self
+            .call_format_runtime("lak_fmt_new", &[], span)?
+            .ok_or_else(|| CodegenError::internal_format_call_failed("lak_fmt_new", span))?
+            .into_pointer_value();
+
+        for piece in &format_string.pieces {
+            match piece {
+                FormatPiece::Literal(literal) => {
+                    let literal_ptr = self
+                        .builder
+                        .build_global_string_ptr(literal, "fmt_lit")
+                        .map_err(|e| {
+                            CodegenError::internal_string_ptr_failed(&e.to_string(), span)
+                        })?
+                        .as_pointer_value();
+                    self.call_format_runtime(
+                        "lak_fmt_push_literal",
+                        &[buffer.into(), literal_ptr.into()],
+                        span,
+                    )?;
+                }
+                FormatPiece::Placeholder(placeholder) => {
+                    let (ty, value) = match &placeholder.arg {
+                        FormatArg::Positional(index) => {
+                            positional.get(*index).cloned().ok_or_else(|| {
+                                CodegenError::internal_format_invalid(
+                                    &format!("argument {} is missing", index),
+                                    format_expr.span,
+                                )
+                            })?
+                        }
+                        FormatArg::Named(name) => {
+                            let capture =
+                                Expr::new(ExprKind::Identifier(name.clone()), format_expr.span);
+                            let ty = self.get_expr_type(&capture)?;
+                            let value = self.generate_expr_value(&capture, &ty)?;
+                            (ty, value)
+                        }
+                    };
+                    self.generate_format_push(buffer, &ty, value, &placeholder.spec, span)?;
+                }
+            }
+        }
+
+        let value = self
+            .call_format_runtime("lak_fmt_finish", &[buffer.into()], span)?
+            .ok_or_else(|| CodegenError::internal_format_call_failed("lak_fmt_finish", span))?;
+        // The result is an owned reference; release it after the statement.
+        self.register_temporary(value, &Type::String, span)?;
+        Ok(value)
+    }
+
+    /// Appends one formatted value to the buffer, widening integers to 64 bits.
+    pub(super) fn generate_format_push(
+        &mut self,
+        buffer: PointerValue<'ctx>,
+        ty: &Type,
+        value: BasicValueEnum<'ctx>,
+        spec: &FormatSpec,
+        span: Span,
+    ) -> Result<(), CodegenError> {
+        let i64_type = self.context.i64_type();
+        let (runtime_name, value): (&str, BasicMetadataValueEnum<'ctx>) = match ty {
+            Type::String => ("lak_fmt_push_str", value.into()),
+            Type::Bool => ("lak_fmt_push_bool", value.into()),
+            Type::I8 | Type::I16 | Type::I32 | Type::I64 => {
+                let widened = self
+                    .builder
+                    .build_int_s_extend_or_bit_cast(value.into_int_value(), i64_type, "fmt_i64")
+                    .map_err(|e| CodegenError::internal_format_call_failed(&e.to_string(), span))?;
+                ("lak_fmt_push_i64", widened.into())
+            }
+            Type::U8 | Type::U16 | Type::U32 | Type::U64 => {
+                let widened = self
+                    .builder
+                    .build_int_z_extend_or_bit_cast(value.into_int_value(), i64_type, "fmt_u64")
+                    .map_err(|e| CodegenError::internal_format_call_failed(&e.to_string(), span))?;
+                ("lak_fmt_push_u64", widened.into())
+            }
+            Type::F32 => ("lak_fmt_push_f32", value.into()),
+            Type::F64 => ("lak_fmt_push_f64", value.into()),
+            Type::Char => ("lak_fmt_push_char", value.into()),
+            Type::Tuple(_)
+            | Type::Map(..)
+            | Type::Set(_)
+            | Type::Option(_)
+            | Type::Rng
+            | Type::Inferred => {
+                return Err(CodegenError::internal_format_invalid(
+                    &format!("cannot format a value of type '{}'", ty),
+                    span,
+                ));
+            }
+        };
+
+        let spec_ptr = self.format_spec_constant(spec);
+        self.call_format_runtime(runtime_name, &[buffer.into(), value, spec_ptr.into()], span)?;
+        Ok(())
+    }
+
+    /// Emits a placeholder spec as a private constant `LakFormatSpec` global.
+    fn format_spec_constant(&self, spec: &FormatSpec) -> PointerValue<'ctx> {
+        let i32_type = self.context.i32_type();
+        let i8_type = self.context.i8_type();
+
+        let align = match spec.align {
+            None => 0,
+            Some(FormatAlign::Left) => 1,
+            Some(FormatAlign::Center) => 2,
+            Some(FormatAlign::Right) => 3,
+        };
+        let flags = u64::from(spec.sign_plus)
+            | u64::from(spec.alternate) << 1
+            | u64::from(spec.zero_pad) << 2;
+        let kind = match spec.kind {
+            FormatKind::Display => 0,
+            FormatKind::LowerHex => 1,
+            FormatKind::UpperHex => 2,
+            FormatKind::Octal => 3,
+            FormatKind::Binary => 4,
+            FormatKind::LowerExp => 5,
+            FormatKind::UpperExp => 6,
+        };
+        let precision = spec.precision.map_or(-1, i64::from);
+
+        let spec_type = self.format_spec_type();
+        let initializer = spec_type.const_named_struct(&[
+            i32_type.const_int(u64::from(spec.fill), false).into(),
+            i32_type
+                .const_int(u64::from(spec.width.unwrap_or(0)), false)
+                .into(),
+            i32_type.const_int(precision as u64, true).into(),
+            i8_type.const_int(align, false).into(),
+            i8_type.const_int(flags, false).into(),
+            i8_type.const_int(kind, false).into(),
+        ]);
+
+        let global = self.module.add_global(spec_type, None, "fmt_spec");
+        global.set_initializer(&initializer);
+        global.set_constant(true);
+        global.set_linkage(Linkage::Private);
+        global.set_unnamed_addr(true);
+        global.as_pointer_value()
+    }
+
+    /// Calls a format runtime function, returning its result if it has one.
+    pub(super) fn call_format_runtime(
+        &self,
+        name: &str,
+        args: &[BasicMetadataValueEnum<'ctx>],
+        span: Span,
+    ) -> Result<Option<BasicValueEnum<'ctx>>, CodegenError> {
+        let function = self
+            .module
+            .get_function(name)
+            .ok_or_else(|| CodegenError::internal_builtin_not_found(name))?;
+        let call = self
+            .builder
+            .build_call(function, args, "")
+            .map_err(|e| CodegenError::internal_format_call_failed(&e.to_string(), span))?;
+        Ok(call.try_as_basic_value().basic())
+    }
+}
@@ -10,7 +10,8 @@
 //!
 //! - Creates an LLVM module and builder
 //! - Generates a `main` function as the program entry point
-//! - Compiles function calls (`println`, `panic`, user-defined functions, module-qualified calls)
+//! - Compiles function calls (print builtins, `format`, `panic`, user-defined
+//!   functions, module-qualified calls)
 //! - Computes path-based mangle prefixes for multi-module compilation
 //! - Handles variable declarations (`let` statements) with stack allocation
 //! - Writes the output to a native object file
@@ -25,7 +26,7 @@
 //! ```no_run
 //! use inkwell::context::Context;
 //! use lak::codegen::Codegen;
-//! use lak::ast::{Program, FnDef, Stmt, StmtKind, Expr, ExprKind, Visibility};
+//! use lak::ast::{Program, FnDef, ReturnType, Stmt, StmtKind, Expr, ExprKind, Visibility};
 //! use lak::semantic::SemanticAnalyzer;
 //! use lak::token::Span;
 //! use std::path::Path;
@@ -36,10 +37,11 @@
 //! let program = Program {
 //!     imports: vec![],
 //!     functions: vec![FnDef {
+//!         doc: None,
 //!         visibility: Visibility::Private,
 //!         name: "main".to_string(),
 //!         params: vec![],
-//!         return_type: "void".to_string(),
+//!         return_type: ReturnType::Void,
 //!         return_type_span: Span::new(0, 0, 1, 1),
 //!         body: vec![Stmt::new(
 //!             StmtKind::Expr(Expr::new(
@@ -74,6 +76,10 @@
 //! - [`stmt`] - Statement code generation
 //! - [`expr`] - Expression code generation
 //! - [`builtins`] - Built-in function implementations
+//! - [`rc`] - Reference counting of heap values
+//! - [`collections`] - `map<K, V>` and `set<T>` construction and methods
+//! - [`options`] - `Option<T>` methods
+//! - [`backtrace`] - Shadow call stack for panic backtraces
 //! - [`target`] - Target machine and object file output
 //! - `tests` - Unit tests (test-only)
 //!
@@ -83,10 +89,17 @@
 //! * [Inkwell documentation](https://thedan64.github.io/inkwell/)
 //! * [LLVM Language Reference](https://llvm.org/docs/LangRef.html)
 
+mod backtrace;
 mod binding;
 mod builtins;
+mod collections;
 mod error;
 mod expr;
+mod format;
+mod numeric;
+mod options;
+mod rc;
+mod rng;
 mod stmt;
 mod target;
 
@@ -95,24 +108,46 @@ mod tests;
 
 pub use error::{CodegenError, CodegenErrorKind};
 
-use crate::ast::{FnDef, FnParam, Program, Type};
+use crate::ast::{FnDef, FnParam, Program, ReturnType, Stmt, Type};
+use crate::prelude::RuntimeFunction;
 use crate::resolver::ResolvedModule;
+use crate::stdlib::{NativeModule, NumericFunction};
 use crate::token::Span;
 use binding::VarBinding;
 use inkwell::AddressSpace;
 use inkwell::basic_block::BasicBlock;
 use inkwell::context::Context;
 use inkwell::types::{BasicMetadataTypeEnum, BasicTypeEnum};
+use inkwell::values::BasicValueEnum;
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
 /// Mangle prefix used for single-file compilation.
 const SINGLE_FILE_MANGLE_PREFIX: &str = "entry";
 
+/// Source file name reported when no file name has been set.
+const UNKNOWN_SOURCE_FILE: &str = "<unknown>";
+
 /// Control-flow targets for the current loop context.
 struct LoopControl<'ctx> {
     continue_block: BasicBlock<'ctx>,
     break_block: BasicBlock<'ctx>,
+    /// Number of variable scopes outside the loop body. Scopes at or above
+    /// this depth are exited (running their deferred bodies) on `break`/`continue`.
+    scope_depth: usize,
+    /// Number of temporary frames outside the loop body. Frames at or above
+    /// this depth are released on `break`/`continue`.
+    temporary_depth: usize,
+}
+
+/// A `defer` body registered in a variable scope.
+#[derive(Clone)]
+struct DeferredBody<'ctx> {
+    body: Vec<Stmt>,
+    /// Variable scopes visible at the `defer` statement. The body is generated
+    /// against this snapshot so later shadowing bindings cannot capture its names.
+    scopes: Vec<HashMap<String, VarBinding<'ctx>>>,
+    span: Span,
 }
 
 /// LLVM code generator for Lak programs.
@@ -142,6 +177,13 @@ pub struct Codegen<'ctx> {
     /// This is reset at the start of each function body and extended for
     /// block statements (e.g., `if` branches) to support shadowing.
     variables: Vec<HashMap<String, VarBinding<'ctx>>>,
+    /// Deferred bodies registered in each variable scope, parallel to `variables`.
+    deferred: Vec<Vec<DeferredBody<'ctx>>>,
+    /// Stack of temporary frames (innermost frame at the end).
+    ///
+    /// Each frame holds the owned values produced while evaluating one
+    /// statement (or one branch of an expression), released when it ends.
+    temporaries: Vec<Vec<(BasicValueEnum<'ctx>, Type)>>,
     /// Mapping from module alias to its mangle prefix.
     ///
     /// When an import has no alias (e.g., `import "./utils"`), the key is the
@@ -152,6 +194,11 @@ pub struct Codegen<'ctx> {
     /// entry directory, ensuring unique mangled names even for modules with
     /// the same filename in different directories.
     module_aliases: HashMap<String, String>,
+    /// Mapping from module alias to a native standard library module.
+    ///
+    /// Calls through these aliases lower to direct runtime calls instead of
+    /// mangled Lak functions.
+    native_module_aliases: HashMap<String, &'static NativeModule>,
     /// The current module's mangle prefix for name mangling.
     ///
     /// When generating code for any module's functions, this is set to that
@@ -182,6 +229,16 @@ pub struct Codegen<'ctx> {
     enforce_semantic_inferred_types: bool,
     /// Stack of loop control-flow targets (innermost loop at the end).
     loop_controls: Vec<LoopControl<'ctx>>,
+    /// File name of the module being generated, reported by runtime checks
+    /// such as failed assertions.
+    ///
+    /// Paths are relative to the entry module's directory. Set with
+    /// [`set_source_file`](Self::set_source_file) for single-module compiles
+    /// and per module by [`compile_modules`](Self::compile_modules).
+    source_file: String,
+    /// If true, calls are recorded in the runtime's shadow call stack so
+    /// panics print a backtrace. See [`set_backtrace`](Self::set_backtrace).
+    backtrace: bool,
 }
 
 /// Creates a mangled function name using a length-prefix scheme.
@@ -277,6 +334,18 @@ fn derive_mangle_prefix(module_path: &Path, entry_dir: &Path) -> Result<String,
     Ok(prefix)
 }
 
+/// Returns the file name reported for a module by runtime checks.
+///
+/// The path is shown relative to the entry module's directory when possible
+/// (e.g. `lib/utils.lak`), and in full otherwise.
+fn source_file_name(module_path: &Path, entry_dir: &Path) -> String {
+    module_path
+        .strip_prefix(entry_dir)
+        .unwrap_or(module_path)
+        .display()
+        .to_string()
+}
+
 /// Computes unique mangle prefixes for imported modules.
 ///
 /// The prefix is derived from the module's path relative to the entry
@@ -407,7 +476,10 @@ impl<'ctx> Codegen<'ctx> {
             module,
             builder,
             variables: Vec::new(),
+            deferred: Vec::new(),
+            temporaries: Vec::new(),
             module_aliases: HashMap::new(),
+            native_module_aliases: HashMap::new(),
             current_module_prefix: None,
             function_param_types: HashMap::new(),
             function_return_types: HashMap::new(),
@@ -415,35 +487,51 @@ impl<'ctx> Codegen<'ctx> {
             module_inferred_binding_types: HashMap::new(),
             enforce_semantic_inferred_types: false,
             loop_controls: Vec::new(),
+            source_file: UNKNOWN_SOURCE_FILE.to_string(),
+            backtrace: false,
         }
     }
 
+    /// Sets the source file name reported by runtime checks in single-module
+    /// compiles (e.g. `"main.lak"` in `assertion failed at main.lak:3:5`).
+    pub fn set_source_file(&mut self, source_file: &str) {
+        self.source_file = source_file.to_string();
+    }
+
+    /// Enables or disables panic backtraces (`lak build --backtrace`).
+    ///
+    /// When enabled, every user function call maintains a shadow call stack
+    /// that the runtime prints when the program panics. Disabled by default.
+    pub fn set_backtrace(&mut self, enabled: bool) {
+        self.backtrace = enabled;
+    }
+
     /// Declares all built-in functions used by the runtime.
     ///
     /// When adding a new builtin here, also update `BUILTIN_NAMES` in `builtins.rs`
     /// and the sync test `test_builtin_names_matches_declare_builtins` in `tests.rs`.
-    fn declare_builtins(&mut self) {
-        self.declare_lak_println();
-        self.declare_lak_println_i8();
-        self.declare_lak_println_i16();
-        self.declare_lak_println_i32();
-        self.declare_lak_println_i64();
-        self.declare_lak_println_u8();
-        self.declare_lak_println_u16();
-        self.declare_lak_println_u32();
-        self.declare_lak_println_u64();
-        self.declare_lak_println_f32();
-        self.declare_lak_println_f64();
-        self.declare_lak_println_bool();
-        self.declare_lak_panic();
+    fn declare_builtins(&mut self) -> Result<(), CodegenError> {
+        for name in builtins::PRINT_BUILTINS {
+            self.declare_lak_print_family(&format!("lak_{}", name));
+        }
+        self.declare_lak_format_builtins();
+        self.declare_lak_panic_at();
+        self.declare_lak_exit();
         self.declare_lak_streq();
         self.declare_lak_strcmp();
+        self.declare_lak_rc_builtins();
+        self.declare_lak_collection_builtins();
+        self.declare_lak_rng_builtins();
+        self.declare_lak_backtrace_builtins();
+        self.declare_lak_args_init();
+        self.declare_lak_runtime_functions()
     }
 
-    fn initialize_compile_state(&mut self) {
-        self.declare_builtins();
+    fn initialize_compile_state(&mut self) -> Result<(), CodegenError> {
+        self.declare_builtins()?;
         self.function_param_types.clear();
         self.function_return_types.clear();
+        Ok(())
     }
 
     pub(super) fn inferred_binding_type(
@@ -577,7 +665,7 @@ impl<'ctx> Codegen<'ctx> {
         if !self.enforce_semantic_inferred_types {
             self.inferred_binding_types.clear();
         }
-        self.initialize_compile_state();
+        self.initialize_compile_state()?;
         self.current_module_prefix = Some(SINGLE_FILE_MANGLE_PREFIX.to_string());
         let result = self.run_compile_passes(
             |codegen| {
@@ -649,7 +737,7 @@ impl<'ctx> Codegen<'ctx> {
             self.inferred_binding_types.clear();
             self.module_inferred_binding_types.clear();
         }
-        self.initialize_compile_state();
+        self.initialize_compile_state()?;
 
         // Validate that entry module exists in the module list
         if !modules.iter().any(|m| m.path() == entry_path) {
@@ -658,6 +746,9 @@ impl<'ctx> Codegen<'ctx> {
 
         let imported_prefixes = compute_mangle_prefixes(modules, entry_path)?;
         let entry_prefix = compute_entry_mangle_prefix(entry_path, &imported_prefixes)?;
+        let entry_dir = entry_path
+            .parent()
+            .ok_or_else(|| CodegenError::internal_entry_path_no_parent(entry_path))?;
 
         let result = self.run_compile_passes(
             |codegen| {
@@ -701,6 +792,7 @@ impl<'ctx> Codegen<'ctx> {
 
                     // Set up this module's alias map for resolving ModuleCall expressions
                     codegen.module_aliases.clear();
+                    codegen.native_module_aliases.clear();
                     for import in &module.program().imports {
                         let canonical_path =
                             module.resolved_imports().get(&import.path).ok_or_else(|| {
@@ -718,12 +810,16 @@ impl<'ctx> Codegen<'ctx> {
                                     import.span,
                                 )
                             })?;
-                        let mangle_prefix =
-                            get_mangle_prefix(&imported_prefixes, canonical_path.as_path())?;
                         let key = import
                             .alias
                             .clone()
                             .unwrap_or_else(|| imported_module.name().to_string());
+                        if let Some(native) = imported_module.native_module() {
+                            codegen.native_module_aliases.insert(key, native);
+                            continue;
+                        }
+                        let mangle_prefix =
+                            get_mangle_prefix(&imported_prefixes, canonical_path.as_path())?;
                         codegen
                             .module_aliases
                             .insert(key, mangle_prefix.to_string());
@@ -737,6 +833,7 @@ impl<'ctx> Codegen<'ctx> {
                         get_mangle_prefix(&imported_prefixes, module.path())?
                     };
                     codegen.current_module_prefix = Some(module_prefix.to_string());
+                    codegen.source_file = source_file_name(module.path(), entry_dir);
 
                     for function in &module.program().functions {
                         if is_entry && function.name == "main" {
@@ -766,7 +863,7 @@ impl<'ctx> Codegen<'ctx> {
         &mut self,
         name: &str,
         params: &[FnParam],
-        return_type: &str,
+        return_type: &ReturnType,
         return_type_span: crate::token::Span,
     ) -> Result<(), CodegenError> {
         if let Some(param) = params.iter().find(|param| !param.ty.is_resolved()) {
@@ -792,15 +889,21 @@ impl<'ctx> Codegen<'ctx> {
             Some(Type::I64) => self.context.i64_type().fn_type(&llvm_param_types, false),
             Some(Type::U8) => self.context.i8_type().fn_type(&llvm_param_types, false),
             Some(Type::U16) => self.context.i16_type().fn_type(&llvm_param_types, false),
-            Some(Type::U32) => self.context.i32_type().fn_type(&llvm_param_types, false),
+            Some(Type::U32 | Type::Char) => {
+                self.context.i32_type().fn_type(&llvm_param_types, false)
+            }
             Some(Type::U64) => self.context.i64_type().fn_type(&llvm_param_types, false),
             Some(Type::F32) => self.context.f32_type().fn_type(&llvm_param_types, false),
             Some(Type::F64) => self.context.f64_type().fn_type(&llvm_param_types, false),
-            Some(Type::String) => self
+            Some(Type::String | Type::Map(..) | Type::Set(_) | Type::Rng) => self
                 .context
                 .ptr_type(AddressSpace::default())
                 .fn_type(&llvm_param_types, false),
             Some(Type::Bool) => self.context.bool_type().fn_type(&llvm_param_types, false),
+            Some(ty @ (Type::Tuple(_) | Type::Option(_))) => self
+                .get_llvm_type(ty, return_type_span)?
+                .into_struct_type()
+                .fn_type(&llvm_param_types, false),
             Some(Type::Inferred) => {
                 return Err(CodegenError::internal_unsupported_function_return_type(
                     "inferred",
@@ -819,12 +922,16 @@ impl<'ctx> Codegen<'ctx> {
 
     fn parse_return_type(
         &self,
-        return_type: &str,
+        return_type: &ReturnType,
         span: crate::token::Span,
     ) -> Result<Option<Type>, CodegenError> {
-        Type::from_function_return_name(return_type).ok_or_else(|| {
-            CodegenError::internal_unsupported_function_return_type(return_type, span)
-        })
+        match return_type {
+            ReturnType::Void => Ok(None),
+            ReturnType::Value(ty) => Ok(Some(ty.clone())),
+            ReturnType::Unknown(name) => Err(
+                CodegenError::internal_unsupported_function_return_type(name, span),
+            ),
+        }
     }
 
     /// Generates the body of a user-defined function.
@@ -843,6 +950,8 @@ impl<'ctx> Codegen<'ctx> {
         fn_def: &FnDef,
     ) -> Result<(), CodegenError> {
         self.variables.clear();
+        self.deferred.clear();
+        self.temporaries.clear();
         self.loop_controls.clear();
         self.enter_variable_scope();
 
@@ -875,6 +984,10 @@ impl<'ctx> Codegen<'ctx> {
                     &context, param.span,
                 ));
             }
+            if let Some(pattern) = &param.pattern {
+                self.bind_pattern_value(pattern, &param.ty, llvm_param)?;
+                continue;
+            }
             let binding = VarBinding::new(
                 &self.builder,
                 self.context,
@@ -882,6 +995,7 @@ impl<'ctx> Codegen<'ctx> {
                 &param.name,
                 param.span,
             )?;
+            self.build_retain(llvm_param, &param.ty, param.span)?;
             self.builder
                 .build_store(binding.alloca(), llvm_param)
                 .map_err(|e| {
@@ -905,6 +1019,8 @@ impl<'ctx> Codegen<'ctx> {
             }
             self.generate_stmt(stmt)?;
         }
+        // Run function-level deferred bodies when control falls off the end.
+        self.generate_scope_cleanup_from(0, fn_def.span)?;
 
         let has_terminator = self
             .builder
@@ -912,14 +1028,14 @@ impl<'ctx> Codegen<'ctx> {
             .and_then(|bb| bb.get_terminator())
             .is_some();
         if !has_terminator {
-            if fn_def.return_type == "void" {
+            if fn_def.return_type == ReturnType::Void {
                 self.builder.build_return(None).map_err(|e| {
                     CodegenError::internal_return_build_failed(&fn_def.name, &e.to_string())
                 })?;
             } else {
                 return Err(CodegenError::internal_missing_return_in_non_void_function(
                     &fn_def.name,
-                    &fn_def.return_type,
+                    &fn_def.return_type.to_string(),
                 ));
             }
         }
@@ -929,23 +1045,37 @@ impl<'ctx> Codegen<'ctx> {
 
     /// Generates the `main` function from a Lak function definition.
     ///
-    /// Creates an LLVM function with the signature `int main()` that executes
-    /// all statements in the function body and returns 0 on success.
+    /// Creates an LLVM function with the signature `int main(int argc, char** argv)`
+    /// that hands the command-line arguments to the runtime and executes all
+    /// statements in the function body. A `void` main returns 0 on
+    /// success; an `i32` or `u8` main returns its value as the exit status.
     ///
     /// # Arguments
     ///
     /// * `main_fn_def` - The Lak `main` function definition
     fn generate_main(&mut self, main_fn_def: &FnDef) -> Result<(), CodegenError> {
         self.variables.clear();
+        self.deferred.clear();
+        self.temporaries.clear();
         self.loop_controls.clear();
         self.enter_variable_scope();
 
+        let return_ty =
+            self.parse_return_type(&main_fn_def.return_type, main_fn_def.return_type_span)?;
+        let returns_exit_code = return_ty.is_some();
+        self.function_return_types
+            .insert("main".to_string(), return_ty);
+
         let i32_type = self.context.i32_type();
-        let main_type = i32_type.fn_type(&[], false);
+        let ptr_type = self.context.ptr_type(AddressSpace::default());
+        let main_type = i32_type.fn_type(&[i32_type.into(), ptr_type.into()], false);
         let main_fn = self.module.add_function("main", main_type, None);
 
         let entry = self.context.append_basic_block(main_fn, "entry");
         self.builder.position_at_end(entry);
+        self.build_rc_init(main_fn_def.span)?;
+        self.build_args_init(main_fn, main_fn_def.span)?;
+        self.build_backtrace_root(main_fn_def.span)?;
 
         for stmt in &main_fn_def.body {
             let has_terminator = self
@@ -958,6 +1088,8 @@ impl<'ctx> Codegen<'ctx> {
             }
             self.generate_stmt(stmt)?;
         }
+        // Run function-level deferred bodies when control falls off the end.
+        self.generate_scope_cleanup_from(0, main_fn_def.span)?;
 
         let has_terminator = self
             .builder
@@ -965,6 +1097,12 @@ impl<'ctx> Codegen<'ctx> {
             .and_then(|bb| bb.get_terminator())
             .is_some();
         if !has_terminator {
+            if returns_exit_code {
+                return Err(CodegenError::internal_missing_return_in_non_void_function(
+                    &main_fn_def.name,
+                    &main_fn_def.return_type.to_string(),
+                ));
+            }
             let zero = i32_type.const_int(0, false);
             self.builder
                 .build_return(Some(&zero))
@@ -974,6 +1112,52 @@ impl<'ctx> Codegen<'ctx> {
         Ok(())
     }
 
+    /// Passes `main`'s `argc` and `argv` to the runtime (`lak_args_init`).
+    fn build_args_init(
+        &self,
+        main_fn: inkwell::values::FunctionValue<'ctx>,
+        span: crate::token::Span,
+    ) -> Result<(), CodegenError> {
+        let args_init = self
+            .module
+            .get_function("lak_args_init")
+            .ok_or_else(|| CodegenError::internal_builtin_not_found("lak_args_init"))?;
+        let args = main_fn
+            .get_param_iter()
+            .map(|param| param.into())
+            .collect::<Vec<_>>();
+        self.builder.build_call(args_init, &args, "").map_err(|e| {
+            CodegenError::internal_call_failed("lak_args_init", &e.to_string(), span)
+        })?;
+        Ok(())
+    }
+
+    /// Converts the value returned by `main` to the C `int` exit status.
+    ///
+    /// `i32` values are returned unchanged; `u8` values are zero-extended.
+    fn build_main_exit_code(
+        &self,
+        value: BasicValueEnum<'ctx>,
+        ty: &Type,
+        span: crate::token::Span,
+    ) -> Result<inkwell::values::IntValue<'ctx>, CodegenError> {
+        let BasicValueEnum::IntValue(value) = value else {
+            return Err(CodegenError::internal_non_integer_value(
+                "main return",
+                span,
+            ));
+        };
+        let i32_type = self.context.i32_type();
+        let exit_code = if ty.is_signed_integer() {
+            self.builder
+                .build_int_s_extend_or_bit_cast(value, i32_type, "exit_code")
+        } else {
+            self.builder
+                .build_int_z_extend_or_bit_cast(value, i32_type, "exit_code")
+        };
+        exit_code.map_err(|e| CodegenError::internal_main_exit_code_failed(&e.to_string(), span))
+    }
+
     /// Returns the LLVM type corresponding to a Lak type.
     ///
     /// # Type Mapping
@@ -990,6 +1174,11 @@ impl<'ctx> Codegen<'ctx> {
     /// - `Type::F64` → LLVM `f64`
     /// - `Type::String` → LLVM `ptr` (opaque pointer)
     /// - `Type::Bool` → LLVM `i1`
+    /// - `Type::Char` → LLVM `i32` holding the Unicode scalar value
+    /// - `Type::Tuple` → LLVM literal struct of the element types
+    /// - `Type::Option` → LLVM literal struct of the payload type and `i1`,
+    ///   which is true when there is a value
+    /// - `Type::Map` / `Type::Set` / `Type::Rng` → LLVM `ptr` (opaque pointer)
     /// - `Type::Inferred` → internal error (must be resolved before mapping)
     fn get_llvm_type(
         &self,
@@ -1007,8 +1196,25 @@ impl<'ctx> Codegen<'ctx> {
             Type::U64 => Ok(self.context.i64_type().into()),
             Type::F32 => Ok(self.context.f32_type().into()),
             Type::F64 => Ok(self.context.f64_type().into()),
-            Type::String => Ok(self.context.ptr_type(AddressSpace::default()).into()),
+            Type::String | Type::Map(..) | Type::Set(_) | Type::Rng => {
+                Ok(self.context.ptr_type(AddressSpace::default()).into())
+            }
             Type::Bool => Ok(self.context.bool_type().into()),
+            Type::Char => Ok(self.context.i32_type().into()),
+            Type::Tuple(elements) => {
+                let element_types = elements
+                    .iter()
+                    .map(|element| self.get_llvm_type(element, span))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(self.context.struct_type(&element_types, false).into())
+            }
+            Type::Option(payload) => {
+                let payload_type = self.get_llvm_type(payload, span)?;
+                Ok(self
+                    .context
+                    .struct_type(&[payload_type, self.context.bool_type().into()], false)
+                    .into())
+            }
             Type::Inferred => Err(CodegenError::internal_unresolved_inferred_type(
                 "LLVM type mapping",
                 span,
@@ -1016,6 +1222,34 @@ impl<'ctx> Codegen<'ctx> {
         }
     }
 
+    /// Looks up a function of a native standard library module imported
+    /// under `alias`. Returns `None` for aliases of Lak modules.
+    pub(crate) fn native_module_function(
+        &self,
+        alias: &str,
+        function: &str,
+        span: crate::token::Span,
+    ) -> Result<Option<&'static RuntimeFunction>, CodegenError> {
+        let Some(native) = self.native_module_aliases.get(alias) else {
+            return Ok(None);
+        };
+        native.function(function).map(Some).ok_or_else(|| {
+            CodegenError::internal_function_not_found(&format!("{}.{}", alias, function), span)
+        })
+    }
+
+    /// Looks up a numeric function of a native standard library module
+    /// imported under `alias`.
+    pub(crate) fn numeric_module_function(
+        &self,
+        alias: &str,
+        function: &str,
+    ) -> Option<&'static NumericFunction> {
+        self.native_module_aliases
+            .get(alias)
+            .and_then(|native| native.numeric_function(function))
+    }
+
     /// Resolves a module alias to its mangle prefix.
     ///
     /// Returns the mangle prefix for a given alias. If the alias is not
@@ -1034,13 +1268,105 @@ impl<'ctx> Codegen<'ctx> {
 
     fn enter_variable_scope(&mut self) {
         self.variables.push(HashMap::new());
+        self.deferred.push(Vec::new());
     }
 
+    /// Leaves the innermost variable scope.
+    ///
+    /// If control can still fall through the current block, the scope's
+    /// deferred bodies are generated first (in reverse registration order),
+    /// followed by releases of its reference-counted variables.
     fn exit_variable_scope(&mut self, span: crate::token::Span) -> Result<(), CodegenError> {
-        self.variables
-            .pop()
-            .map(|_| ())
-            .ok_or_else(|| CodegenError::internal_no_variable_scope(span))
+        let depth = self
+            .variables
+            .len()
+            .checked_sub(1)
+            .ok_or_else(|| CodegenError::internal_no_variable_scope(span))?;
+        let defer_result = self.generate_scope_cleanup_from(depth, span);
+        self.deferred.pop();
+        self.variables.pop();
+        defer_result
+    }
+
+    /// Registers a `defer` body in the innermost variable scope.
+    fn register_deferred_body(
+        &mut self,
+        body: &[Stmt],
+        span: crate::token::Span,
+    ) -> Result<(), CodegenError> {
+        let deferred = DeferredBody {
+            body: body.to_vec(),
+            scopes: self.variables.clone(),
+            span,
+        };
+        self.deferred
+            .last_mut()
+            .ok_or_else(|| CodegenError::internal_no_variable_scope(span))?
+            .push(deferred);
+        Ok(())
+    }
+
+    /// Generates the cleanup of every scope at or above `scope_depth`,
+    /// innermost scope first: the scope's deferred bodies in reverse
+    /// registration order, then releases of its reference-counted variables.
+    ///
+    /// Used on scope fallthrough and before `return`, `break` and `continue`.
+    /// Nothing is generated once the current block is terminated (e.g. after a
+    /// `panic` inside a deferred body).
+    fn generate_scope_cleanup_from(
+        &mut self,
+        scope_depth: usize,
+        span: crate::token::Span,
+    ) -> Result<(), CodegenError> {
+        for depth in (scope_depth..self.deferred.len()).rev() {
+            let pending: Vec<DeferredBody<'ctx>> =
+                self.deferred[depth].iter().rev().cloned().collect();
+            for deferred in &pending {
+                if self.current_block_is_terminated() {
+                    return Ok(());
+                }
+                self.generate_deferred_body(deferred)?;
+            }
+            if self.current_block_is_terminated() {
+                return Ok(());
+            }
+            self.release_scope_variables(depth, span)?;
+        }
+        Ok(())
+    }
+
+    /// Generates one deferred body against the scopes captured at its `defer`.
+    fn generate_deferred_body(
+        &mut self,
+        deferred: &DeferredBody<'ctx>,
+    ) -> Result<(), CodegenError> {
+        let saved_variables = std::mem::replace(&mut self.variables, deferred.scopes.clone());
+        let saved_deferred =
+            std::mem::replace(&mut self.deferred, vec![Vec::new(); deferred.scopes.len()]);
+        let saved_loop_controls = std::mem::take(&mut self.loop_controls);
+
+        self.enter_variable_scope();
+        let body_result = (|| -> Result<(), CodegenError> {
+            for stmt in &deferred.body {
+                let has_terminator = self
+                    .builder
+                    .get_insert_block()
+                    .and_then(|bb| bb.get_terminator())
+                    .is_some();
+                if has_terminator {
+                    break;
+                }
+                self.generate_stmt(stmt)?;
+            }
+            Ok(())
+        })();
+        let exit_result = self.exit_variable_scope(deferred.span);
+
+        self.variables = saved_variables;
+        self.deferred = saved_deferred;
+        self.loop_controls = saved_loop_controls;
+
+        body_result.and(exit_result)
     }
 
     fn variable_in_current_scope(&self, name: &str) -> bool {
@@ -1078,6 +1404,8 @@ impl<'ctx> Codegen<'ctx> {
         self.loop_controls.push(LoopControl {
             continue_block,
             break_block,
+            scope_depth: self.variables.len(),
+            temporary_depth: self.temporaries.len(),
         });
     }
 
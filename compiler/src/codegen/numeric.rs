@@ -0,0 +1,244 @@
+//! Code generation for numeric functions of native standard modules
+//! (e.g. `math.sqrt`, `math.max`).
+//!
+//! Calls are lowered inline rather than to runtime functions. Floating-point
+//! operations map to LLVM intrinsics (`llvm.sqrt.f64`, `llvm.minnum.f32`,
+//! ...), and integer `min`/`max` to `llvm.smin`, `llvm.umax`, etc. Integer
+//! `abs` panics on overflow like unary `-`, and `clamp` panics if `low` is
+//! greater than `high`.
+//!
+//! Semantic analysis has already checked that the operand type is one the
+//! function is defined for, so other types are internal errors.
+
+use super::Codegen;
+use super::error::CodegenError;
+use crate::ast::{Expr, Type};
+use crate::stdlib::{NumericFunction, NumericOp};
+use crate::token::Span;
+use inkwell::intrinsics::Intrinsic;
+use inkwell::values::{BasicMetadataValueEnum, BasicValueEnum, FloatValue, IntValue, ValueKind};
+use inkwell::{FloatPredicate, IntPredicate};
+
+/// Panic message of `clamp` with `low > high`.
+const CLAMP_RANGE_MESSAGE: &str = "clamp called with low greater than high";
+
+impl<'ctx> Codegen<'ctx> {
+    /// Returns the argument whose type is the operand type of a numeric
+    /// function call without an expected type.
+    ///
+    /// Mirrors `SemanticAnalyzer::check_numeric_call`.
+    pub(super) fn numeric_operand<'a>(
+        callee: &str,
+        function: &NumericFunction,
+        args: &'a [Expr],
+        span: Span,
+    ) -> Result<&'a Expr, CodegenError> {
+        Expr::numeric_operand_index(args)
+            .map(|index| &args[index])
+            .ok_or_else(|| {
+                CodegenError::internal_call_arg_count_mismatch(
+                    callee,
+                    function.params.len(),
+                    args.len(),
+                    span,
+                )
+            })
+    }
+
+    /// Generates a call of a numeric function whose operand and result type
+    /// is `ty`.
+    pub(super) fn generate_numeric_call(
+        &mut self,
+        callee: &str,
+        function: &NumericFunction,
+        args: &[Expr],
+        ty: &Type,
+        span: Span,
+    ) -> Result<BasicValueEnum<'ctx>, CodegenError> {
+        if args.len() != function.params.len() {
+            return Err(CodegenError::internal_call_arg_count_mismatch(
+                callee,
+                function.params.len(),
+                args.len(),
+                span,
+            ));
+        }
+        if !function.types.contains(ty) {
+            return Err(CodegenError::internal_numeric_function_type(
+                callee, ty, span,
+            ));
+        }
+
+        let values = args
+            .iter()
+            .map(|arg| self.generate_expr_value(arg, ty))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if ty.is_float() {
+            let values = values
+                .into_iter()
+                .map(|value| match value {
+                    BasicValueEnum::FloatValue(v) => Ok(v),
+                    _ => Err(CodegenError::internal_non_float_value(callee, span)),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            self.generate_float_numeric_op(function.op, &values, span)
+        } else {
+            let values = values
+                .into_iter()
+                .map(|value| match value {
+                    BasicValueEnum::IntValue(v) => Ok(v),
+                    _ => Err(CodegenError::internal_non_integer_value(callee, span)),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            self.generate_int_numeric_op(callee, function.op, &values, ty, span)
+        }
+    }
+
+    fn generate_float_numeric_op(
+        &mut self,
+        op: NumericOp,
+        values: &[FloatValue<'ctx>],
+        span: Span,
+    ) -> Result<BasicValueEnum<'ctx>, CodegenError> {
+        let intrinsic = match op {
+            NumericOp::Sqrt => "llvm.sqrt",
+            NumericOp::Pow => "llvm.pow",
+            NumericOp::Sin => "llvm.sin",
+            NumericOp::Cos => "llvm.cos",
+            NumericOp::Exp => "llvm.exp",
+            NumericOp::Log => "llvm.log",
+            NumericOp::Floor => "llvm.floor",
+            NumericOp::Ceil => "llvm.ceil",
+            NumericOp::Abs => "llvm.fabs",
+            NumericOp::Min => "llvm.minnum",
+            NumericOp::Max => "llvm.maxnum",
+            NumericOp::Clamp => {
+                let (x, low, high) = (values[0], values[1], values[2]);
+                let out_of_order = self
+                    .builder
+                    .build_float_compare(FloatPredicate::OGT, low, high, "clamp_range")
+                    .map_err(|e| CodegenError::internal_compare_failed(&e.to_string(), span))?;
+                self.generate_clamp_range_check(out_of_order, span)?;
+                let lower =
+                    self.call_numeric_intrinsic("llvm.maxnum", &[x.into(), low.into()], span)?;
+                return self.call_numeric_intrinsic("llvm.minnum", &[lower, high.into()], span);
+            }
+        };
+        let args: Vec<BasicValueEnum<'ctx>> = values.iter().map(|&v| v.into()).collect();
+        self.call_numeric_intrinsic(intrinsic, &args, span)
+    }
+
+    fn generate_int_numeric_op(
+        &mut self,
+        callee: &str,
+        op: NumericOp,
+        values: &[IntValue<'ctx>],
+        ty: &Type,
+        span: Span,
+    ) -> Result<BasicValueEnum<'ctx>, CodegenError> {
+        let signed = ty.is_signed_integer();
+        let (min, max) = if signed {
+            ("llvm.smin", "llvm.smax")
+        } else {
+            ("llvm.umin", "llvm.umax")
+        };
+        match op {
+            NumericOp::Abs if signed => {
+                let x = values[0];
+                let zero = x.get_type().const_int(0, false);
+                // Only the minimum value overflows, and its absolute value
+                // overflows too, so checking the negation is enough.
+                let negated =
+                    self.generate_overflow_checked_binop(zero, x, "llvm.ssub.with.overflow", span)?;
+                let is_negative = self
+                    .builder
+                    .build_int_compare(IntPredicate::SLT, x, zero, "abs_neg")
+                    .map_err(|e| CodegenError::internal_compare_failed(&e.to_string(), span))?;
+                self.builder
+                    .build_select(is_negative, negated, x, "abs")
+                    .map_err(|e| {
+                        CodegenError::internal_numeric_call_failed(callee, &e.to_string(), span)
+                    })
+            }
+            NumericOp::Min => {
+                self.call_numeric_intrinsic(min, &[values[0].into(), values[1].into()], span)
+            }
+            NumericOp::Max => {
+                self.call_numeric_intrinsic(max, &[values[0].into(), values[1].into()], span)
+            }
+            NumericOp::Clamp => {
+                let (x, low, high) = (values[0], values[1], values[2]);
+                let predicate = if signed {
+                    IntPredicate::SGT
+                } else {
+                    IntPredicate::UGT
+                };
+                let out_of_order = self
+                    .builder
+                    .build_int_compare(predicate, low, high, "clamp_range")
+                    .map_err(|e| CodegenError::internal_compare_failed(&e.to_string(), span))?;
+                self.generate_clamp_range_check(out_of_order, span)?;
+                let lower = self.call_numeric_intrinsic(max, &[x.into(), low.into()], span)?;
+                self.call_numeric_intrinsic(min, &[lower, high.into()], span)
+            }
+            NumericOp::Abs
+            | NumericOp::Sqrt
+            | NumericOp::Pow
+            | NumericOp::Sin
+            | NumericOp::Cos
+            | NumericOp::Exp
+            | NumericOp::Log
+            | NumericOp::Floor
+            | NumericOp::Ceil => Err(CodegenError::internal_numeric_function_type(
+                callee, ty, span,
+            )),
+        }
+    }
+
+    /// Panics if `clamp` was called with `low > high`.
+    fn generate_clamp_range_check(
+        &mut self,
+        out_of_order: IntValue<'ctx>,
+        span: Span,
+    ) -> Result<(), CodegenError> {
+        self.generate_runtime_check_branch(
+            out_of_order,
+            "clamp_panic",
+            "clamp_safe",
+            CLAMP_RANGE_MESSAGE,
+            "clamp_msg",
+            span,
+        )
+    }
+
+    /// Calls the overloaded LLVM intrinsic `name` for the type of the first
+    /// argument.
+    fn call_numeric_intrinsic(
+        &mut self,
+        name: &str,
+        args: &[BasicValueEnum<'ctx>],
+        span: Span,
+    ) -> Result<BasicValueEnum<'ctx>, CodegenError> {
+        let intrinsic = Intrinsic::find(name)
+            .ok_or_else(|| CodegenError::internal_intrinsic_not_found(name, span))?;
+        let function = intrinsic
+            .get_declaration(&self.module, &[args[0].get_type()])
+            .ok_or_else(|| CodegenError::internal_intrinsic_declaration_failed(name, span))?;
+        let args: Vec<BasicMetadataValueEnum<'ctx>> = args.iter().map(|&v| v.into()).collect();
+        let call = self
+            .builder
+            .build_call(function, &args, "math")
+            .map_err(|e| {
+                CodegenError::internal_intrinsic_call_failed(name, &e.to_string(), span)
+            })?;
+        match call.try_as_basic_value() {
+            ValueKind::Basic(value) => Ok(value),
+            ValueKind::Instruction(_) => Err(CodegenError::internal_intrinsic_call_failed(
+                name,
+                "intrinsic returned void",
+                span,
+            )),
+        }
+    }
+}
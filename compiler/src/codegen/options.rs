@@ -0,0 +1,107 @@
+//! `Option<T>` code generation.
+//!
+//! An option is an LLVM struct of its payload and an `i1` that is true when
+//! there is a value, the same layout as the `(T, bool)` tuple returned by
+//! `map.get` before options existed. The payload is always valid, so an
+//! empty `Option<string>` holds the empty string and reference counting
+//! never needs to look at the flag. Method calls (see [`crate::options`])
+//! are generated inline.
+
+use super::Codegen;
+use super::error::CodegenError;
+use crate::ast::{Expr, Type};
+use crate::options::OptionMethod;
+use crate::token::Span;
+use inkwell::values::BasicValueEnum;
+
+impl<'ctx> Codegen<'ctx> {
+    /// Returns the type of `variable` if it names an `Option` variable in
+    /// scope, so that `variable.f(...)` is a method call.
+    pub(super) fn option_variable_type(&self, variable: &str) -> Option<Type> {
+        let ty = self.lookup_variable(variable)?.ty();
+        ty.is_option().then(|| ty.clone())
+    }
+
+    /// Returns the type of the value of `variable.method(...)` on an option
+    /// of type `ty`.
+    pub(super) fn option_method_value_type(
+        variable: &str,
+        method: &str,
+        ty: &Type,
+        span: Span,
+    ) -> Result<Type, CodegenError> {
+        OptionMethod::lookup(ty, method)
+            .map(|method_kind| method_kind.return_type(ty))
+            .ok_or_else(|| {
+                CodegenError::internal_collection_method_not_found(variable, method, ty, span)
+            })
+    }
+
+    /// Generates the method call `variable.method(args)` on an `Option`
+    /// variable and returns its value.
+    ///
+    /// The value of `unwrap_or` is borrowed, either from the variable like
+    /// any variable read or from the default argument.
+    pub(super) fn generate_option_method(
+        &mut self,
+        variable: &str,
+        method: &str,
+        args: &[Expr],
+        span: Span,
+    ) -> Result<BasicValueEnum<'ctx>, CodegenError> {
+        let binding = self
+            .lookup_variable(variable)
+            .cloned()
+            .ok_or_else(|| CodegenError::internal_variable_not_found(variable, span))?;
+        let ty = binding.ty();
+        let method_kind = OptionMethod::lookup(ty, method).ok_or_else(|| {
+            CodegenError::internal_collection_method_not_found(variable, method, ty, span)
+        })?;
+        let param_types = method_kind.param_types(ty);
+        if args.len() != param_types.len() {
+            return Err(CodegenError::internal_call_arg_count_mismatch(
+                &format!("{}.{}", variable, method),
+                param_types.len(),
+                args.len(),
+                span,
+            ));
+        }
+
+        let callee = format!("{}.{}", variable, method);
+        let map_err = |e: inkwell::builder::BuilderError| {
+            CodegenError::internal_call_failed(&callee, &e.to_string(), span)
+        };
+        let option_type = self.get_llvm_type(ty, span)?;
+        let option = self
+            .builder
+            .build_load(option_type, binding.alloca(), &format!("{}_load", variable))
+            .map_err(|e| {
+                CodegenError::internal_variable_load_failed(variable, &e.to_string(), span)
+            })?
+            .into_struct_value();
+        let has_value = self
+            .builder
+            .build_extract_value(option, 1, "has_value")
+            .map_err(map_err)?
+            .into_int_value();
+
+        match method_kind {
+            OptionMethod::IsSome => Ok(has_value.into()),
+            OptionMethod::IsNone => Ok(self
+                .builder
+                .build_not(has_value, "is_none")
+                .map_err(map_err)?
+                .into()),
+            OptionMethod::UnwrapOr => {
+                let default = self.generate_expr_value(&args[0], &param_types[0])?;
+                let payload = self
+                    .builder
+                    .build_extract_value(option, 0, "payload")
+                    .map_err(map_err)?;
+                self.builder
+                    .build_select(has_value, payload, default, "unwrap_or")
+                    .map_err(map_err)
+            }
+        }
+    }
+}
@@ -0,0 +1,291 @@
+//! Reference counting code generation.
+//!
+//! Heap values (strings, maps, sets and random number generators) are
+//! reference-counted by the runtime (`lak_retain` / `lak_release`; maps and
+//! sets are released with `lak_map_release` / `lak_set_release`, which also
+//! release their contents). This module inserts those calls so every heap object is freed
+//! once its last owner is gone.
+//!
+//! # Ownership rules
+//!
+//! - Expression values are *borrowed*. Values returned to the caller with an
+//!   owned reference (`format` results, user function results) are registered
+//!   as temporaries and released at the end of the enclosing statement.
+//! - Copying a value into a variable, a parameter or a return value retains
+//!   it; reassignment releases the previous value.
+//! - Variables are released when their scope exits, including on the
+//!   `return`, `break` and `continue` paths, after the scope's deferred bodies.
+//! - Values of `if` and block expressions are retained before the inner scope
+//!   exits and registered as temporaries of the enclosing statement.
+//!
+//! String literals are emitted as static objects with an immortal reference
+//! count, so they can flow anywhere a heap string can.
+
+use super::Codegen;
+use super::error::CodegenError;
+use crate::ast::Type;
+use crate::token::Span;
+use inkwell::AddressSpace;
+use inkwell::module::Linkage;
+use inkwell::values::{BasicValueEnum, PointerValue};
+
+/// Reference count of a static object; must match `IMMORTAL` in `lak-runtime`.
+const IMMORTAL_COUNT: u64 = u64::MAX;
+
+/// Returns true if values of `ty` hold references to heap objects.
+pub(super) fn type_needs_rc(ty: &Type) -> bool {
+    match ty {
+        Type::String | Type::Map(..) | Type::Set(_) | Type::Rng => true,
+        Type::Tuple(elements) => elements.iter().any(type_needs_rc),
+        Type::Option(payload) => type_needs_rc(payload),
+        _ => false,
+    }
+}
+
+impl<'ctx> Codegen<'ctx> {
+    /// Declares the Lak runtime reference counting functions for use in generated code.
+    ///
+    /// This creates external function declarations with the signatures:
+    /// - `void lak_rc_init()`
+    /// - `void lak_retain(ptr object)`
+    /// - `void lak_release(ptr object)`
+    pub(super) fn declare_lak_rc_builtins(&self) {
+        let void_type = self.context.void_type();
+        let ptr_type = self.context.ptr_type(AddressSpace::default());
+
+        let init_type = void_type.fn_type(&[], false);
+        self.module
+            .add_function("lak_rc_init", init_type, Some(Linkage::External));
+
+        let fn_type = void_type.fn_type(&[ptr_type.into()], false);
+        for name in ["lak_retain", "lak_release"] {
+            self.module
+                .add_function(name, fn_type, Some(Linkage::External));
+        }
+    }
+
+    /// Calls `lak_rc_init` so the runtime sets up leak checking before any
+    /// Lak code runs.
+    pub(super) fn build_rc_init(&self, span: Span) -> Result<(), CodegenError> {
+        let function = self
+            .module
+            .get_function("lak_rc_init")
+            .ok_or_else(|| CodegenError::internal_builtin_not_found("lak_rc_init"))?;
+        self.builder
+            .build_call(function, &[], "")
+            .map_err(|e| CodegenError::internal_call_failed("lak_rc_init", &e.to_string(), span))?;
+        Ok(())
+    }
+
+    /// Emits a string literal as a static object and returns a pointer to its bytes.
+    ///
+    /// The global has the runtime object layout `{ i64 count, i64 size, [n x i8] }`
+    /// with an immortal count, so retain and release leave it untouched. The
+    /// lexer rejects NUL characters in literals, so the bytes read as a C
+    /// string are exactly `text`.
+    pub(super) fn build_string_object(
+        &self,
+        text: &str,
+        span: Span,
+    ) -> Result<PointerValue<'ctx>, CodegenError> {
+        if text.contains('\0') {
+            return Err(CodegenError::internal_string_ptr_failed(
+                "string literal contains a NUL character",
+                span,
+            ));
+        }
+        let i64_type = self.context.i64_type();
+        let bytes = self.context.const_string(text.as_bytes(), true);
+        let size = text.len() as u64 + 1;
+
+        let object_type = self.context.struct_type(
+            &[i64_type.into(), i64_type.into(), bytes.get_type().into()],
+            false,
+        );
+        let initializer = object_type.const_named_struct(&[
+            i64_type.const_int(IMMORTAL_COUNT, false).into(),
+            i64_type.const_int(size, false).into(),
+            bytes.into(),
+        ]);
+
+        let global = self.module.add_global(object_type, None, "str");
+        global.set_initializer(&initializer);
+        global.set_constant(true);
+        global.set_linkage(Linkage::Private);
+        global.set_unnamed_addr(true);
+
+        self.builder
+            .build_struct_gep(object_type, global.as_pointer_value(), 2, "str_bytes")
+            .map_err(|e| CodegenError::internal_string_ptr_failed(&e.to_string(), span))
+    }
+
+    /// Adds an owner to every heap object referenced by `value`.
+    pub(super) fn build_retain(
+        &self,
+        value: BasicValueEnum<'ctx>,
+        ty: &Type,
+        span: Span,
+    ) -> Result<(), CodegenError> {
+        self.build_rc_call("lak_retain", value, ty, span)
+    }
+
+    /// Removes an owner from every heap object referenced by `value`.
+    pub(super) fn build_release(
+        &self,
+        value: BasicValueEnum<'ctx>,
+        ty: &Type,
+        span: Span,
+    ) -> Result<(), CodegenError> {
+        self.build_rc_call("lak_release", value, ty, span)
+    }
+
+    /// Calls `runtime_name` on each heap object in `value`, descending into
+    /// tuples and options.
+    ///
+    /// An option always holds a valid payload, even when it has no value, so
+    /// its payload is retained and released unconditionally.
+    fn build_rc_call(
+        &self,
+        runtime_name: &str,
+        value: BasicValueEnum<'ctx>,
+        ty: &Type,
+        span: Span,
+    ) -> Result<(), CodegenError> {
+        match (ty, value) {
+            (
+                Type::String | Type::Map(..) | Type::Set(_) | Type::Rng,
+                BasicValueEnum::PointerValue(ptr),
+            ) => {
+                let runtime_name = match (runtime_name, ty) {
+                    ("lak_release", Type::Map(..)) => "lak_map_release",
+                    ("lak_release", Type::Set(_)) => "lak_set_release",
+                    _ => runtime_name,
+                };
+                let function = self
+                    .module
+                    .get_function(runtime_name)
+                    .ok_or_else(|| CodegenError::internal_builtin_not_found(runtime_name))?;
+                self.builder
+                    .build_call(function, &[ptr.into()], "")
+                    .map_err(|e| {
+                        CodegenError::internal_call_failed(runtime_name, &e.to_string(), span)
+                    })?;
+                Ok(())
+            }
+            (Type::Tuple(elements), BasicValueEnum::StructValue(tuple)) => {
+                for (index, element_ty) in elements.iter().enumerate() {
+                    if !type_needs_rc(element_ty) {
+                        continue;
+                    }
+                    let element = self
+                        .builder
+                        .build_extract_value(tuple, index as u32, "rc_elem")
+                        .map_err(|e| {
+                            CodegenError::internal_tuple_extract_failed(index, &e.to_string(), span)
+                        })?;
+                    self.build_rc_call(runtime_name, element, element_ty, span)?;
+                }
+                Ok(())
+            }
+            (Type::Option(payload_ty), BasicValueEnum::StructValue(option)) => {
+                if !type_needs_rc(payload_ty) {
+                    return Ok(());
+                }
+                let payload = self
+                    .builder
+                    .build_extract_value(option, 0, "rc_payload")
+                    .map_err(|e| {
+                        CodegenError::internal_tuple_extract_failed(0, &e.to_string(), span)
+                    })?;
+                self.build_rc_call(runtime_name, payload, payload_ty, span)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Starts a frame collecting the temporaries of a statement or branch.
+    pub(super) fn push_temporary_frame(&mut self) {
+        self.temporaries.push(Vec::new());
+    }
+
+    /// Ends the innermost temporary frame, releasing its temporaries if
+    /// control can still fall through the current block.
+    pub(super) fn pop_temporary_frame(&mut self, span: Span) -> Result<(), CodegenError> {
+        let depth = self
+            .temporaries
+            .len()
+            .checked_sub(1)
+            .ok_or_else(|| CodegenError::internal_no_temporary_frame(span))?;
+        let release_result = self.release_temporaries_from(depth, span);
+        self.temporaries.pop();
+        release_result
+    }
+
+    /// Registers an owned value to be released when the innermost temporary
+    /// frame ends. Values without heap references are ignored.
+    pub(super) fn register_temporary(
+        &mut self,
+        value: BasicValueEnum<'ctx>,
+        ty: &Type,
+        span: Span,
+    ) -> Result<(), CodegenError> {
+        if !type_needs_rc(ty) {
+            return Ok(());
+        }
+        self.temporaries
+            .last_mut()
+            .ok_or_else(|| CodegenError::internal_no_temporary_frame(span))?
+            .push((value, ty.clone()));
+        Ok(())
+    }
+
+    /// Releases the temporaries of every frame at or above `depth`, innermost
+    /// frame first, without ending the frames.
+    ///
+    /// Used when a frame ends and before `return`, `break` and `continue`.
+    pub(super) fn release_temporaries_from(
+        &self,
+        depth: usize,
+        span: Span,
+    ) -> Result<(), CodegenError> {
+        if self.current_block_is_terminated() {
+            return Ok(());
+        }
+        for frame in self.temporaries.iter().skip(depth).rev() {
+            for (value, ty) in frame.iter().rev() {
+                self.build_release(*value, ty, span)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Releases the variables defined in the variable scope at `depth`.
+    ///
+    /// Variables are released in name order so the generated IR is deterministic.
+    pub(super) fn release_scope_variables(
+        &self,
+        depth: usize,
+        span: Span,
+    ) -> Result<(), CodegenError> {
+        let Some(scope) = self.variables.get(depth) else {
+            return Err(CodegenError::internal_no_variable_scope(span));
+        };
+        let mut owned: Vec<_> = scope
+            .iter()
+            .filter(|(_, binding)| type_needs_rc(binding.ty()))
+            .collect();
+        owned.sort_by(|a, b| a.0.cmp(b.0));
+
+        for (name, binding) in owned {
+            let llvm_type = self.get_llvm_type(binding.ty(), span)?;
+            let value = self
+                .builder
+                .build_load(llvm_type, binding.alloca(), &format!("{}_release", name))
+                .map_err(|e| {
+                    CodegenError::internal_variable_load_failed(name, &e.to_string(), span)
+                })?;
+            self.build_release(value, binding.ty(), span)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+//! `rng` code generation.
+//!
+//! A generator is a pointer to a runtime object holding the generator
+//! state, reference-counted like a string. Every method advances the
+//! generator, so it first calls `lak_rng_unique` and stores the result back
+//! into the variable: a copy of a generator shares the object until one of
+//! them draws a number and then continues the sequence on its own. Method
+//! calls (see [`crate::rng`]) lower to the `lak_rng_*` runtime functions
+//! declared in `builtins.rs`; `shuffle` calls `lak_map_shuffle` or
+//! `lak_set_shuffle`.
+
+use super::Codegen;
+use super::error::CodegenError;
+use crate::ast::{Expr, Type};
+use crate::rng::RngMethod;
+use crate::token::Span;
+use inkwell::AddressSpace;
+use inkwell::values::{BasicMetadataValueEnum, BasicValueEnum};
+
+impl<'ctx> Codegen<'ctx> {
+    /// Returns true if `variable` names an `rng` variable in scope, so that
+    /// `variable.f(...)` is a method call.
+    pub(super) fn is_rng_variable(&self, variable: &str) -> bool {
+        self.lookup_variable(variable)
+            .is_some_and(|binding| *binding.ty() == Type::Rng)
+    }
+
+    /// Returns the type of the value of `variable.method(args)` on an `rng`.
+    ///
+    /// `shuffle` returns a value of the type of its argument, which
+    /// `arg_type` determines.
+    pub(super) fn rng_method_value_type(
+        variable: &str,
+        method: &str,
+        args: &[Expr],
+        span: Span,
+        arg_type: impl FnOnce(&Expr) -> Result<Type, CodegenError>,
+    ) -> Result<Type, CodegenError> {
+        let method_kind = RngMethod::lookup(method).ok_or_else(|| {
+            CodegenError::internal_collection_method_not_found(variable, method, &Type::Rng, span)
+        })?;
+        if let Some(return_ty) = method_kind.return_type() {
+            return Ok(return_ty);
+        }
+        let items = args.first().ok_or_else(|| {
+            CodegenError::internal_call_arg_count_mismatch(
+                &format!("{}.{}", variable, method),
+                method_kind.arg_count(),
+                args.len(),
+                span,
+            )
+        })?;
+        arg_type(items)
+    }
+
+    /// Generates the method call `variable.method(args)` on an `rng`
+    /// variable and returns its value.
+    ///
+    /// The arguments are evaluated before the generator is loaded, so an
+    /// argument drawing from the same generator is seen by the call. The
+    /// collection returned by `shuffle` is owned and registered as a
+    /// temporary of the enclosing statement.
+    pub(super) fn generate_rng_method(
+        &mut self,
+        variable: &str,
+        method: &str,
+        args: &[Expr],
+        span: Span,
+    ) -> Result<BasicValueEnum<'ctx>, CodegenError> {
+        let binding = self
+            .lookup_variable(variable)
+            .cloned()
+            .ok_or_else(|| CodegenError::internal_variable_not_found(variable, span))?;
+        let method_kind = RngMethod::lookup(method).ok_or_else(|| {
+            CodegenError::internal_collection_method_not_found(variable, method, &Type::Rng, span)
+        })?;
+        if args.len() != method_kind.arg_count() {
+            return Err(CodegenError::internal_call_arg_count_mismatch(
+                &format!("{}.{}", variable, method),
+                method_kind.arg_count(),
+                args.len(),
+                span,
+            ));
+        }
+
+        let (runtime_name, items_ty, mut call_args) = match method_kind.param_types() {
+            Some(param_types) => {
+                let mut call_args: Vec<BasicMetadataValueEnum<'ctx>> = Vec::new();
+                for (arg, param_ty) in args.iter().zip(&param_types) {
+                    call_args.push(self.generate_expr_value(arg, param_ty)?.into());
+                }
+                (format!("lak_rng_{}", method), None, call_args)
+            }
+            None => {
+                let items_ty = self.infer_expr_type_for_comparison(&args[0])?;
+                let runtime_name = match items_ty {
+                    Type::Map(..) => "lak_map_shuffle",
+                    Type::Set(_) => "lak_set_shuffle",
+                    _ => {
+                        return Err(CodegenError::internal_collection_method_not_found(
+                            variable,
+                            method,
+                            &Type::Rng,
+                            span,
+                        ));
+                    }
+                };
+                let items = self.generate_expr_value(&args[0], &items_ty)?;
+                (runtime_name.to_string(), Some(items_ty), vec![items.into()])
+            }
+        };
+
+        let rng = self
+            .builder
+            .build_load(
+                self.context.ptr_type(AddressSpace::default()),
+                binding.alloca(),
+                &format!("{}_load", variable),
+            )
+            .map_err(|e| {
+                CodegenError::internal_variable_load_failed(variable, &e.to_string(), span)
+            })?;
+        let rng = self
+            .build_collection_call("lak_rng_unique", &[rng.into()], span)?
+            .ok_or_else(|| CodegenError::internal_call_returned_void("lak_rng_unique", span))?;
+        self.builder
+            .build_store(binding.alloca(), rng)
+            .map_err(|e| {
+                CodegenError::internal_variable_store_failed(variable, &e.to_string(), span)
+            })?;
+
+        if items_ty.is_some() {
+            call_args.push(rng.into());
+        } else {
+            call_args.insert(0, rng.into());
+        }
+        if method_kind.takes_location() {
+            call_args.extend(self.build_call_location(span)?);
+        }
+        let value = self
+            .build_collection_call(&runtime_name, &call_args, span)?
+            .ok_or_else(|| CodegenError::internal_call_returned_void(&runtime_name, span))?;
+        if let Some(items_ty) = items_ty {
+            self.register_temporary(value, &items_ty, span)?;
+        }
+        Ok(value)
+    }
+}
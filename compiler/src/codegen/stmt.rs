@@ -1,20 +1,33 @@
 //! Statement code generation.
 //!
 //! This module implements code generation for Lak statements, including
-//! expression statements, `let` bindings, reassignment statements,
-//! `let _ = ...` discard statements, `return` statements, and control flow
-//! (`if`, `while`, `break`, `continue`).
+//! expression statements, `let` bindings (including destructuring
+//! patterns), reassignment statements,
+//! `let _ = ...` discard statements, `return` statements, control flow
+//! (`if`, `while`, `break`, `continue`), and `defer` registration.
 
 use super::Codegen;
 use super::binding::VarBinding;
 use super::error::CodegenError;
-use crate::ast::{BinaryOperator, Expr, ExprKind, Stmt, StmtKind, Type, UnaryOperator};
+use crate::ast::{
+    BinaryOperator, Expr, ExprKind, Pattern, PatternKind, Stmt, StmtKind, Type, UnaryOperator,
+};
 use crate::token::Span;
 use inkwell::values::BasicValueEnum;
 
 impl<'ctx> Codegen<'ctx> {
     /// Generates LLVM IR for a single statement.
+    ///
+    /// Owned temporaries produced by the statement are released once it
+    /// completes.
     pub(super) fn generate_stmt(&mut self, stmt: &Stmt) -> Result<(), CodegenError> {
+        self.push_temporary_frame();
+        let stmt_result = self.generate_stmt_kind(stmt);
+        let release_result = self.pop_temporary_frame(stmt.span);
+        stmt_result.and(release_result)
+    }
+
+    fn generate_stmt_kind(&mut self, stmt: &Stmt) -> Result<(), CodegenError> {
         match &stmt.kind {
             StmtKind::Expr(expr) => {
                 self.generate_expr(expr)?;
@@ -28,6 +41,9 @@ impl<'ctx> Codegen<'ctx> {
                 ty,
                 init,
             } => self.generate_let(*is_mutable, name, ty, init, stmt.span),
+            StmtKind::LetPattern {
+                pattern, ty, init, ..
+            } => self.generate_let_pattern(pattern, ty, init, stmt.span),
             StmtKind::Assign { name, value } => self.generate_assign(name, value, stmt.span),
             StmtKind::If {
                 condition,
@@ -37,6 +53,7 @@ impl<'ctx> Codegen<'ctx> {
             StmtKind::While { condition, body } => self.generate_while(condition, body, stmt.span),
             StmtKind::Break => self.generate_break(stmt.span),
             StmtKind::Continue => self.generate_continue(stmt.span),
+            StmtKind::Defer(body) => self.register_deferred_body(body, stmt.span),
         }
     }
 
@@ -77,6 +94,7 @@ impl<'ctx> Codegen<'ctx> {
         let binding = VarBinding::new(&self.builder, self.context, &resolved_ty, name, span)?;
 
         let init_value = self.generate_expr_value(init, &resolved_ty)?;
+        self.build_retain(init_value, &resolved_ty, span)?;
 
         self.builder
             .build_store(binding.alloca(), init_value)
@@ -89,6 +107,84 @@ impl<'ctx> Codegen<'ctx> {
         Ok(())
     }
 
+    /// Generates LLVM IR for a destructuring `let` statement.
+    ///
+    /// The initializer is evaluated once as a whole value and then split
+    /// into the pattern's bindings with `extractvalue`.
+    fn generate_let_pattern(
+        &mut self,
+        pattern: &Pattern,
+        ty: &Type,
+        init: &Expr,
+        span: Span,
+    ) -> Result<(), CodegenError> {
+        let resolved_ty =
+            self.resolve_let_type_for_codegen(&pattern.to_string(), ty, init, span)?;
+        let init_value = self.generate_expr_value(init, &resolved_ty)?;
+        self.bind_pattern_value(pattern, &resolved_ty, init_value)
+    }
+
+    /// Binds the variables of `pattern` to the parts of `value`.
+    ///
+    /// Each binding gets its own stack slot in the current scope. Wildcards
+    /// discard their part of the value. Semantic analysis guarantees the
+    /// pattern shape matches `ty`.
+    pub(super) fn bind_pattern_value(
+        &mut self,
+        pattern: &Pattern,
+        ty: &Type,
+        value: BasicValueEnum<'ctx>,
+    ) -> Result<(), CodegenError> {
+        match (&pattern.kind, ty) {
+            (PatternKind::Binding(name), _) => {
+                let binding = VarBinding::new(&self.builder, self.context, ty, name, pattern.span)?;
+                self.build_retain(value, ty, pattern.span)?;
+                self.builder
+                    .build_store(binding.alloca(), value)
+                    .map_err(|e| {
+                        CodegenError::internal_variable_store_failed(
+                            name,
+                            &e.to_string(),
+                            pattern.span,
+                        )
+                    })?;
+                self.define_variable_in_current_scope(name, binding, pattern.span)
+            }
+            (PatternKind::Wildcard, _) => Ok(()),
+            (PatternKind::Tuple(elements), Type::Tuple(element_types))
+                if elements.len() == element_types.len() =>
+            {
+                let BasicValueEnum::StructValue(tuple_value) = value else {
+                    return Err(CodegenError::internal_pattern_type_mismatch(
+                        &pattern.to_string(),
+                        &ty.to_string(),
+                        pattern.span,
+                    ));
+                };
+                for (index, (element, element_ty)) in elements.iter().zip(element_types).enumerate()
+                {
+                    let element_value = self
+                        .builder
+                        .build_extract_value(tuple_value, index as u32, "tuple_elem")
+                        .map_err(|e| {
+                            CodegenError::internal_tuple_extract_failed(
+                                index,
+                                &e.to_string(),
+                                element.span,
+                            )
+                        })?;
+                    self.bind_pattern_value(element, element_ty, element_value)?;
+                }
+                Ok(())
+            }
+            _ => Err(CodegenError::internal_pattern_type_mismatch(
+                &pattern.to_string(),
+                &ty.to_string(),
+                pattern.span,
+            )),
+        }
+    }
+
     /// Resolves the effective type of a `let` binding for code generation.
     ///
     /// Parser-produced `Type::Inferred` placeholders remain in the AST.
@@ -117,6 +213,9 @@ impl<'ctx> Codegen<'ctx> {
     }
 
     /// Generates LLVM IR for a reassignment statement.
+    ///
+    /// The new value is retained before the previous value is released, so
+    /// assigning a variable to itself is safe.
     pub(super) fn generate_assign(
         &mut self,
         name: &str,
@@ -131,9 +230,25 @@ impl<'ctx> Codegen<'ctx> {
         };
 
         let rhs_value = self.generate_expr_value(value, &variable_ty)?;
+        let previous_value = if super::rc::type_needs_rc(&variable_ty) {
+            self.build_retain(rhs_value, &variable_ty, span)?;
+            let llvm_type = self.get_llvm_type(&variable_ty, span)?;
+            let previous = self
+                .builder
+                .build_load(llvm_type, alloca, &format!("{}_previous", name))
+                .map_err(|e| {
+                    CodegenError::internal_variable_load_failed(name, &e.to_string(), span)
+                })?;
+            Some(previous)
+        } else {
+            None
+        };
         self.builder.build_store(alloca, rhs_value).map_err(|e| {
             CodegenError::internal_variable_store_failed(name, &e.to_string(), span)
         })?;
+        if let Some(previous) = previous_value {
+            self.build_release(previous, &variable_ty, span)?;
+        }
 
         Ok(())
     }
@@ -180,7 +295,7 @@ impl<'ctx> Codegen<'ctx> {
         let merge_block = self.context.append_basic_block(parent_fn, "if_end");
         let else_block = else_branch.map(|_| self.context.append_basic_block(parent_fn, "if_else"));
 
-        let condition_value = match self.generate_expr_value(condition, &Type::Bool)? {
+        let condition_value = match self.generate_condition_value(condition)? {
             BasicValueEnum::IntValue(value) => value,
             _ => {
                 return Err(CodegenError::internal_non_integer_value(
@@ -213,6 +328,7 @@ impl<'ctx> Codegen<'ctx> {
             }
             self.generate_stmt(stmt)?;
         }
+        self.exit_variable_scope(span)?;
         let then_has_terminator = self
             .builder
             .get_insert_block()
@@ -223,7 +339,6 @@ impl<'ctx> Codegen<'ctx> {
                 .build_unconditional_branch(merge_block)
                 .map_err(|e| CodegenError::internal_branch_failed(&e.to_string(), span))?;
         }
-        self.exit_variable_scope(span)?;
 
         let mut else_has_terminator = false;
         if let (Some(else_bb), Some(else_stmts)) = (else_block, else_branch) {
@@ -240,6 +355,7 @@ impl<'ctx> Codegen<'ctx> {
                 }
                 self.generate_stmt(stmt)?;
             }
+            self.exit_variable_scope(span)?;
             else_has_terminator = self
                 .builder
                 .get_insert_block()
@@ -250,7 +366,6 @@ impl<'ctx> Codegen<'ctx> {
                     .build_unconditional_branch(merge_block)
                     .map_err(|e| CodegenError::internal_branch_failed(&e.to_string(), span))?;
             }
-            self.exit_variable_scope(span)?;
         }
 
         if else_block.is_some() && then_has_terminator && else_has_terminator {
@@ -290,7 +405,7 @@ impl<'ctx> Codegen<'ctx> {
             .map_err(|e| CodegenError::internal_branch_failed(&e.to_string(), span))?;
 
         self.builder.position_at_end(cond_block);
-        let condition_value = match self.generate_expr_value(condition, &Type::Bool)? {
+        let condition_value = match self.generate_condition_value(condition)? {
             BasicValueEnum::IntValue(value) => value,
             _ => {
                 return Err(CodegenError::internal_non_integer_value(
@@ -304,8 +419,8 @@ impl<'ctx> Codegen<'ctx> {
             .map_err(|e| CodegenError::internal_branch_failed(&e.to_string(), span))?;
 
         self.builder.position_at_end(body_block);
-        self.enter_variable_scope();
         self.push_loop_control(cond_block, end_block);
+        self.enter_variable_scope();
 
         let body_result = (|| -> Result<(), CodegenError> {
             for stmt in body {
@@ -319,24 +434,24 @@ impl<'ctx> Codegen<'ctx> {
                 }
                 self.generate_stmt(stmt)?;
             }
-
-            let body_has_terminator = self
-                .builder
-                .get_insert_block()
-                .and_then(|bb| bb.get_terminator())
-                .is_some();
-            if !body_has_terminator {
-                self.builder
-                    .build_unconditional_branch(cond_block)
-                    .map_err(|e| CodegenError::internal_branch_failed(&e.to_string(), span))?;
-            }
-
             Ok(())
         })();
 
+        let exit_result = self.exit_variable_scope(span);
         self.pop_loop_control(span)?;
-        self.exit_variable_scope(span)?;
         body_result?;
+        exit_result?;
+
+        let body_has_terminator = self
+            .builder
+            .get_insert_block()
+            .and_then(|bb| bb.get_terminator())
+            .is_some();
+        if !body_has_terminator {
+            self.builder
+                .build_unconditional_branch(cond_block)
+                .map_err(|e| CodegenError::internal_branch_failed(&e.to_string(), span))?;
+        }
 
         if is_infinite_loop && !loop_may_break {
             self.builder.position_at_end(end_block);
@@ -351,12 +466,27 @@ impl<'ctx> Codegen<'ctx> {
     }
 
     /// Generates LLVM IR for a break statement.
+    ///
+    /// Deferred bodies of the scopes inside the loop run before the jump, and
+    /// the temporaries and variables owned inside the loop are released.
     pub(super) fn generate_break(&mut self, span: Span) -> Result<(), CodegenError> {
-        let break_block = self
+        let (break_block, scope_depth, temporary_depth) = self
             .current_loop_control()
-            .map(|loop_control| loop_control.break_block)
+            .map(|loop_control| {
+                (
+                    loop_control.break_block,
+                    loop_control.scope_depth,
+                    loop_control.temporary_depth,
+                )
+            })
             .ok_or_else(|| CodegenError::internal_break_outside_loop(span))?;
 
+        self.release_temporaries_from(temporary_depth, span)?;
+        self.generate_scope_cleanup_from(scope_depth, span)?;
+        if self.current_block_is_terminated() {
+            return Ok(());
+        }
+
         self.builder
             .build_unconditional_branch(break_block)
             .map_err(|e| CodegenError::internal_branch_failed(&e.to_string(), span))?;
@@ -364,12 +494,27 @@ impl<'ctx> Codegen<'ctx> {
     }
 
     /// Generates LLVM IR for a continue statement.
+    ///
+    /// Deferred bodies of the scopes inside the loop run before the jump, and
+    /// the temporaries and variables owned inside the loop are released.
     pub(super) fn generate_continue(&mut self, span: Span) -> Result<(), CodegenError> {
-        let continue_block = self
+        let (continue_block, scope_depth, temporary_depth) = self
             .current_loop_control()
-            .map(|loop_control| loop_control.continue_block)
+            .map(|loop_control| {
+                (
+                    loop_control.continue_block,
+                    loop_control.scope_depth,
+                    loop_control.temporary_depth,
+                )
+            })
             .ok_or_else(|| CodegenError::internal_continue_outside_loop(span))?;
 
+        self.release_temporaries_from(temporary_depth, span)?;
+        self.generate_scope_cleanup_from(scope_depth, span)?;
+        if self.current_block_is_terminated() {
+            return Ok(());
+        }
+
         self.builder
             .build_unconditional_branch(continue_block)
             .map_err(|e| CodegenError::internal_branch_failed(&e.to_string(), span))?;
@@ -432,6 +577,20 @@ impl<'ctx> Codegen<'ctx> {
         }
     }
 
+    /// Generates an `if` or `while` condition, releasing its temporaries
+    /// before the branch.
+    fn generate_condition_value(
+        &mut self,
+        condition: &Expr,
+    ) -> Result<BasicValueEnum<'ctx>, CodegenError> {
+        self.push_temporary_frame();
+        let condition_result = self.generate_expr_value(condition, &Type::Bool);
+        let release_result = self.pop_temporary_frame(condition.span);
+        let condition_value = condition_result?;
+        release_result?;
+        Ok(condition_value)
+    }
+
     fn generate_discard(&mut self, expr: &Expr, span: Span) -> Result<(), CodegenError> {
         match expr.kind {
             crate::ast::ExprKind::Call { .. } | crate::ast::ExprKind::ModuleCall { .. } => {
@@ -441,6 +600,18 @@ impl<'ctx> Codegen<'ctx> {
         }
     }
 
+    pub(super) fn current_block_is_terminated(&self) -> bool {
+        self.builder
+            .get_insert_block()
+            .and_then(|bb| bb.get_terminator())
+            .is_some()
+    }
+
+    /// Generates LLVM IR for a return statement.
+    ///
+    /// Deferred bodies of every enclosing scope run before the function returns,
+    /// and all temporaries and variables are released. A returned value is
+    /// retained first, so the caller receives an owned reference.
     fn generate_return(&mut self, value: Option<&Expr>, span: Span) -> Result<(), CodegenError> {
         let parent_fn = self
             .builder
@@ -450,12 +621,27 @@ impl<'ctx> Codegen<'ctx> {
         let llvm_fn_name = parent_fn.get_name().to_string_lossy().to_string();
 
         if llvm_fn_name == "main" {
-            if value.is_some() {
-                return Err(CodegenError::internal_main_return_with_value(span));
+            // `main` returns its value (or 0 for `void`) as the exit status.
+            let exit_code = match value {
+                Some(value) => {
+                    let return_ty = self
+                        .function_return_types
+                        .get("main")
+                        .cloned()
+                        .flatten()
+                        .ok_or_else(|| CodegenError::internal_main_return_with_value(span))?;
+                    let return_value = self.generate_expr_value(value, &return_ty)?;
+                    self.build_main_exit_code(return_value, &return_ty, span)?
+                }
+                None => self.context.i32_type().const_int(0, false),
+            };
+            self.release_temporaries_from(0, span)?;
+            self.generate_scope_cleanup_from(0, span)?;
+            if self.current_block_is_terminated() {
+                return Ok(());
             }
-            let zero = self.context.i32_type().const_int(0, false);
             self.builder
-                .build_return(Some(&zero))
+                .build_return(Some(&exit_code))
                 .map_err(|e| CodegenError::internal_main_return_build_failed(&e.to_string()))?;
             return Ok(());
         }
@@ -474,6 +660,11 @@ impl<'ctx> Codegen<'ctx> {
                 if value.is_some() {
                     return Err(CodegenError::internal_return_value_in_void_function(span));
                 }
+                self.release_temporaries_from(0, span)?;
+                self.generate_scope_cleanup_from(0, span)?;
+                if self.current_block_is_terminated() {
+                    return Ok(());
+                }
                 self.builder.build_return(None).map_err(|e| {
                     CodegenError::internal_return_build_failed(display_fn_name, &e.to_string())
                 })?;
@@ -482,6 +673,13 @@ impl<'ctx> Codegen<'ctx> {
                 let value =
                     value.ok_or_else(|| CodegenError::internal_missing_return_value(span))?;
                 let return_value = self.generate_expr_value(value, &expected_ty)?;
+                self.build_retain(return_value, &expected_ty, span)?;
+                // The return value is computed before deferred bodies run.
+                self.release_temporaries_from(0, span)?;
+                self.generate_scope_cleanup_from(0, span)?;
+                if self.current_block_is_terminated() {
+                    return Ok(());
+                }
                 self.builder
                     .build_return(Some(&return_value))
                     .map_err(|e| {
@@ -19,10 +19,11 @@ fn make_program(body: Vec<Stmt>) -> Program {
     Program {
         imports: vec![],
         functions: vec![FnDef {
+            doc: None,
             visibility: Visibility::Private,
             name: "main".to_string(),
             params: vec![],
-            return_type: "void".to_string(),
+            return_type: ReturnType::Void,
             return_type_span: dummy_span(),
             body,
             span: dummy_span(),
@@ -188,10 +189,11 @@ fn test_compile_non_void_function_with_while_true_return_compiles() {
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "foo".to_string(),
                 params: vec![],
-                return_type: "i64".to_string(),
+                return_type: ReturnType::Value(Type::I64),
                 return_type_span: dummy_span(),
                 body: vec![Stmt::new(
                     StmtKind::While {
@@ -209,10 +211,11 @@ fn test_compile_non_void_function_with_while_true_return_compiles() {
                 span: dummy_span(),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![],
                 span: dummy_span(),
@@ -234,10 +237,11 @@ fn test_compile_non_void_function_with_if_true_return_compiles() {
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "foo".to_string(),
                 params: vec![],
-                return_type: "i64".to_string(),
+                return_type: ReturnType::Value(Type::I64),
                 return_type_span: dummy_span(),
                 body: vec![Stmt::new(
                     StmtKind::If {
@@ -256,10 +260,11 @@ fn test_compile_non_void_function_with_if_true_return_compiles() {
                 span: dummy_span(),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![],
                 span: dummy_span(),
@@ -281,10 +286,11 @@ fn test_compile_non_void_function_with_if_not_false_return_compiles() {
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "foo".to_string(),
                 params: vec![],
-                return_type: "i64".to_string(),
+                return_type: ReturnType::Value(Type::I64),
                 return_type_span: dummy_span(),
                 body: vec![Stmt::new(
                     StmtKind::If {
@@ -312,10 +318,11 @@ fn test_compile_non_void_function_with_if_not_false_return_compiles() {
                 span: dummy_span(),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![],
                 span: dummy_span(),
@@ -337,10 +344,11 @@ fn test_compile_non_void_function_with_if_true_and_true_return_compiles() {
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "foo".to_string(),
                 params: vec![],
-                return_type: "i64".to_string(),
+                return_type: ReturnType::Value(Type::I64),
                 return_type_span: dummy_span(),
                 body: vec![Stmt::new(
                     StmtKind::If {
@@ -372,10 +380,11 @@ fn test_compile_non_void_function_with_if_true_and_true_return_compiles() {
                 span: dummy_span(),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![],
                 span: dummy_span(),
@@ -397,10 +406,11 @@ fn test_compile_non_void_function_with_if_false_else_return_compiles() {
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "foo".to_string(),
                 params: vec![],
-                return_type: "i64".to_string(),
+                return_type: ReturnType::Value(Type::I64),
                 return_type_span: dummy_span(),
                 body: vec![Stmt::new(
                     StmtKind::If {
@@ -419,10 +429,11 @@ fn test_compile_non_void_function_with_if_false_else_return_compiles() {
                 span: dummy_span(),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![],
                 span: dummy_span(),
@@ -444,19 +455,21 @@ fn test_compile_missing_return_error_uses_source_function_name() {
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "foo".to_string(),
                 params: vec![],
-                return_type: "i64".to_string(),
+                return_type: ReturnType::Value(Type::I64),
                 return_type_span: dummy_span(),
                 body: vec![],
                 span: dummy_span(),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![],
                 span: dummy_span(),
@@ -489,19 +502,21 @@ fn test_compile_invalid_return_type_reports_internal_error_at_return_type_span()
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "helper".to_string(),
                 params: vec![],
-                return_type: "int".to_string(),
+                return_type: ReturnType::Unknown("int".to_string()),
                 return_type_span: invalid_return_type_span,
                 body: vec![],
                 span: dummy_span(),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![],
                 span: dummy_span(),
@@ -531,23 +546,26 @@ fn test_compile_invalid_parameter_type_reports_internal_error_at_param_span() {
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "helper".to_string(),
                 params: vec![FnParam {
                     name: "x".to_string(),
+                    pattern: None,
                     ty: Type::Inferred,
                     span: invalid_param_span,
                 }],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span,
                 body: vec![],
                 span: dummy_span(),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![],
                 span: dummy_span(),
@@ -728,19 +746,21 @@ fn test_compile_single_file_user_functions_are_mangled() {
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "helper".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![],
                 span: dummy_span(),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![expr_stmt(ExprKind::Call {
                     callee: "helper".to_string(),
@@ -769,14 +789,16 @@ fn test_compile_single_file_function_with_parameters() {
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "helper".to_string(),
                 params: vec![FnParam {
                     name: "name".to_string(),
+                    pattern: None,
                     ty: Type::String,
                     span: dummy_span(),
                 }],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![expr_stmt(ExprKind::Call {
                     callee: "println".to_string(),
@@ -788,10 +810,11 @@ fn test_compile_single_file_function_with_parameters() {
                 span: dummy_span(),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![expr_stmt(ExprKind::Call {
                     callee: "helper".to_string(),
@@ -902,8 +925,8 @@ fn test_main_function_signature() {
     let main_fn = codegen.module.get_function("main").unwrap();
     // main returns i32
     assert!(main_fn.get_type().get_return_type().is_some());
-    // main takes no arguments
-    assert_eq!(main_fn.count_params(), 0);
+    // main takes argc and argv
+    assert_eq!(main_fn.count_params(), 2);
 }
 
 #[test]
@@ -916,19 +939,21 @@ fn test_main_function_not_first() {
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "helper".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![],
                 span: dummy_span(),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![],
                 span: dummy_span(),
@@ -1001,6 +1026,60 @@ fn test_compile_let_with_variable_reference() {
         .expect("Let with variable reference should compile");
 }
 
+#[test]
+fn test_compile_map_method_calls_use_key_specialized_runtime_functions() {
+    let context = Context::create();
+    let mut codegen = Codegen::new(&context, "test");
+
+    let map_ty = Type::Map(Box::new(Type::String), Box::new(Type::I64));
+    let string_arg = || Expr::new(ExprKind::StringLiteral("a".to_string()), dummy_span());
+    let program = make_program(vec![
+        let_stmt(
+            "m",
+            map_ty,
+            ExprKind::Call {
+                callee: "map".to_string(),
+                args: vec![],
+            },
+        ),
+        expr_stmt(ExprKind::ModuleCall {
+            module: "m".to_string(),
+            function: "insert".to_string(),
+            args: vec![
+                string_arg(),
+                Expr::new(ExprKind::IntLiteral(1), dummy_span()),
+            ],
+        }),
+        let_stmt(
+            "found",
+            Type::Bool,
+            ExprKind::ModuleCall {
+                module: "m".to_string(),
+                function: "contains".to_string(),
+                args: vec![string_arg()],
+            },
+        ),
+    ]);
+
+    codegen.compile(&program).expect("Map calls should compile");
+    let ir = codegen.module.print_to_string().to_string();
+    let calls: Vec<&str> = ir.lines().filter(|line| line.contains(" call ")).collect();
+    for name in [
+        "lak_map_new",
+        "lak_map_insert_string",
+        "lak_map_contains_string",
+        "lak_map_release",
+    ] {
+        let callee = format!("@{}(", name);
+        assert!(
+            calls.iter().any(|line| line.contains(&callee)),
+            "missing call to {} in:\n{}",
+            name,
+            ir
+        );
+    }
+}
+
 #[test]
 fn test_compile_let_mixed_with_println() {
     let context = Context::create();
@@ -1149,8 +1228,8 @@ fn test_internal_function_not_found_constructor() {
 }
 
 #[test]
-fn test_internal_println_arg_count_constructor() {
-    let err = CodegenError::internal_println_arg_count(3, dummy_span());
+fn test_internal_print_arg_count_constructor() {
+    let err = CodegenError::internal_print_arg_count("println", 3, dummy_span());
     assert_eq!(err.kind(), CodegenErrorKind::InternalError);
     assert!(err.span().is_some());
     assert_eq!(
@@ -1170,6 +1249,18 @@ fn test_internal_variable_type_mismatch_constructor() {
     );
 }
 
+#[test]
+fn test_internal_collection_method_not_found_constructor() {
+    let ty = Type::Set(Box::new(Type::I64));
+    let err = CodegenError::internal_collection_method_not_found("seen", "get", &ty, dummy_span());
+    assert_eq!(err.kind(), CodegenErrorKind::InternalError);
+    assert!(err.span().is_some());
+    assert_eq!(
+        err.message(),
+        "Internal error: 'seen' of type 'set<i64>' has no method 'get' in codegen. Semantic analysis should have caught this. This is a compiler bug."
+    );
+}
+
 #[test]
 fn test_internal_builtin_not_found_constructor() {
     let err = CodegenError::internal_builtin_not_found("lak_println");
@@ -2008,7 +2099,7 @@ fn test_internal_non_pointer_value_constructor() {
 fn test_builtin_names_matches_declare_builtins() {
     let context = Context::create();
     let mut codegen = Codegen::new(&context, "test");
-    codegen.declare_builtins();
+    codegen.declare_builtins().unwrap();
 
     let mut declared: Vec<&str> = builtins::BUILTIN_NAMES.to_vec();
     declared.sort();
@@ -2033,14 +2124,16 @@ fn test_compile_and_compile_modules_equivalent_for_single_entry_module() {
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "helper".to_string(),
                 params: vec![FnParam {
                     name: "message".to_string(),
+                    pattern: None,
                     ty: Type::String,
                     span: dummy_span(),
                 }],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![expr_stmt(ExprKind::Call {
                     callee: "println".to_string(),
@@ -2052,10 +2145,11 @@ fn test_compile_and_compile_modules_equivalent_for_single_entry_module() {
                 span: dummy_span(),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![expr_stmt(ExprKind::Call {
                     callee: "helper".to_string(),
@@ -2111,10 +2205,11 @@ fn test_compile_modules_basic() {
     let imported_program = Program {
         imports: vec![],
         functions: vec![FnDef {
+            doc: None,
             visibility: Visibility::Public,
             name: "greet".to_string(),
             params: vec![],
-            return_type: "void".to_string(),
+            return_type: ReturnType::Void,
             return_type_span: dummy_span(),
             body: vec![expr_stmt(ExprKind::Call {
                 callee: "println".to_string(),
@@ -2142,10 +2237,11 @@ fn test_compile_modules_basic() {
             span: dummy_span(),
         }],
         functions: vec![FnDef {
+            doc: None,
             visibility: Visibility::Private,
             name: "main".to_string(),
             params: vec![],
-            return_type: "void".to_string(),
+            return_type: ReturnType::Void,
             return_type_span: dummy_span(),
             body: vec![expr_stmt(ExprKind::ModuleCall {
                 module: "utils".to_string(),
@@ -2188,14 +2284,16 @@ fn test_compile_modules_function_call_with_arguments() {
     let imported_program = Program {
         imports: vec![],
         functions: vec![FnDef {
+            doc: None,
             visibility: Visibility::Public,
             name: "greet".to_string(),
             params: vec![FnParam {
                 name: "name".to_string(),
+                pattern: None,
                 ty: Type::String,
                 span: dummy_span(),
             }],
-            return_type: "void".to_string(),
+            return_type: ReturnType::Void,
             return_type_span: dummy_span(),
             body: vec![expr_stmt(ExprKind::Call {
                 callee: "println".to_string(),
@@ -2222,10 +2320,11 @@ fn test_compile_modules_function_call_with_arguments() {
             span: dummy_span(),
         }],
         functions: vec![FnDef {
+            doc: None,
             visibility: Visibility::Private,
             name: "main".to_string(),
             params: vec![],
-            return_type: "void".to_string(),
+            return_type: ReturnType::Void,
             return_type_span: dummy_span(),
             body: vec![expr_stmt(ExprKind::ModuleCall {
                 module: "utils".to_string(),
@@ -2273,10 +2372,11 @@ fn test_compile_modules_with_alias() {
     let imported_program = Program {
         imports: vec![],
         functions: vec![FnDef {
+            doc: None,
             visibility: Visibility::Public,
             name: "greet".to_string(),
             params: vec![],
-            return_type: "void".to_string(),
+            return_type: ReturnType::Void,
             return_type_span: dummy_span(),
             body: vec![expr_stmt(ExprKind::Call {
                 callee: "println".to_string(),
@@ -2304,10 +2404,11 @@ fn test_compile_modules_with_alias() {
             span: dummy_span(),
         }],
         functions: vec![FnDef {
+            doc: None,
             visibility: Visibility::Private,
             name: "main".to_string(),
             params: vec![],
-            return_type: "void".to_string(),
+            return_type: ReturnType::Void,
             return_type_span: dummy_span(),
             body: vec![expr_stmt(ExprKind::ModuleCall {
                 module: "u".to_string(),
@@ -2350,10 +2451,11 @@ fn test_compile_modules_entry_and_imported_mangled_name_collision() {
     let imported_program = Program {
         imports: vec![],
         functions: vec![FnDef {
+            doc: None,
             visibility: Visibility::Public,
             name: "foo".to_string(),
             params: vec![],
-            return_type: "void".to_string(),
+            return_type: ReturnType::Void,
             return_type_span: dummy_span(),
             body: vec![expr_stmt(ExprKind::Call {
                 callee: "println".to_string(),
@@ -2381,10 +2483,11 @@ fn test_compile_modules_entry_and_imported_mangled_name_collision() {
         }],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "_L5_utils_foo".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![expr_stmt(ExprKind::Call {
                     callee: "println".to_string(),
@@ -2396,10 +2499,11 @@ fn test_compile_modules_entry_and_imported_mangled_name_collision() {
                 span: dummy_span(),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![
                     expr_stmt(ExprKind::ModuleCall {
@@ -2455,10 +2559,11 @@ fn test_compile_modules_subdirectory() {
     let imported_program = Program {
         imports: vec![],
         functions: vec![FnDef {
+            doc: None,
             visibility: Visibility::Public,
             name: "greet".to_string(),
             params: vec![],
-            return_type: "void".to_string(),
+            return_type: ReturnType::Void,
             return_type_span: dummy_span(),
             body: vec![expr_stmt(ExprKind::Call {
                 callee: "println".to_string(),
@@ -2487,10 +2592,11 @@ fn test_compile_modules_subdirectory() {
             span: dummy_span(),
         }],
         functions: vec![FnDef {
+            doc: None,
             visibility: Visibility::Private,
             name: "main".to_string(),
             params: vec![],
-            return_type: "void".to_string(),
+            return_type: ReturnType::Void,
             return_type_span: dummy_span(),
             body: vec![expr_stmt(ExprKind::ModuleCall {
                 module: "utils".to_string(),
@@ -2925,3 +3031,39 @@ fn test_path_components_to_strings_rejects_cur_dir() {
          Paths must be canonicalized before code generation. This is a compiler bug."
     );
 }
+
+#[test]
+fn test_string_variable_is_released_at_scope_exit() {
+    let program = make_program(vec![let_stmt(
+        "s",
+        Type::String,
+        ExprKind::StringLiteral("hi".to_string()),
+    )]);
+    let context = Context::create();
+    let mut codegen = Codegen::new(&context, "test");
+    codegen.compile(&program).unwrap();
+
+    let ir = codegen.module.print_to_string().to_string();
+    assert!(
+        ir.contains("{ i64 -1, i64 3, [3 x i8] c\"hi\\00\" }"),
+        "{ir}"
+    );
+    let main = ir.split("define i32 @main(").nth(1).unwrap();
+    let retain = main.find("call void @lak_retain").unwrap();
+    let release = main.find("call void @lak_release").unwrap();
+    assert!(retain < release, "{ir}");
+}
+
+#[test]
+fn test_main_initializes_rc_before_args() {
+    let program = make_program(vec![]);
+    let context = Context::create();
+    let mut codegen = Codegen::new(&context, "test");
+    codegen.compile(&program).unwrap();
+
+    let ir = codegen.module.print_to_string().to_string();
+    let main = ir.split("define i32 @main(").nth(1).unwrap();
+    let rc_init = main.find("call void @lak_rc_init()").unwrap();
+    let args_init = main.find("call void @lak_args_init(").unwrap();
+    assert!(rc_init < args_init, "{ir}");
+}
@@ -0,0 +1,218 @@
+//! Methods of the `map<K, V>` and `set<T>` collection types.
+//!
+//! Collections are called like module functions on a variable
+//! (`scores.insert("alice", 30)`), so the parser produces
+//! [`ExprKind::ModuleCall`](crate::ast::ExprKind::ModuleCall) nodes for
+//! them. Semantic analysis and codegen treat a call whose module name is a
+//! variable of collection type as a method call and look the method up
+//! here. Each method lowers to one `lak_map_*` or `lak_set_*` runtime
+//! function (see `codegen::collections`).
+//!
+//! Empty collections are created with the `map()` and `set()` builtins,
+//! which take their key and value types from the expected type, e.g.
+//! `let mut seen: set<string> = set()`.
+//!
+//! `get` returns an `Option<V>` (see [`crate::options`]). Lak has no
+//! iterators yet, so iteration walks the entries by position with `len` and
+//! `key_at`/`value_at`/`item_at`.
+
+use crate::ast::Type;
+
+/// Returns true when `callee` is a collection constructor (`map` or `set`).
+pub fn is_constructor(callee: &str) -> bool {
+    matches!(callee, "map" | "set")
+}
+
+/// Returns true when the constructor `callee` creates a value of type `ty`.
+pub fn constructor_creates(callee: &str, ty: &Type) -> bool {
+    matches!((callee, ty), ("map", Type::Map(..)) | ("set", Type::Set(_)))
+}
+
+/// A method of a map or set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CollectionMethod {
+    /// `insert(key, value)` on maps, `insert(item)` on sets.
+    Insert,
+    /// `get(key) -> Option<V>` on maps.
+    Get,
+    /// `remove(key)` on maps, `remove(item)` on sets.
+    Remove,
+    /// `contains(key) -> bool` on maps, `contains(item) -> bool` on sets.
+    Contains,
+    /// `len() -> i64`.
+    Len,
+    /// `key_at(index) -> K` on maps.
+    KeyAt,
+    /// `value_at(index) -> V` on maps.
+    ValueAt,
+    /// `item_at(index) -> T` on sets.
+    ItemAt,
+}
+
+impl CollectionMethod {
+    /// All methods, in the order they are listed in diagnostics.
+    const ALL: [Self; 8] = [
+        Self::Insert,
+        Self::Get,
+        Self::Remove,
+        Self::Contains,
+        Self::Len,
+        Self::KeyAt,
+        Self::ValueAt,
+        Self::ItemAt,
+    ];
+
+    /// Looks up the method `name` of `collection`, which must be a map or
+    /// set type.
+    pub fn lookup(collection: &Type, name: &str) -> Option<Self> {
+        let method = Self::ALL.into_iter().find(|method| method.name() == name)?;
+        let is_map = match collection {
+            Type::Map(..) => true,
+            Type::Set(_) => false,
+            _ => return None,
+        };
+        let supported = match method {
+            Self::Insert | Self::Remove | Self::Contains | Self::Len => true,
+            Self::Get | Self::KeyAt | Self::ValueAt => is_map,
+            Self::ItemAt => !is_map,
+        };
+        supported.then_some(method)
+    }
+
+    /// Returns the names of the methods of `collection`.
+    pub fn names(collection: &Type) -> Vec<&'static str> {
+        Self::ALL
+            .into_iter()
+            .map(Self::name)
+            .filter(|name| Self::lookup(collection, name).is_some())
+            .collect()
+    }
+
+    /// Returns the name of the method in Lak source code.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Insert => "insert",
+            Self::Get => "get",
+            Self::Remove => "remove",
+            Self::Contains => "contains",
+            Self::Len => "len",
+            Self::KeyAt => "key_at",
+            Self::ValueAt => "value_at",
+            Self::ItemAt => "item_at",
+        }
+    }
+
+    /// Returns true when the method changes the collection, which requires
+    /// a `let mut` variable.
+    pub fn is_mutating(self) -> bool {
+        matches!(self, Self::Insert | Self::Remove)
+    }
+
+    /// Returns the parameter types of the method on `collection`.
+    pub fn param_types(self, collection: &Type) -> Vec<Type> {
+        let (key, value) = element_types(collection);
+        match self {
+            Self::Insert => match value {
+                Some(value) => vec![key.clone(), value.clone()],
+                None => vec![key.clone()],
+            },
+            Self::Get | Self::Remove | Self::Contains => vec![key.clone()],
+            Self::Len => Vec::new(),
+            Self::KeyAt | Self::ValueAt | Self::ItemAt => vec![Type::I64],
+        }
+    }
+
+    /// Returns the return type of the method on `collection`, or `None` for
+    /// `void`.
+    pub fn return_type(self, collection: &Type) -> Option<Type> {
+        let (key, value) = element_types(collection);
+        match self {
+            Self::Insert | Self::Remove => None,
+            Self::Get => value.map(|value| Type::Option(Box::new(value.clone()))),
+            Self::Contains => Some(Type::Bool),
+            Self::Len => Some(Type::I64),
+            Self::KeyAt | Self::ItemAt => Some(key.clone()),
+            Self::ValueAt => value.cloned(),
+        }
+    }
+}
+
+/// Returns the key (or item) type and the value type of a collection.
+///
+/// The value type is `None` for sets.
+fn element_types(collection: &Type) -> (&Type, Option<&Type>) {
+    match collection {
+        Type::Map(key, value) => (key, Some(value)),
+        Type::Set(item) => (item, None),
+        _ => unreachable!("collection methods are only looked up on map and set types"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn map_type() -> Type {
+        Type::Map(Box::new(Type::String), Box::new(Type::I64))
+    }
+
+    #[test]
+    fn test_lookup_restricts_methods_to_their_collection() {
+        let set = Type::Set(Box::new(Type::I32));
+        assert_eq!(
+            CollectionMethod::lookup(&map_type(), "get"),
+            Some(CollectionMethod::Get)
+        );
+        assert_eq!(CollectionMethod::lookup(&map_type(), "item_at"), None);
+        assert_eq!(CollectionMethod::lookup(&set, "get"), None);
+        assert_eq!(
+            CollectionMethod::lookup(&set, "item_at"),
+            Some(CollectionMethod::ItemAt)
+        );
+        assert_eq!(CollectionMethod::lookup(&set, "push"), None);
+        assert_eq!(CollectionMethod::lookup(&Type::String, "len"), None);
+        assert_eq!(
+            CollectionMethod::names(&set),
+            vec!["insert", "remove", "contains", "len", "item_at"]
+        );
+    }
+
+    #[test]
+    fn test_constructor_creates_its_collection() {
+        let set = Type::Set(Box::new(Type::I32));
+        assert!(constructor_creates("map", &map_type()));
+        assert!(constructor_creates("set", &set));
+        assert!(!constructor_creates("map", &set));
+        assert!(!constructor_creates("set", &Type::I32));
+    }
+
+    #[test]
+    fn test_map_method_signatures() {
+        let map = map_type();
+        assert_eq!(
+            CollectionMethod::Insert.param_types(&map),
+            vec![Type::String, Type::I64]
+        );
+        assert_eq!(
+            CollectionMethod::Get.return_type(&map),
+            Some(Type::Option(Box::new(Type::I64)))
+        );
+        assert_eq!(CollectionMethod::Remove.return_type(&map), None);
+        assert_eq!(CollectionMethod::KeyAt.param_types(&map), vec![Type::I64]);
+        assert_eq!(
+            CollectionMethod::KeyAt.return_type(&map),
+            Some(Type::String)
+        );
+        assert_eq!(CollectionMethod::ValueAt.return_type(&map), Some(Type::I64));
+    }
+
+    #[test]
+    fn test_set_method_signatures() {
+        let set = Type::Set(Box::new(Type::Bool));
+        assert_eq!(CollectionMethod::Insert.param_types(&set), vec![Type::Bool]);
+        assert_eq!(CollectionMethod::Len.return_type(&set), Some(Type::I64));
+        assert_eq!(CollectionMethod::ItemAt.return_type(&set), Some(Type::Bool));
+        assert!(CollectionMethod::Insert.is_mutating());
+        assert!(!CollectionMethod::Contains.is_mutating());
+    }
+}
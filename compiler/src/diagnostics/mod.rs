@@ -177,7 +177,8 @@ pub(crate) fn report_error(filename: &str, source: &str, error: &CompileError) {
         | CompileError::TempDirCreationError(_)
         | CompileError::ExecutableRunError(_)
         | CompileError::EntryModuleNotFound { .. }
-        | CompileError::FilenameError { .. } => {
+        | CompileError::FilenameError { .. }
+        | CompileError::DocOutputError { .. } => {
             eprintln!("Error: {}", error);
         }
     }
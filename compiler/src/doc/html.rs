@@ -0,0 +1,128 @@
+//! HTML rendering of documentation pages.
+
+use super::{DocSet, ModuleDoc, signature, source_file_name, summary};
+
+/// Minimal stylesheet embedded in every page, so the output is self-contained.
+const STYLE: &str = "body { font-family: sans-serif; max-width: 50rem; margin: 2rem auto; padding: 0 1rem; line-height: 1.5; }
+pre { background: #f4f4f4; padding: 0.5rem 0.75rem; overflow-x: auto; }
+code { font-family: monospace; }
+nav { margin-bottom: 1.5rem; }
+.fn { margin-bottom: 2rem; }";
+
+/// Renders the index page listing every module and its public functions.
+pub(super) fn render_index(docs: &DocSet) -> String {
+    let mut body = String::from("<h1>Modules</h1>\n");
+    if docs.modules().is_empty() {
+        body.push_str("<p>No modules.</p>\n");
+    }
+    for module in docs.modules() {
+        body.push_str(&format!(
+            "<h2><a href=\"{}.html\">{}</a></h2>\n",
+            escape(module.page()),
+            escape(module.name())
+        ));
+        if module.functions().is_empty() {
+            body.push_str("<p>No public functions.</p>\n");
+            continue;
+        }
+        body.push_str("<ul>\n");
+        for function in module.functions() {
+            body.push_str(&format!(
+                "<li><a href=\"{}.html#fn.{}\"><code>{}</code></a>",
+                escape(module.page()),
+                escape(function.name()),
+                escape(function.name())
+            ));
+            if let Some(doc) = function.doc() {
+                body.push_str(&format!(" &mdash; {}", escape(&summary(doc))));
+            }
+            body.push_str("</li>\n");
+        }
+        body.push_str("</ul>\n");
+    }
+    page("Modules", &body)
+}
+
+/// Renders the page for a single module.
+pub(super) fn render_module(docs: &DocSet, module: &ModuleDoc) -> String {
+    let mut body = String::from("<nav><a href=\"index.html\">Index</a></nav>\n");
+    body.push_str(&format!(
+        "<h1>Module <code>{}</code></h1>\n<p>Source: <code>{}</code></p>\n",
+        escape(module.name()),
+        escape(&source_file_name(module))
+    ));
+
+    if !module.imports().is_empty() {
+        body.push_str("<h2>Imports</h2>\n<ul>\n");
+        for &index in module.imports() {
+            let imported = &docs.modules()[index];
+            body.push_str(&format!(
+                "<li><a href=\"{}.html\">{}</a></li>\n",
+                escape(imported.page()),
+                escape(imported.name())
+            ));
+        }
+        body.push_str("</ul>\n");
+    }
+
+    body.push_str("<h2>Functions</h2>\n");
+    if module.functions().is_empty() {
+        body.push_str("<p>No public functions.</p>\n");
+    }
+    for function in module.functions() {
+        body.push_str(&format!(
+            "<section class=\"fn\" id=\"fn.{}\">\n<pre><code>{}</code></pre>\n",
+            escape(function.name()),
+            escape(&signature(function))
+        ));
+        if let Some(doc) = function.doc() {
+            body.push_str(&paragraphs(doc));
+        }
+        body.push_str("</section>\n");
+    }
+
+    page(&format!("Module {}", module.name()), &body)
+}
+
+/// Wraps a page body in a complete HTML document.
+fn page(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>\n{}\n</style>\n</head>\n<body>\n{}</body>\n</html>\n",
+        escape(title),
+        STYLE,
+        body
+    )
+}
+
+/// Renders doc comment text as HTML paragraphs, split on blank lines.
+fn paragraphs(doc: &str) -> String {
+    let mut html = String::new();
+    let mut current: Vec<&str> = Vec::new();
+    for line in doc.lines().chain(std::iter::once("")) {
+        if line.trim().is_empty() {
+            if !current.is_empty() {
+                html.push_str(&format!("<p>{}</p>\n", escape(&current.join("\n"))));
+                current.clear();
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    html
+}
+
+/// Escapes text for use in HTML content and attribute values.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
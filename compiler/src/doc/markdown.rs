@@ -0,0 +1,84 @@
+//! Markdown rendering of documentation pages.
+//!
+//! Doc comment text is emitted verbatim, so Markdown written in `///`
+//! comments is rendered as Markdown.
+
+use super::{DocSet, ModuleDoc, signature, source_file_name, summary};
+
+/// Renders the index page listing every module and its public functions.
+pub(super) fn render_index(docs: &DocSet) -> String {
+    let mut out = String::from("# Modules\n");
+    if docs.modules().is_empty() {
+        out.push_str("\nNo modules.\n");
+    }
+    for module in docs.modules() {
+        out.push_str(&format!(
+            "\n## [{}]({}.md)\n\n",
+            module.name(),
+            module.page()
+        ));
+        if module.functions().is_empty() {
+            out.push_str("No public functions.\n");
+            continue;
+        }
+        for function in module.functions() {
+            out.push_str(&format!(
+                "- [`{}`]({}.md#{})",
+                function.name(),
+                module.page(),
+                function_anchor(function.name())
+            ));
+            if let Some(doc) = function.doc() {
+                out.push_str(&format!(" - {}", summary(doc)));
+            }
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// Renders the page for a single module.
+pub(super) fn render_module(docs: &DocSet, module: &ModuleDoc) -> String {
+    let mut out = format!(
+        "[Index](index.md)\n\n# Module `{}`\n\nSource: `{}`\n",
+        module.name(),
+        source_file_name(module)
+    );
+
+    if !module.imports().is_empty() {
+        out.push_str("\n## Imports\n\n");
+        for &index in module.imports() {
+            let imported = &docs.modules()[index];
+            out.push_str(&format!(
+                "- [{}]({}.md)\n",
+                imported.name(),
+                imported.page()
+            ));
+        }
+    }
+
+    out.push_str("\n## Functions\n");
+    if module.functions().is_empty() {
+        out.push_str("\nNo public functions.\n");
+    }
+    for function in module.functions() {
+        out.push_str(&format!(
+            "\n### `{}`\n\n```lak\n{}\n```\n",
+            function.name(),
+            signature(function)
+        ));
+        if let Some(doc) = function.doc() {
+            out.push_str(&format!("\n{}\n", doc));
+        }
+    }
+    out
+}
+
+/// Returns the anchor generated for a function heading.
+///
+/// Markdown renderers derive heading anchors from the heading text; for a
+/// heading consisting of a code span such as `` `add_two` `` this is the
+/// lowercased function name.
+fn function_anchor(name: &str) -> String {
+    name.to_lowercase()
+}
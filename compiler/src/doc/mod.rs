@@ -0,0 +1,246 @@
+//! Documentation generation for Lak programs.
+//!
+//! This module turns the public API of every module in a resolved module
+//! graph into static documentation pages, as used by the `lak doc` command.
+//!
+//! # Overview
+//!
+//! [`DocSet::from_modules`] collects each module's public functions through
+//! [`ModuleExports`], including the `///` doc comments attached to them, and
+//! records which modules each module imports. [`DocSet::render`] then produces
+//! one page per module plus an index page, either as HTML or as Markdown.
+//! Pages cross-link to each other: the index links to every module and
+//! function, and each module page links to the modules it imports.
+//!
+//! # Module Structure
+//!
+//! - `html` - HTML rendering
+//! - `markdown` - Markdown rendering
+//! - `tests` - Unit tests (test-only)
+//!
+//! # See Also
+//!
+//! * [`crate::resolver`] - Builds the module graph that is documented
+//! * [`crate::semantic::ModuleExports`] - Source of the public signatures
+
+mod html;
+mod markdown;
+
+#[cfg(test)]
+mod tests;
+
+use crate::resolver::ResolvedModule;
+use crate::semantic::{FunctionExport, ModuleExports, SemanticError};
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// The output format of generated documentation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocFormat {
+    /// Static HTML pages.
+    Html,
+    /// Markdown pages.
+    Markdown,
+}
+
+impl DocFormat {
+    /// Returns the file extension used for pages in this format.
+    pub fn extension(self) -> &'static str {
+        match self {
+            DocFormat::Html => "html",
+            DocFormat::Markdown => "md",
+        }
+    }
+}
+
+/// A single generated documentation page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocPage {
+    /// The file name of the page (e.g., `utils.html`).
+    pub file_name: String,
+    /// The page contents.
+    pub contents: String,
+}
+
+/// Documentation for one module.
+#[derive(Debug, Clone)]
+pub struct ModuleDoc {
+    /// The module name.
+    name: String,
+    /// The page file stem, unique within the [`DocSet`].
+    page: String,
+    /// The canonical path of the module source file.
+    path: PathBuf,
+    /// Indices (into [`DocSet::modules`]) of the modules this module imports.
+    imports: Vec<usize>,
+    /// Public functions in declaration order.
+    functions: Vec<FunctionExport>,
+}
+
+impl ModuleDoc {
+    /// Returns the module name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the page file stem.
+    ///
+    /// This is the module name, followed by `-2`, `-3`, ... when several
+    /// modules share a name or the module is named `index`.
+    pub fn page(&self) -> &str {
+        &self.page
+    }
+
+    /// Returns the canonical path of the module source file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the indices of the imported modules within the [`DocSet`].
+    pub fn imports(&self) -> &[usize] {
+        &self.imports
+    }
+
+    /// Returns the public functions in declaration order.
+    pub fn functions(&self) -> &[FunctionExport] {
+        &self.functions
+    }
+}
+
+/// Documentation for every module in a module graph.
+#[derive(Debug, Clone)]
+pub struct DocSet {
+    /// Documented modules, sorted by module name.
+    modules: Vec<ModuleDoc>,
+}
+
+impl DocSet {
+    /// Collects documentation from resolved modules.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the public exports of a module cannot be collected.
+    pub fn from_modules(modules: &[ResolvedModule]) -> Result<Self, SemanticError> {
+        let mut sorted: Vec<&ResolvedModule> = modules.iter().collect();
+        sorted.sort_by(|a, b| a.name().cmp(b.name()).then_with(|| a.path().cmp(b.path())));
+
+        let index_by_path: HashMap<&Path, usize> = sorted
+            .iter()
+            .enumerate()
+            .map(|(index, module)| (module.path(), index))
+            .collect();
+
+        // `index` is taken by the index page.
+        let mut name_counts: HashMap<&str, usize> = HashMap::from([("index", 1)]);
+        let mut docs = Vec::with_capacity(sorted.len());
+        for module in &sorted {
+            let count = name_counts.entry(module.name()).or_insert(0);
+            *count += 1;
+            let page = if *count == 1 {
+                module.name().to_string()
+            } else {
+                format!("{}-{}", module.name(), count)
+            };
+
+            let exports = ModuleExports::from_module(module)?;
+            let mut functions: Vec<FunctionExport> =
+                exports.functions().values().cloned().collect();
+            functions.sort_by_key(|function| function.definition_span().start);
+
+            let mut imports: Vec<usize> = Vec::new();
+            for import in &module.program().imports {
+                if let Some(path) = module.resolved_imports().get(&import.path)
+                    && let Some(&index) = index_by_path.get(path.as_path())
+                    && !imports.contains(&index)
+                {
+                    imports.push(index);
+                }
+            }
+
+            docs.push(ModuleDoc {
+                name: module.name().to_string(),
+                page,
+                path: module.path().to_path_buf(),
+                imports,
+                functions,
+            });
+        }
+
+        Ok(DocSet { modules: docs })
+    }
+
+    /// Returns the documented modules, sorted by module name.
+    pub fn modules(&self) -> &[ModuleDoc] {
+        &self.modules
+    }
+
+    /// Renders the index page and one page per module in the given format.
+    ///
+    /// The index page is always first and is named `index.html` or `index.md`.
+    pub fn render(&self, format: DocFormat) -> Vec<DocPage> {
+        let extension = format.extension();
+        let render_index = match format {
+            DocFormat::Html => html::render_index,
+            DocFormat::Markdown => markdown::render_index,
+        };
+        let render_module = match format {
+            DocFormat::Html => html::render_module,
+            DocFormat::Markdown => markdown::render_module,
+        };
+
+        let mut pages = vec![DocPage {
+            file_name: format!("index.{}", extension),
+            contents: render_index(self),
+        }];
+        for module in &self.modules {
+            pages.push(DocPage {
+                file_name: format!("{}.{}", module.page, extension),
+                contents: render_module(self, module),
+            });
+        }
+        pages
+    }
+}
+
+/// Formats the signature of a public function, e.g. `pub fn add(a: i32, b: i32) -> i32`.
+fn signature(function: &FunctionExport) -> String {
+    let params: Vec<String> = if function.numeric().is_some() {
+        function
+            .param_names()
+            .iter()
+            .map(|name| format!("{}: T", name))
+            .collect()
+    } else {
+        function
+            .param_names()
+            .iter()
+            .zip(function.param_types())
+            .map(|(name, ty)| format!("{}: {}", name, ty))
+            .collect()
+    };
+    format!(
+        "pub fn {}({}) -> {}",
+        function.name(),
+        params.join(", "),
+        function.return_type()
+    )
+}
+
+/// Returns the first paragraph of a doc comment, used as a summary.
+fn summary(doc: &str) -> String {
+    doc.lines()
+        .take_while(|line| !line.trim().is_empty())
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Returns the file name of a module's source, for display.
+fn source_file_name(module: &ModuleDoc) -> String {
+    module
+        .path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| module.path.display().to_string())
+}
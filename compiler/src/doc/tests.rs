@@ -0,0 +1,115 @@
+//! Unit tests for documentation generation.
+
+use super::*;
+use crate::lexer::Lexer;
+use crate::parser::Parser;
+
+fn module(name: &str, source: &str) -> ResolvedModule {
+    module_at(&format!("/project/{}.lak", name), name, source)
+}
+
+fn module_at(path: &str, name: &str, source: &str) -> ResolvedModule {
+    let tokens = Lexer::new(source).tokenize().unwrap();
+    let program = Parser::new(tokens).parse().unwrap();
+    ResolvedModule::for_testing(
+        PathBuf::from(path),
+        name.to_string(),
+        program,
+        source.to_string(),
+    )
+}
+
+fn sample_modules() -> Vec<ResolvedModule> {
+    let mut main = module(
+        "main",
+        "import \"./math\"\n\nfn main() -> void {\n    println(math.add(1, 2))\n}",
+    );
+    main.add_resolved_import_for_testing("./math".to_string(), PathBuf::from("/project/math.lak"));
+    let math = module(
+        "math",
+        "/// Adds two numbers.\n///\n/// Wraps on <overflow>.\npub fn add(a: i64, b: i64) -> i64 {\n    return a + b\n}\n\nfn helper() -> void {}\n\npub fn zero() -> i64 {\n    return 0\n}",
+    );
+    vec![main, math]
+}
+
+fn page<'a>(pages: &'a [DocPage], file_name: &str) -> &'a str {
+    &pages
+        .iter()
+        .find(|page| page.file_name == file_name)
+        .unwrap_or_else(|| panic!("missing page {}", file_name))
+        .contents
+}
+
+#[test]
+fn test_collects_public_functions_in_declaration_order() {
+    let docs = DocSet::from_modules(&sample_modules()).unwrap();
+    let names: Vec<_> = docs.modules().iter().map(ModuleDoc::name).collect();
+    assert_eq!(names, vec!["main", "math"]);
+
+    let math = &docs.modules()[1];
+    let functions: Vec<_> = math.functions().iter().map(|f| f.name()).collect();
+    assert_eq!(functions, vec!["add", "zero"]);
+    assert_eq!(
+        math.functions()[0].doc(),
+        Some("Adds two numbers.\n\nWraps on <overflow>.")
+    );
+    assert_eq!(docs.modules()[0].imports(), [1]);
+}
+
+#[test]
+fn test_signature_includes_parameter_names_and_types() {
+    let docs = DocSet::from_modules(&sample_modules()).unwrap();
+    let add = &docs.modules()[1].functions()[0];
+    assert_eq!(signature(add), "pub fn add(a: i64, b: i64) -> i64");
+}
+
+#[test]
+fn test_render_html_pages_and_links() {
+    let docs = DocSet::from_modules(&sample_modules()).unwrap();
+    let pages = docs.render(DocFormat::Html);
+    let file_names: Vec<_> = pages.iter().map(|p| p.file_name.as_str()).collect();
+    assert_eq!(file_names, vec!["index.html", "main.html", "math.html"]);
+
+    let index = page(&pages, "index.html");
+    assert!(
+        index.contains(
+            "<a href=\"math.html#fn.add\"><code>add</code></a> &mdash; Adds two numbers."
+        )
+    );
+
+    let main = page(&pages, "main.html");
+    assert!(main.contains("<li><a href=\"math.html\">math</a></li>"));
+    assert!(main.contains("<p>No public functions.</p>"));
+
+    let math = page(&pages, "math.html");
+    assert!(math.contains("<section class=\"fn\" id=\"fn.add\">"));
+    assert!(math.contains("<pre><code>pub fn add(a: i64, b: i64) -&gt; i64</code></pre>"));
+    assert!(math.contains("<p>Wraps on &lt;overflow&gt;.</p>"));
+    assert!(!math.contains("helper"));
+}
+
+#[test]
+fn test_render_markdown_pages_and_links() {
+    let docs = DocSet::from_modules(&sample_modules()).unwrap();
+    let pages = docs.render(DocFormat::Markdown);
+
+    let index = page(&pages, "index.md");
+    assert!(index.contains("## [math](math.md)"));
+    assert!(index.contains("- [`add`](math.md#add) - Adds two numbers."));
+
+    assert!(page(&pages, "main.md").contains("- [math](math.md)"));
+
+    let math = page(&pages, "math.md");
+    assert!(math.contains("### `add`\n\n```lak\npub fn add(a: i64, b: i64) -> i64\n```\n\nAdds two numbers.\n\nWraps on <overflow>.\n"));
+}
+
+#[test]
+fn test_page_names_are_unique() {
+    let first = module("utils", "pub fn a() -> void {}");
+    let second = module_at("/project/lib/utils.lak", "utils", "pub fn b() -> void {}");
+    let index = module("index", "pub fn c() -> void {}");
+
+    let docs = DocSet::from_modules(&[first, second, index]).unwrap();
+    let pages: Vec<_> = docs.modules().iter().map(ModuleDoc::page).collect();
+    assert_eq!(pages, vec!["index-2", "utils", "utils-2"]);
+}
@@ -1,11 +1,13 @@
 use inkwell::context::Context;
 use lak::codegen::{Codegen, CodegenError};
+use lak::doc::{DocFormat, DocSet};
 use lak::resolver::{ModuleResolver, ResolvedModule, ResolverError};
 use lak::semantic::SemanticAnalyzer;
 use lak::semantic::SemanticError;
+use lak::stdlib::STDLIB_DIR_ENV;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
-use std::process::{Command, ExitStatus};
+use std::process::{Command, ExitStatus, Stdio};
 use tempfile::TempDir;
 
 #[cfg(unix)]
@@ -53,6 +55,11 @@ pub(crate) enum CompileError {
     EntryModuleNotFound { path: String },
     /// Cannot determine filename from path or filename is not valid UTF-8.
     FilenameError { path: String, reason: &'static str },
+    /// Failed to create the documentation directory or write a page into it.
+    DocOutputError {
+        path: PathBuf,
+        source: std::io::Error,
+    },
 }
 
 /// Context for a semantic error in an imported module.
@@ -202,6 +209,13 @@ impl CompileError {
         }
     }
 
+    fn doc_output_error(path: impl Into<PathBuf>, source: std::io::Error) -> Self {
+        CompileError::DocOutputError {
+            path: path.into(),
+            source,
+        }
+    }
+
     fn module_semantic(module: &ResolvedModule, error: SemanticError) -> Self {
         CompileError::ModuleSemantic(Box::new(ModuleSemanticContext {
             error,
@@ -249,6 +263,14 @@ impl std::fmt::Display for CompileError {
             CompileError::FilenameError { path, reason } => {
                 write!(f, "{}: {}", reason, path)
             }
+            CompileError::DocOutputError { path, source } => {
+                write!(
+                    f,
+                    "Failed to write documentation to '{}': {}",
+                    path.display(),
+                    source
+                )
+            }
         }
     }
 }
@@ -318,6 +340,37 @@ fn get_exit_code_with_signal(status: &ExitStatus) -> i32 {
     1
 }
 
+/// Code generation options shared by `lak build` and `lak run`.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct BuildOptions {
+    /// Print a backtrace of Lak function calls when the program panics.
+    pub(crate) backtrace: bool,
+}
+
+/// Resolves the module graph starting from the context's entry file.
+///
+/// # Returns
+///
+/// The canonical path of the entry file and all resolved modules.
+fn resolve_modules(
+    context: &CompileContext,
+) -> Result<(PathBuf, Vec<ResolvedModule>), CompileError> {
+    let entry_path = Path::new(&context.filename);
+    let canonical_entry = entry_path
+        .canonicalize()
+        .map_err(|e| CompileError::path_resolution_error(&context.filename, e))?;
+
+    let mut resolver = ModuleResolver::new();
+    if let Some(dir) = std::env::var_os(STDLIB_DIR_ENV).filter(|dir| !dir.is_empty()) {
+        resolver = resolver.with_stdlib_dir(PathBuf::from(dir));
+    }
+    resolver
+        .resolve_from_entry_with_source(&canonical_entry, context.source.clone())
+        .map_err(CompileError::Resolve)?;
+
+    Ok((canonical_entry, resolver.into_modules()))
+}
+
 /// Compiles a Lak source file and links it into an executable.
 ///
 /// This is the shared compilation pipeline used by both `build` and `run` commands.
@@ -327,6 +380,7 @@ fn get_exit_code_with_signal(status: &ExitStatus) -> i32 {
 /// # Arguments
 ///
 /// * `context` - The compilation context containing filename and source
+/// * `options` - Code generation options
 /// * `object_path` - Path to write the object file
 /// * `output_path` - Path to write the final executable
 ///
@@ -336,21 +390,12 @@ fn get_exit_code_with_signal(status: &ExitStatus) -> i32 {
 /// * `Err(CompileError)` - Compilation failed
 fn compile_to_executable(
     context: &CompileContext,
+    options: BuildOptions,
     object_path: &Path,
     output_path: &Path,
 ) -> Result<(), CompileError> {
     // Phase 1: Resolve modules (load and parse all imported files)
-    let entry_path = Path::new(&context.filename);
-    let canonical_entry = entry_path
-        .canonicalize()
-        .map_err(|e| CompileError::path_resolution_error(&context.filename, e))?;
-
-    let mut resolver = ModuleResolver::new();
-    resolver
-        .resolve_from_entry_with_source(&canonical_entry, context.source.clone())
-        .map_err(CompileError::Resolve)?;
-
-    let modules = resolver.into_modules();
+    let (canonical_entry, modules) = resolve_modules(context)?;
 
     // Find entry module
     let entry_module = modules
@@ -404,9 +449,13 @@ fn compile_to_executable(
     // Phase 3: Code generation
     let llvm_context = Context::create();
     let mut codegen = Codegen::new(&llvm_context, "lak_module");
+    codegen.set_backtrace(options.backtrace);
 
     if modules.len() == 1 {
         // Single module: use simple compile
+        if let Some(file_name) = entry_module.path().file_name() {
+            codegen.set_source_file(&file_name.to_string_lossy());
+        }
         codegen
             .compile_with_inferred_types(entry_module.program(), &entry_inferred_binding_types)
             .map_err(CompileError::Codegen)?;
@@ -448,6 +497,7 @@ fn compile_to_executable(
 ///
 /// * `file` - Path to the Lak source file
 /// * `output` - Optional path for the output executable. If `None`, uses input file stem.
+/// * `options` - Code generation options (e.g. `--backtrace`)
 ///
 /// # Returns
 ///
@@ -460,7 +510,11 @@ fn compile_to_executable(
 /// - Without `-o`: produces `example` executable
 /// - With `-o myapp`: produces `myapp` executable
 /// - Temporary object file in an isolated temp directory (auto-cleaned)
-pub(crate) fn build(file: &str, output: Option<&str>) -> Result<(), Box<CompileErrorWithContext>> {
+pub(crate) fn build(
+    file: &str,
+    output: Option<&str>,
+    options: BuildOptions,
+) -> Result<(), Box<CompileErrorWithContext>> {
     let source = std::fs::read_to_string(file).map_err(|e| {
         Box::new(CompileContext::new(file, "").with_error(CompileError::file_read_error(file, e)))
     })?;
@@ -497,7 +551,7 @@ pub(crate) fn build(file: &str, output: Option<&str>) -> Result<(), Box<CompileE
         None => PathBuf::from(format!("{}{}", stem, std::env::consts::EXE_SUFFIX)),
     };
 
-    compile_to_executable(&context, &object_path, &output_path)
+    compile_to_executable(&context, options, &object_path, &output_path)
         .map_err(|e| Box::new(context.with_error(e)))?;
 
     println!("Built: {}", output_path.display());
@@ -509,19 +563,26 @@ pub(crate) fn build(file: &str, output: Option<&str>) -> Result<(), Box<CompileE
 /// This function:
 /// 1. Creates a temporary directory for build artifacts
 /// 2. Compiles the source file to an executable in the temp directory
-/// 3. Runs the executable
+/// 3. Runs the executable with `args` as its command-line arguments and the
+///    standard input of `lak` as its stdin
 /// 4. Returns the exit code of the executed program
 /// 5. Cleans up temporary files automatically
 ///
 /// # Arguments
 ///
 /// * `file` - Path to the Lak source file
+/// * `options` - Code generation options (e.g. `--backtrace`)
+/// * `args` - Arguments passed to the program (after `--` on the command line)
 ///
 /// # Returns
 ///
 /// * `Ok(i32)` - The exit code of the executed program
 /// * `Err(CompileErrorWithContext)` - Compilation or execution failed
-pub(crate) fn run(file: &str) -> Result<i32, Box<CompileErrorWithContext>> {
+pub(crate) fn run(
+    file: &str,
+    options: BuildOptions,
+    args: &[String],
+) -> Result<i32, Box<CompileErrorWithContext>> {
     let source = std::fs::read_to_string(file).map_err(|e| {
         Box::new(CompileContext::new(file, "").with_error(CompileError::file_read_error(file, e)))
     })?;
@@ -542,7 +603,7 @@ pub(crate) fn run(file: &str) -> Result<i32, Box<CompileErrorWithContext>> {
         .path()
         .join(format!("program{}", std::env::consts::EXE_SUFFIX));
 
-    compile_to_executable(&context, &object_path, &executable_path)
+    compile_to_executable(&context, options, &object_path, &executable_path)
         .map_err(|e| Box::new(context.clone().with_error(e)))?;
 
     // Run the executable
@@ -555,6 +616,8 @@ pub(crate) fn run(file: &str) -> Result<i32, Box<CompileErrorWithContext>> {
     })?;
 
     let status = Command::new(exec_str)
+        .args(args)
+        .stdin(Stdio::inherit())
         .status()
         .map_err(|e| Box::new(context.with_error(CompileError::executable_run_error(e))))?;
 
@@ -566,10 +629,85 @@ pub(crate) fn run(file: &str) -> Result<i32, Box<CompileErrorWithContext>> {
     Ok(exit_code)
 }
 
+/// Generates documentation for a Lak source file and the modules it imports.
+///
+/// This function:
+/// 1. Reads the source file and resolves its module graph
+/// 2. Collects the public functions and doc comments of every module
+/// 3. Writes an index page and one page per module into `output_dir`
+///
+/// No semantic analysis or code generation is performed, so documentation
+/// can be generated for programs that do not yet type-check.
+///
+/// # Arguments
+///
+/// * `file` - Path to the entry Lak source file
+/// * `output_dir` - Directory to write pages into (created if missing)
+/// * `format` - Whether to write HTML or Markdown pages
+///
+/// # Returns
+///
+/// * `Ok(())` - Documentation was written
+/// * `Err(CompileErrorWithContext)` - Resolution failed or a page could not be written
+pub(crate) fn doc(
+    file: &str,
+    output_dir: &str,
+    format: DocFormat,
+) -> Result<(), Box<CompileErrorWithContext>> {
+    let source = std::fs::read_to_string(file).map_err(|e| {
+        Box::new(CompileContext::new(file, "").with_error(CompileError::file_read_error(file, e)))
+    })?;
+
+    let context = CompileContext::new(file, source);
+
+    let (_, modules) =
+        resolve_modules(&context).map_err(|e| Box::new(context.clone().with_error(e)))?;
+    let docs = DocSet::from_modules(&modules)
+        .map_err(|e| Box::new(context.clone().with_error(CompileError::Semantic(e))))?;
+
+    let output_dir = Path::new(output_dir);
+    std::fs::create_dir_all(output_dir).map_err(|e| {
+        Box::new(
+            context
+                .clone()
+                .with_error(CompileError::doc_output_error(output_dir, e)),
+        )
+    })?;
+
+    for page in docs.render(format) {
+        let page_path = output_dir.join(&page.file_name);
+        std::fs::write(&page_path, page.contents).map_err(|e| {
+            Box::new(
+                context
+                    .clone()
+                    .with_error(CompileError::doc_output_error(&page_path, e)),
+            )
+        })?;
+    }
+
+    println!(
+        "Documented: {}",
+        output_dir
+            .join(format!("index.{}", format.extension()))
+            .display()
+    );
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_display_doc_output_error() {
+        let io_err = std::io::Error::new(std::io::ErrorKind::PermissionDenied, "permission denied");
+        let err = CompileError::doc_output_error("docs/index.html", io_err);
+        assert_eq!(
+            err.to_string(),
+            "Failed to write documentation to 'docs/index.html': permission denied"
+        );
+    }
+
     #[test]
     fn test_display_entry_module_not_found() {
         let err = CompileError::entry_module_not_found("/tmp/main.lak");
@@ -14,11 +14,28 @@
 //! - **Character errors**: [`unexpected_character()`](LexError::unexpected_character),
 //!   [`invalid_identifier_character()`](LexError::invalid_identifier_character),
 //!   [`invalid_whitespace()`](LexError::invalid_whitespace)
+//! - **Comment errors**: [`unterminated_block_comment()`](LexError::unterminated_block_comment)
 //! - **String errors**: [`unknown_escape_sequence()`](LexError::unknown_escape_sequence),
 //!   [`unterminated_string()`](LexError::unterminated_string),
-//!   [`unterminated_string_newline()`](LexError::unterminated_string_newline)
-//! - **Integer errors**: [`integer_overflow()`](LexError::integer_overflow)
-//! - **Float errors**: [`invalid_float_literal()`](LexError::invalid_float_literal)
+//!   [`unterminated_string_newline()`](LexError::unterminated_string_newline),
+//!   [`unterminated_raw_string()`](LexError::unterminated_raw_string)
+//! - **Character literal errors**: [`invalid_char_literal()`](LexError::invalid_char_literal),
+//!   [`unterminated_char_literal()`](LexError::unterminated_char_literal),
+//!   [`nul_in_char_literal()`](LexError::nul_in_char_literal)
+//! - **Escape errors**: [`invalid_hex_escape()`](LexError::invalid_hex_escape),
+//!   [`hex_escape_out_of_range()`](LexError::hex_escape_out_of_range),
+//!   [`invalid_unicode_escape()`](LexError::invalid_unicode_escape),
+//!   [`invalid_unicode_scalar()`](LexError::invalid_unicode_scalar)
+//! - **Multi-line string errors**: [`multiline_string_opening_content()`](LexError::multiline_string_opening_content),
+//!   [`multiline_string_closing_content()`](LexError::multiline_string_closing_content),
+//!   [`multiline_string_insufficient_indentation()`](LexError::multiline_string_insufficient_indentation)
+//! - **Integer errors**: [`integer_overflow()`](LexError::integer_overflow),
+//!   [`missing_radix_digits()`](LexError::missing_radix_digits),
+//!   [`invalid_digit_for_radix()`](LexError::invalid_digit_for_radix),
+//!   [`invalid_integer_suffix()`](LexError::invalid_integer_suffix)
+//! - **Float errors**: [`invalid_float_literal()`](LexError::invalid_float_literal),
+//!   [`float_literal_out_of_range()`](LexError::float_literal_out_of_range),
+//!   [`invalid_float_suffix()`](LexError::invalid_float_suffix)
 
 use crate::token::Span;
 
@@ -36,12 +53,27 @@ pub enum LexErrorKind {
     InvalidIdentifierCharacter,
     /// Non-ASCII whitespace character.
     InvalidWhitespace,
+    /// Block comment not closed before end of file.
+    UnterminatedBlockComment,
     /// Unknown escape sequence in string literal.
     UnknownEscapeSequence,
     /// String literal not closed before end of line or file.
     UnterminatedString,
+    /// Malformed `\x` or `\u{...}` escape sequence in string literal.
+    InvalidEscapeSequence,
+    /// Multi-line string literal with misplaced delimiters or bad indentation.
+    InvalidMultilineString,
+    /// NUL character, written directly or as an escape, in string or
+    /// character literal.
+    NulInString,
+    /// Character literal that is empty or holds more than one character.
+    InvalidCharLiteral,
     /// Integer literal exceeds representable range.
     IntegerOverflow,
+    /// Integer literal with a missing or out-of-radix digit.
+    InvalidIntegerLiteral,
+    /// Numeric literal followed by an unknown suffix.
+    InvalidLiteralSuffix,
     /// Float literal could not be parsed.
     InvalidFloatLiteral,
 }
@@ -108,9 +140,16 @@ impl LexError {
             LexErrorKind::UnexpectedCharacter => "Unexpected character",
             LexErrorKind::InvalidIdentifierCharacter => "Invalid identifier character",
             LexErrorKind::InvalidWhitespace => "Invalid whitespace",
+            LexErrorKind::UnterminatedBlockComment => "Unterminated block comment",
             LexErrorKind::UnknownEscapeSequence => "Unknown escape sequence",
             LexErrorKind::UnterminatedString => "Unterminated string",
+            LexErrorKind::InvalidEscapeSequence => "Invalid escape sequence",
+            LexErrorKind::InvalidMultilineString => "Invalid multi-line string",
+            LexErrorKind::NulInString => "NUL character in string",
+            LexErrorKind::InvalidCharLiteral => "Invalid character literal",
             LexErrorKind::IntegerOverflow => "Integer overflow",
+            LexErrorKind::InvalidIntegerLiteral => "Invalid integer literal",
+            LexErrorKind::InvalidLiteralSuffix => "Invalid literal suffix",
             LexErrorKind::InvalidFloatLiteral => "Invalid float literal",
         }
     }
@@ -161,6 +200,25 @@ impl LexError {
         )
     }
 
+    // =========================================================================
+    // Comment errors
+    // =========================================================================
+
+    /// Creates an "unterminated block comment" error.
+    ///
+    /// `depth` is the number of nested `/*` still open at end of file.
+    pub fn unterminated_block_comment(depth: usize, span: Span) -> Self {
+        let message = if depth > 1 {
+            format!(
+                "Unterminated block comment ({} nested comments are still open, expected '*/')",
+                depth
+            )
+        } else {
+            "Unterminated block comment (expected '*/')".to_string()
+        };
+        Self::new(LexErrorKind::UnterminatedBlockComment, message, span)
+    }
+
     // =========================================================================
     // String errors
     // =========================================================================
@@ -192,6 +250,137 @@ impl LexError {
         )
     }
 
+    /// Creates an "unterminated raw string" error.
+    pub fn unterminated_raw_string(hashes: usize, span: Span) -> Self {
+        Self::new(
+            LexErrorKind::UnterminatedString,
+            format!(
+                "Unterminated raw string literal (expected closing '\"{}')",
+                "#".repeat(hashes)
+            ),
+            span,
+        )
+    }
+
+    /// Creates an error for a NUL character, written directly or as an
+    /// escape such as `\0`, in a string literal.
+    pub fn nul_in_string(span: Span) -> Self {
+        Self::new(
+            LexErrorKind::NulInString,
+            "Strings cannot contain NUL characters, since runtime strings are NUL-terminated",
+            span,
+        )
+    }
+
+    // =========================================================================
+    // Character literal errors
+    // =========================================================================
+
+    /// Creates an error for a character literal that does not hold exactly
+    /// one character.
+    pub fn invalid_char_literal(span: Span) -> Self {
+        Self::new(
+            LexErrorKind::InvalidCharLiteral,
+            "Character literal must contain exactly one character; use double quotes for strings",
+            span,
+        )
+    }
+
+    /// Creates an "unterminated character literal" error.
+    pub fn unterminated_char_literal(span: Span) -> Self {
+        Self::new(
+            LexErrorKind::UnterminatedString,
+            "Unterminated character literal (expected closing \')",
+            span,
+        )
+    }
+
+    /// Creates an error for a NUL character literal.
+    pub fn nul_in_char_literal(span: Span) -> Self {
+        Self::new(
+            LexErrorKind::NulInString,
+            "Character literals cannot be NUL, since characters are printed as NUL-terminated strings",
+            span,
+        )
+    }
+
+    // =========================================================================
+    // Escape errors
+    // =========================================================================
+
+    /// Creates an "invalid hex escape" error for a `\x` not followed by two hex digits.
+    pub fn invalid_hex_escape(span: Span) -> Self {
+        Self::new(
+            LexErrorKind::InvalidEscapeSequence,
+            "Invalid hex escape: '\\x' must be followed by exactly two hex digits",
+            span,
+        )
+    }
+
+    /// Creates a "hex escape out of range" error for `\x` values above `\x7F`.
+    pub fn hex_escape_out_of_range(digits: &str, span: Span) -> Self {
+        Self::new(
+            LexErrorKind::InvalidEscapeSequence,
+            format!(
+                "Hex escape '\\x{}' is out of range; only '\\x00' to '\\x7F' are allowed, use '\\u{{...}}' for other characters",
+                digits
+            ),
+            span,
+        )
+    }
+
+    /// Creates an "invalid unicode escape" error for a malformed `\u{...}`.
+    pub fn invalid_unicode_escape(span: Span) -> Self {
+        Self::new(
+            LexErrorKind::InvalidEscapeSequence,
+            "Invalid unicode escape: expected '\\u{' followed by 1 to 6 hex digits and '}'",
+            span,
+        )
+    }
+
+    /// Creates an "invalid unicode scalar" error for a `\u{...}` that is not a valid character.
+    pub fn invalid_unicode_scalar(digits: &str, span: Span) -> Self {
+        Self::new(
+            LexErrorKind::InvalidEscapeSequence,
+            format!(
+                "Unicode escape '\\u{{{}}}' is not a valid Unicode scalar value",
+                digits
+            ),
+            span,
+        )
+    }
+
+    // =========================================================================
+    // Multi-line string errors
+    // =========================================================================
+
+    /// Creates an error for text after the opening `"""` of a multi-line string.
+    pub fn multiline_string_opening_content(span: Span) -> Self {
+        Self::new(
+            LexErrorKind::InvalidMultilineString,
+            "Multi-line string content must start on the line after the opening '\"\"\"'",
+            span,
+        )
+    }
+
+    /// Creates an error for text before the closing `"""` of a multi-line string.
+    pub fn multiline_string_closing_content(span: Span) -> Self {
+        Self::new(
+            LexErrorKind::InvalidMultilineString,
+            "Closing '\"\"\"' of a multi-line string must be on its own line",
+            span,
+        )
+    }
+
+    /// Creates an error for a multi-line string line indented less than the closing delimiter.
+    pub fn multiline_string_insufficient_indentation(span: Span) -> Self {
+        Self::new(
+            LexErrorKind::InvalidMultilineString,
+            "Multi-line string line is indented less than the closing '\"\"\"'",
+            span,
+        )
+    }
+
     // =========================================================================
     // Integer errors
     // =========================================================================
@@ -208,6 +397,40 @@ impl LexError {
         )
     }
 
+    /// Creates a "missing digits" error for a radix prefix with no digits after it.
+    pub fn missing_radix_digits(prefix: &str, span: Span) -> Self {
+        Self::new(
+            LexErrorKind::InvalidIntegerLiteral,
+            format!("Missing digits after '{}' in integer literal", prefix),
+            span,
+        )
+    }
+
+    /// Creates an "invalid digit" error for a digit outside the literal's radix.
+    pub fn invalid_digit_for_radix(ch: char, radix_name: &str, span: Span) -> Self {
+        Self::new(
+            LexErrorKind::InvalidIntegerLiteral,
+            format!("Invalid digit '{}' in {} literal", ch, radix_name),
+            span,
+        )
+    }
+
+    /// Creates an "invalid suffix" error for an integer literal.
+    pub fn invalid_integer_suffix(suffix: &str, span: Span) -> Self {
+        Self::new(
+            LexErrorKind::InvalidLiteralSuffix,
+            format!(
+                "Invalid suffix '{}' on integer literal; expected one of i8, i16, i32, i64, u8, u16, u32, u64, f32, f64",
+                suffix
+            ),
+            span,
+        )
+    }
+
+    // =========================================================================
+    // Float errors
+    // =========================================================================
+
     /// Creates an "invalid float literal" error.
     pub fn invalid_float_literal(value_str: &str, span: Span) -> Self {
         Self::new(
@@ -216,6 +439,27 @@ impl LexError {
             span,
         )
     }
+
+    /// Creates an "out of range" error for a float literal too large for its type.
+    pub fn float_literal_out_of_range(value_str: &str, ty: &str, span: Span) -> Self {
+        Self::new(
+            LexErrorKind::InvalidFloatLiteral,
+            format!("Float literal '{}' is out of range for {}", value_str, ty),
+            span,
+        )
+    }
+
+    /// Creates an "invalid suffix" error for a float literal.
+    pub fn invalid_float_suffix(suffix: &str, span: Span) -> Self {
+        Self::new(
+            LexErrorKind::InvalidLiteralSuffix,
+            format!(
+                "Invalid suffix '{}' on float literal; expected f32 or f64",
+                suffix
+            ),
+            span,
+        )
+    }
 }
 
 impl std::fmt::Display for LexError {
@@ -15,15 +15,24 @@
 //!
 //! - **Keywords**: `fn`, `let`
 //! - **Identifiers**: Start with an ASCII alphabetic character (a-z, A-Z) or underscore, contain ASCII alphanumerics and underscores. Non-ASCII characters are rejected with an error.
-//! - **Integer literals**: Sequences of ASCII digits (e.g., `42`, `0`, `9223372036854775807`).
-//!   Stored as `i64` values. Negative literals (e.g., `-42`) are not directly supported;
-//!   the minus sign is only recognized as part of the `->` arrow syntax.
-//!   Values exceeding `i64::MAX` result in a lexer error.
-//! - **String literals**: Enclosed in double quotes, support escape sequences (`\n`, `\t`, `\r`, `\\`, `\"`)
+//! - **Integer literals**: Decimal digits (e.g., `42`, `9223372036854775807`) or `0x`, `0o`, `0b`
+//!   prefixed digits (e.g., `0xFF`, `0b1010`), with optional `_` separators (`1_000_000`) and an
+//!   optional integer type suffix (`255u8`, `1_000i64`). Stored as `u64` values.
+//!   Negative literals (e.g., `-42`) are not directly supported; the parser folds a
+//!   leading minus sign into the literal. Values exceeding `u64::MAX` result in a lexer error.
+//! - **Float literals**: Decimal with a fraction and/or exponent (e.g., `2.5`, `1e-9`, `6.02e23`),
+//!   an optional `f32`/`f64` suffix (`1.5f32`, `2f32`), and the special values `inf` and `nan`.
+//! - **String literals**: Enclosed in double quotes, support escape sequences (`\n`, `\t`, `\r`, `\\`, `\"`,
+//!   `\x7F`, `\u{1F600}`). Raw strings (`r"..."`, `r#"..."#`) skip escape processing, and
+//!   multi-line strings (`"""` ... `"""`) strip the closing delimiter's indentation from each line.
+//!   No form may contain a NUL character, since runtime strings are NUL-terminated.
 //! - **Punctuation**: `(`, `)`, `{`, `}`, `,`, `:`, `=`, `->`
 //! - **Newline**: Emitted after certain tokens (identifiers, literals, `)`, `}`) for statement termination,
 //!   inspired by Go's automatic semicolon insertion
-//! - **Comments**: Line comments starting with `//`
+//! - **Comments**: Line comments starting with `//` and block comments between `/*` and `*/`,
+//!   which may be nested. A block comment spanning lines terminates a statement like a newline.
+//! - **Doc comments**: Lines starting with `///` produce [`TokenKind::DocComment`] tokens, which
+//!   the parser attaches to the following declaration
 //!
 //! # Examples
 //!
@@ -44,6 +53,8 @@
 //! - [`cursor`] - Position tracking and character navigation
 //! - [`skip`] - Whitespace and comment handling
 //! - [`tokens`] - Token recognition and reading
+//! - [`strings`] - String literal reading (quoted, raw, and multi-line)
+//! - [`numbers`] - Numeric literal reading (radix prefixes, separators, and suffixes)
 //! - `tests` - Unit tests (test-only)
 //!
 //! # See Also
@@ -53,7 +64,9 @@
 
 mod cursor;
 mod error;
+mod numbers;
 mod skip;
+mod strings;
 mod tokens;
 
 #[cfg(test)]
@@ -124,8 +137,8 @@ impl<'a> Lexer<'a> {
     ///
     /// Returns an error if:
     /// - An unexpected character is encountered (not whitespace, identifier, string, or punctuation)
-    /// - A string literal is not properly terminated
-    /// - An unknown escape sequence is used in a string literal
+    /// - A string literal or block comment is not properly terminated
+    /// - An unknown escape sequence or a NUL character is used in a string literal
     /// - An integer literal exceeds the i64 range
     /// - A minus sign `-` is not followed by `>` (only `->` is valid)
     pub fn tokenize(&mut self) -> Result<Vec<Token>, LexError> {
@@ -147,6 +160,13 @@ impl<'a> Lexer<'a> {
                 continue;
             }
 
+            // Doc comments are kept as tokens but leave the trailing newline
+            // in place, so they do not change which Newline tokens are emitted
+            if let Some(token) = self.read_doc_comment() {
+                tokens.push(token);
+                continue;
+            }
+
             // Check for and skip comments (which may consume a trailing newline)
             if let Some(consumed_newline) = self.skip_comment() {
                 // Only emit a Newline token if the comment actually consumed a newline
@@ -161,6 +181,18 @@ impl<'a> Lexer<'a> {
                 continue;
             }
 
+            // A block comment containing a newline terminates a statement
+            // just like the newline itself would
+            let (comment_pos, comment_line, comment_column) = (self.pos, self.line, self.column);
+            if let Some(contains_newline) = self.skip_block_comment()? {
+                if contains_newline && self.should_emit_newline() {
+                    let span = Span::new(comment_pos, self.pos, comment_line, comment_column);
+                    tokens.push(Token::new(TokenKind::Newline, span));
+                    self.last_token_kind = Some(TokenKind::Newline);
+                }
+                continue;
+            }
+
             if self.is_eof() {
                 let span = Span::new(self.pos, self.pos, self.line, self.column);
                 tokens.push(Token::new(TokenKind::Eof, span));
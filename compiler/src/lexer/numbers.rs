@@ -0,0 +1,260 @@
+//! Numeric literal reading for the lexer.
+//!
+//! Integer literals may be written in decimal, hexadecimal (`0x`), octal
+//! (`0o`) or binary (`0b`), may contain `_` digit separators, and may end
+//! with an integer type suffix (`255u8`, `1_000i64`). Float literals are
+//! decimal with a fraction and/or exponent (`2.5`, `1e-9`, `6.02e23`), also
+//! with `_` separators, and may end with a float type suffix (`1.5f32`).
+//! A decimal integer with a float suffix (`2f32`) is a float literal.
+
+use super::Lexer;
+use super::error::LexError;
+use crate::token::{Span, Token, TokenKind};
+
+/// Type names accepted as integer literal suffixes.
+const INTEGER_SUFFIXES: &[&str] = &["i8", "i16", "i32", "i64", "u8", "u16", "u32", "u64"];
+
+/// Type names accepted as float literal suffixes.
+const FLOAT_SUFFIXES: &[&str] = &["f32", "f64"];
+
+impl<'a> Lexer<'a> {
+    /// Reads a numeric literal from the input.
+    ///
+    /// Numeric literals are either:
+    /// - Integer literals: decimal digits, or `0x`/`0o`/`0b` followed by
+    ///   digits of that radix, optionally followed by an integer type suffix
+    /// - Float literals: `digits ('.' digits)? (('e' | 'E') ('+' | '-')? digits)?`
+    ///   with at least a fraction or an exponent, optionally followed by
+    ///   `f32` or `f64`; or decimal digits followed by `f32` or `f64`
+    ///
+    /// `_` may appear anywhere after the first digit (or after the radix
+    /// prefix) and is ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_pos` - The byte position of the first digit
+    /// * `start_line` - The line number of the first digit
+    /// * `start_column` - The column number of the first digit
+    ///
+    /// # Returns
+    ///
+    /// A [`Token`] with kind [`TokenKind::IntLiteral`],
+    /// [`TokenKind::SuffixedIntLiteral`], [`TokenKind::FloatLiteral`] or
+    /// [`TokenKind::SuffixedFloatLiteral`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LexError`] if:
+    /// - a radix prefix has no digits, or is followed by a digit outside its radix
+    /// - an integer is too large to fit in a `u64`
+    /// - a literal ends in an unknown suffix
+    /// - a float literal cannot be parsed or is too large for its type
+    pub(super) fn read_number(
+        &mut self,
+        start_pos: usize,
+        start_line: usize,
+        start_column: usize,
+    ) -> Result<Token, LexError> {
+        let rest = &self.input[self.pos..];
+        let radix = match rest.get(..2) {
+            Some("0x") => Some((16, "hexadecimal")),
+            Some("0o") => Some((8, "octal")),
+            Some("0b") => Some((2, "binary")),
+            _ => None,
+        };
+        if let Some((radix, radix_name)) = radix {
+            return self.read_radix_integer(radix, radix_name, start_pos, start_line, start_column);
+        }
+
+        self.skip_digits(10);
+        let mut is_float = false;
+
+        // Parse a fraction only when a dot is followed by at least one digit.
+        if self.current_char() == Some('.') && self.peek_char(1).is_some_and(|c| c.is_ascii_digit())
+        {
+            self.advance(); // consume '.'
+            self.skip_digits(10);
+            is_float = true;
+        }
+
+        // Parse an exponent only when `e`/`E` is followed by digits, so that
+        // `1else` or `2em` fall through to the suffix check.
+        if matches!(self.current_char(), Some('e' | 'E')) {
+            let exponent_digit = match self.peek_char(1) {
+                Some('+' | '-') => self.peek_char(2),
+                next => next,
+            };
+            if exponent_digit.is_some_and(|c| c.is_ascii_digit()) {
+                self.advance(); // consume 'e'
+                if matches!(self.current_char(), Some('+' | '-')) {
+                    self.advance();
+                }
+                self.skip_digits(10);
+                is_float = true;
+            }
+        }
+
+        let digits_end = self.pos;
+        let suffix = self.read_literal_suffix();
+        match suffix {
+            Some(suffix) if FLOAT_SUFFIXES.contains(&suffix) => {
+                self.finish_float(suffix, start_pos, digits_end, start_line, start_column)
+            }
+            Some(suffix) if is_float => Err(LexError::invalid_float_suffix(
+                suffix,
+                Span::new(start_pos, self.pos, start_line, start_column),
+            )),
+            None if is_float => {
+                self.finish_float("f64", start_pos, digits_end, start_line, start_column)
+            }
+            _ => self.finish_integer(10, suffix, start_pos, digits_end, start_line, start_column),
+        }
+    }
+
+    /// Reads the digits after a `0x`, `0o` or `0b` prefix.
+    fn read_radix_integer(
+        &mut self,
+        radix: u32,
+        radix_name: &str,
+        start_pos: usize,
+        start_line: usize,
+        start_column: usize,
+    ) -> Result<Token, LexError> {
+        self.advance(); // skip 0
+        self.advance(); // skip radix letter
+        let digits_start = self.pos;
+        self.skip_digits(radix);
+
+        if let Some(c) = self.current_char().filter(char::is_ascii_digit) {
+            return Err(LexError::invalid_digit_for_radix(
+                c,
+                radix_name,
+                Span::new(self.pos, self.pos + 1, self.line, self.column),
+            ));
+        }
+        if !self.input[digits_start..self.pos]
+            .chars()
+            .any(|c| c.is_digit(radix))
+        {
+            let prefix = &self.input[start_pos..digits_start];
+            return Err(LexError::missing_radix_digits(
+                prefix,
+                Span::new(start_pos, self.pos, start_line, start_column),
+            ));
+        }
+
+        let digits_end = self.pos;
+        let suffix = self.read_literal_suffix();
+        self.finish_integer(
+            radix,
+            suffix,
+            start_pos,
+            digits_end,
+            start_line,
+            start_column,
+        )
+    }
+
+    /// Parses the integer literal whose digits end at `digits_end`, checking
+    /// its already-read type suffix.
+    fn finish_integer(
+        &mut self,
+        radix: u32,
+        suffix: Option<&str>,
+        start_pos: usize,
+        digits_end: usize,
+        start_line: usize,
+        start_column: usize,
+    ) -> Result<Token, LexError> {
+        let span = Span::new(start_pos, self.pos, start_line, start_column);
+        if let Some(suffix) = suffix
+            && !INTEGER_SUFFIXES.contains(&suffix)
+        {
+            return Err(LexError::invalid_integer_suffix(suffix, span));
+        }
+
+        let input = self.input;
+        let value_str = &input[start_pos..digits_end];
+        let digits: String = if radix == 10 {
+            value_str.replace('_', "")
+        } else {
+            value_str[2..].replace('_', "")
+        };
+        let value = u64::from_str_radix(&digits, radix)
+            .map_err(|_: std::num::ParseIntError| LexError::integer_overflow(value_str, span))?;
+
+        let kind = match suffix {
+            Some(suffix) => TokenKind::SuffixedIntLiteral(value, suffix.to_string()),
+            None => TokenKind::IntLiteral(value),
+        };
+        Ok(Token::new(kind, span))
+    }
+
+    /// Parses the float literal whose digits end at `digits_end` as a value
+    /// of type `ty` (`f32` or `f64`).
+    ///
+    /// An unsuffixed literal is checked against the `f64` range but produces
+    /// a plain [`TokenKind::FloatLiteral`], whose type adapts to its context.
+    fn finish_float(
+        &mut self,
+        ty: &str,
+        start_pos: usize,
+        digits_end: usize,
+        start_line: usize,
+        start_column: usize,
+    ) -> Result<Token, LexError> {
+        let span = Span::new(start_pos, self.pos, start_line, start_column);
+        let input = self.input;
+        let value_str = &input[start_pos..digits_end];
+        let digits = value_str.replace('_', "");
+
+        let value = if ty == "f32" {
+            digits.parse::<f32>().map(f64::from)
+        } else {
+            digits.parse::<f64>()
+        }
+        .map_err(|_: std::num::ParseFloatError| LexError::invalid_float_literal(value_str, span))?;
+        if value.is_infinite() {
+            return Err(LexError::float_literal_out_of_range(value_str, ty, span));
+        }
+
+        let suffixed = digits_end != self.pos;
+        let kind = if suffixed {
+            TokenKind::SuffixedFloatLiteral(value, ty.to_string())
+        } else {
+            TokenKind::FloatLiteral(value)
+        };
+        Ok(Token::new(kind, span))
+    }
+
+    /// Skips digits of the given radix and `_` separators.
+    fn skip_digits(&mut self, radix: u32) {
+        while self
+            .current_char()
+            .is_some_and(|c| c.is_digit(radix) || c == '_')
+        {
+            self.advance();
+        }
+    }
+
+    /// Returns the character `offset` characters after the current one.
+    fn peek_char(&self, offset: usize) -> Option<char> {
+        self.input[self.pos..].chars().nth(offset)
+    }
+
+    /// Reads an alphanumeric suffix directly following a numeric literal,
+    /// if there is one.
+    fn read_literal_suffix(&mut self) -> Option<&'a str> {
+        if !self.current_char().is_some_and(|c| c.is_ascii_alphabetic()) {
+            return None;
+        }
+        let start = self.pos;
+        while self
+            .current_char()
+            .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            self.advance();
+        }
+        Some(&self.input[start..self.pos])
+    }
+}
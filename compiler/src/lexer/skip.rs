@@ -1,11 +1,12 @@
 //! Whitespace and comment skipping for the lexer.
 //!
-//! This module provides methods for skipping over whitespace characters
-//! and line comments during tokenization.
+//! This module provides methods for skipping over whitespace characters,
+//! line comments and (nested) block comments during tokenization, and for
+//! reading `///` doc comments, which are kept as tokens.
 
 use super::Lexer;
 use super::error::LexError;
-use crate::token::{Span, TokenKind};
+use crate::token::{Span, Token, TokenKind};
 
 impl<'a> Lexer<'a> {
     /// Skips consecutive whitespace characters except newlines.
@@ -59,8 +60,11 @@ impl<'a> Lexer<'a> {
             &self.last_token_kind,
             Some(TokenKind::Identifier(_))
                 | Some(TokenKind::IntLiteral(_))
+                | Some(TokenKind::SuffixedIntLiteral(..))
                 | Some(TokenKind::FloatLiteral(_))
+                | Some(TokenKind::SuffixedFloatLiteral(..))
                 | Some(TokenKind::StringLiteral(_))
+                | Some(TokenKind::CharLiteral(_))
                 | Some(TokenKind::BoolLiteral(_))
                 | Some(TokenKind::Return)
                 | Some(TokenKind::Break)
@@ -95,4 +99,86 @@ impl<'a> Lexer<'a> {
             None
         }
     }
+
+    /// Reads a `///` doc comment if one is present at the current position.
+    ///
+    /// The comment extends to the end of the line. Unlike [`skip_comment`],
+    /// the trailing newline is left in place so that newline emission works
+    /// exactly as if the doc comment were absent. Four or more slashes
+    /// (`////`) start an ordinary line comment, not a doc comment.
+    ///
+    /// [`skip_comment`]: Self::skip_comment
+    ///
+    /// # Returns
+    ///
+    /// A [`TokenKind::DocComment`] token holding the text after `///`, with
+    /// a single leading space and any trailing `\r` removed, or `None` if no
+    /// doc comment was present.
+    pub(super) fn read_doc_comment(&mut self) -> Option<Token> {
+        let rest = &self.input[self.pos..];
+        if !rest.starts_with("///") || rest.starts_with("////") {
+            return None;
+        }
+
+        let (start_pos, start_line, start_column) = (self.pos, self.line, self.column);
+        while self.current_char().is_some_and(|c| c != '\n') {
+            self.advance();
+        }
+
+        let input = self.input;
+        let text = &input[start_pos + 3..self.pos];
+        let text = text.strip_prefix(' ').unwrap_or(text);
+        let text = text.strip_suffix('\r').unwrap_or(text);
+        Some(Token::new(
+            TokenKind::DocComment(text.to_string()),
+            Span::new(start_pos, self.pos, start_line, start_column),
+        ))
+    }
+
+    /// Skips a block comment if one is present at the current position.
+    ///
+    /// Block comments start with `/*`, end with `*/`, and may be nested:
+    /// `/* outer /* inner */ still a comment */` is a single comment.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(None)` if no comment was present
+    /// - `Ok(Some(true))` if a comment was skipped and contained a newline
+    /// - `Ok(Some(false))` if a comment was skipped and fit on one line
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input ends before every opened comment is closed.
+    pub(super) fn skip_block_comment(&mut self) -> Result<Option<bool>, LexError> {
+        if !self.input[self.pos..].starts_with("/*") {
+            return Ok(None);
+        }
+
+        let (start_pos, start_line, start_column) = (self.pos, self.line, self.column);
+        let mut depth = 0usize;
+        let mut contains_newline = false;
+        loop {
+            let rest = &self.input[self.pos..];
+            if rest.starts_with("/*") {
+                self.advance();
+                self.advance();
+                depth += 1;
+            } else if rest.starts_with("*/") {
+                self.advance();
+                self.advance();
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(Some(contains_newline));
+                }
+            } else if let Some(c) = self.current_char() {
+                contains_newline |= c == '\n';
+                self.advance();
+            } else {
+                return Err(LexError::unterminated_block_comment(
+                    depth,
+                    Span::new(start_pos, start_pos + 2, start_line, start_column),
+                ));
+            }
+        }
+    }
 }
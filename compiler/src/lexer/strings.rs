@@ -0,0 +1,543 @@
+//! String and character literal reading for the lexer.
+//!
+//! This module reads the three forms of string literal:
+//!
+//! - **Quoted strings** (`"..."`): single-line, with escape sequences
+//! - **Raw strings** (`r"..."`, `r#"..."#`): no escape processing, may span lines
+//! - **Multi-line strings** (`"""` ... `"""`): escape sequences, with the
+//!   indentation of the closing delimiter stripped from every line
+//!
+//! and character literals (`'a'`, `'\n'`), which use the same escape
+//! sequences as quoted strings.
+
+use super::Lexer;
+use super::error::{LexError, LexErrorKind};
+use crate::token::{Span, Token, TokenKind};
+
+/// The delimiter that opens and closes a multi-line string.
+const MULTILINE_DELIMITER: &str = "\"\"\"";
+
+impl<'a> Lexer<'a> {
+    /// Reads a string literal from the input.
+    ///
+    /// The opening double quote should be at the current position. This method
+    /// processes escape sequences (see [`read_escape`](Self::read_escape)) and
+    /// returns the unescaped string value.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_pos` - The byte position of the opening quote
+    /// * `start_line` - The line number of the opening quote
+    /// * `start_column` - The column number of the opening quote
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LexError`] if:
+    /// - The string contains an unknown or malformed escape sequence
+    /// - The string contains a NUL character
+    /// - The string is not terminated (reaches end of line or file)
+    pub(super) fn read_string(
+        &mut self,
+        start_pos: usize,
+        start_line: usize,
+        start_column: usize,
+    ) -> Result<Token, LexError> {
+        self.advance(); // skip opening "
+        let mut value = String::new();
+
+        loop {
+            match self.current_char() {
+                Some('"') => {
+                    self.advance(); // skip closing "
+                    let span = Span::new(start_pos, self.pos, start_line, start_column);
+                    return Ok(Token::new(TokenKind::StringLiteral(value), span));
+                }
+                Some('\\') => {
+                    value.push(self.read_escape(start_pos, start_line, start_column)?);
+                }
+                Some('\0') => return Err(self.nul_character_error()),
+                Some('\n') => {
+                    return Err(LexError::unterminated_string_newline(Span::new(
+                        start_pos,
+                        self.pos,
+                        start_line,
+                        start_column,
+                    )));
+                }
+                Some(c) => {
+                    value.push(c);
+                    self.advance();
+                }
+                None => {
+                    return Err(LexError::unterminated_string(Span::new(
+                        start_pos,
+                        self.pos,
+                        start_line,
+                        start_column,
+                    )));
+                }
+            }
+        }
+    }
+
+    /// Reads a character literal from the input.
+    ///
+    /// The opening single quote should be at the current position. The
+    /// literal holds exactly one character or escape sequence (see
+    /// [`read_escape`](Self::read_escape)).
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LexError`] if the literal is empty, holds more than one
+    /// character, is NUL, contains a malformed escape sequence, or is not
+    /// terminated on the same line.
+    pub(super) fn read_char(
+        &mut self,
+        start_pos: usize,
+        start_line: usize,
+        start_column: usize,
+    ) -> Result<Token, LexError> {
+        self.advance(); // skip opening '
+        let span = |lexer: &Self| Span::new(start_pos, lexer.pos, start_line, start_column);
+
+        let value = match self.current_char() {
+            Some('\'') => {
+                self.advance();
+                return Err(LexError::invalid_char_literal(span(self)));
+            }
+            Some('\\') => self
+                .read_escape(start_pos, start_line, start_column)
+                .map_err(|error| {
+                    if error.kind() == LexErrorKind::NulInString {
+                        LexError::nul_in_char_literal(error.span())
+                    } else {
+                        error
+                    }
+                })?,
+            Some('\0') => {
+                return Err(LexError::nul_in_char_literal(Span::new(
+                    self.pos,
+                    self.pos + 1,
+                    self.line,
+                    self.column,
+                )));
+            }
+            Some('\n') | None => return Err(LexError::unterminated_char_literal(span(self))),
+            Some(c) => {
+                self.advance();
+                c
+            }
+        };
+
+        if self.current_char() != Some('\'') {
+            // Consume the rest of a literal like 'ab' so the error covers it.
+            while let Some(c) = self.current_char() {
+                if c == '\n' {
+                    break;
+                }
+                self.advance();
+                if c == '\'' {
+                    return Err(LexError::invalid_char_literal(span(self)));
+                }
+            }
+            return Err(LexError::unterminated_char_literal(span(self)));
+        }
+        self.advance(); // skip closing '
+        Ok(Token::new(TokenKind::CharLiteral(value), span(self)))
+    }
+
+    /// Reads an escape sequence starting at the backslash under the cursor.
+    ///
+    /// # Supported Escape Sequences
+    ///
+    /// - `\n` - newline
+    /// - `\t` - tab
+    /// - `\r` - carriage return
+    /// - `\\` - backslash
+    /// - `\"` - double quote
+    /// - `\'` - single quote
+    /// - `\xHH` - ASCII character with exactly two hex digits, `\x01` to `\x7F`
+    /// - `\u{H...}` - Unicode scalar value with 1 to 6 hex digits
+    ///
+    /// Escapes for NUL (`\0`, `\x00`, `\u{0}`) are rejected, since runtime
+    /// strings are NUL-terminated.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_pos` - The byte position of the literal's opening delimiter
+    /// * `start_line` - The line number of the literal's opening delimiter
+    /// * `start_column` - The column number of the literal's opening delimiter
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LexError`] if the escape is unknown, malformed or NUL, or
+    /// if the input ends after the backslash.
+    fn read_escape(
+        &mut self,
+        start_pos: usize,
+        start_line: usize,
+        start_column: usize,
+    ) -> Result<char, LexError> {
+        let escape_pos = self.pos;
+        let escape_line = self.line;
+        let escape_column = self.column;
+        self.advance(); // skip backslash
+
+        let simple = match self.current_char() {
+            Some('n') => '\n',
+            Some('t') => '\t',
+            Some('r') => '\r',
+            Some('0') => {
+                return Err(LexError::nul_in_string(Span::new(
+                    escape_pos,
+                    self.pos + 1,
+                    escape_line,
+                    escape_column,
+                )));
+            }
+            Some('\\') => '\\',
+            Some('"') => '"',
+            Some('\'') => '\'',
+            Some('x') => {
+                self.advance();
+                return self.read_hex_escape(escape_pos, escape_line, escape_column);
+            }
+            Some('u') => {
+                self.advance();
+                return self.read_unicode_escape(escape_pos, escape_line, escape_column);
+            }
+            Some(c) => {
+                return Err(LexError::unknown_escape_sequence(
+                    c,
+                    Span::new(
+                        escape_pos,
+                        self.pos + c.len_utf8(),
+                        escape_line,
+                        escape_column,
+                    ),
+                ));
+            }
+            None => {
+                return Err(LexError::unterminated_string(Span::new(
+                    start_pos,
+                    self.pos,
+                    start_line,
+                    start_column,
+                )));
+            }
+        };
+        self.advance();
+        Ok(simple)
+    }
+
+    /// Reads the two hex digits of a `\x` escape. The cursor is after the `x`.
+    fn read_hex_escape(
+        &mut self,
+        escape_pos: usize,
+        escape_line: usize,
+        escape_column: usize,
+    ) -> Result<char, LexError> {
+        let mut digits = String::new();
+        while digits.len() < 2 {
+            match self.current_char() {
+                Some(c) if c.is_ascii_hexdigit() => {
+                    digits.push(c);
+                    self.advance();
+                }
+                _ => {
+                    return Err(LexError::invalid_hex_escape(Span::new(
+                        escape_pos,
+                        self.pos,
+                        escape_line,
+                        escape_column,
+                    )));
+                }
+            }
+        }
+
+        let span = Span::new(escape_pos, self.pos, escape_line, escape_column);
+        // Two hex digits always fit in a u8.
+        let value =
+            u8::from_str_radix(&digits, 16).map_err(|_| LexError::invalid_hex_escape(span))?;
+        if !value.is_ascii() {
+            return Err(LexError::hex_escape_out_of_range(&digits, span));
+        }
+        if value == 0 {
+            return Err(LexError::nul_in_string(span));
+        }
+        Ok(char::from(value))
+    }
+
+    /// Reads the `{H...}` part of a `\u` escape. The cursor is after the `u`.
+    fn read_unicode_escape(
+        &mut self,
+        escape_pos: usize,
+        escape_line: usize,
+        escape_column: usize,
+    ) -> Result<char, LexError> {
+        let invalid = |lexer: &Self| {
+            LexError::invalid_unicode_escape(Span::new(
+                escape_pos,
+                lexer.pos,
+                escape_line,
+                escape_column,
+            ))
+        };
+
+        if self.current_char() != Some('{') {
+            return Err(invalid(self));
+        }
+        self.advance();
+
+        let mut digits = String::new();
+        while let Some(c) = self.current_char().filter(char::is_ascii_hexdigit) {
+            digits.push(c);
+            self.advance();
+        }
+        if digits.is_empty() || digits.len() > 6 || self.current_char() != Some('}') {
+            return Err(invalid(self));
+        }
+        self.advance(); // skip }
+
+        // At most six hex digits always fit in a u32.
+        let value = u32::from_str_radix(&digits, 16).map_err(|_| invalid(self))?;
+        let span = Span::new(escape_pos, self.pos, escape_line, escape_column);
+        if value == 0 {
+            return Err(LexError::nul_in_string(span));
+        }
+        char::from_u32(value).ok_or_else(|| LexError::invalid_unicode_scalar(&digits, span))
+    }
+
+    /// Returns `true` if the cursor is at the `r` of a raw string prefix
+    /// (`r"` or `r#...#"`).
+    pub(super) fn at_raw_string_start(&self) -> bool {
+        let rest = &self.input[self.pos + 1..];
+        rest.trim_start_matches('#').starts_with('"')
+    }
+
+    /// Reads a raw string literal from the input.
+    ///
+    /// The `r` prefix should be at the current position. It may be followed by
+    /// any number of `#` characters before the opening quote; the literal ends
+    /// at the first `"` followed by the same number of `#` characters. The
+    /// content is taken verbatim: backslashes are not escapes and newlines are
+    /// kept as written.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_pos` - The byte position of the `r` prefix
+    /// * `start_line` - The line number of the `r` prefix
+    /// * `start_column` - The column number of the `r` prefix
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LexError`] if the literal contains a NUL character or the
+    /// closing delimiter is not found before the end of the file.
+    pub(super) fn read_raw_string(
+        &mut self,
+        start_pos: usize,
+        start_line: usize,
+        start_column: usize,
+    ) -> Result<Token, LexError> {
+        self.advance(); // skip r
+        let mut hashes = 0;
+        while self.current_char() == Some('#') {
+            hashes += 1;
+            self.advance();
+        }
+        self.advance(); // skip opening "
+
+        let closing = format!("\"{}", "#".repeat(hashes));
+        let mut value = String::new();
+
+        loop {
+            if self.input[self.pos..].starts_with(&closing) {
+                for _ in 0..closing.len() {
+                    self.advance();
+                }
+                let span = Span::new(start_pos, self.pos, start_line, start_column);
+                return Ok(Token::new(TokenKind::StringLiteral(value), span));
+            }
+            match self.current_char() {
+                Some('\0') => return Err(self.nul_character_error()),
+                Some(c) => {
+                    value.push(c);
+                    self.advance();
+                }
+                None => {
+                    return Err(LexError::unterminated_raw_string(
+                        hashes,
+                        Span::new(start_pos, self.pos, start_line, start_column),
+                    ));
+                }
+            }
+        }
+    }
+
+    /// Reads a multi-line string literal from the input.
+    ///
+    /// The opening `"""` should be at the current position and must be
+    /// followed only by whitespace up to the end of its line. The closing
+    /// `"""` must be on its own line, and its leading whitespace is removed
+    /// from every content line:
+    ///
+    /// ```text
+    /// let text = """
+    ///     first
+    ///       indented
+    ///     """
+    /// ```
+    ///
+    /// produces `"first\n  indented"`. Lines are joined with `\n`, with no
+    /// trailing newline; write an empty last line to get one. Whitespace-only
+    /// lines become empty. Escape sequences are processed as in quoted strings,
+    /// and `\"""` writes a literal `"""`.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_pos` - The byte position of the opening delimiter
+    /// * `start_line` - The line number of the opening delimiter
+    /// * `start_column` - The column number of the opening delimiter
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LexError`] if:
+    /// - Text follows the opening delimiter on its line
+    /// - Text precedes the closing delimiter on its line
+    /// - A non-blank line is indented less than the closing delimiter
+    /// - The literal contains an unknown or malformed escape sequence
+    /// - The literal contains a NUL character
+    /// - The closing delimiter is missing
+    pub(super) fn read_multiline_string(
+        &mut self,
+        start_pos: usize,
+        start_line: usize,
+        start_column: usize,
+    ) -> Result<Token, LexError> {
+        let unterminated = |end: usize| {
+            LexError::unterminated_string(Span::new(start_pos, end, start_line, start_column))
+        };
+
+        for _ in 0..MULTILINE_DELIMITER.len() {
+            self.advance();
+        }
+        self.skip_inline_whitespace();
+        self.skip_carriage_return_before_newline();
+        match self.current_char() {
+            Some('\n') => self.advance(),
+            Some(c) => {
+                return Err(LexError::multiline_string_opening_content(Span::new(
+                    self.pos,
+                    self.pos + c.len_utf8(),
+                    self.line,
+                    self.column,
+                )));
+            }
+            None => return Err(unterminated(self.pos)),
+        }
+
+        let Some(indent) = self.multiline_closing_indent() else {
+            return Err(unterminated(self.input.len()));
+        };
+
+        let mut lines: Vec<String> = Vec::new();
+        loop {
+            let line_rest = &self.input[self.pos..];
+            let line_text = line_rest.split('\n').next().unwrap_or_default();
+
+            // The closing delimiter, preceded only by whitespace.
+            if line_text
+                .trim_start_matches([' ', '\t'])
+                .starts_with(MULTILINE_DELIMITER)
+            {
+                self.skip_inline_whitespace();
+                for _ in 0..MULTILINE_DELIMITER.len() {
+                    self.advance();
+                }
+                let span = Span::new(start_pos, self.pos, start_line, start_column);
+                return Ok(Token::new(TokenKind::StringLiteral(lines.join("\n")), span));
+            }
+
+            if line_text.trim().is_empty() {
+                self.skip_inline_whitespace();
+                self.skip_carriage_return_before_newline();
+                if self.current_char().is_none() {
+                    return Err(unterminated(self.pos));
+                }
+                self.advance(); // skip \n
+                lines.push(String::new());
+                continue;
+            }
+
+            if !line_text.starts_with(indent) {
+                return Err(LexError::multiline_string_insufficient_indentation(
+                    Span::new(self.pos, self.pos + line_text.len(), self.line, self.column),
+                ));
+            }
+            for _ in 0..indent.chars().count() {
+                self.advance();
+            }
+
+            let mut line = String::new();
+            loop {
+                self.skip_carriage_return_before_newline();
+                match self.current_char() {
+                    Some('\n') => {
+                        self.advance();
+                        break;
+                    }
+                    Some('\\') => {
+                        line.push(self.read_escape(start_pos, start_line, start_column)?);
+                    }
+                    Some('\0') => return Err(self.nul_character_error()),
+                    Some('"') if self.input[self.pos..].starts_with(MULTILINE_DELIMITER) => {
+                        return Err(LexError::multiline_string_closing_content(Span::new(
+                            self.pos,
+                            self.pos + MULTILINE_DELIMITER.len(),
+                            self.line,
+                            self.column,
+                        )));
+                    }
+                    Some(c) => {
+                        line.push(c);
+                        self.advance();
+                    }
+                    None => return Err(unterminated(self.pos)),
+                }
+            }
+            lines.push(line);
+        }
+    }
+
+    /// Returns the error for a NUL character under the cursor in a string
+    /// literal.
+    fn nul_character_error(&self) -> LexError {
+        LexError::nul_in_string(Span::new(self.pos, self.pos + 1, self.line, self.column))
+    }
+
+    /// Returns the leading whitespace of the line holding the closing `"""`
+    /// of the multi-line string whose content starts at the cursor, or `None`
+    /// if no line consists of whitespace followed by `"""`.
+    fn multiline_closing_indent(&self) -> Option<&'a str> {
+        self.input[self.pos..].split('\n').find_map(|line| {
+            let content = line.trim_start_matches([' ', '\t']);
+            content
+                .starts_with(MULTILINE_DELIMITER)
+                .then(|| &line[..line.len() - content.len()])
+        })
+    }
+
+    /// Skips spaces and tabs without leaving the current line.
+    fn skip_inline_whitespace(&mut self) {
+        while matches!(self.current_char(), Some(' ' | '\t')) {
+            self.advance();
+        }
+    }
+
+    /// Skips a `\r` that ends a line, so CRLF line endings read as `\n`.
+    fn skip_carriage_return_before_newline(&mut self) {
+        if self.input[self.pos..].starts_with("\r\n") {
+            self.advance();
+        }
+    }
+}
@@ -62,3 +62,100 @@ fn test_multiple_comments() {
         vec![TokenKind::Identifier("foo".to_string()), TokenKind::Eof]
     );
 }
+
+#[test]
+fn test_block_comment_between_tokens() {
+    let kinds = tokenize_kinds("a /* comment */ b");
+    assert_eq!(
+        kinds,
+        vec![
+            TokenKind::Identifier("a".to_string()),
+            TokenKind::Identifier("b".to_string()),
+            TokenKind::Eof
+        ]
+    );
+}
+
+#[test]
+fn test_block_comment_nested() {
+    let kinds = tokenize_kinds("/* outer /* inner */ still comment */ foo");
+    assert_eq!(
+        kinds,
+        vec![TokenKind::Identifier("foo".to_string()), TokenKind::Eof]
+    );
+}
+
+#[test]
+fn test_block_comment_spanning_lines_emits_newline() {
+    // A multi-line block comment terminates the statement like a newline
+    let kinds = tokenize_kinds("a /* first\nsecond */ b");
+    assert_eq!(
+        kinds,
+        vec![
+            TokenKind::Identifier("a".to_string()),
+            TokenKind::Newline,
+            TokenKind::Identifier("b".to_string()),
+            TokenKind::Eof
+        ]
+    );
+}
+
+#[test]
+fn test_block_comment_does_not_hide_line_comment_marker() {
+    // `//` inside a block comment does not swallow the closing `*/`
+    let kinds = tokenize_kinds("/* a // b */ c");
+    assert_eq!(
+        kinds,
+        vec![TokenKind::Identifier("c".to_string()), TokenKind::Eof]
+    );
+}
+
+#[test]
+fn test_doc_comment_token() {
+    let kinds = tokenize_kinds("/// Adds two numbers.\nfn");
+    assert_eq!(
+        kinds,
+        vec![
+            TokenKind::DocComment("Adds two numbers.".to_string()),
+            TokenKind::Fn,
+            TokenKind::Eof
+        ]
+    );
+}
+
+#[test]
+fn test_doc_comment_keeps_extra_indentation_and_strips_cr() {
+    let kinds = tokenize_kinds("///   indented\r\n///\n");
+    assert_eq!(
+        kinds,
+        vec![
+            TokenKind::DocComment("  indented".to_string()),
+            TokenKind::DocComment(String::new()),
+            TokenKind::Eof
+        ]
+    );
+}
+
+#[test]
+fn test_four_slashes_is_plain_comment() {
+    let kinds = tokenize_kinds("//// not a doc comment\nfoo");
+    assert_eq!(
+        kinds,
+        vec![TokenKind::Identifier("foo".to_string()), TokenKind::Eof]
+    );
+}
+
+#[test]
+fn test_doc_comment_after_code_keeps_newline() {
+    let kinds = tokenize_kinds("}\n/// doc\nfn");
+    assert_eq!(
+        kinds,
+        vec![
+            TokenKind::RightBrace,
+            TokenKind::Newline,
+            TokenKind::DocComment("doc".to_string()),
+            TokenKind::Fn,
+            TokenKind::Eof
+        ]
+    );
+}
@@ -23,9 +23,194 @@ fn test_error_newline_in_string() {
 
 #[test]
 fn test_error_unknown_escape() {
-    let err = tokenize_error(r#""\x""#);
+    let err = tokenize_error(r#""\q""#);
     assert_eq!(err.kind(), LexErrorKind::UnknownEscapeSequence);
-    assert_eq!(err.message(), "Unknown escape sequence: '\\x'");
+    assert_eq!(err.message(), "Unknown escape sequence: '\\q'");
+}
+
+#[test]
+fn test_error_hex_escape_missing_digits() {
+    let err = tokenize_error(r#""\x4""#);
+    assert_eq!(err.kind(), LexErrorKind::InvalidEscapeSequence);
+    assert_eq!(
+        err.message(),
+        "Invalid hex escape: '\\x' must be followed by exactly two hex digits"
+    );
+    assert_eq!(err.span().start, 1);
+    assert_eq!(err.span().end, 4);
+}
+
+#[test]
+fn test_error_hex_escape_out_of_range() {
+    let err = tokenize_error(r#""\xFF""#);
+    assert_eq!(err.kind(), LexErrorKind::InvalidEscapeSequence);
+    assert_eq!(
+        err.message(),
+        "Hex escape '\\xFF' is out of range; only '\\x00' to '\\x7F' are allowed, use '\\u{...}' for other characters"
+    );
+}
+
+#[test]
+fn test_error_unicode_escape_malformed() {
+    for input in [
+        r#""\u41""#,
+        r#""\u{}""#,
+        r#""\u{1234567}""#,
+        r#""\u{41""#,
+        r#""\u{4G}""#,
+    ] {
+        let err = tokenize_error(input);
+        assert_eq!(err.kind(), LexErrorKind::InvalidEscapeSequence, "{}", input);
+        assert_eq!(
+            err.message(),
+            "Invalid unicode escape: expected '\\u{' followed by 1 to 6 hex digits and '}'"
+        );
+    }
+}
+
+#[test]
+fn test_error_unicode_escape_not_scalar() {
+    let err = tokenize_error(r#""\u{D800}""#);
+    assert_eq!(err.kind(), LexErrorKind::InvalidEscapeSequence);
+    assert_eq!(
+        err.message(),
+        "Unicode escape '\\u{D800}' is not a valid Unicode scalar value"
+    );
+
+    let err = tokenize_error(r#""\u{110000}""#);
+    assert_eq!(err.kind(), LexErrorKind::InvalidEscapeSequence);
+}
+
+#[test]
+fn test_error_nul_escape() {
+    for (input, start, end) in [
+        (r#""a\0b""#, 2, 4),
+        (r#""a\x00b""#, 2, 6),
+        (r#""a\u{0}b""#, 2, 7),
+        ("\"\"\"\n    a\\0\n    \"\"\"", 9, 11),
+    ] {
+        let err = tokenize_error(input);
+        assert_eq!(err.kind(), LexErrorKind::NulInString, "{:?}", input);
+        assert_eq!(
+            err.message(),
+            "Strings cannot contain NUL characters, since runtime strings are NUL-terminated"
+        );
+        assert_eq!(
+            (err.span().start, err.span().end),
+            (start, end),
+            "{:?}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_error_nul_character_in_string() {
+    for input in ["\"a\0b\"", "r\"a\0b\"", "\"\"\"\n    a\0b\n    \"\"\""] {
+        let err = tokenize_error(input);
+        assert_eq!(err.kind(), LexErrorKind::NulInString, "{:?}", input);
+        let start = input.find('\0').unwrap();
+        assert_eq!((err.span().start, err.span().end), (start, start + 1));
+    }
+}
+
+#[test]
+fn test_error_unterminated_raw_string() {
+    let err = tokenize_error(r###"r#"abc"###);
+    assert_eq!(err.kind(), LexErrorKind::UnterminatedString);
+    assert_eq!(
+        err.message(),
+        "Unterminated raw string literal (expected closing '\"#')"
+    );
+}
+
+#[test]
+fn test_error_raw_string_hash_count_mismatch() {
+    let err = tokenize_error(r###"r##"abc"#"###);
+    assert_eq!(err.kind(), LexErrorKind::UnterminatedString);
+}
+
+#[test]
+fn test_error_multiline_string_opening_content() {
+    let err = tokenize_error("\"\"\"text\n\"\"\"");
+    assert_eq!(err.kind(), LexErrorKind::InvalidMultilineString);
+    assert_eq!(
+        err.message(),
+        "Multi-line string content must start on the line after the opening '\"\"\"'"
+    );
+    assert_eq!(err.span().start, 3);
+}
+
+#[test]
+fn test_error_multiline_string_closing_content() {
+    let err = tokenize_error("\"\"\"\n  text\"\"\"\n  \"\"\"");
+    assert_eq!(err.kind(), LexErrorKind::InvalidMultilineString);
+    assert_eq!(
+        err.message(),
+        "Closing '\"\"\"' of a multi-line string must be on its own line"
+    );
+    assert_eq!(err.span().line, 2);
+    assert_eq!(err.span().column, 7);
+}
+
+#[test]
+fn test_error_multiline_string_insufficient_indentation() {
+    let err = tokenize_error("\"\"\"\n    ok\n  short\n    \"\"\"");
+    assert_eq!(err.kind(), LexErrorKind::InvalidMultilineString);
+    assert_eq!(
+        err.message(),
+        "Multi-line string line is indented less than the closing '\"\"\"'"
+    );
+    assert_eq!(err.span().line, 3);
+}
+
+#[test]
+fn test_error_multiline_string_unterminated() {
+    let err = tokenize_error("\"\"\"\n  text\n");
+    assert_eq!(err.kind(), LexErrorKind::UnterminatedString);
+    assert_eq!(err.message(), "Unterminated string literal");
+}
+
+#[test]
+fn test_error_empty_char_literal() {
+    let err = tokenize_error("''");
+    assert_eq!(err.kind(), LexErrorKind::InvalidCharLiteral);
+    assert_eq!(
+        err.message(),
+        "Character literal must contain exactly one character; use double quotes for strings"
+    );
+}
+
+#[test]
+fn test_error_char_literal_with_several_characters() {
+    let err = tokenize_error("'ab' + 1");
+    assert_eq!(err.kind(), LexErrorKind::InvalidCharLiteral);
+    assert_eq!(err.span().start, 0);
+    assert_eq!(err.span().end, 4);
+}
+
+#[test]
+fn test_error_unterminated_char_literal() {
+    for input in ["'a", "'a\nb'", "'"] {
+        let err = tokenize_error(input);
+        assert_eq!(err.kind(), LexErrorKind::UnterminatedString, "{:?}", input);
+        assert_eq!(
+            err.message(),
+            "Unterminated character literal (expected closing ')"
+        );
+    }
+}
+
+#[test]
+fn test_error_nul_char_literal() {
+    for input in ["'\\0'", "'\\x00'", "'\0'"] {
+        let err = tokenize_error(input);
+        assert_eq!(err.kind(), LexErrorKind::NulInString, "{:?}", input);
+        assert_eq!(
+            err.message(),
+            "Character literals cannot be NUL, since characters are printed as NUL-terminated strings"
+        );
+    }
 }
 
 #[test]
@@ -80,3 +265,25 @@ fn test_lex_error_display() {
     let display = format!("{}", err);
     assert_eq!(display, "2:3: Test error");
 }
+
+#[test]
+fn test_error_unterminated_block_comment() {
+    let err = tokenize_error("foo /* never closed");
+    assert_eq!(err.kind(), LexErrorKind::UnterminatedBlockComment);
+    assert_eq!(err.message(), "Unterminated block comment (expected '*/')");
+    assert_eq!(err.span().start, 4);
+    assert_eq!(err.span().column, 5);
+}
+
+#[test]
+fn test_error_unterminated_nested_block_comment() {
+    let err = tokenize_error("/* outer /* inner */");
+    assert_eq!(err.kind(), LexErrorKind::UnterminatedBlockComment);
+    assert_eq!(err.message(), "Unterminated block comment (expected '*/')");
+
+    let err = tokenize_error("/* a /* b");
+    assert_eq!(
+        err.message(),
+        "Unterminated block comment (2 nested comments are still open, expected '*/')"
+    );
+}
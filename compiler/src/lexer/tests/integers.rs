@@ -1,6 +1,7 @@
 //! Tests for integer literal parsing.
 
 use super::*;
+use crate::lexer::LexErrorKind;
 
 #[test]
 fn test_integer_literal_simple() {
@@ -95,3 +96,173 @@ fn test_float_literal_in_let_statement_tokens() {
         ]
     );
 }
+
+#[test]
+fn test_integer_radix_prefixes() {
+    let kinds = tokenize_kinds("0xFF 0xdead_BEEF 0o755 0b1010");
+    assert_eq!(
+        kinds,
+        vec![
+            TokenKind::IntLiteral(255),
+            TokenKind::IntLiteral(0xdead_beef),
+            TokenKind::IntLiteral(0o755),
+            TokenKind::IntLiteral(0b1010),
+            TokenKind::Eof
+        ]
+    );
+}
+
+#[test]
+fn test_integer_underscore_separators() {
+    let kinds = tokenize_kinds("1_000_000 0b1111_0000 1_000.5");
+    assert_eq!(
+        kinds,
+        vec![
+            TokenKind::IntLiteral(1_000_000),
+            TokenKind::IntLiteral(0b1111_0000),
+            TokenKind::FloatLiteral(1000.5),
+            TokenKind::Eof
+        ]
+    );
+}
+
+#[test]
+fn test_integer_type_suffixes() {
+    let kinds = tokenize_kinds("255u8 1_000i64 1_000_i32 0xFFu16");
+    assert_eq!(
+        kinds,
+        vec![
+            TokenKind::SuffixedIntLiteral(255, "u8".to_string()),
+            TokenKind::SuffixedIntLiteral(1000, "i64".to_string()),
+            TokenKind::SuffixedIntLiteral(1000, "i32".to_string()),
+            TokenKind::SuffixedIntLiteral(0xFF, "u16".to_string()),
+            TokenKind::Eof
+        ]
+    );
+}
+
+#[test]
+fn test_hex_integer_overflow() {
+    let err = tokenize_error("0x1_0000_0000_0000_0000");
+    assert_eq!(err.kind(), LexErrorKind::IntegerOverflow);
+    assert_eq!(
+        err.message(),
+        "Integer literal '0x1_0000_0000_0000_0000' is too large (exceeds maximum representable value)"
+    );
+}
+
+#[test]
+fn test_error_radix_prefix_without_digits() {
+    let err = tokenize_error("0x");
+    assert_eq!(err.kind(), LexErrorKind::InvalidIntegerLiteral);
+    assert_eq!(
+        err.message(),
+        "Missing digits after '0x' in integer literal"
+    );
+
+    let err = tokenize_error("0b_");
+    assert_eq!(err.kind(), LexErrorKind::InvalidIntegerLiteral);
+}
+
+#[test]
+fn test_error_digit_outside_radix() {
+    let err = tokenize_error("0b1012");
+    assert_eq!(err.kind(), LexErrorKind::InvalidIntegerLiteral);
+    assert_eq!(err.message(), "Invalid digit '2' in binary literal");
+    assert_eq!(err.span().start, 5);
+
+    let err = tokenize_error("0o78");
+    assert_eq!(err.message(), "Invalid digit '8' in octal literal");
+}
+
+#[test]
+fn test_error_invalid_integer_suffix() {
+    let err = tokenize_error("10abc");
+    assert_eq!(err.kind(), LexErrorKind::InvalidLiteralSuffix);
+    assert_eq!(
+        err.message(),
+        "Invalid suffix 'abc' on integer literal; expected one of i8, i16, i32, i64, u8, u16, u32, u64, f32, f64"
+    );
+    assert_eq!(err.span().end, 5);
+}
+
+#[test]
+fn test_error_invalid_float_suffix() {
+    let err = tokenize_error("1.5u8");
+    assert_eq!(err.kind(), LexErrorKind::InvalidLiteralSuffix);
+    assert_eq!(
+        err.message(),
+        "Invalid suffix 'u8' on float literal; expected f32 or f64"
+    );
+}
+
+#[test]
+fn test_float_literal_exponents() {
+    let kinds = tokenize_kinds("1e-9 6.02e23 1E5 2.5e+3");
+    assert_eq!(
+        kinds,
+        vec![
+            TokenKind::FloatLiteral(1e-9),
+            TokenKind::FloatLiteral(6.02e23),
+            TokenKind::FloatLiteral(1e5),
+            TokenKind::FloatLiteral(2.5e3),
+            TokenKind::Eof
+        ]
+    );
+}
+
+#[test]
+fn test_float_literal_suffixes() {
+    let kinds = tokenize_kinds("1.5f32 2f32 1e3f64");
+    assert_eq!(
+        kinds,
+        vec![
+            TokenKind::SuffixedFloatLiteral(1.5, "f32".to_string()),
+            TokenKind::SuffixedFloatLiteral(2.0, "f32".to_string()),
+            TokenKind::SuffixedFloatLiteral(1e3, "f64".to_string()),
+            TokenKind::Eof
+        ]
+    );
+}
+
+#[test]
+fn test_float_literal_f32_suffix_rounds_to_f32() {
+    let kinds = tokenize_kinds("0.1f32");
+    assert_eq!(
+        kinds,
+        vec![
+            TokenKind::SuffixedFloatLiteral(f64::from(0.1f32), "f32".to_string()),
+            TokenKind::Eof
+        ]
+    );
+}
+
+#[test]
+fn test_special_float_literals() {
+    let kinds = tokenize_kinds("inf nan infinity");
+    assert_eq!(kinds[0], TokenKind::FloatLiteral(f64::INFINITY));
+    assert!(matches!(kinds[1], TokenKind::FloatLiteral(v) if v.is_nan()));
+    assert_eq!(kinds[2], TokenKind::Identifier("infinity".to_string()));
+}
+
+#[test]
+fn test_exponent_without_digits_is_suffix_error() {
+    let err = tokenize_error("1e");
+    assert_eq!(err.kind(), LexErrorKind::InvalidLiteralSuffix);
+}
+
+#[test]
+fn test_error_float_literal_out_of_range() {
+    let err = tokenize_error("1e400");
+    assert_eq!(err.kind(), LexErrorKind::InvalidFloatLiteral);
+    assert_eq!(
+        err.message(),
+        "Float literal '1e400' is out of range for f64"
+    );
+
+    let err = tokenize_error("1e39f32");
+    assert_eq!(
+        err.message(),
+        "Float literal '1e39' is out of range for f32"
+    );
+}
@@ -75,6 +75,24 @@ fn test_keyword_continue() {
     assert_eq!(kinds, vec![TokenKind::Continue, TokenKind::Eof]);
 }
 
+#[test]
+fn test_keyword_defer() {
+    let kinds = tokenize_kinds("defer");
+    assert_eq!(kinds, vec![TokenKind::Defer, TokenKind::Eof]);
+}
+
+#[test]
+fn test_defer_not_prefix() {
+    let kinds = tokenize_kinds("deferred");
+    assert_eq!(
+        kinds,
+        vec![
+            TokenKind::Identifier("deferred".to_string()),
+            TokenKind::Eof
+        ]
+    );
+}
+
 #[test]
 fn test_return_not_prefix() {
     let kinds = tokenize_kinds("returning");
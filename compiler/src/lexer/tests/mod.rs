@@ -90,6 +90,14 @@ fn test_lex_error_invalid_whitespace_constructor() {
     );
 }
 
+#[test]
+fn test_lex_error_unterminated_block_comment_constructor() {
+    let err = LexError::unterminated_block_comment(1, dummy_span());
+    assert_eq!(err.kind(), LexErrorKind::UnterminatedBlockComment);
+    assert_eq!(err.short_message(), "Unterminated block comment");
+    assert_eq!(err.message(), "Unterminated block comment (expected '*/')");
+}
+
 #[test]
 fn test_lex_error_unknown_escape_sequence_constructor() {
     let err = LexError::unknown_escape_sequence('q', dummy_span());
@@ -114,6 +122,52 @@ fn test_lex_error_unterminated_string_newline_constructor() {
     );
 }
 
+#[test]
+fn test_lex_error_unterminated_raw_string_constructor() {
+    let err = LexError::unterminated_raw_string(2, dummy_span());
+    assert_eq!(err.kind(), LexErrorKind::UnterminatedString);
+    assert_eq!(
+        err.message(),
+        "Unterminated raw string literal (expected closing '\"##')"
+    );
+}
+
+#[test]
+fn test_lex_error_escape_constructors() {
+    let err = LexError::invalid_hex_escape(dummy_span());
+    assert_eq!(err.kind(), LexErrorKind::InvalidEscapeSequence);
+    assert_eq!(err.short_message(), "Invalid escape sequence");
+
+    let err = LexError::hex_escape_out_of_range("80", dummy_span());
+    assert_eq!(err.kind(), LexErrorKind::InvalidEscapeSequence);
+    assert!(
+        err.message()
+            .starts_with("Hex escape '\\x80' is out of range")
+    );
+
+    let err = LexError::invalid_unicode_escape(dummy_span());
+    assert_eq!(err.kind(), LexErrorKind::InvalidEscapeSequence);
+
+    let err = LexError::invalid_unicode_scalar("DFFF", dummy_span());
+    assert_eq!(err.kind(), LexErrorKind::InvalidEscapeSequence);
+    assert_eq!(
+        err.message(),
+        "Unicode escape '\\u{DFFF}' is not a valid Unicode scalar value"
+    );
+}
+
+#[test]
+fn test_lex_error_multiline_string_constructors() {
+    for err in [
+        LexError::multiline_string_opening_content(dummy_span()),
+        LexError::multiline_string_closing_content(dummy_span()),
+        LexError::multiline_string_insufficient_indentation(dummy_span()),
+    ] {
+        assert_eq!(err.kind(), LexErrorKind::InvalidMultilineString);
+        assert_eq!(err.short_message(), "Invalid multi-line string");
+    }
+}
+
 #[test]
 fn test_lex_error_integer_overflow_constructor() {
     let err = LexError::integer_overflow("99999999999999999999", dummy_span());
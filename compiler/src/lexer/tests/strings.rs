@@ -1,4 +1,4 @@
-//! Tests for string literals and escape sequences.
+//! Tests for string and character literals and escape sequences.
 
 use super::*;
 
@@ -103,3 +103,125 @@ fn test_string_all_escapes_combined() {
         ]
     );
 }
+
+fn single_string(input: &str) -> String {
+    match tokenize_kinds(input).as_slice() {
+        [TokenKind::StringLiteral(value), TokenKind::Eof] => value.clone(),
+        kinds => panic!("Expected a single string literal, got {:?}", kinds),
+    }
+}
+
+#[test]
+fn test_string_escape_hex() {
+    assert_eq!(single_string(r#""\x41\x7a\x7F""#), "Az\x7f");
+}
+
+#[test]
+fn test_string_escape_unicode() {
+    assert_eq!(
+        single_string(r#""\u{41}\u{e9}\u{1F600}\u{10FFFF}""#),
+        "A\u{e9}\u{1F600}\u{10FFFF}"
+    );
+}
+
+#[test]
+fn test_raw_string() {
+    assert_eq!(single_string(r#"r"C:\path\n""#), "C:\\path\\n");
+}
+
+#[test]
+fn test_raw_string_with_hashes() {
+    assert_eq!(single_string(r###"r#"say "hi" \d+"#"###), "say \"hi\" \\d+");
+    assert_eq!(single_string(r###"r##"a"#b"##"###), "a\"#b");
+}
+
+#[test]
+fn test_raw_string_spans_lines() {
+    assert_eq!(single_string("r\"a\n  b\""), "a\n  b");
+}
+
+#[test]
+fn test_r_identifier_is_not_raw_string() {
+    let kinds = tokenize_kinds("r + raw");
+    assert_eq!(
+        kinds,
+        vec![
+            TokenKind::Identifier("r".to_string()),
+            TokenKind::Plus,
+            TokenKind::Identifier("raw".to_string()),
+            TokenKind::Eof
+        ]
+    );
+}
+
+#[test]
+fn test_multiline_string_strips_closing_indent() {
+    let input = "\"\"\"\n    first\n      indented\n\n    last\n    \"\"\"";
+    assert_eq!(single_string(input), "first\n  indented\n\nlast");
+}
+
+#[test]
+fn test_multiline_string_trailing_newline() {
+    let input = "\"\"\"\n  line\n\n  \"\"\"";
+    assert_eq!(single_string(input), "line\n");
+}
+
+#[test]
+fn test_multiline_string_empty() {
+    assert_eq!(single_string("\"\"\"\n\"\"\""), "");
+}
+
+#[test]
+fn test_multiline_string_escapes_and_quotes() {
+    let input = "\"\"\"\n  tab\\there \"quoted\" \\\"\"\"\n  \"\"\"";
+    assert_eq!(single_string(input), "tab\there \"quoted\" \"\"\"");
+}
+
+#[test]
+fn test_multiline_string_crlf() {
+    let input = "\"\"\"\r\n  a\r\n  b\r\n  \"\"\"";
+    assert_eq!(single_string(input), "a\nb");
+}
+
+#[test]
+fn test_multiline_string_followed_by_tokens() {
+    let kinds = tokenize_kinds("f(\"\"\"\n  x\n  \"\"\")\ny");
+    assert_eq!(
+        kinds,
+        vec![
+            TokenKind::Identifier("f".to_string()),
+            TokenKind::LeftParen,
+            TokenKind::StringLiteral("x".to_string()),
+            TokenKind::RightParen,
+            TokenKind::Newline,
+            TokenKind::Identifier("y".to_string()),
+            TokenKind::Eof
+        ]
+    );
+}
+
+#[test]
+fn test_char_literals() {
+    let kinds = tokenize_kinds(r#"'a' 'é' '😀' '\'' '\\' '\n' '\u{3bb}' '\x41' '\"' '"'"#);
+    assert_eq!(
+        kinds,
+        vec![
+            TokenKind::CharLiteral('a'),
+            TokenKind::CharLiteral('é'),
+            TokenKind::CharLiteral('😀'),
+            TokenKind::CharLiteral('\''),
+            TokenKind::CharLiteral('\\'),
+            TokenKind::CharLiteral('\n'),
+            TokenKind::CharLiteral('λ'),
+            TokenKind::CharLiteral('A'),
+            TokenKind::CharLiteral('"'),
+            TokenKind::CharLiteral('"'),
+            TokenKind::Eof
+        ]
+    );
+}
+
+#[test]
+fn test_string_escape_single_quote() {
+    assert_eq!(single_string(r#""it\'s""#), "it's");
+}
@@ -1,7 +1,9 @@
 //! Token reading and recognition for the lexer.
 //!
 //! This module provides methods for reading various token types from the input,
-//! including identifiers, keywords, string literals, integer literals, and punctuation.
+//! including identifiers, keywords, and punctuation. String literals are read
+//! by the [`strings`](super::strings) module and numeric literals by the
+//! [`numbers`](super::numbers) module.
 
 use super::Lexer;
 use super::error::LexError;
@@ -207,7 +209,14 @@ impl<'a> Lexer<'a> {
         start_column: usize,
     ) -> Option<Result<Token, LexError>> {
         match c {
+            '"' if self.input[self.pos..].starts_with("\"\"\"") => {
+                Some(self.read_multiline_string(start_pos, start_line, start_column))
+            }
             '"' => Some(self.read_string(start_pos, start_line, start_column)),
+            '\'' => Some(self.read_char(start_pos, start_line, start_column)),
+            'r' if self.at_raw_string_start() => {
+                Some(self.read_raw_string(start_pos, start_line, start_column))
+            }
             _ if c.is_ascii_digit() => Some(self.read_number(start_pos, start_line, start_column)),
             _ => None,
         }
@@ -227,122 +236,15 @@ impl<'a> Lexer<'a> {
         }
     }
 
-    /// Reads a string literal from the input.
-    ///
-    /// The opening double quote should be at the current position. This method
-    /// processes escape sequences and returns the unescaped string value.
-    ///
-    /// # Supported Escape Sequences
-    ///
-    /// - `\n` - newline
-    /// - `\t` - tab
-    /// - `\r` - carriage return
-    /// - `\\` - backslash
-    /// - `\"` - double quote
-    ///
-    /// # Arguments
-    ///
-    /// * `start_pos` - The byte position of the opening quote
-    /// * `start_line` - The line number of the opening quote
-    /// * `start_column` - The column number of the opening quote
-    ///
-    /// # Errors
-    ///
-    /// Returns a [`LexError`] if:
-    /// - The string contains an unknown escape sequence
-    /// - The string is not terminated (reaches end of line or file)
-    fn read_string(
-        &mut self,
-        start_pos: usize,
-        start_line: usize,
-        start_column: usize,
-    ) -> Result<Token, LexError> {
-        self.advance(); // skip opening "
-        let mut value = String::new();
-
-        loop {
-            match self.current_char() {
-                Some('"') => {
-                    self.advance(); // skip closing "
-                    let span = Span::new(start_pos, self.pos, start_line, start_column);
-                    return Ok(Token::new(TokenKind::StringLiteral(value), span));
-                }
-                Some('\\') => {
-                    self.advance(); // skip backslash
-                    match self.current_char() {
-                        Some('n') => {
-                            value.push('\n');
-                            self.advance();
-                        }
-                        Some('t') => {
-                            value.push('\t');
-                            self.advance();
-                        }
-                        Some('r') => {
-                            value.push('\r');
-                            self.advance();
-                        }
-                        Some('\\') => {
-                            value.push('\\');
-                            self.advance();
-                        }
-                        Some('"') => {
-                            value.push('"');
-                            self.advance();
-                        }
-                        Some(c) => {
-                            return Err(LexError::unknown_escape_sequence(
-                                c,
-                                Span::new(
-                                    self.pos - 1,
-                                    self.pos + c.len_utf8(),
-                                    self.line,
-                                    self.column - 1,
-                                ),
-                            ));
-                        }
-                        None => {
-                            return Err(LexError::unterminated_string(Span::new(
-                                start_pos,
-                                self.pos,
-                                start_line,
-                                start_column,
-                            )));
-                        }
-                    }
-                }
-                Some('\n') => {
-                    return Err(LexError::unterminated_string_newline(Span::new(
-                        start_pos,
-                        self.pos,
-                        start_line,
-                        start_column,
-                    )));
-                }
-                Some(c) => {
-                    value.push(c);
-                    self.advance();
-                }
-                None => {
-                    return Err(LexError::unterminated_string(Span::new(
-                        start_pos,
-                        self.pos,
-                        start_line,
-                        start_column,
-                    )));
-                }
-            }
-        }
-    }
-
     /// Reads an identifier or keyword from the input.
     ///
     /// Identifiers consist of an ASCII alphabetic character (a-z, A-Z) or underscore
     /// followed by any number of ASCII alphanumeric characters (a-z, A-Z, 0-9) or underscores.
     /// Non-ASCII characters (e.g., Unicode letters) are not allowed in identifiers.
     /// If the identifier matches a keyword (`fn`, `let`, `mut`, `if`, `else`, `return`,
-    /// `while`, `break`, `continue`, `pub`, `import`, `as`) or boolean literal
-    /// (`true`, `false`), the corresponding token kind is returned instead.
+    /// `while`, `break`, `continue`, `pub`, `import`, `as`), boolean literal
+    /// (`true`, `false`) or special float literal (`inf`, `nan`), the
+    /// corresponding token kind is returned instead.
     ///
     /// # Arguments
     ///
@@ -380,7 +282,7 @@ impl<'a> Lexer<'a> {
         let value = self.input[start_pos..self.pos].to_string();
         let span = Span::new(start_pos, self.pos, start_line, start_column);
 
-        // Check for keywords and boolean literals
+        // Check for keywords, boolean literals and special float literals
         let kind = match value.as_str() {
             "fn" => TokenKind::Fn,
             "let" => TokenKind::Let,
@@ -391,77 +293,17 @@ impl<'a> Lexer<'a> {
             "while" => TokenKind::While,
             "break" => TokenKind::Break,
             "continue" => TokenKind::Continue,
+            "defer" => TokenKind::Defer,
             "pub" => TokenKind::Pub,
             "import" => TokenKind::Import,
             "as" => TokenKind::As,
             "true" => TokenKind::BoolLiteral(true),
             "false" => TokenKind::BoolLiteral(false),
+            "inf" => TokenKind::FloatLiteral(f64::INFINITY),
+            "nan" => TokenKind::FloatLiteral(f64::NAN),
             _ => TokenKind::Identifier(value),
         };
 
         Ok(Token::new(kind, span))
     }
-
-    /// Reads a numeric literal from the input.
-    ///
-    /// Numeric literals are either:
-    /// - Integer literals: one or more ASCII digits
-    /// - Float literals: `digits '.' digits`
-    ///
-    /// # Arguments
-    ///
-    /// * `start_pos` - The byte position of the first digit
-    /// * `start_line` - The line number of the first digit
-    /// * `start_column` - The column number of the first digit
-    ///
-    /// # Returns
-    ///
-    /// A [`Token`] with kind [`TokenKind::IntLiteral`] or [`TokenKind::FloatLiteral`].
-    ///
-    /// # Errors
-    ///
-    /// Returns a [`LexError`] if:
-    /// - an integer is too large to fit in a `u64`
-    /// - a float literal cannot be parsed
-    fn read_number(
-        &mut self,
-        start_pos: usize,
-        start_line: usize,
-        start_column: usize,
-    ) -> Result<Token, LexError> {
-        while self.current_char().is_some_and(|c| c.is_ascii_digit()) {
-            self.advance();
-        }
-
-        // Parse float literal only when a dot is followed by at least one digit.
-        let has_fraction = self.current_char() == Some('.')
-            && self
-                .input
-                .get(self.pos..)
-                .and_then(|s| s.chars().nth(1))
-                .is_some_and(|c| c.is_ascii_digit());
-
-        if has_fraction {
-            self.advance(); // consume '.'
-            while self.current_char().is_some_and(|c| c.is_ascii_digit()) {
-                self.advance();
-            }
-
-            let value_str = &self.input[start_pos..self.pos];
-            let span = Span::new(start_pos, self.pos, start_line, start_column);
-            let value: f64 = value_str.parse().map_err(|_: std::num::ParseFloatError| {
-                LexError::invalid_float_literal(value_str, span)
-            })?;
-            return Ok(Token::new(TokenKind::FloatLiteral(value), span));
-        }
-
-        let value_str = &self.input[start_pos..self.pos];
-        let span = Span::new(start_pos, self.pos, start_line, start_column);
-
-        let value: u64 = value_str
-            .parse()
-            .map_err(|_: std::num::ParseIntError| LexError::integer_overflow(value_str, span))?;
-
-        Ok(Token::new(TokenKind::IntLiteral(value), span))
-    }
 }
@@ -10,6 +10,12 @@
 //! - [`parser`] - Recursive descent parser
 //! - [`ast`] - Abstract Syntax Tree definitions
 //! - [`codegen`] - LLVM code generation
+//! - [`collections`] - Methods of the `map<K, V>` and `set<T>` types
+//! - [`doc`] - Documentation generation (`lak doc`)
+//! - [`options`] - Methods of the `Option<T>` type
+//! - [`prelude`] - Prelude functions implemented directly by the runtime
+//! - [`rng`] - Methods of the `rng` random number generator type
+//! - [`stdlib`] - Standard library modules imported by name
 //!
 //! # Example
 //!
@@ -42,9 +48,15 @@
 
 pub mod ast;
 pub mod codegen;
+pub mod collections;
+pub mod doc;
 pub mod lexer;
 pub mod linker;
+pub mod options;
 pub mod parser;
+pub mod prelude;
 pub mod resolver;
+pub mod rng;
 pub mod semantic;
+pub mod stdlib;
 pub mod token;
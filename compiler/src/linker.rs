@@ -187,7 +187,9 @@ pub fn create_linker_command(
     #[cfg(not(all(target_os = "windows", target_env = "msvc")))]
     {
         let mut cmd = Command::new("cc");
-        cmd.args([object_path, runtime_path, "-o", output_path]);
+        // `-lm` provides the libm functions that floating-point `math`
+        // intrinsics (e.g. `llvm.pow`) lower to.
+        cmd.args([object_path, runtime_path, "-lm", "-o", output_path]);
         Ok(cmd)
     }
 }
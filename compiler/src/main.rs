@@ -1,9 +1,10 @@
 //! The Lak programming language compiler CLI.
 //!
-//! This binary provides `lak build` and `lak run` commands and delegates
-//! compilation/link/run orchestration to the driver module.
+//! This binary provides `lak build`, `lak run` and `lak doc` commands and
+//! delegates compilation/link/run orchestration to the driver module.
 
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
+use lak::doc::DocFormat;
 
 mod diagnostics;
 mod driver;
@@ -30,28 +31,89 @@ enum Commands {
         /// If not specified, uses the input filename without extension.
         #[arg(short = 'o', long = "output")]
         output: Option<String>,
+
+        /// Print a backtrace of Lak function calls when the program panics.
+        #[arg(long = "backtrace")]
+        backtrace: bool,
     },
     /// Compile and run a Lak program.
     Run {
         /// The source file to run (e.g., `hello.lak`).
         file: String,
+
+        /// Print a backtrace of Lak function calls when the program panics.
+        #[arg(long = "backtrace")]
+        backtrace: bool,
+
+        /// Arguments passed to the program (e.g., `lak run main.lak -- a b`).
+        #[arg(last = true)]
+        args: Vec<String>,
+    },
+    /// Generate documentation for a Lak program and the modules it imports.
+    Doc {
+        /// The entry source file (e.g., `main.lak`).
+        file: String,
+
+        /// Directory to write the documentation into.
+        #[arg(short = 'o', long = "output", default_value = "doc")]
+        output: String,
+
+        /// Output format of the generated pages.
+        #[arg(long = "format", value_enum, default_value_t = DocFormatArg::Html)]
+        format: DocFormatArg,
     },
 }
 
+/// Documentation output formats accepted by `lak doc --format`.
+#[derive(Clone, Copy, ValueEnum)]
+enum DocFormatArg {
+    /// Static HTML pages.
+    Html,
+    /// Markdown pages.
+    Markdown,
+}
+
+impl From<DocFormatArg> for DocFormat {
+    fn from(format: DocFormatArg) -> Self {
+        match format {
+            DocFormatArg::Html => DocFormat::Html,
+            DocFormatArg::Markdown => DocFormat::Markdown,
+        }
+    }
+}
+
 /// Entry point for the Lak compiler.
 fn main() {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Build { file, output } => {
-            if let Err(error) = driver::build(&file, output.as_deref()) {
+        Commands::Build {
+            file,
+            output,
+            backtrace,
+        } => {
+            let options = driver::BuildOptions { backtrace };
+            if let Err(error) = driver::build(&file, output.as_deref(), options) {
                 report_and_exit(*error);
             }
         }
-        Commands::Run { file } => match driver::run(&file) {
+        Commands::Run {
+            file,
+            backtrace,
+            args,
+        } => match driver::run(&file, driver::BuildOptions { backtrace }, &args) {
             Ok(exit_code) => std::process::exit(exit_code),
             Err(error) => report_and_exit(*error),
         },
+        Commands::Doc {
+            file,
+            output,
+            format,
+        } => {
+            if let Err(error) = driver::doc(&file, &output, format.into()) {
+                report_and_exit(*error);
+            }
+        }
     }
 }
 
@@ -0,0 +1,104 @@
+//! Methods of the `Option<T>` type.
+//!
+//! Like collection methods (see [`crate::collections`]), option methods are
+//! called on a variable (`age.unwrap_or(0)`), so the parser produces
+//! [`ExprKind::ModuleCall`](crate::ast::ExprKind::ModuleCall) nodes for
+//! them. Semantic analysis and codegen treat a call whose module name is a
+//! variable of `Option` type as a method call and look the method up here.
+//!
+//! There is no forced unwrap: the value is read with `unwrap_or`, which
+//! needs a default for the empty case. Options are currently only produced
+//! by `map.get`; there is no syntax to create one directly and no `match`
+//! to take one apart.
+
+use crate::ast::Type;
+
+/// A method of an `Option<T>` value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptionMethod {
+    /// `is_some() -> bool`.
+    IsSome,
+    /// `is_none() -> bool`.
+    IsNone,
+    /// `unwrap_or(default: T) -> T`.
+    UnwrapOr,
+}
+
+impl OptionMethod {
+    /// All methods, in the order they are listed in diagnostics.
+    const ALL: [Self; 3] = [Self::IsSome, Self::IsNone, Self::UnwrapOr];
+
+    /// Looks up the method `name` of `option`, which must be an `Option`
+    /// type.
+    pub fn lookup(option: &Type, name: &str) -> Option<Self> {
+        if !option.is_option() {
+            return None;
+        }
+        Self::ALL.into_iter().find(|method| method.name() == name)
+    }
+
+    /// Returns the names of all methods.
+    pub fn names() -> Vec<&'static str> {
+        Self::ALL.into_iter().map(Self::name).collect()
+    }
+
+    /// Returns the name of the method in Lak source code.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::IsSome => "is_some",
+            Self::IsNone => "is_none",
+            Self::UnwrapOr => "unwrap_or",
+        }
+    }
+
+    /// Returns the parameter types of the method on `option`.
+    pub fn param_types(self, option: &Type) -> Vec<Type> {
+        match self {
+            Self::IsSome | Self::IsNone => Vec::new(),
+            Self::UnwrapOr => vec![payload_type(option).clone()],
+        }
+    }
+
+    /// Returns the return type of the method on `option`.
+    pub fn return_type(self, option: &Type) -> Type {
+        match self {
+            Self::IsSome | Self::IsNone => Type::Bool,
+            Self::UnwrapOr => payload_type(option).clone(),
+        }
+    }
+}
+
+/// Returns the payload type of an `Option` type.
+fn payload_type(option: &Type) -> &Type {
+    match option {
+        Type::Option(payload) => payload,
+        _ => unreachable!("option methods are only looked up on Option types"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_option_method_signatures() {
+        let option = Type::Option(Box::new(Type::String));
+        assert_eq!(
+            OptionMethod::lookup(&option, "unwrap_or"),
+            Some(OptionMethod::UnwrapOr)
+        );
+        assert_eq!(OptionMethod::lookup(&option, "get"), None);
+        assert_eq!(OptionMethod::lookup(&Type::String, "unwrap"), None);
+        assert_eq!(
+            OptionMethod::UnwrapOr.param_types(&option),
+            vec![Type::String]
+        );
+        assert_eq!(OptionMethod::lookup(&option, "unwrap"), None);
+        assert_eq!(OptionMethod::UnwrapOr.return_type(&option), Type::String);
+        assert_eq!(OptionMethod::IsNone.return_type(&option), Type::Bool);
+        assert_eq!(
+            OptionMethod::names(),
+            vec!["is_some", "is_none", "unwrap_or"]
+        );
+    }
+}
@@ -19,6 +19,8 @@
 //!   [`missing_fn_call_parens_float()`](ParseError::missing_fn_call_parens_float),
 //!   [`missing_fn_call_parens_ident()`](ParseError::missing_fn_call_parens_ident)
 //! - **Expression errors**: [`unexpected_expression_start()`](ParseError::unexpected_expression_start)
+//! - **Tuple errors**: [`tuple_too_few_elements()`](ParseError::tuple_too_few_elements)
+//! - **Collection errors**: [`invalid_collection_element_type()`](ParseError::invalid_collection_element_type)
 //! - **Unsupported syntax**: [`invalid_mutable_discard()`](ParseError::invalid_mutable_discard),
 //!   [`invalid_typed_discard()`](ParseError::invalid_typed_discard)
 
@@ -52,6 +54,10 @@ pub enum ParseErrorKind {
     MissingElseInIfExpression,
     /// `if` expression branch does not end with a value expression.
     MissingIfExpressionBranchValue,
+    /// Block expression does not end with a value expression.
+    MissingBlockExpressionValue,
+    /// Tuple type, expression or pattern has fewer than two elements.
+    InvalidTupleArity,
     /// Internal parser inconsistency (compiler bug).
     InternalError,
 }
@@ -128,6 +134,8 @@ impl ParseError {
             ParseErrorKind::MissingIfExpressionBranchValue => {
                 "Missing branch value in if expression"
             }
+            ParseErrorKind::MissingBlockExpressionValue => "Missing value in block expression",
+            ParseErrorKind::InvalidTupleArity => "Invalid tuple arity",
             ParseErrorKind::InternalError => "Internal error",
         }
     }
@@ -290,6 +298,15 @@ impl ParseError {
         )
     }
 
+    /// Creates an error for a block expression without a trailing value expression.
+    pub fn missing_block_expression_value(span: Span) -> Self {
+        Self::new(
+            ParseErrorKind::MissingBlockExpressionValue,
+            "block expression must end with a value expression",
+            span,
+        )
+    }
+
     /// Creates an error for nested member access (e.g., a.b.c).
     ///
     /// Nested member access is not yet supported. Only simple module.function
@@ -326,6 +343,35 @@ impl ParseError {
         )
     }
 
+    /// Creates an error for a tuple with fewer than two elements.
+    ///
+    /// `what` names the construct, e.g. "tuple type" or "tuple pattern".
+    /// Single-element tuples are rejected so `(x)` stays a plain grouping.
+    pub fn tuple_too_few_elements(what: &str, span: Span) -> Self {
+        Self::new(
+            ParseErrorKind::InvalidTupleArity,
+            format!("A {} must have at least two elements", what),
+            span,
+        )
+    }
+
+    /// Creates an error for a map key, map value or set item of a type that
+    /// collections cannot hold.
+    ///
+    /// `what` names the position, e.g. `"map key"`.
+    pub fn invalid_collection_element_type(
+        what: &str,
+        ty: &str,
+        allowed: &str,
+        span: Span,
+    ) -> Self {
+        Self::new(
+            ParseErrorKind::ExpectedType,
+            format!("Invalid {} type '{}'. Expected {}", what, ty, allowed),
+            span,
+        )
+    }
+
     /// Creates an error for empty import path.
     ///
     /// Import paths cannot be empty strings.
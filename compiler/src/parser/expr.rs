@@ -7,7 +7,7 @@
 
 use super::Parser;
 use super::error::ParseError;
-use crate::ast::{BinaryOperator, Expr, ExprKind, IfExprBlock, StmtKind, UnaryOperator};
+use crate::ast::{BinaryOperator, Expr, ExprKind, IfExprBlock, StmtKind, Type, UnaryOperator};
 use crate::token::{Span, TokenKind};
 
 /// Operator precedence levels (higher number = lower precedence = looser binding).
@@ -72,6 +72,14 @@ fn token_to_binary_op(kind: &TokenKind) -> Option<BinaryOperator> {
     }
 }
 
+/// Builds the expression kind for an integer literal with an optional type suffix.
+fn int_literal_kind(value: i128, suffix: Option<Type>) -> ExprKind {
+    match suffix {
+        Some(ty) => ExprKind::SuffixedIntLiteral { value, ty },
+        None => ExprKind::IntLiteral(value),
+    }
+}
+
 impl Parser {
     /// Parses an expression using Pratt parsing.
     ///
@@ -165,6 +173,7 @@ impl Parser {
     /// - Identifiers (variable references)
     /// - Function calls
     /// - Parenthesized expressions
+    /// - `if` expressions and block expressions
     fn parse_primary_expr(&mut self) -> Result<Expr, ParseError> {
         let start_span = self.current_span();
 
@@ -183,9 +192,11 @@ impl Parser {
                 // Positive literals are tokenized as u64; only values up to
                 // i64::MIN.unsigned_abs() can be negated into a valid signed literal.
                 if matches!(op, UnaryOperator::Neg)
-                    && let TokenKind::IntLiteral(unsigned_value) = self.current_kind()
+                    && let TokenKind::IntLiteral(unsigned_value)
+                    | TokenKind::SuffixedIntLiteral(unsigned_value, _) = self.current_kind()
                 {
                     let unsigned_value = *unsigned_value;
+                    let suffix = self.int_literal_suffix()?;
                     let literal_span = self.current_span();
                     self.advance(); // consume the literal
 
@@ -207,7 +218,7 @@ impl Parser {
                         ));
                     };
 
-                    return Ok(Expr::new(ExprKind::IntLiteral(signed_value), span));
+                    return Ok(Expr::new(int_literal_kind(signed_value, suffix), span));
                 }
 
                 // Not a literal — parse as normal unary operation
@@ -229,13 +240,25 @@ impl Parser {
                 ))
             }
             TokenKind::LeftParen => {
-                // Parenthesized expression
+                // Parenthesized expression or tuple expression
                 self.advance(); // consume '('
                 self.skip_newlines();
 
                 let inner = self.parse_expr()?;
 
                 self.skip_newlines();
+                let mut elements = Vec::new();
+                let is_tuple = matches!(self.current_kind(), TokenKind::Comma);
+                while matches!(self.current_kind(), TokenKind::Comma) {
+                    self.advance();
+                    self.skip_newlines();
+                    if matches!(self.current_kind(), TokenKind::RightParen) {
+                        break;
+                    }
+                    elements.push(self.parse_expr()?);
+                    self.skip_newlines();
+                }
+
                 // Store span before consuming to avoid index issues
                 let close_paren_span = self.current_span();
                 self.expect(&TokenKind::RightParen)?;
@@ -247,9 +270,17 @@ impl Parser {
                     start_span.line,
                     start_span.column,
                 );
-                Ok(Expr::new(inner.kind, span))
+                if !is_tuple {
+                    return Ok(Expr::new(inner.kind, span));
+                }
+                if elements.is_empty() {
+                    return Err(ParseError::tuple_too_few_elements("tuple expression", span));
+                }
+                elements.insert(0, inner);
+                Ok(Expr::new(ExprKind::Tuple(elements), span))
             }
             TokenKind::If => self.parse_if_expr(),
+            TokenKind::LeftBrace => self.parse_block_expr(),
             TokenKind::Identifier(name) => {
                 let name = name.clone();
                 self.advance();
@@ -320,11 +351,10 @@ impl Parser {
                         TokenKind::StringLiteral(_) => Err(
                             ParseError::missing_fn_call_parens_string(&name, self.current_span()),
                         ),
-                        TokenKind::IntLiteral(_) => Err(ParseError::missing_fn_call_parens_int(
-                            &name,
-                            self.current_span(),
-                        )),
-                        TokenKind::FloatLiteral(_) => Err(
+                        TokenKind::IntLiteral(_) | TokenKind::SuffixedIntLiteral(..) => Err(
+                            ParseError::missing_fn_call_parens_int(&name, self.current_span()),
+                        ),
+                        TokenKind::FloatLiteral(_) | TokenKind::SuffixedFloatLiteral(..) => Err(
                             ParseError::missing_fn_call_parens_float(&name, self.current_span()),
                         ),
                         TokenKind::Identifier(next_name) => {
@@ -346,12 +376,14 @@ impl Parser {
                 self.advance();
                 Ok(Expr::new(ExprKind::StringLiteral(value), start_span))
             }
-            TokenKind::IntLiteral(unsigned_value) => {
+            TokenKind::IntLiteral(unsigned_value)
+            | TokenKind::SuffixedIntLiteral(unsigned_value, _) => {
                 let unsigned_value = *unsigned_value;
+                let suffix = self.int_literal_suffix()?;
                 self.advance();
 
                 Ok(Expr::new(
-                    ExprKind::IntLiteral(unsigned_value as i128),
+                    int_literal_kind(unsigned_value as i128, suffix),
                     start_span,
                 ))
             }
@@ -360,11 +392,35 @@ impl Parser {
                 self.advance();
                 Ok(Expr::new(ExprKind::FloatLiteral(value), start_span))
             }
+            TokenKind::SuffixedFloatLiteral(value, suffix) => {
+                let value = *value;
+                let ty = Type::from_source_name(suffix)
+                    .filter(Type::is_float)
+                    .ok_or_else(|| {
+                        ParseError::internal(
+                            format!(
+                                "Internal parser error: lexer produced unknown float suffix '{}'. This is a compiler bug, please report it.",
+                                suffix
+                            ),
+                            start_span,
+                        )
+                    })?;
+                self.advance();
+                Ok(Expr::new(
+                    ExprKind::SuffixedFloatLiteral { value, ty },
+                    start_span,
+                ))
+            }
             TokenKind::BoolLiteral(value) => {
                 let value = *value;
                 self.advance();
                 Ok(Expr::new(ExprKind::BoolLiteral(value), start_span))
             }
+            TokenKind::CharLiteral(value) => {
+                let value = *value;
+                self.advance();
+                Ok(Expr::new(ExprKind::CharLiteral(value), start_span))
+            }
             _ => Err(ParseError::unexpected_expression_start(
                 &Self::token_kind_display(self.current_kind()),
                 start_span,
@@ -413,13 +469,42 @@ impl Parser {
         ))
     }
 
-    /// Parses a branch block in an `if` expression.
+    /// Parses a block expression.
     ///
-    /// The block must end with a value expression. Any preceding statements are
-    /// treated as side effects.
+    /// # Grammar
+    ///
+    /// ```text
+    /// block_expr → "{" stmt* expr "}"
+    /// ```
+    fn parse_block_expr(&mut self) -> Result<Expr, ParseError> {
+        let start_span = self.current_span();
+        let (block, end) = self.parse_value_block(ParseError::missing_block_expression_value)?;
+
+        let span = Span::new(start_span.start, end, start_span.line, start_span.column);
+
+        Ok(Expr::new(ExprKind::Block(block), span))
+    }
+
+    /// Parses a branch block in an `if` expression.
     fn parse_if_expr_block(
         &mut self,
         branch_name: &str,
+    ) -> Result<(IfExprBlock, usize), ParseError> {
+        self.parse_value_block(|span| {
+            ParseError::missing_if_expression_branch_value(branch_name, span)
+        })
+    }
+
+    /// Parses a `{ stmt* expr }` block that yields a value.
+    ///
+    /// The block must end with a value expression. Any preceding statements are
+    /// treated as side effects. `missing_value` builds the error reported when
+    /// the block has no trailing value expression.
+    ///
+    /// Returns the parsed block and the end offset of the closing `}`.
+    fn parse_value_block(
+        &mut self,
+        missing_value: impl Fn(Span) -> ParseError,
     ) -> Result<(IfExprBlock, usize), ParseError> {
         self.expect(&TokenKind::LeftBrace)?;
         self.skip_newlines();
@@ -428,10 +513,7 @@ impl Parser {
 
         loop {
             if matches!(self.current_kind(), TokenKind::RightBrace) {
-                return Err(ParseError::missing_if_expression_branch_value(
-                    branch_name,
-                    self.current_span(),
-                ));
+                return Err(missing_value(self.current_span()));
             }
 
             let value_candidate_pos = self.pos;
@@ -461,10 +543,7 @@ impl Parser {
                 || self.next_non_newline_is_right_brace())
                 && !matches!(stmt.kind, StmtKind::Expr(_))
             {
-                return Err(ParseError::missing_if_expression_branch_value(
-                    branch_name,
-                    self.current_span(),
-                ));
+                return Err(missing_value(self.current_span()));
             }
 
             self.expect_statement_terminator()?;
@@ -619,4 +698,24 @@ impl Parser {
             ))
         }
     }
+
+    /// Returns the type named by the suffix of the integer literal token at
+    /// the current position, or `None` for an unsuffixed literal.
+    fn int_literal_suffix(&self) -> Result<Option<Type>, ParseError> {
+        let TokenKind::SuffixedIntLiteral(_, suffix) = self.current_kind() else {
+            return Ok(None);
+        };
+        Type::from_source_name(suffix)
+            .filter(Type::is_integer)
+            .map(Some)
+            .ok_or_else(|| {
+                ParseError::internal(
+                    format!(
+                        "Internal parser error: lexer produced unknown integer suffix '{}'. This is a compiler bug, please report it.",
+                        suffix
+                    ),
+                    self.current_span(),
+                )
+            })
+    }
 }
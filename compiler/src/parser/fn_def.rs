@@ -2,7 +2,7 @@
 
 use super::Parser;
 use super::error::ParseError;
-use crate::ast::{FnDef, FnParam, Visibility};
+use crate::ast::{FnDef, FnParam, ReturnType, Visibility};
 use crate::token::{Span, TokenKind};
 
 impl Parser {
@@ -11,12 +11,15 @@ impl Parser {
     /// # Grammar
     ///
     /// ```text
-    /// fn_def → ("pub")? "fn" IDENTIFIER "(" param_list? ")" "->" IDENTIFIER "{" stmt* "}"
-    /// param_list → IDENTIFIER ":" type ("," IDENTIFIER ":" type)*
+    /// fn_def → DOC_COMMENT* ("pub")? "fn" IDENTIFIER "(" param_list? ")" "->" return_type "{" stmt* "}"
+    /// param_list → param ("," param)*
+    /// param → (IDENTIFIER | pattern) ":" type
+    /// return_type → IDENTIFIER | tuple_type | map_type | set_type
     /// ```
     pub(super) fn parse_fn_def(&mut self) -> Result<FnDef, ParseError> {
         // Record start position for span (could be `pub` or `fn`)
         let start_span = self.current().span;
+        let doc = self.take_doc_comment();
 
         // Check for optional `pub` keyword
         let visibility = if matches!(self.current_kind(), TokenKind::Pub) {
@@ -39,7 +42,10 @@ impl Parser {
         let mut params = Vec::new();
         if !matches!(self.current_kind(), TokenKind::RightParen) {
             loop {
-                if !matches!(self.current_kind(), TokenKind::Identifier(_)) {
+                if !matches!(
+                    self.current_kind(),
+                    TokenKind::Identifier(_) | TokenKind::LeftParen
+                ) {
                     let expected = if params.is_empty() {
                         "parameter name or ')'"
                     } else {
@@ -53,23 +59,29 @@ impl Parser {
                 }
 
                 let param_start = self.current_span();
-                let name = self.expect_identifier()?;
+                let (name, pattern) = if self.at_destructuring_pattern() {
+                    let pattern = self.parse_pattern()?;
+                    (pattern.to_string(), Some(pattern))
+                } else {
+                    (self.expect_identifier()?, None)
+                };
 
                 self.skip_newlines();
                 self.expect(&TokenKind::Colon)?;
                 self.skip_newlines();
 
-                let ty_span = self.current_span();
                 let ty = self.parse_type()?;
+                let ty_end = self.tokens[self.pos - 1].span.end;
 
                 let param_span = Span::new(
                     param_start.start,
-                    ty_span.end,
+                    ty_end,
                     param_start.line,
                     param_start.column,
                 );
                 params.push(FnParam {
                     name,
+                    pattern,
                     ty,
                     span: param_span,
                 });
@@ -91,7 +103,22 @@ impl Parser {
         self.expect(&TokenKind::Arrow)?;
         // Capture return type span before consuming the token
         let return_type_span = self.current().span;
-        let return_type = self.expect_identifier()?;
+        let (return_type, return_type_span) = if self.at_compound_type() {
+            let ty = self.parse_type()?;
+            let end = self.tokens[self.pos - 1].span.end;
+            let span = Span::new(
+                return_type_span.start,
+                end,
+                return_type_span.line,
+                return_type_span.column,
+            );
+            (ReturnType::Value(ty), span)
+        } else {
+            (
+                ReturnType::from_name(&self.expect_identifier()?),
+                return_type_span,
+            )
+        };
 
         // Record end position (before `{`) for span
         let end_span = self.current().span;
@@ -118,6 +145,7 @@ impl Parser {
         };
 
         Ok(FnDef {
+            doc,
             visibility,
             name,
             params,
@@ -36,12 +36,19 @@ impl Parser {
             TokenKind::While => "'while' keyword".to_string(),
             TokenKind::Break => "'break' keyword".to_string(),
             TokenKind::Continue => "'continue' keyword".to_string(),
+            TokenKind::Defer => "'defer' keyword".to_string(),
             TokenKind::Colon => "':'".to_string(),
             TokenKind::Equals => "'='".to_string(),
             TokenKind::IntLiteral(n) => format!("integer '{}'", n),
+            TokenKind::SuffixedIntLiteral(n, suffix) => format!("integer '{}{}'", n, suffix),
+            TokenKind::FloatLiteral(n) if n.is_nan() => "'nan' literal".to_string(),
+            TokenKind::FloatLiteral(n) if n.is_infinite() => "'inf' literal".to_string(),
             TokenKind::FloatLiteral(n) => format!("float '{}'", n),
+            TokenKind::SuffixedFloatLiteral(n, suffix) => format!("float '{}{}'", n, suffix),
             TokenKind::BoolLiteral(b) => format!("boolean '{}'", b),
+            TokenKind::CharLiteral(c) => format!("character {:?}", c),
             TokenKind::Newline => "newline".to_string(),
+            TokenKind::DocComment(_) => "doc comment".to_string(),
             TokenKind::Plus => "'+'".to_string(),
             TokenKind::Minus => "'-'".to_string(),
             TokenKind::Star => "'*'".to_string(),
@@ -81,6 +88,13 @@ impl Parser {
         self.current().span
     }
 
+    /// Takes the doc comment attached to the current token, if any.
+    ///
+    /// Declaration parsers call this before consuming their first token.
+    pub(super) fn take_doc_comment(&mut self) -> Option<String> {
+        self.docs.remove(&self.pos)
+    }
+
     /// Returns `true` if the current token is `Eof`.
     pub(super) fn is_eof(&self) -> bool {
         matches!(self.current_kind(), TokenKind::Eof)
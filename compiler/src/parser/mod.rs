@@ -16,20 +16,27 @@
 //! ```text
 //! program     → import* fn_def* EOF
 //! import      → "import" STRING ("as" IDENTIFIER)?
-//! fn_def      → ("pub")? "fn" IDENTIFIER "(" param_list? ")" "->" IDENTIFIER "{" stmt* "}"
-//! param_list  → IDENTIFIER ":" type ("," IDENTIFIER ":" type)*
-//! stmt        → let_stmt | assign_stmt | return_stmt | if_stmt | while_stmt | break_stmt | continue_stmt | expr_stmt
-//! let_stmt    → "let" "mut"? IDENTIFIER ":" type "=" expr | "let" "_" "=" expr
+//! fn_def      → DOC_COMMENT* ("pub")? "fn" IDENTIFIER "(" param_list? ")" "->" (IDENTIFIER | tuple_type) "{" stmt* "}"
+//! param_list  → param ("," param)*
+//! param       → (IDENTIFIER | pattern) ":" type
+//! stmt        → let_stmt | assign_stmt | return_stmt | if_stmt | while_stmt | break_stmt | continue_stmt | defer_stmt | expr_stmt
+//! let_stmt    → "let" "mut"? (IDENTIFIER | pattern) (":" type)? "=" expr | "let" "_" "=" expr
+//! pattern     → "_" | IDENTIFIER | "(" pattern ("," pattern)+ ")" | IDENTIFIER "{" field_pattern ("," field_pattern)* "}"
+//! field_pattern → IDENTIFIER (":" pattern)?
 //! assign_stmt → IDENTIFIER "=" expr
 //! return_stmt → "return" expr?
 //! if_stmt     → "if" expr "{" stmt* "}" ("else" (if_stmt | "{" stmt* "}"))?
 //! while_stmt  → "while" expr "{" stmt* "}"
 //! break_stmt  → "break"
 //! continue_stmt → "continue"
-//! type        → integer primitives | "string" | "bool"
+//! defer_stmt  → "defer" ("{" stmt* "}" | stmt)
+//! type        → integer primitives | "string" | "bool" | tuple_type
+//! tuple_type  → "(" type ("," type)+ ")"
 //! expr_stmt   → expr
-//! expr        → if_expr | call | member_access | IDENTIFIER | STRING | INT
+//! expr        → if_expr | block_expr | tuple_expr | call | member_access | IDENTIFIER | STRING | INT
 //! if_expr     → "if" expr "{" stmt* expr "}" "else" "{" stmt* expr "}"
+//! block_expr  → "{" stmt* expr "}"
+//! tuple_expr  → "(" expr ("," expr)+ ")"
 //! call        → IDENTIFIER "(" arguments? ")"
 //! member_access → IDENTIFIER "." IDENTIFIER
 //! arguments   → expr ("," expr)*
@@ -59,6 +66,7 @@
 //! - `import` - Import declaration parsing
 //! - `stmt` - Statement parsing
 //! - `types` - Type annotation parsing
+//! - `pattern` - Destructuring pattern parsing
 //! - `expr` - Expression parsing
 //! - `tests` - Unit tests (test-only)
 //!
@@ -73,6 +81,7 @@ mod expr;
 mod fn_def;
 mod helpers;
 mod import;
+mod pattern;
 mod stmt;
 mod types;
 
@@ -81,6 +90,8 @@ mod tests;
 
 pub use error::{ParseError, ParseErrorKind};
 
+use std::collections::HashMap;
+
 use crate::ast::Program;
 use crate::token::{Span, Token, TokenKind};
 
@@ -98,6 +109,11 @@ pub struct Parser {
     tokens: Vec<Token>,
     /// Current position in the token stream.
     pos: usize,
+    /// Doc comment text keyed by the index of the token it documents.
+    ///
+    /// [`TokenKind::DocComment`] tokens are removed from `tokens` on
+    /// construction, so the rest of the parser never sees them.
+    docs: HashMap<usize, String>,
 }
 
 impl Parser {
@@ -105,25 +121,64 @@ impl Parser {
         Token::new(TokenKind::Eof, Span::new(0, 0, 1, 1))
     }
 
+    /// Removes doc comment tokens from `tokens`, keying each run of
+    /// consecutive doc comments by the index of the next token that is
+    /// neither a doc comment nor a newline.
+    ///
+    /// Lines of a run are joined with `\n`.
+    fn extract_doc_comments(tokens: Vec<Token>) -> (Vec<Token>, HashMap<usize, String>) {
+        let mut kept = Vec::with_capacity(tokens.len());
+        let mut docs = HashMap::new();
+        let mut pending: Option<String> = None;
+
+        for token in tokens {
+            match token.kind {
+                TokenKind::DocComment(line) => match &mut pending {
+                    Some(doc) => {
+                        doc.push('\n');
+                        doc.push_str(&line);
+                    }
+                    None => pending = Some(line),
+                },
+                TokenKind::Newline => kept.push(token),
+                _ => {
+                    if let Some(doc) = pending.take() {
+                        docs.insert(kept.len(), doc);
+                    }
+                    kept.push(token);
+                }
+            }
+        }
+
+        (kept, docs)
+    }
+
     /// Creates a new parser from a token list.
     ///
     /// This compatibility constructor never panics. If `tokens` is empty,
     /// it inserts a synthetic `Eof` token to preserve parser invariants.
     pub fn new(tokens: Vec<Token>) -> Self {
+        let (tokens, docs) = Self::extract_doc_comments(tokens);
         if tokens.is_empty() {
             return Parser {
                 tokens: vec![Self::eof_placeholder_token()],
                 pos: 0,
+                docs,
             };
         }
 
-        Parser { tokens, pos: 0 }
+        Parser {
+            tokens,
+            pos: 0,
+            docs,
+        }
     }
 
     /// Creates a new parser from a token list, returning an error if it is empty.
     ///
     /// Use this when callers want explicit validation instead of normalization.
     pub fn try_new(tokens: Vec<Token>) -> Result<Self, ParseError> {
+        let (tokens, docs) = Self::extract_doc_comments(tokens);
         if tokens.is_empty() {
             return Err(ParseError::internal(
                 "Internal error: parser received an empty token stream. This is a compiler bug.",
@@ -131,7 +186,11 @@ impl Parser {
             ));
         }
 
-        Ok(Parser { tokens, pos: 0 })
+        Ok(Parser {
+            tokens,
+            pos: 0,
+            docs,
+        })
     }
 
     /// Parses the entire token stream into a [`Program`].
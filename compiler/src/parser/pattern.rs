@@ -0,0 +1,78 @@
+//! Destructuring pattern parsing.
+
+use super::Parser;
+use super::error::ParseError;
+use crate::ast::{Pattern, PatternKind};
+use crate::token::{Span, TokenKind};
+
+impl Parser {
+    /// Returns true when the current tokens start a destructuring pattern
+    /// rather than a plain identifier binding.
+    ///
+    /// Destructuring starts with `(` (tuple pattern).
+    pub(super) fn at_destructuring_pattern(&self) -> bool {
+        matches!(self.current_kind(), TokenKind::LeftParen)
+    }
+
+    /// Parses a destructuring pattern.
+    ///
+    /// # Grammar
+    ///
+    /// ```text
+    /// pattern → "_" | IDENTIFIER | tuple_pattern
+    /// tuple_pattern → "(" pattern ("," pattern)+ ","? ")"
+    /// ```
+    pub(super) fn parse_pattern(&mut self) -> Result<Pattern, ParseError> {
+        let start_span = self.current_span();
+        match self.current_kind() {
+            TokenKind::LeftParen => self.parse_tuple_pattern(),
+            TokenKind::Identifier(name) => {
+                let kind = if name == "_" {
+                    PatternKind::Wildcard
+                } else {
+                    PatternKind::Binding(name.clone())
+                };
+                self.advance();
+                Ok(Pattern::new(kind, start_span))
+            }
+            _ => Err(ParseError::unexpected_token(
+                "pattern",
+                &Self::token_kind_display(self.current_kind()),
+                start_span,
+            )),
+        }
+    }
+
+    fn parse_tuple_pattern(&mut self) -> Result<Pattern, ParseError> {
+        let start_span = self.current_span();
+        self.expect(&TokenKind::LeftParen)?;
+        self.skip_newlines();
+
+        let mut elements = Vec::new();
+        while !matches!(self.current_kind(), TokenKind::RightParen) {
+            elements.push(self.parse_pattern()?);
+            self.skip_newlines();
+            if matches!(self.current_kind(), TokenKind::Comma) {
+                self.advance();
+                self.skip_newlines();
+            } else {
+                break;
+            }
+        }
+
+        let close_paren_span = self.current_span();
+        self.expect(&TokenKind::RightParen)?;
+
+        let span = Span::new(
+            start_span.start,
+            close_paren_span.end,
+            start_span.line,
+            start_span.column,
+        );
+        if elements.len() < 2 {
+            return Err(ParseError::tuple_too_few_elements("tuple pattern", span));
+        }
+
+        Ok(Pattern::new(PatternKind::Tuple(elements), span))
+    }
+}
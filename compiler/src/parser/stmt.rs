@@ -21,6 +21,7 @@ impl Parser {
             TokenKind::While => self.parse_while_stmt(),
             TokenKind::Break => self.parse_break_stmt(),
             TokenKind::Continue => self.parse_continue_stmt(),
+            TokenKind::Defer => self.parse_defer_stmt(),
             _ => {
                 let next_kind = self.tokens.get(self.pos + 1).map(|token| &token.kind);
                 if matches!(self.current_kind(), TokenKind::Identifier(_))
@@ -41,9 +42,12 @@ impl Parser {
     /// # Grammar
     ///
     /// ```text
-    /// let_stmt → "let" "mut"? IDENTIFIER (":" type)? "=" expr | "let" "_" "=" expr
-    /// type → integer/float primitives | "string" | "bool"
+    /// let_stmt → "let" "mut"? (IDENTIFIER | pattern) (":" type)? "=" expr | "let" "_" "=" expr
+    /// type → integer/float primitives | "string" | "bool" | tuple type
     /// ```
+    ///
+    /// Tuple patterns produce [`StmtKind::LetPattern`]; a plain
+    /// identifier keeps producing [`StmtKind::Let`].
     pub(super) fn parse_let_stmt(&mut self) -> Result<Stmt, ParseError> {
         let start_span = self.current_span();
 
@@ -56,6 +60,10 @@ impl Parser {
             self.advance();
         }
 
+        if self.at_destructuring_pattern() {
+            return self.parse_let_pattern_rest(start_span, is_mutable);
+        }
+
         // Expect variable name
         let name_span = self.current_span();
         let name = self.expect_identifier()?;
@@ -125,6 +133,47 @@ impl Parser {
         ))
     }
 
+    /// Parses the remainder of a destructuring `let` after `let` / `let mut`.
+    fn parse_let_pattern_rest(
+        &mut self,
+        start_span: Span,
+        is_mutable: bool,
+    ) -> Result<Stmt, ParseError> {
+        let pattern = self.parse_pattern()?;
+
+        let ty = if matches!(self.current_kind(), TokenKind::Colon) {
+            self.expect(&TokenKind::Colon)?;
+            self.parse_type()?
+        } else if matches!(self.current_kind(), TokenKind::Equals) {
+            crate::ast::Type::Inferred
+        } else {
+            return Err(ParseError::expected_type_annotation_or_initializer(
+                &Self::token_kind_display(self.current_kind()),
+                self.current_span(),
+            ));
+        };
+
+        self.expect(&TokenKind::Equals)?;
+        let init = self.parse_expr()?;
+
+        let span = Span::new(
+            start_span.start,
+            init.span.end,
+            start_span.line,
+            start_span.column,
+        );
+
+        Ok(Stmt::new(
+            StmtKind::LetPattern {
+                is_mutable,
+                pattern,
+                ty,
+                init,
+            },
+            span,
+        ))
+    }
+
     /// Parses a reassignment statement.
     ///
     /// # Grammar
@@ -275,6 +324,32 @@ impl Parser {
         Ok(Stmt::new(StmtKind::Continue, span))
     }
 
+    /// Parses a defer statement.
+    ///
+    /// # Grammar
+    ///
+    /// ```text
+    /// defer_stmt → "defer" ("{" stmt* "}" | stmt)
+    /// ```
+    pub(super) fn parse_defer_stmt(&mut self) -> Result<Stmt, ParseError> {
+        let start_span = self.current_span();
+        self.expect(&TokenKind::Defer)?;
+
+        let (body, end) = if matches!(self.current_kind(), TokenKind::LeftBrace) {
+            let body = self.parse_block_stmts()?;
+            // `parse_block_stmts` consumed the closing brace as the previous token.
+            let end = self.tokens[self.pos - 1].span.end;
+            (body, end)
+        } else {
+            let stmt = self.parse_stmt()?;
+            let end = stmt.span.end;
+            (vec![stmt], end)
+        };
+
+        let span = Span::new(start_span.start, end, start_span.line, start_span.column);
+        Ok(Stmt::new(StmtKind::Defer(body), span))
+    }
+
     pub(super) fn parse_block_stmts(&mut self) -> Result<Vec<Stmt>, ParseError> {
         self.expect(&TokenKind::LeftBrace)?;
         self.skip_newlines();
@@ -5,6 +5,7 @@
 //! - Function definition errors
 //! - Call expression errors
 //! - Unexpected token errors
+//! - Collection type errors
 
 use super::*;
 use crate::parser::ParseErrorKind;
@@ -186,3 +187,37 @@ fn test_error_unexpected_string_in_call_arg() {
     );
     assert_eq!(err.kind(), ParseErrorKind::MissingFunctionCallParentheses);
 }
+
+// ===================
+// Collection type errors
+// ===================
+
+#[test]
+fn test_error_invalid_map_key_type() {
+    let err = parse_error("fn main() -> void { let m: map<f64, i64> = map() }");
+    assert_eq!(err.kind(), ParseErrorKind::ExpectedType);
+    assert_eq!(
+        err.message(),
+        "Invalid map key type 'f64'. Expected an integer type, 'string', 'bool' or 'char'"
+    );
+}
+
+#[test]
+fn test_error_invalid_set_item_type() {
+    let err = parse_error("fn main() -> void { let s: set<(i64, bool)> = set() }");
+    assert_eq!(err.kind(), ParseErrorKind::ExpectedType);
+    assert_eq!(
+        err.message(),
+        "Invalid set item type '(i64, bool)'. Expected an integer type, 'string', 'bool' or 'char'"
+    );
+}
+
+#[test]
+fn test_error_invalid_option_payload_type() {
+    let err = parse_error("fn f(x: Option<set<i64>>) -> void {}");
+    assert_eq!(err.kind(), ParseErrorKind::ExpectedType);
+    assert_eq!(
+        err.message(),
+        "Invalid Option payload type 'set<i64>'. Expected an integer or float type, 'string', 'bool' or 'char'"
+    );
+}
@@ -248,6 +248,76 @@ fn test_if_expression_missing_branch_value_error() {
     );
 }
 
+#[test]
+fn test_block_expression_basic() {
+    let program = parse(
+        r#"fn main() -> void {
+            let value: i64 = {
+                let x: i64 = 1
+                x + 1
+            }
+        }"#,
+    )
+    .unwrap();
+
+    match &program.functions[0].body[0].kind {
+        StmtKind::Let { init, .. } => match &init.kind {
+            ExprKind::Block(block) => {
+                assert_eq!(block.stmts.len(), 1);
+                assert!(matches!(block.stmts[0].kind, StmtKind::Let { .. }));
+                assert!(matches!(block.value.kind, ExprKind::BinaryOp { .. }));
+            }
+            _ => panic!("Expected Block expression"),
+        },
+        _ => panic!("Expected Let statement"),
+    }
+}
+
+#[test]
+fn test_block_expression_as_binary_operand() {
+    let program = parse(
+        r#"fn main() -> void {
+            let value: i64 = { 1 } + { 2 }
+        }"#,
+    )
+    .unwrap();
+
+    match &program.functions[0].body[0].kind {
+        StmtKind::Let { init, .. } => match &init.kind {
+            ExprKind::BinaryOp { left, right, .. } => {
+                assert!(matches!(left.kind, ExprKind::Block(_)));
+                assert!(matches!(right.kind, ExprKind::Block(_)));
+            }
+            _ => panic!("Expected BinaryOp expression"),
+        },
+        _ => panic!("Expected Let statement"),
+    }
+}
+
+#[test]
+fn test_block_expression_missing_value_error() {
+    let err = parse_error(
+        r#"fn main() -> void {
+            let value: i64 = { let x: i64 = 1 }
+        }"#,
+    );
+    assert_eq!(err.kind(), ParseErrorKind::MissingBlockExpressionValue);
+    assert_eq!(
+        err.message(),
+        "block expression must end with a value expression"
+    );
+}
+
+#[test]
+fn test_block_expression_empty_error() {
+    let err = parse_error(
+        r#"fn main() -> void {
+            let value: i64 = {}
+        }"#,
+    );
+    assert_eq!(err.kind(), ParseErrorKind::MissingBlockExpressionValue);
+}
+
 // ===================
 // Integer literal parsing
 // ===================
@@ -335,10 +405,58 @@ fn test_int_literal_neg_i64_max() {
     }
 }
 
+#[test]
+fn test_suffixed_int_literal() {
+    let program = parse("fn main() -> void { let x = 255u8 }").unwrap();
+    match &program.functions[0].body[0].kind {
+        StmtKind::Let { init, .. } => {
+            assert!(matches!(
+                init.kind,
+                ExprKind::SuffixedIntLiteral {
+                    value: 255,
+                    ty: Type::U8
+                }
+            ));
+        }
+        _ => panic!("Expected Let statement"),
+    }
+}
+
+#[test]
+fn test_negative_suffixed_int_literal_is_folded() {
+    let program = parse("fn main() -> void { let x = -128i8 }").unwrap();
+    match &program.functions[0].body[0].kind {
+        StmtKind::Let { init, .. } => {
+            assert!(matches!(
+                init.kind,
+                ExprKind::SuffixedIntLiteral {
+                    value: -128,
+                    ty: Type::I8
+                }
+            ));
+        }
+        _ => panic!("Expected Let statement"),
+    }
+}
+
 // ===================
 // Float literal parsing
 // ===================
 
+#[test]
+fn test_suffixed_float_literal() {
+    let program = parse("fn main() -> void { let x = 1.5f32 }").unwrap();
+    match &program.functions[0].body[0].kind {
+        StmtKind::Let { init, .. } => {
+            assert!(matches!(
+                init.kind,
+                ExprKind::SuffixedFloatLiteral { value, ty: Type::F32 } if value == 1.5
+            ));
+        }
+        _ => panic!("Expected Let statement"),
+    }
+}
+
 #[test]
 fn test_float_literal() {
     let program = parse("fn main() -> void { let x: f64 = 2.5 }").unwrap();
@@ -361,6 +479,22 @@ fn test_missing_function_call_parentheses_with_float_literal() {
     );
 }
 
+// ===================
+// Character literal parsing
+// ===================
+
+#[test]
+fn test_char_literal() {
+    let program = parse("fn main() -> void { let c: char = 'λ' }").unwrap();
+    match &program.functions[0].body[0].kind {
+        StmtKind::Let { ty, init, .. } => {
+            assert_eq!(*ty, Type::Char);
+            assert!(matches!(init.kind, ExprKind::CharLiteral('λ')));
+        }
+        _ => panic!("Expected Let statement"),
+    }
+}
+
 // ===================
 // Variable reference parsing
 // ===================
@@ -900,3 +1034,47 @@ fn test_member_access_vs_member_call() {
     let call_expr = parse_first_expr("foo.bar()");
     assert!(matches!(call_expr.kind, ExprKind::ModuleCall { .. }));
 }
+
+#[test]
+fn test_tuple_expression() {
+    let expr = parse_first_expr("f((1, \"two\", (x, true)))");
+    let ExprKind::Call { args, .. } = &expr.kind else {
+        panic!("Expected call expression");
+    };
+    match &args[0].kind {
+        ExprKind::Tuple(elements) => {
+            assert_eq!(elements.len(), 3);
+            assert!(matches!(elements[0].kind, ExprKind::IntLiteral(1)));
+            assert!(matches!(&elements[2].kind, ExprKind::Tuple(inner) if inner.len() == 2));
+        }
+        other => panic!("Expected tuple expression, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_parenthesized_expression_is_not_a_tuple() {
+    let expr = parse_first_expr("f((1))");
+    let ExprKind::Call { args, .. } = &expr.kind else {
+        panic!("Expected call expression");
+    };
+    assert!(matches!(args[0].kind, ExprKind::IntLiteral(1)));
+}
+
+#[test]
+fn test_tuple_expression_allows_trailing_comma() {
+    let expr = parse_first_expr("f((1, 2,))");
+    let ExprKind::Call { args, .. } = &expr.kind else {
+        panic!("Expected call expression");
+    };
+    assert!(matches!(&args[0].kind, ExprKind::Tuple(elements) if elements.len() == 2));
+}
+
+#[test]
+fn test_single_element_tuple_expression_error() {
+    let err = parse_error("fn main() -> void { f((1,)) }");
+    assert_eq!(err.kind(), ParseErrorKind::InvalidTupleArity);
+    assert_eq!(
+        err.message(),
+        "A tuple expression must have at least two elements"
+    );
+}
@@ -4,9 +4,10 @@
 //! - Basic function definition parsing
 //! - FnDef span calculation
 //! - Return type span calculation
+//! - Doc comment attachment
 
 use super::*;
-use crate::ast::Visibility;
+use crate::ast::{ReturnType, Visibility};
 
 // ===================
 // Function definition parsing
@@ -23,7 +24,7 @@ fn test_main_function_empty_body() {
     let program = parse("fn main() -> void {}").unwrap();
     assert_eq!(program.functions.len(), 1);
     assert_eq!(program.functions[0].name, "main");
-    assert_eq!(program.functions[0].return_type, "void");
+    assert_eq!(program.functions[0].return_type, ReturnType::Void);
     assert!(program.functions[0].body.is_empty());
 }
 
@@ -63,6 +64,74 @@ fn test_function_with_multiple_parameters() {
     assert_eq!(fn_def.params[2].ty, Type::Bool);
 }
 
+#[test]
+fn test_function_with_destructured_parameter() {
+    let program = parse("fn f((a, _): (i64, string), b: bool) -> (bool, i64) {}").unwrap();
+
+    let fn_def = &program.functions[0];
+    assert_eq!(fn_def.params.len(), 2);
+    assert_eq!(fn_def.params[0].name, "(a, _)");
+    assert!(fn_def.params[0].pattern.is_some());
+    assert_eq!(
+        fn_def.params[0].ty,
+        Type::Tuple(vec![Type::I64, Type::String])
+    );
+    assert_eq!(fn_def.params[1].name, "b");
+    assert!(fn_def.params[1].pattern.is_none());
+    assert_eq!(
+        fn_def.return_type,
+        ReturnType::Value(Type::Tuple(vec![Type::Bool, Type::I64]))
+    );
+    assert_eq!(fn_def.return_type_span.start, 40);
+    assert_eq!(fn_def.return_type_span.end, 51);
+}
+
+#[test]
+fn test_function_with_collection_types() {
+    let program =
+        parse("fn f(counts: map<string, i64>, seen: set<u8>) -> map<bool, f32> {}").unwrap();
+    let fn_def = &program.functions[0];
+    assert_eq!(
+        fn_def.params[0].ty,
+        Type::Map(Box::new(Type::String), Box::new(Type::I64))
+    );
+    assert_eq!(fn_def.params[1].ty, Type::Set(Box::new(Type::U8)));
+    assert_eq!(
+        fn_def.return_type,
+        ReturnType::Value(Type::Map(Box::new(Type::Bool), Box::new(Type::F32)))
+    );
+}
+
+#[test]
+fn test_function_with_option_types() {
+    let program = parse("fn f(name: Option<string>) -> Option<f64> {}").unwrap();
+    let fn_def = &program.functions[0];
+    assert_eq!(fn_def.params[0].ty, Type::Option(Box::new(Type::String)));
+    assert_eq!(
+        fn_def.return_type,
+        ReturnType::Value(Type::Option(Box::new(Type::F64)))
+    );
+}
+
+#[test]
+fn test_function_with_char_key_types() {
+    let program = parse("fn f(seen: set<char>) -> map<char, i64> {}").unwrap();
+    let fn_def = &program.functions[0];
+    assert_eq!(fn_def.params[0].ty, Type::Set(Box::new(Type::Char)));
+    assert_eq!(
+        fn_def.return_type,
+        ReturnType::Value(Type::Map(Box::new(Type::Char), Box::new(Type::I64)))
+    );
+}
+
+#[test]
+fn test_function_with_rng_types() {
+    let program = parse("fn split(dice: rng) -> rng {}").unwrap();
+    let fn_def = &program.functions[0];
+    assert_eq!(fn_def.params[0].ty, Type::Rng);
+    assert_eq!(fn_def.return_type, ReturnType::Value(Type::Rng));
+}
+
 #[test]
 fn test_function_with_float_parameters() {
     let program = parse("fn blend(a: f32, b: f64) -> void {}").unwrap();
@@ -222,6 +291,8 @@ fn test_return_type_span_int() {
     // "int" spans from 13 to 16
     assert_eq!(fn_def.return_type_span.start, 13);
     assert_eq!(fn_def.return_type_span.end, 16);
+    // Unknown names are left for semantic analysis to report.
+    assert_eq!(fn_def.return_type, ReturnType::Unknown("int".to_string()));
 }
 
 #[test]
@@ -277,7 +348,7 @@ fn test_fn_def_span_with_extra_whitespace() {
     // Function span should start at 'f' and end before '{'
     assert_eq!(fn_def.span.start, 0);
     // return_type_span should point to 'void'
-    assert_eq!(fn_def.return_type, "void");
+    assert_eq!(fn_def.return_type, ReturnType::Void);
     assert!(fn_def.return_type_span.start > 0);
     assert!(fn_def.return_type_span.end > fn_def.return_type_span.start);
 }
@@ -378,3 +449,53 @@ fn test_pub_fn_span_with_leading_whitespace() {
     );
     assert_eq!(fn_def.span.column, 3);
 }
+
+// ============================================================
+// Doc comment tests
+// ============================================================
+
+#[test]
+fn test_doc_comment_attached_to_fn() {
+    let source = "/// Entry point.\n/// Prints a greeting.\nfn main() -> void {}";
+    let program = parse(source).unwrap();
+    assert_eq!(
+        program.functions[0].doc.as_deref(),
+        Some("Entry point.\nPrints a greeting.")
+    );
+}
+
+#[test]
+fn test_doc_comment_attached_to_pub_fn_and_span_starts_at_pub() {
+    let source = "/// Helper.\npub fn helper() -> void {}";
+    let program = parse(source).unwrap();
+    let fn_def = &program.functions[0];
+    assert_eq!(fn_def.doc.as_deref(), Some("Helper."));
+    assert_eq!(fn_def.span.line, 2);
+    assert_eq!(fn_def.span.column, 1);
+}
+
+#[test]
+fn test_doc_comment_only_applies_to_following_fn() {
+    let source = "fn a() -> void {}\n\n/// Documents b.\nfn b() -> void {}\nfn c() -> void {}";
+    let program = parse(source).unwrap();
+    let docs: Vec<_> = program.functions.iter().map(|f| f.doc.as_deref()).collect();
+    assert_eq!(docs, vec![None, Some("Documents b."), None]);
+}
+
+#[test]
+fn test_doc_comment_inside_body_is_ignored() {
+    let source =
+        "fn main() -> void {\n    /// not attached\n    let x = 1\n    println(x) /// trailing\n}";
+    let program = parse(source).unwrap();
+    let fn_def = &program.functions[0];
+    assert!(fn_def.doc.is_none());
+    assert_eq!(fn_def.body.len(), 2);
+}
+
+#[test]
+fn test_block_comment_between_functions() {
+    let source = "/* header\n   /* nested */ */\nfn main() -> void {}";
+    let program = parse(source).unwrap();
+    assert_eq!(program.functions.len(), 1);
+    assert!(program.functions[0].doc.is_none());
+}
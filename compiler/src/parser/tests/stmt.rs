@@ -694,3 +694,111 @@ fn test_error_break_continue_same_line() {
         "Expected newline after statement, found 'continue' keyword"
     );
 }
+
+#[test]
+fn test_defer_single_stmt() {
+    let program = parse(
+        r#"fn main() -> void {
+            defer println("bye")
+        }"#,
+    )
+    .unwrap();
+
+    match &program.functions[0].body[0].kind {
+        StmtKind::Defer(body) => {
+            assert_eq!(body.len(), 1);
+            assert!(matches!(&body[0].kind, StmtKind::Expr(_)));
+        }
+        _ => panic!("Expected Defer statement"),
+    }
+}
+
+#[test]
+fn test_defer_block() {
+    let program = parse(
+        r#"fn main() -> void {
+            defer {
+                let x: i64 = 1
+                println(x)
+            }
+            println("body")
+        }"#,
+    )
+    .unwrap();
+
+    let body = &program.functions[0].body;
+    assert_eq!(body.len(), 2);
+    match &body[0].kind {
+        StmtKind::Defer(deferred) => {
+            assert_eq!(deferred.len(), 2);
+            assert!(matches!(&deferred[0].kind, StmtKind::Let { .. }));
+            assert!(matches!(&deferred[1].kind, StmtKind::Expr(_)));
+        }
+        _ => panic!("Expected Defer statement"),
+    }
+}
+
+#[test]
+fn test_defer_assign_stmt() {
+    let program = parse(
+        r#"fn main() -> void {
+            defer x = 2
+        }"#,
+    )
+    .unwrap();
+
+    match &program.functions[0].body[0].kind {
+        StmtKind::Defer(body) => {
+            assert!(matches!(&body[0].kind, StmtKind::Assign { .. }));
+        }
+        _ => panic!("Expected Defer statement"),
+    }
+}
+
+#[test]
+fn test_let_tuple_pattern() {
+    let program = parse(
+        r#"fn main() -> void {
+            let mut (a, _, (b, c)): (i64, bool, (string, u8)) = value
+        }"#,
+    )
+    .unwrap();
+
+    match &program.functions[0].body[0].kind {
+        StmtKind::LetPattern {
+            is_mutable,
+            pattern,
+            ty,
+            ..
+        } => {
+            assert!(*is_mutable);
+            assert_eq!(pattern.to_string(), "(a, _, (b, c))");
+            let bindings: Vec<&str> = pattern.bindings().iter().map(|(name, _)| *name).collect();
+            assert_eq!(bindings, vec!["a", "b", "c"]);
+            assert_eq!(
+                *ty,
+                Type::Tuple(vec![
+                    Type::I64,
+                    Type::Bool,
+                    Type::Tuple(vec![Type::String, Type::U8]),
+                ])
+            );
+        }
+        _ => panic!("Expected LetPattern statement"),
+    }
+}
+
+#[test]
+fn test_plain_let_is_not_a_pattern() {
+    let program = parse(
+        r#"fn main() -> void {
+            let x = 1
+        }"#,
+    )
+    .unwrap();
+
+    assert!(matches!(
+        &program.functions[0].body[0].kind,
+        StmtKind::Let { .. }
+    ));
+}
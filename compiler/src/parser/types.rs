@@ -3,6 +3,13 @@
 use super::Parser;
 use super::error::ParseError;
 use crate::ast::Type;
+use crate::token::{Span, TokenKind};
+
+/// Description of the types allowed as map keys and set items.
+const KEY_TYPES: &str = "an integer type, 'string', 'bool' or 'char'";
+
+/// Description of the types allowed as map values and `Option` payloads.
+const MAP_VALUE_TYPES: &str = "an integer or float type, 'string', 'bool' or 'char'";
 
 impl Parser {
     /// Parses a type annotation.
@@ -10,11 +17,136 @@ impl Parser {
     /// # Grammar
     ///
     /// ```text
-    /// type → "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "u64" | "f32" | "f64" | "byte" | "string" | "bool"
+    /// type → primitive | tuple_type | map_type | set_type | option_type
+    /// primitive → "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "u64" | "f32" | "f64" | "byte" | "string" | "bool" | "char" | "rng"
+    /// tuple_type → "(" type ("," type)+ ","? ")"
+    /// map_type → "map" "<" type "," type ">"
+    /// set_type → "set" "<" type ">"
+    /// option_type → "Option" "<" type ">"
     /// ```
+    ///
+    /// Map keys and set items must be integers, `string`, `bool` or `char`; map
+    /// values and `Option` payloads may also be floats.
     pub(super) fn parse_type(&mut self) -> Result<Type, ParseError> {
+        if matches!(self.current_kind(), TokenKind::LeftParen) {
+            return self.parse_tuple_type();
+        }
+
         let type_span = self.current_span();
         let name = self.expect_identifier()?;
+        if matches!(self.current_kind(), TokenKind::LessThan) {
+            match name.as_str() {
+                "map" => return self.parse_map_type(),
+                "set" => return self.parse_set_type(),
+                "Option" => return self.parse_option_type(),
+                _ => {}
+            }
+        }
         Type::from_source_name(&name).ok_or_else(|| ParseError::unknown_type(&name, type_span))
     }
+
+    /// Returns true when the current token starts a tuple, map, set or
+    /// `Option` type.
+    pub(super) fn at_compound_type(&self) -> bool {
+        match self.current_kind() {
+            TokenKind::LeftParen => true,
+            TokenKind::Identifier(name) if matches!(name.as_str(), "map" | "set" | "Option") => {
+                self.tokens
+                    .get(self.pos + 1)
+                    .is_some_and(|token| matches!(token.kind, TokenKind::LessThan))
+            }
+            _ => false,
+        }
+    }
+
+    /// Parses the `<K, V>` part of a map type.
+    fn parse_map_type(&mut self) -> Result<Type, ParseError> {
+        self.expect(&TokenKind::LessThan)?;
+        let key =
+            self.parse_collection_element_type("map key", Type::is_collection_key, KEY_TYPES)?;
+        self.expect(&TokenKind::Comma)?;
+        let value =
+            self.parse_collection_element_type("map value", Type::is_map_value, MAP_VALUE_TYPES)?;
+        self.expect(&TokenKind::GreaterThan)?;
+        Ok(Type::Map(Box::new(key), Box::new(value)))
+    }
+
+    /// Parses the `<T>` part of a set type.
+    fn parse_set_type(&mut self) -> Result<Type, ParseError> {
+        self.expect(&TokenKind::LessThan)?;
+        let item =
+            self.parse_collection_element_type("set item", Type::is_collection_key, KEY_TYPES)?;
+        self.expect(&TokenKind::GreaterThan)?;
+        Ok(Type::Set(Box::new(item)))
+    }
+
+    /// Parses the `<T>` part of an `Option` type.
+    fn parse_option_type(&mut self) -> Result<Type, ParseError> {
+        self.expect(&TokenKind::LessThan)?;
+        let payload = self.parse_collection_element_type(
+            "Option payload",
+            Type::is_map_value,
+            MAP_VALUE_TYPES,
+        )?;
+        self.expect(&TokenKind::GreaterThan)?;
+        Ok(Type::Option(Box::new(payload)))
+    }
+
+    /// Parses the type of a map key, map value, set item or `Option` payload, rejecting types
+    /// for which `allowed` is false. `expected` describes the allowed types
+    /// in the error.
+    fn parse_collection_element_type(
+        &mut self,
+        what: &str,
+        allowed: fn(&Type) -> bool,
+        expected: &str,
+    ) -> Result<Type, ParseError> {
+        self.skip_newlines();
+        let span = self.current_span();
+        let ty = self.parse_type()?;
+        self.skip_newlines();
+        if !allowed(&ty) {
+            return Err(ParseError::invalid_collection_element_type(
+                what,
+                &ty.to_string(),
+                expected,
+                span,
+            ));
+        }
+        Ok(ty)
+    }
+
+    /// Parses a parenthesized tuple type such as `(i64, string)`.
+    fn parse_tuple_type(&mut self) -> Result<Type, ParseError> {
+        let start_span = self.current_span();
+        self.expect(&TokenKind::LeftParen)?;
+        self.skip_newlines();
+
+        let mut elements = Vec::new();
+        while !matches!(self.current_kind(), TokenKind::RightParen) {
+            elements.push(self.parse_type()?);
+            self.skip_newlines();
+            if matches!(self.current_kind(), TokenKind::Comma) {
+                self.advance();
+                self.skip_newlines();
+            } else {
+                break;
+            }
+        }
+
+        let close_paren_span = self.current_span();
+        self.expect(&TokenKind::RightParen)?;
+
+        if elements.len() < 2 {
+            let span = Span::new(
+                start_span.start,
+                close_paren_span.end,
+                start_span.line,
+                start_span.column,
+            );
+            return Err(ParseError::tuple_too_few_elements("tuple type", span));
+        }
+
+        Ok(Type::Tuple(elements))
+    }
 }
@@ -0,0 +1,245 @@
+//! Prelude functions implemented directly by the Lak runtime.
+//!
+//! Builtins such as `print`, `format` or `assert` need dedicated semantic
+//! checks and code generation. The functions listed here do not: each one
+//! has a fixed signature and lowers to a single call of a `lak_*` runtime
+//! function. Semantic analysis type-checks calls against [`RuntimeFunction`]
+//! and codegen declares and calls the runtime symbol.
+//!
+//! The same description is used for the functions of native standard
+//! modules (see [`crate::stdlib`]).
+//!
+//! String results are owned references (see `codegen::rc`).
+
+use crate::ast::Type;
+
+/// A function backed by a single runtime function.
+#[derive(Debug)]
+pub struct RuntimeFunction {
+    /// Name of the function in Lak source code.
+    pub name: &'static str,
+    /// Name of the `extern "C"` runtime function implementing it.
+    pub symbol: &'static str,
+    /// Parameter names and types, in order.
+    pub params: &'static [(&'static str, Type)],
+    /// What the function returns.
+    pub returns: RuntimeReturn,
+    /// If true, the runtime function takes the call site as three trailing
+    /// arguments (`const char* file, i32 line, i32 column`) so it can report
+    /// panics with their source location.
+    pub takes_location: bool,
+    /// Doc comment shown by `lak doc`.
+    pub doc: &'static str,
+}
+
+/// The result of a runtime-backed function.
+#[derive(Debug)]
+pub enum RuntimeReturn {
+    /// The function returns nothing.
+    Void,
+    /// The function returns a value of the given type.
+    Value(Type),
+    /// The function returns `(T, bool)`: the value and whether one was
+    /// produced. The runtime function returns a pointer that is null when
+    /// there is no value, in which case codegen substitutes the empty value
+    /// of `T`. Only `string` is supported.
+    Optional(Type),
+    /// The function returns a `string` error message, empty on success.
+    /// The runtime function returns an owned error string, or null on
+    /// success.
+    Error,
+    /// The function returns `(T, string)`: the value and an error message,
+    /// empty on success. The runtime function takes a trailing
+    /// `const char** error` parameter and always stores an owned error
+    /// string or null there. On error, a `string` result is returned as
+    /// null and a numeric result as zero.
+    Result(Type),
+    /// The function returns `(T, string, string)`: a value and two output
+    /// strings. The runtime function returns the value and takes two
+    /// trailing `const char**` parameters, where it stores owned strings.
+    Outputs(Type),
+}
+
+impl RuntimeFunction {
+    /// Returns the Lak return type, or `None` for `void`.
+    pub fn return_type(&self) -> Option<Type> {
+        match &self.returns {
+            RuntimeReturn::Void => None,
+            RuntimeReturn::Value(ty) => Some(ty.clone()),
+            RuntimeReturn::Optional(ty) => Some(Type::Tuple(vec![ty.clone(), Type::Bool])),
+            RuntimeReturn::Error => Some(Type::String),
+            RuntimeReturn::Result(ty) => Some(Type::Tuple(vec![ty.clone(), Type::String])),
+            RuntimeReturn::Outputs(ty) => {
+                Some(Type::Tuple(vec![ty.clone(), Type::String, Type::String]))
+            }
+        }
+    }
+
+    /// Returns the return type name as written in a function signature
+    /// (e.g. `"void"`, `"string"`).
+    pub fn return_type_name(&self) -> String {
+        self.return_type()
+            .map_or_else(|| "void".to_string(), |ty| ty.to_string())
+    }
+}
+
+/// All prelude functions implemented directly by the runtime.
+pub const RUNTIME_FUNCTIONS: &[RuntimeFunction] = &[
+    RuntimeFunction {
+        name: "arg_count",
+        symbol: "lak_arg_count",
+        params: &[],
+        returns: RuntimeReturn::Value(Type::I64),
+        takes_location: false,
+        doc: "Returns the number of command-line arguments, excluding the program name.",
+    },
+    RuntimeFunction {
+        name: "arg",
+        symbol: "lak_arg",
+        params: &[("index", Type::I64)],
+        returns: RuntimeReturn::Value(Type::String),
+        takes_location: true,
+        doc: "Returns the command-line argument at `index`. Panics if it is out of range.",
+    },
+    RuntimeFunction {
+        name: "read_line",
+        symbol: "lak_read_line",
+        params: &[],
+        returns: RuntimeReturn::Optional(Type::String),
+        takes_location: true,
+        doc: "Reads the next line of stdin without its line ending.\n\
+              Returns `(\"\", false)` at end of input.",
+    },
+    RuntimeFunction {
+        name: "read_all",
+        symbol: "lak_read_all",
+        params: &[],
+        returns: RuntimeReturn::Value(Type::String),
+        takes_location: true,
+        doc: "Reads the rest of stdin. Returns `\"\"` at end of input.",
+    },
+    RuntimeFunction {
+        name: "parse_i8",
+        symbol: "lak_parse_i8",
+        params: &[("text", Type::String)],
+        returns: RuntimeReturn::Result(Type::I8),
+        takes_location: false,
+        doc: "Parses `text` as a decimal `i8`. Returns the value, or 0, and an error\n\
+              message, empty on success.",
+    },
+    RuntimeFunction {
+        name: "parse_i16",
+        symbol: "lak_parse_i16",
+        params: &[("text", Type::String)],
+        returns: RuntimeReturn::Result(Type::I16),
+        takes_location: false,
+        doc: "Parses `text` as a decimal `i16`. Returns the value, or 0, and an error\n\
+              message, empty on success.",
+    },
+    RuntimeFunction {
+        name: "parse_i32",
+        symbol: "lak_parse_i32",
+        params: &[("text", Type::String)],
+        returns: RuntimeReturn::Result(Type::I32),
+        takes_location: false,
+        doc: "Parses `text` as a decimal `i32`. Returns the value, or 0, and an error\n\
+              message, empty on success.",
+    },
+    RuntimeFunction {
+        name: "parse_i64",
+        symbol: "lak_parse_i64",
+        params: &[("text", Type::String)],
+        returns: RuntimeReturn::Result(Type::I64),
+        takes_location: false,
+        doc: "Parses `text` as a decimal `i64`. Returns the value, or 0, and an error\n\
+              message, empty on success.",
+    },
+    RuntimeFunction {
+        name: "parse_u8",
+        symbol: "lak_parse_u8",
+        params: &[("text", Type::String)],
+        returns: RuntimeReturn::Result(Type::U8),
+        takes_location: false,
+        doc: "Parses `text` as a decimal `u8`. Returns the value, or 0, and an error\n\
+              message, empty on success.",
+    },
+    RuntimeFunction {
+        name: "parse_u16",
+        symbol: "lak_parse_u16",
+        params: &[("text", Type::String)],
+        returns: RuntimeReturn::Result(Type::U16),
+        takes_location: false,
+        doc: "Parses `text` as a decimal `u16`. Returns the value, or 0, and an error\n\
+              message, empty on success.",
+    },
+    RuntimeFunction {
+        name: "parse_u32",
+        symbol: "lak_parse_u32",
+        params: &[("text", Type::String)],
+        returns: RuntimeReturn::Result(Type::U32),
+        takes_location: false,
+        doc: "Parses `text` as a decimal `u32`. Returns the value, or 0, and an error\n\
+              message, empty on success.",
+    },
+    RuntimeFunction {
+        name: "parse_u64",
+        symbol: "lak_parse_u64",
+        params: &[("text", Type::String)],
+        returns: RuntimeReturn::Result(Type::U64),
+        takes_location: false,
+        doc: "Parses `text` as a decimal `u64`. Returns the value, or 0, and an error\n\
+              message, empty on success.",
+    },
+    RuntimeFunction {
+        name: "parse_f32",
+        symbol: "lak_parse_f32",
+        params: &[("text", Type::String)],
+        returns: RuntimeReturn::Result(Type::F32),
+        takes_location: false,
+        doc: "Parses `text` as an `f32` (e.g. `1.5`, `-2e3`, `inf`). Returns the value,\n\
+              or 0, and an error message, empty on success. A number too large\n\
+              for `f32` is out of range.",
+    },
+    RuntimeFunction {
+        name: "parse_f64",
+        symbol: "lak_parse_f64",
+        params: &[("text", Type::String)],
+        returns: RuntimeReturn::Result(Type::F64),
+        takes_location: false,
+        doc: "Parses `text` as an `f64` (e.g. `1.5`, `-2e3`, `inf`). Returns the value,\n\
+              or 0, and an error message, empty on success. A number too large\n\
+              for `f64` is out of range.",
+    },
+];
+
+/// Prelude functions with dedicated semantic checks and code generation.
+pub const BUILTIN_FUNCTIONS: &[&str] = &[
+    "print",
+    "println",
+    "eprint",
+    "eprintln",
+    "format",
+    "panic",
+    "exit",
+    "assert",
+    "assert_eq",
+    "assert_ne",
+    "map",
+    "set",
+];
+
+/// Returns the names of all prelude functions, which user code cannot
+/// redefine: the builtins followed by the runtime-backed functions.
+pub fn reserved_function_names() -> impl Iterator<Item = &'static str> {
+    BUILTIN_FUNCTIONS
+        .iter()
+        .copied()
+        .chain(RUNTIME_FUNCTIONS.iter().map(|function| function.name))
+}
+
+/// Looks up a runtime-backed prelude function by its Lak name.
+pub fn runtime_function(name: &str) -> Option<&'static RuntimeFunction> {
+    RUNTIME_FUNCTIONS
+        .iter()
+        .find(|function| function.name == name)
+}
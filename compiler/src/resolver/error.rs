@@ -24,8 +24,8 @@ pub enum ResolverErrorKind {
     LexError,
     /// Parsing failed.
     ParseError,
-    /// Standard library imports are not yet supported.
-    StandardLibraryNotSupported,
+    /// Non-relative import of a module that is not in the standard library.
+    UnknownStandardModule,
 }
 
 /// Source context for errors that occur in a different file than the entry module.
@@ -190,7 +190,7 @@ impl ResolverError {
             ResolverErrorKind::InvalidModuleName => "Invalid module name",
             ResolverErrorKind::LexError => "Lexical error in module",
             ResolverErrorKind::ParseError => "Parse error in module",
-            ResolverErrorKind::StandardLibraryNotSupported => "Standard library not supported",
+            ResolverErrorKind::UnknownStandardModule => "Unknown standard module",
         }
     }
 
@@ -274,16 +274,20 @@ impl ResolverError {
     }
 
     // =========================================================================
-    // Unsupported features
+    // Standard library errors
     // =========================================================================
 
-    /// Creates a "standard library not supported" error.
-    pub fn standard_library_not_supported(path: &str, span: Span) -> Self {
+    /// Creates an error for a non-relative import that names no standard
+    /// library module.
+    pub fn unknown_standard_module(path: &str, span: Span) -> Self {
         Self::with_span_and_help(
-            ResolverErrorKind::StandardLibraryNotSupported,
-            format!("Standard library imports are not yet supported: '{}'", path),
+            ResolverErrorKind::UnknownStandardModule,
+            format!("Unknown standard library module: '{}'", path),
             span,
-            "use relative paths like './module' instead",
+            format!(
+                "available standard modules: {}; use relative paths like './module' for your own modules",
+                crate::stdlib::module_names().join(", ")
+            ),
         )
     }
 
@@ -644,11 +648,14 @@ mod tests {
     }
 
     #[test]
-    fn test_standard_library_not_supported_help() {
-        let err = ResolverError::standard_library_not_supported("math", dummy_span());
+    fn test_unknown_standard_module_help() {
+        let err = ResolverError::unknown_standard_module("json", dummy_span());
+        assert_eq!(err.message(), "Unknown standard library module: 'json'");
         assert_eq!(
             err.help(),
-            Some("use relative paths like './module' instead")
+            Some(
+                "available standard modules: fs, math, process, random, strings, time; use relative paths like './module' for your own modules"
+            )
         );
     }
 
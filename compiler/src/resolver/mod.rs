@@ -2,6 +2,10 @@
 //!
 //! This module handles loading and parsing imported modules, building a
 //! dependency graph, and detecting circular imports.
+//!
+//! Non-relative imports (e.g. `import "strings"`) name standard library
+//! modules (see [`crate::stdlib`]). Bundled modules resolve to the virtual
+//! path `<std>/<name>.lak`; native ones have no source and an empty program.
 
 mod error;
 
@@ -10,6 +14,7 @@ pub use error::{ResolverError, ResolverErrorKind};
 use crate::ast::Program;
 use crate::lexer::Lexer;
 use crate::parser::Parser;
+use crate::stdlib::{NativeModule, native_module, source_module};
 use crate::token::Span;
 
 use std::collections::HashMap;
@@ -18,7 +23,8 @@ use std::path::{Path, PathBuf};
 /// A resolved module with its parsed AST and metadata.
 #[derive(Debug)]
 pub struct ResolvedModule {
-    /// The canonical absolute path to the module file.
+    /// The canonical absolute path to the module file, or the virtual
+    /// `<std>/<name>.lak` path of a bundled standard library module.
     path: PathBuf,
     /// The module name (last segment of path, without extension).
     name: String,
@@ -28,6 +34,8 @@ pub struct ResolvedModule {
     source: String,
     /// Map from import path strings to their resolved canonical paths.
     resolved_imports: HashMap<String, PathBuf>,
+    /// The runtime implementation of a native standard library module.
+    native: Option<&'static NativeModule>,
 }
 
 impl ResolvedModule {
@@ -56,6 +64,12 @@ impl ResolvedModule {
         &self.resolved_imports
     }
 
+    /// Returns the runtime implementation if this is a native standard
+    /// library module. Its program is empty.
+    pub fn native_module(&self) -> Option<&'static NativeModule> {
+        self.native
+    }
+
     /// Creates a `ResolvedModule` for testing purposes.
     ///
     /// This constructor allows tests to create `ResolvedModule` instances
@@ -73,6 +87,7 @@ impl ResolvedModule {
             program,
             source,
             resolved_imports: HashMap::new(),
+            native: None,
         }
     }
 }
@@ -162,6 +177,9 @@ pub struct ModuleResolver {
     modules: HashMap<PathBuf, ResolvedModule>,
     /// Current resolution stack and cycle reporting utilities.
     cycle_tracker: CycleTracker,
+    /// Directory searched for standard library modules before the bundled
+    /// ones.
+    stdlib_dir: Option<PathBuf>,
 }
 
 impl ModuleResolver {
@@ -170,9 +188,17 @@ impl ModuleResolver {
         ModuleResolver {
             modules: HashMap::new(),
             cycle_tracker: CycleTracker::new(),
+            stdlib_dir: None,
         }
     }
 
+    /// Makes `<dir>/<name>.lak` take precedence over the bundled standard
+    /// library module `<name>`.
+    pub fn with_stdlib_dir(mut self, dir: PathBuf) -> Self {
+        self.stdlib_dir = Some(dir);
+        self
+    }
+
     /// Resolves a module starting from an entry point file with pre-read source.
     ///
     /// This avoids reading the entry file a second time when the caller has
@@ -265,6 +291,13 @@ impl ModuleResolver {
         // Collect dependencies and resolve them
         let mut resolved_imports = HashMap::new();
         for import in &program.imports {
+            if !is_relative_import(&import.path)
+                && let Some(std_path) = self.resolve_standard_module(&import.path, import.span)?
+            {
+                resolved_imports.insert(import.path.clone(), std_path);
+                continue;
+            }
+
             let import_path = Self::resolve_import_path(&import.path, path, import.span)?;
             Self::module_name_from_path(&import_path, import.span)?;
             resolved_imports.insert(import.path.clone(), import_path.clone());
@@ -280,6 +313,7 @@ impl ModuleResolver {
             program,
             source,
             resolved_imports,
+            native: None,
         };
         self.modules.insert(path.to_path_buf(), module);
 
@@ -292,7 +326,7 @@ impl ModuleResolver {
         importing_file: &Path,
         span: Span,
     ) -> Result<PathBuf, ResolverError> {
-        if import_path.starts_with("./") || import_path.starts_with("../") {
+        if is_relative_import(import_path) {
             // Relative path: resolve relative to the importing file's directory
             let base_dir = importing_file.parent().ok_or_else(|| {
                 ResolverError::invalid_import_path("Cannot determine parent directory", span)
@@ -319,14 +353,68 @@ impl ModuleResolver {
                 }
             })
         } else {
-            // Standard library path (not implemented yet)
-            Err(ResolverError::standard_library_not_supported(
-                import_path,
-                span,
-            ))
+            // Standard library modules are resolved by `resolve_standard_module`
+            Err(ResolverError::unknown_standard_module(import_path, span))
         }
     }
 
+    /// Resolves a standard library module and its dependencies.
+    ///
+    /// Looks in the override directory, then the bundled sources, then the
+    /// native modules. Returns the module path, or `None` if no standard
+    /// library module has this name.
+    fn resolve_standard_module(
+        &mut self,
+        name: &str,
+        span: Span,
+    ) -> Result<Option<PathBuf>, ResolverError> {
+        if !is_valid_identifier(name) {
+            return Ok(None);
+        }
+
+        if let Some(dir) = &self.stdlib_dir {
+            let candidate = dir.join(format!("{}.lak", name));
+            if candidate.is_file() {
+                let path = candidate
+                    .canonicalize()
+                    .map_err(|e| ResolverError::io_error_resolve_import(name, &e, span))?;
+                self.resolve_module(&path, Some(span), None)?;
+                return Ok(Some(path));
+            }
+        }
+
+        if let Some(module) = source_module(name) {
+            let path = Self::standard_module_path(name);
+            self.resolve_module(&path, Some(span), Some(module.source.to_string()))?;
+            return Ok(Some(path));
+        }
+
+        if let Some(native) = native_module(name) {
+            let path = Self::standard_module_path(name);
+            self.modules
+                .entry(path.clone())
+                .or_insert_with(|| ResolvedModule {
+                    path: path.clone(),
+                    name: native.name.to_string(),
+                    program: Program {
+                        imports: Vec::new(),
+                        functions: Vec::new(),
+                    },
+                    source: String::new(),
+                    resolved_imports: HashMap::new(),
+                    native: Some(native),
+                });
+            return Ok(Some(path));
+        }
+
+        Ok(None)
+    }
+
+    /// Returns the virtual path identifying a bundled standard library module.
+    fn standard_module_path(name: &str) -> PathBuf {
+        PathBuf::from(format!("<std>/{}.lak", name))
+    }
+
     /// Extracts module name from a file path.
     fn module_name_from_path(path: &Path, span: Span) -> Result<String, ResolverError> {
         let file_stem = path
@@ -364,6 +452,11 @@ impl Default for ModuleResolver {
     }
 }
 
+/// Returns true if an import path is relative to the importing file.
+fn is_relative_import(import_path: &str) -> bool {
+    import_path.starts_with("./") || import_path.starts_with("../")
+}
+
 /// Checks if a string is a valid Lak identifier.
 ///
 /// Valid identifiers start with an ASCII letter or underscore, followed by
@@ -569,12 +662,78 @@ mod tests {
     }
 
     #[test]
-    fn test_resolve_import_path_standard_library_rejected() {
+    fn test_resolve_native_module_import() {
+        let temp = tempfile::tempdir().unwrap();
+        let main_path = temp.path().join("main.lak");
+        let source = "import \"fs\"\nfn main() -> void {}\n".to_string();
+        std::fs::write(&main_path, &source).unwrap();
+
+        let mut resolver = ModuleResolver::new();
+        resolver
+            .resolve_from_entry_with_source(&main_path, source)
+            .unwrap();
+        let modules = resolver.into_modules();
+        let native = modules
+            .iter()
+            .find(|module| module.native_module().is_some())
+            .unwrap();
+        assert_eq!(native.name(), "fs");
+        assert_eq!(native.path(), Path::new("<std>/fs.lak"));
+        assert!(native.program().functions.is_empty());
+    }
+
+    #[test]
+    fn test_resolve_source_standard_module_import() {
+        let temp = tempfile::tempdir().unwrap();
+        let main_path = temp.path().join("main.lak");
+        let source = "import \"strings\"\nfn main() -> void {}\n".to_string();
+        std::fs::write(&main_path, &source).unwrap();
+
+        let mut resolver = ModuleResolver::new();
+        resolver
+            .resolve_from_entry_with_source(&main_path, source)
+            .unwrap();
+        let modules = resolver.into_modules();
+        let strings = modules
+            .iter()
+            .find(|module| module.path() == Path::new("<std>/strings.lak"))
+            .unwrap();
+        assert_eq!(strings.name(), "strings");
+        assert!(strings.native_module().is_none());
+        assert!(!strings.program().functions.is_empty());
+    }
+
+    #[test]
+    fn test_resolve_standard_module_from_stdlib_dir() {
+        let temp = tempfile::tempdir().unwrap();
+        let stdlib_dir = temp.path().join("stdlib");
+        std::fs::create_dir(&stdlib_dir).unwrap();
+        let override_path = stdlib_dir.join("strings.lak");
+        std::fs::write(&override_path, "pub fn custom() -> void {}\n").unwrap();
+        let main_path = temp.path().join("main.lak");
+        let source = "import \"strings\"\nfn main() -> void {}\n".to_string();
+        std::fs::write(&main_path, &source).unwrap();
+
+        let mut resolver = ModuleResolver::new().with_stdlib_dir(stdlib_dir);
+        resolver
+            .resolve_from_entry_with_source(&main_path, source)
+            .unwrap();
+        let modules = resolver.into_modules();
+        let strings = modules
+            .iter()
+            .find(|module| module.name() == "strings")
+            .unwrap();
+        assert_eq!(strings.path(), override_path.canonicalize().unwrap());
+        assert_eq!(strings.program().functions[0].name, "custom");
+    }
+
+    #[test]
+    fn test_resolve_import_path_unknown_standard_module() {
         let temp = tempfile::tempdir().unwrap();
         let importing_file = temp.path().join("main.lak");
-        let result = ModuleResolver::resolve_import_path("math", &importing_file, dummy_span());
+        let result = ModuleResolver::resolve_import_path("json", &importing_file, dummy_span());
         assert!(result.is_err());
         let err = result.unwrap_err();
-        assert_eq!(err.kind(), ResolverErrorKind::StandardLibraryNotSupported);
+        assert_eq!(err.kind(), ResolverErrorKind::UnknownStandardModule);
     }
 }
@@ -0,0 +1,122 @@
+//! Methods of the `rng` pseudo-random number generator type.
+//!
+//! Generators are created by the `random` module (`random.seeded(42)`) and,
+//! like collection methods (see [`crate::collections`]), their methods are
+//! called on a variable (`dice.int_range(1, 7)`), so the parser produces
+//! [`ExprKind::ModuleCall`](crate::ast::ExprKind::ModuleCall) nodes for
+//! them. Semantic analysis and codegen treat a call whose module name is a
+//! variable of type `rng` as a method call and look the method up here.
+//!
+//! Every method advances the generator, so all of them require a `let mut`
+//! variable. Copies of a generator continue the sequence independently.
+
+use crate::ast::Type;
+
+/// A method of an `rng` value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RngMethod {
+    /// `next_u64() -> u64`.
+    NextU64,
+    /// `int_range(low: i64, high: i64) -> i64`.
+    IntRange,
+    /// `float() -> f64`.
+    Float,
+    /// `float_range(low: f64, high: f64) -> f64`.
+    FloatRange,
+    /// `shuffle(items: C) -> C` for any map or set type `C`.
+    Shuffle,
+}
+
+impl RngMethod {
+    /// All methods, in the order they are listed in diagnostics.
+    const ALL: [Self; 5] = [
+        Self::NextU64,
+        Self::IntRange,
+        Self::Float,
+        Self::FloatRange,
+        Self::Shuffle,
+    ];
+
+    /// Looks up the method `name`.
+    pub fn lookup(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|method| method.name() == name)
+    }
+
+    /// Returns the names of all methods.
+    pub fn names() -> Vec<&'static str> {
+        Self::ALL.into_iter().map(Self::name).collect()
+    }
+
+    /// Returns the name of the method in Lak source code.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::NextU64 => "next_u64",
+            Self::IntRange => "int_range",
+            Self::Float => "float",
+            Self::FloatRange => "float_range",
+            Self::Shuffle => "shuffle",
+        }
+    }
+
+    /// Returns the number of arguments of the method.
+    pub fn arg_count(self) -> usize {
+        match self {
+            Self::NextU64 | Self::Float => 0,
+            Self::Shuffle => 1,
+            Self::IntRange | Self::FloatRange => 2,
+        }
+    }
+
+    /// Returns the parameter types of the method, or `None` for `shuffle`,
+    /// which takes a map or set of any type.
+    pub fn param_types(self) -> Option<Vec<Type>> {
+        match self {
+            Self::NextU64 | Self::Float => Some(Vec::new()),
+            Self::IntRange => Some(vec![Type::I64, Type::I64]),
+            Self::FloatRange => Some(vec![Type::F64, Type::F64]),
+            Self::Shuffle => None,
+        }
+    }
+
+    /// Returns the return type of the method, or `None` for `shuffle`, which
+    /// returns a value of the type of its argument.
+    pub fn return_type(self) -> Option<Type> {
+        match self {
+            Self::NextU64 => Some(Type::U64),
+            Self::IntRange => Some(Type::I64),
+            Self::Float | Self::FloatRange => Some(Type::F64),
+            Self::Shuffle => None,
+        }
+    }
+
+    /// Returns true when the runtime function reports a panic at the call
+    /// site, which it takes as trailing arguments.
+    pub fn takes_location(self) -> bool {
+        matches!(self, Self::IntRange | Self::FloatRange)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng_method_signatures() {
+        assert_eq!(RngMethod::lookup("int_range"), Some(RngMethod::IntRange));
+        assert_eq!(RngMethod::lookup("seed"), None);
+        assert_eq!(
+            RngMethod::FloatRange.param_types(),
+            Some(vec![Type::F64, Type::F64])
+        );
+        assert_eq!(RngMethod::Shuffle.param_types(), None);
+        assert_eq!(RngMethod::Shuffle.arg_count(), 1);
+        assert_eq!(RngMethod::NextU64.return_type(), Some(Type::U64));
+        assert_eq!(RngMethod::Shuffle.return_type(), None);
+        assert!(RngMethod::IntRange.takes_location());
+        assert!(!RngMethod::Shuffle.takes_location());
+        assert_eq!(
+            RngMethod::names(),
+            vec!["next_u64", "int_range", "float", "float_range", "shuffle"]
+        );
+    }
+}
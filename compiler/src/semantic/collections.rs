@@ -0,0 +1,96 @@
+//! `map<K, V>` and `set<T>` checking.
+//!
+//! Collections are created by the `map()` and `set()` builtins, whose type
+//! comes from the expected type, and used through method calls on variables
+//! (see [`crate::collections`]). Methods that change the collection require
+//! a `let mut` variable.
+
+use super::{SemanticAnalyzer, SemanticError};
+
+use crate::ast::{Expr, Type};
+use crate::collections::{CollectionMethod, constructor_creates};
+use crate::token::Span;
+
+impl SemanticAnalyzer {
+    /// Checks a `map()` or `set()` call against the type its value must have.
+    pub(super) fn check_collection_constructor(
+        &mut self,
+        callee: &str,
+        args: &[Expr],
+        expected_ty: &Type,
+        span: Span,
+    ) -> Result<(), SemanticError> {
+        if !args.is_empty() {
+            return Err(SemanticError::invalid_argument_fn_expects_no_args(
+                callee,
+                args.len(),
+                span,
+            ));
+        }
+        if !constructor_creates(callee, expected_ty) {
+            return Err(SemanticError::type_mismatch_collection_constructor(
+                callee,
+                &expected_ty.to_string(),
+                span,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the mutability and type of `name` if it is a variable holding
+    /// a map or set, so that `name.f(...)` is a method call.
+    pub(super) fn collection_variable(&self, name: &str) -> Option<(bool, Type)> {
+        let var = self.symbols.lookup_variable(name)?;
+        var.ty
+            .is_collection()
+            .then(|| (var.is_mutable, var.ty.clone()))
+    }
+
+    /// Type-checks the method call `variable.method(args)` on a collection
+    /// variable and returns the method's return type, or `None` for `void`.
+    pub(super) fn analyze_collection_method(
+        &mut self,
+        variable: &str,
+        (is_mutable, collection_ty): (bool, Type),
+        method_name: &str,
+        args: &[Expr],
+        span: Span,
+    ) -> Result<Option<Type>, SemanticError> {
+        let method = CollectionMethod::lookup(&collection_ty, method_name).ok_or_else(|| {
+            SemanticError::undefined_collection_method(
+                variable,
+                &collection_ty.to_string(),
+                method_name,
+                &CollectionMethod::names(&collection_ty),
+                span,
+            )
+        })?;
+        if method.is_mutating() && !is_mutable {
+            return Err(SemanticError::immutable_collection_mutation(
+                variable,
+                method.name(),
+                span,
+            ));
+        }
+
+        let callee = format!("{}.{}", variable, method.name());
+        let param_types = method.param_types(&collection_ty);
+        if args.len() != param_types.len() {
+            return Err(if param_types.is_empty() {
+                SemanticError::invalid_argument_fn_expects_no_args(&callee, args.len(), span)
+            } else {
+                SemanticError::invalid_argument_fn_expects_args(
+                    &callee,
+                    param_types.len(),
+                    args.len(),
+                    span,
+                )
+            });
+        }
+        for (arg, param_ty) in args.iter().zip(&param_types) {
+            self.check_expr_type(arg, param_ty)?;
+        }
+
+        Ok(method.return_type(&collection_ty))
+    }
+}
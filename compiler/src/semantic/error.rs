@@ -19,6 +19,12 @@
 //! - **Argument errors**: [`invalid_argument_println_count()`](SemanticError::invalid_argument_println_count),
 //!   [`reserved_prelude_function_name()`](SemanticError::reserved_prelude_function_name), etc.
 //! - **Expression errors**: [`invalid_expression_string_literal()`](SemanticError::invalid_expression_string_literal), etc.
+//! - **Pattern errors**: [`pattern_type_mismatch()`](SemanticError::pattern_type_mismatch),
+//!   [`tuple_pattern_arity_mismatch()`](SemanticError::tuple_pattern_arity_mismatch), etc.
+//! - **Collection errors**: [`undefined_collection_method()`](SemanticError::undefined_collection_method),
+//!   [`immutable_collection_mutation()`](SemanticError::immutable_collection_mutation), etc.
+//! - **Format string errors**: [`invalid_format_string()`](SemanticError::invalid_format_string),
+//!   [`format_argument_missing()`](SemanticError::format_argument_missing), etc.
 //! - **Structural errors**: [`invalid_main_signature()`](SemanticError::invalid_main_signature)
 //! - **Internal errors**: [`internal_check_integer_range_string()`](SemanticError::internal_check_integer_range_string), etc.
 
@@ -40,6 +46,8 @@ use crate::token::Span;
 ///   [`IntegerOverflow`](Self::IntegerOverflow), [`InvalidArgument`](Self::InvalidArgument),
 ///   [`InvalidExpression`](Self::InvalidExpression)
 /// - **Control-flow errors** (have span): [`InvalidControlFlow`](Self::InvalidControlFlow)
+/// - **Pattern errors** (have span): [`InvalidPattern`](Self::InvalidPattern)
+/// - **Format string errors** (have span): [`InvalidFormatString`](Self::InvalidFormatString)
 /// - **Structural errors**: [`MissingMainFunction`](Self::MissingMainFunction) (no span),
 ///   [`InvalidMainSignature`](Self::InvalidMainSignature) (has span pointing to return type)
 /// - **Module errors** (have span): [`ModuleAccessNotImplemented`](Self::ModuleAccessNotImplemented),
@@ -72,6 +80,10 @@ pub enum SemanticErrorKind {
     InvalidControlFlow,
     /// Expression used in an invalid context (e.g., literal as statement).
     InvalidExpression,
+    /// A destructuring pattern does not match the shape of the value's type.
+    InvalidPattern,
+    /// A `format` string is malformed or a placeholder spec does not fit its argument type.
+    InvalidFormatString,
     /// No main function was found in the program.
     MissingMainFunction,
     /// The main function has an invalid signature (e.g., wrong return type).
@@ -227,6 +239,8 @@ impl SemanticError {
             SemanticErrorKind::InvalidArgument => "Invalid argument",
             SemanticErrorKind::InvalidControlFlow => "Invalid control flow",
             SemanticErrorKind::InvalidExpression => "Invalid expression",
+            SemanticErrorKind::InvalidPattern => "Invalid pattern",
+            SemanticErrorKind::InvalidFormatString => "Invalid format string",
             SemanticErrorKind::MissingMainFunction => "Missing main function",
             SemanticErrorKind::InvalidMainSignature => "Invalid main signature",
             SemanticErrorKind::InternalError => "Internal error",
@@ -340,6 +354,35 @@ impl SemanticError {
         )
     }
 
+    /// Creates a type mismatch error for a suffixed integer literal.
+    pub fn type_mismatch_suffixed_int(
+        value: i128,
+        actual_ty: &str,
+        expected_ty: &str,
+        span: Span,
+    ) -> Self {
+        Self::new(
+            SemanticErrorKind::TypeMismatch,
+            format!(
+                "Type mismatch: integer literal '{}{}' has type '{}', expected '{}'",
+                value, actual_ty, actual_ty, expected_ty
+            ),
+            span,
+        )
+    }
+
+    /// Creates a type mismatch error for a suffixed float literal.
+    pub fn type_mismatch_suffixed_float(actual_ty: &str, expected_ty: &str, span: Span) -> Self {
+        Self::new(
+            SemanticErrorKind::TypeMismatch,
+            format!(
+                "Type mismatch: float literal with suffix '{}' has type '{}', expected '{}'",
+                actual_ty, actual_ty, expected_ty
+            ),
+            span,
+        )
+    }
+
     /// Creates a type mismatch error for variable type.
     pub fn type_mismatch_variable(
         name: &str,
@@ -381,6 +424,19 @@ impl SemanticError {
         )
     }
 
+    /// Creates a type mismatch error for assigning a character literal to a
+    /// non-char type.
+    pub fn type_mismatch_char_to_type(expected_ty: &str, span: Span) -> Self {
+        Self::new(
+            SemanticErrorKind::TypeMismatch,
+            format!(
+                "Type mismatch: character literal cannot be assigned to type '{}'",
+                expected_ty
+            ),
+            span,
+        )
+    }
+
     /// Creates a type mismatch error for assigning float to non-float type.
     pub fn type_mismatch_float_to_type(expected_ty: &str, span: Span) -> Self {
         Self::new(
@@ -426,6 +482,25 @@ impl SemanticError {
         )
     }
 
+    /// Creates an error for a numeric function called with a type it is not
+    /// defined for (e.g. `math.sqrt` with an `i64`).
+    pub fn numeric_function_type_not_supported(
+        callee: &str,
+        actual_ty: &str,
+        supported: &str,
+        span: Span,
+    ) -> Self {
+        Self::new_with_help(
+            SemanticErrorKind::TypeMismatch,
+            format!(
+                "Function '{}' is not defined for type '{}'",
+                callee, actual_ty
+            ),
+            span,
+            format!("'{}' accepts {}", callee, supported),
+        )
+    }
+
     /// Creates a type mismatch error for return expression type.
     pub fn type_mismatch_return_value(actual_ty: &str, expected_ty: &str, span: Span) -> Self {
         Self::new(
@@ -495,11 +570,11 @@ impl SemanticError {
     // Argument errors
     // =========================================================================
 
-    /// Creates an error for println with wrong argument count.
-    pub fn invalid_argument_println_count(span: Span) -> Self {
+    /// Creates an error for a print builtin called with the wrong argument count.
+    pub fn invalid_argument_print_count(callee: &str, span: Span) -> Self {
         Self::new(
             SemanticErrorKind::InvalidArgument,
-            "println expects exactly 1 argument",
+            format!("{} expects exactly 1 argument", callee),
             span,
         )
     }
@@ -566,7 +641,10 @@ impl SemanticError {
                 name
             ),
             span,
-            "use a different name; prelude names 'println' and 'panic' are reserved",
+            format!(
+                "use a different name; prelude names {} are reserved",
+                quoted_list(crate::prelude::reserved_function_names())
+            ),
         )
     }
 
@@ -589,6 +667,39 @@ impl SemanticError {
         )
     }
 
+    /// Creates an error for exit with wrong argument count.
+    pub fn invalid_argument_exit_count(span: Span) -> Self {
+        Self::new(
+            SemanticErrorKind::InvalidArgument,
+            "exit expects exactly 1 argument",
+            span,
+        )
+    }
+
+    /// Creates an error for an assertion builtin called with the wrong argument count.
+    pub fn invalid_argument_assert_count(callee: &str, expected: usize, span: Span) -> Self {
+        Self::new(
+            SemanticErrorKind::InvalidArgument,
+            format!(
+                "{} expects exactly {} argument{}",
+                callee,
+                expected,
+                if expected == 1 { "" } else { "s" }
+            ),
+            span,
+        )
+    }
+
+    /// Creates an error for `assert` with a non-bool condition.
+    pub fn invalid_argument_assert_type(actual_ty: &str, span: Span) -> Self {
+        Self::new_with_help(
+            SemanticErrorKind::InvalidArgument,
+            format!("assert requires a bool condition, but got '{}'", actual_ty),
+            span,
+            "use assert_eq(a, b) or assert_ne(a, b) to compare values",
+        )
+    }
+
     /// Creates an error for `break` used outside of a loop.
     pub fn break_outside_loop(span: Span) -> Self {
         Self::new_with_help(
@@ -609,6 +720,16 @@ impl SemanticError {
         )
     }
 
+    /// Creates an error for `return`, `break` or `continue` leaving a `defer` body.
+    pub fn control_flow_out_of_defer(keyword: &str, span: Span) -> Self {
+        Self::new_with_help(
+            SemanticErrorKind::InvalidControlFlow,
+            format!("{} statement cannot leave a deferred block", keyword),
+            span,
+            "deferred statements must run to completion; move the control flow outside `defer`",
+        )
+    }
+
     // =========================================================================
     // Expression errors
     // =========================================================================
@@ -649,6 +770,15 @@ impl SemanticError {
         )
     }
 
+    /// Creates an error for character literal used as statement.
+    pub fn invalid_expression_char_literal(span: Span) -> Self {
+        Self::new(
+            SemanticErrorKind::InvalidExpression,
+            "Character literal as a statement has no effect",
+            span,
+        )
+    }
+
     /// Creates an error for identifier used as statement.
     pub fn invalid_expression_identifier(name: &str, span: Span) -> Self {
         Self::new(
@@ -671,6 +801,26 @@ impl SemanticError {
         )
     }
 
+    /// Creates an error for block expression used as statement.
+    pub fn invalid_expression_block(span: Span) -> Self {
+        Self::new_with_help(
+            SemanticErrorKind::InvalidExpression,
+            "Block expression value is not used",
+            span,
+            "assign the block to a variable: `let result = { ... }`",
+        )
+    }
+
+    /// Creates an error for tuple expression used as statement.
+    pub fn invalid_expression_tuple(span: Span) -> Self {
+        Self::new_with_help(
+            SemanticErrorKind::InvalidExpression,
+            "Tuple expression value is not used",
+            span,
+            "assign the tuple to a variable: `let (a, b) = (..., ...)`",
+        )
+    }
+
     /// Creates an error for invalid operand type in binary operation.
     pub fn invalid_binary_op_type(
         op: crate::ast::BinaryOperator,
@@ -698,7 +848,7 @@ impl SemanticError {
                 op, actual_ty
             ),
             span,
-            "ordering operators (<, >, <=, >=) only work with comparable types (integers, floats, string)",
+            "ordering operators (<, >, <=, >=) only work with comparable types (integers, floats, string, char)",
         )
     }
 
@@ -793,7 +943,7 @@ impl SemanticError {
         Self::new(
             SemanticErrorKind::InvalidMainSignature,
             format!(
-                "main function must return void, but found return type '{}'",
+                "main function must return void, i32 or u8, but found return type '{}'",
                 return_type
             ),
             span,
@@ -802,13 +952,14 @@ impl SemanticError {
 
     /// Creates an error for `main` function with parameters.
     pub fn invalid_main_signature_has_params(param_count: usize, span: Span) -> Self {
-        Self::new(
+        Self::new_with_help(
             SemanticErrorKind::InvalidMainSignature,
             format!(
                 "main function must not have parameters, but found {}",
                 param_count
             ),
             span,
+            "use arg_count() and arg(index) to read command-line arguments",
         )
     }
 
@@ -928,6 +1079,19 @@ impl SemanticError {
         )
     }
 
+    /// Creates an internal error for integer range checks receiving a char,
+    /// tuple, collection or option type.
+    pub fn internal_check_integer_range_unexpected_type(value: i128, ty: &str, span: Span) -> Self {
+        Self::new(
+            SemanticErrorKind::InternalError,
+            format!(
+                "Internal error: integer range check received non-integer type {} for value '{}'. This is a compiler bug.",
+                ty, value
+            ),
+            span,
+        )
+    }
+
     /// Creates an internal error for integer range checks receiving `inferred`.
     pub fn internal_check_integer_range_unexpected_inferred(value: i128, span: Span) -> Self {
         Self::new(
@@ -1036,6 +1200,415 @@ impl SemanticError {
         )
     }
 
+    // =========================================================================
+    // Pattern errors
+    // =========================================================================
+
+    /// Creates an error for a destructuring pattern applied to a value whose
+    /// type has a different shape (e.g., a tuple pattern against `i64`).
+    pub fn pattern_type_mismatch(pattern: &str, actual_ty: &str, span: Span) -> Self {
+        Self::new(
+            SemanticErrorKind::InvalidPattern,
+            format!(
+                "Pattern '{}' cannot destructure a value of type '{}'",
+                pattern, actual_ty
+            ),
+            span,
+        )
+    }
+
+    /// Creates an error for a tuple pattern whose element count differs from
+    /// the tuple type being destructured.
+    pub fn tuple_pattern_arity_mismatch(
+        pattern: &str,
+        expected: usize,
+        actual_ty: &str,
+        got: usize,
+        span: Span,
+    ) -> Self {
+        Self::new_with_help(
+            SemanticErrorKind::InvalidPattern,
+            format!(
+                "Tuple pattern '{}' has {} elements, but type '{}' has {}",
+                pattern, got, actual_ty, expected
+            ),
+            span,
+            "use `_` for elements you want to ignore",
+        )
+    }
+
+    /// Creates a type mismatch error for a tuple expression used where a
+    /// non-tuple type is expected.
+    pub fn type_mismatch_tuple_to_type(expected_ty: &str, span: Span) -> Self {
+        Self::new(
+            SemanticErrorKind::TypeMismatch,
+            format!(
+                "Type mismatch: tuple expression cannot be assigned to type '{}'",
+                expected_ty
+            ),
+            span,
+        )
+    }
+
+    /// Creates a type mismatch error for a tuple expression with the wrong
+    /// number of elements.
+    pub fn type_mismatch_tuple_arity(expected_ty: &str, got: usize, span: Span) -> Self {
+        Self::new(
+            SemanticErrorKind::TypeMismatch,
+            format!(
+                "Type mismatch: tuple expression with {} elements cannot be assigned to type '{}'",
+                got, expected_ty
+            ),
+            span,
+        )
+    }
+
+    /// Creates an error for comparing tuple values with an operator.
+    pub fn invalid_tuple_comparison(
+        op: crate::ast::BinaryOperator,
+        actual_ty: &str,
+        span: Span,
+    ) -> Self {
+        Self::new_with_help(
+            SemanticErrorKind::TypeMismatch,
+            format!("Operator '{}' cannot be used with '{}' type", op, actual_ty),
+            span,
+            "destructure the tuples and compare their elements individually",
+        )
+    }
+
+    /// Creates an error for passing a tuple to a print builtin.
+    pub fn invalid_argument_print_tuple(callee: &str, actual_ty: &str, span: Span) -> Self {
+        Self::new_with_help(
+            SemanticErrorKind::InvalidArgument,
+            format!(
+                "{} cannot print a value of tuple type '{}'",
+                callee, actual_ty
+            ),
+            span,
+            "destructure the tuple and print its elements individually",
+        )
+    }
+
+    // =========================================================================
+    // Collection errors
+    // =========================================================================
+
+    /// Creates an error for calling a method that a map, set or `Option`
+    /// type does not have.
+    pub fn undefined_collection_method(
+        variable: &str,
+        collection_ty: &str,
+        method: &str,
+        available: &[&str],
+        span: Span,
+    ) -> Self {
+        Self::new_with_help(
+            SemanticErrorKind::UndefinedFunction,
+            format!(
+                "Variable '{}' of type '{}' has no method '{}'",
+                variable, collection_ty, method
+            ),
+            span,
+            format!("available methods: {}", available.join(", ")),
+        )
+    }
+
+    /// Creates an error for calling `insert` or `remove` on an immutable
+    /// map or set variable.
+    pub fn immutable_collection_mutation(variable: &str, method: &str, span: Span) -> Self {
+        Self::new_with_help(
+            SemanticErrorKind::ImmutableVariableReassignment,
+            format!(
+                "Cannot call '{}' on immutable variable '{}'",
+                method, variable
+            ),
+            span,
+            format!(
+                "declare '{}' as mutable first: `let mut {}: <type> = ...`",
+                variable, variable
+            ),
+        )
+    }
+
+    /// Creates an error for `map()` or `set()` used where the collection
+    /// type is not known.
+    pub fn collection_constructor_needs_type(callee: &str, span: Span) -> Self {
+        let example = if callee == "map" {
+            "let scores: map<string, i64> = map()"
+        } else {
+            "let seen: set<string> = set()"
+        };
+        Self::new_with_help(
+            SemanticErrorKind::TypeMismatch,
+            format!("Cannot infer the type of '{}()'", callee),
+            span,
+            format!(
+                "annotate the binding with the collection type: `{}`",
+                example
+            ),
+        )
+    }
+
+    /// Creates a type mismatch error for `map()` or `set()` used where a
+    /// different type is expected.
+    pub fn type_mismatch_collection_constructor(
+        callee: &str,
+        expected_ty: &str,
+        span: Span,
+    ) -> Self {
+        Self::new(
+            SemanticErrorKind::TypeMismatch,
+            format!(
+                "Type mismatch: '{}()' creates a {} and cannot be assigned to type '{}'",
+                callee, callee, expected_ty
+            ),
+            span,
+        )
+    }
+
+    /// Creates an error for comparing maps or sets.
+    pub fn invalid_collection_comparison(
+        op: crate::ast::BinaryOperator,
+        actual_ty: &str,
+        span: Span,
+    ) -> Self {
+        Self::new_with_help(
+            SemanticErrorKind::TypeMismatch,
+            format!("Operator '{}' cannot be used with '{}' type", op, actual_ty),
+            span,
+            "compare the entries with `len` and `contains` instead",
+        )
+    }
+
+    /// Creates an error for passing a map or set to a print builtin.
+    pub fn invalid_argument_print_collection(callee: &str, actual_ty: &str, span: Span) -> Self {
+        Self::new_with_help(
+            SemanticErrorKind::InvalidArgument,
+            format!("{} cannot print a value of type '{}'", callee, actual_ty),
+            span,
+            "print the entries individually",
+        )
+    }
+
+    /// Creates an error for passing a map or set to `format`.
+    pub fn format_collection_argument(actual_ty: &str, span: Span) -> Self {
+        Self::new_with_help(
+            SemanticErrorKind::InvalidFormatString,
+            format!("format cannot format a value of type '{}'", actual_ty),
+            span,
+            "format the entries individually",
+        )
+    }
+
+    // =========================================================================
+    // Option errors
+    // =========================================================================
+
+    /// Creates an error for comparing `Option` values.
+    pub fn invalid_option_comparison(
+        op: crate::ast::BinaryOperator,
+        actual_ty: &str,
+        span: Span,
+    ) -> Self {
+        Self::new_with_help(
+            SemanticErrorKind::TypeMismatch,
+            format!("Operator '{}' cannot be used with '{}' type", op, actual_ty),
+            span,
+            "compare the values with `is_some` and `unwrap_or` instead",
+        )
+    }
+
+    /// Creates an error for passing an `Option` to a print builtin.
+    pub fn invalid_argument_print_option(callee: &str, actual_ty: &str, span: Span) -> Self {
+        Self::new_with_help(
+            SemanticErrorKind::InvalidArgument,
+            format!("{} cannot print a value of type '{}'", callee, actual_ty),
+            span,
+            "print the value with `unwrap_or`",
+        )
+    }
+
+    /// Creates an error for passing an `Option` to `format`.
+    pub fn format_option_argument(actual_ty: &str, span: Span) -> Self {
+        Self::new_with_help(
+            SemanticErrorKind::InvalidFormatString,
+            format!("format cannot format a value of type '{}'", actual_ty),
+            span,
+            "format the value with `unwrap_or`",
+        )
+    }
+
+    // =========================================================================
+    // Random number generator errors
+    // =========================================================================
+
+    /// Creates an error for comparing `rng` values.
+    pub fn invalid_rng_comparison(
+        op: crate::ast::BinaryOperator,
+        actual_ty: &str,
+        span: Span,
+    ) -> Self {
+        Self::new_with_help(
+            SemanticErrorKind::TypeMismatch,
+            format!("Operator '{}' cannot be used with '{}' type", op, actual_ty),
+            span,
+            "compare values drawn from the generators instead",
+        )
+    }
+
+    /// Creates an error for passing an `rng` to a print builtin.
+    pub fn invalid_argument_print_rng(callee: &str, actual_ty: &str, span: Span) -> Self {
+        Self::new_with_help(
+            SemanticErrorKind::InvalidArgument,
+            format!("{} cannot print a value of type '{}'", callee, actual_ty),
+            span,
+            "print a value drawn from the generator, e.g. with `next_u64`",
+        )
+    }
+
+    /// Creates an error for passing an `rng` to `format`.
+    pub fn format_rng_argument(actual_ty: &str, span: Span) -> Self {
+        Self::new_with_help(
+            SemanticErrorKind::InvalidFormatString,
+            format!("format cannot format a value of type '{}'", actual_ty),
+            span,
+            "format a value drawn from the generator, e.g. with `next_u64`",
+        )
+    }
+
+    /// Creates an error for calling `shuffle` with a value that is not a map
+    /// or set.
+    pub fn invalid_argument_rng_shuffle(callee: &str, actual_ty: &str, span: Span) -> Self {
+        Self::new_with_help(
+            SemanticErrorKind::TypeMismatch,
+            format!(
+                "Function '{}' expects a map or set argument, but got '{}'",
+                callee, actual_ty
+            ),
+            span,
+            "shuffle a `map<K, V>` or `set<T>` value",
+        )
+    }
+
+    // =========================================================================
+    // Format string errors
+    // =========================================================================
+
+    /// Creates an error for a `format` call without a format string.
+    pub fn format_missing_format_string(span: Span) -> Self {
+        Self::new(
+            SemanticErrorKind::InvalidArgument,
+            "format expects a format string as its first argument",
+            span,
+        )
+    }
+
+    /// Creates an error for a `format` call whose format string is not a literal.
+    pub fn format_string_not_literal(span: Span) -> Self {
+        Self::new_with_help(
+            SemanticErrorKind::InvalidArgument,
+            "format string must be a string literal",
+            span,
+            "placeholders are checked at compile time, e.g. format(\"{:8.3}\", value)",
+        )
+    }
+
+    /// Creates an error for a malformed format string.
+    pub fn invalid_format_string(detail: &str, span: Span) -> Self {
+        Self::new(
+            SemanticErrorKind::InvalidFormatString,
+            format!("Invalid format string: {}", detail),
+            span,
+        )
+    }
+
+    /// Creates an error for a placeholder that refers to a missing positional argument.
+    pub fn format_argument_missing(index: usize, given: usize, span: Span) -> Self {
+        Self::new(
+            SemanticErrorKind::InvalidArgument,
+            format!(
+                "Format string refers to argument {}, but only {} argument{} given",
+                index,
+                given,
+                if given == 1 { " was" } else { "s were" }
+            ),
+            span,
+        )
+    }
+
+    /// Creates an error for a positional argument no placeholder refers to.
+    pub fn format_argument_unused(index: usize, span: Span) -> Self {
+        Self::new_with_help(
+            SemanticErrorKind::InvalidArgument,
+            format!("Format argument {} is never used", index),
+            span,
+            "add a '{}' placeholder for it or remove the argument",
+        )
+    }
+
+    /// Creates an error for a format type character applied to the wrong kind of value
+    /// (e.g., `x` with a float).
+    pub fn format_type_mismatch(
+        type_char: char,
+        expected: &str,
+        actual_ty: &str,
+        span: Span,
+    ) -> Self {
+        Self::new(
+            SemanticErrorKind::InvalidFormatString,
+            format!(
+                "Format type '{}' requires {} value, but the argument has type '{}'",
+                type_char, expected, actual_ty
+            ),
+            span,
+        )
+    }
+
+    /// Creates an error for a numeric-only flag (`+` or `0`) applied to a non-numeric value.
+    pub fn format_flag_requires_numeric(flag: char, actual_ty: &str, span: Span) -> Self {
+        Self::new(
+            SemanticErrorKind::InvalidFormatString,
+            format!(
+                "Format flag '{}' requires a numeric value, but the argument has type '{}'",
+                flag, actual_ty
+            ),
+            span,
+        )
+    }
+
+    /// Creates an error for the `#` flag without a radix format type.
+    pub fn format_alternate_requires_radix(span: Span) -> Self {
+        Self::new(
+            SemanticErrorKind::InvalidFormatString,
+            "Format flag '#' requires a radix format type ('x', 'X', 'o' or 'b')",
+            span,
+        )
+    }
+
+    /// Creates an error for a precision applied to a value that has no precision.
+    pub fn format_precision_unsupported(actual_ty: &str, span: Span) -> Self {
+        Self::new_with_help(
+            SemanticErrorKind::InvalidFormatString,
+            format!(
+                "Format precision cannot be used with a value of type '{}'",
+                actual_ty
+            ),
+            span,
+            "precision applies to floats (digits after the point) and strings (maximum length)",
+        )
+    }
+
+    /// Creates an error for a tuple value passed to `format`.
+    pub fn format_tuple_argument(actual_ty: &str, span: Span) -> Self {
+        Self::new_with_help(
+            SemanticErrorKind::InvalidFormatString,
+            format!("format cannot format a value of tuple type '{}'", actual_ty),
+            span,
+            "destructure the tuple and format its elements individually",
+        )
+    }
+
     // =========================================================================
     // Module errors
     // =========================================================================
@@ -1215,6 +1788,16 @@ impl SemanticError {
     }
 }
 
+/// Formats names as `'a', 'b' and 'c'`.
+fn quoted_list<'a>(names: impl Iterator<Item = &'a str>) -> String {
+    let quoted: Vec<String> = names.map(|name| format!("'{}'", name)).collect();
+    match quoted.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} and {}", rest.join(", "), last),
+        Some((last, _)) => last.clone(),
+        None => String::new(),
+    }
+}
+
 impl std::fmt::Display for SemanticError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if let Some(span) = &self.span {
@@ -0,0 +1,163 @@
+//! `format` builtin checking.
+//!
+//! The format string must be a literal so that every placeholder can be
+//! validated here: positional references must point at a supplied argument,
+//! every argument must be referenced, named placeholders must name a variable
+//! in scope, and each spec must fit the type of the value it formats.
+
+use super::{SemanticAnalyzer, SemanticError};
+
+use crate::ast::{Expr, ExprKind, FormatArg, FormatSpec, FormatString, Type};
+use crate::token::Span;
+
+impl SemanticAnalyzer {
+    /// Checks a `format(fmt, args...)` call. The result is always `string`.
+    pub(super) fn analyze_format_call(
+        &mut self,
+        args: &[Expr],
+        span: Span,
+    ) -> Result<Type, SemanticError> {
+        let Some((format_expr, values)) = args.split_first() else {
+            return Err(SemanticError::format_missing_format_string(span));
+        };
+        let ExprKind::StringLiteral(text) = &format_expr.kind else {
+            return Err(SemanticError::format_string_not_literal(format_expr.span));
+        };
+        let format_string = FormatString::parse(text)
+            .map_err(|detail| SemanticError::invalid_format_string(&detail, format_expr.span))?;
+
+        let mut value_types = Vec::with_capacity(values.len());
+        for value in values {
+            let ty = self.infer_expr_type(value)?;
+            if ty.is_tuple() {
+                return Err(SemanticError::format_tuple_argument(
+                    &ty.to_string(),
+                    value.span,
+                ));
+            }
+            if ty.is_collection() {
+                return Err(SemanticError::format_collection_argument(
+                    &ty.to_string(),
+                    value.span,
+                ));
+            }
+            if ty.is_option() {
+                return Err(SemanticError::format_option_argument(
+                    &ty.to_string(),
+                    value.span,
+                ));
+            }
+            if ty == Type::Rng {
+                return Err(SemanticError::format_rng_argument(
+                    &ty.to_string(),
+                    value.span,
+                ));
+            }
+            self.check_expr_type(value, &ty)?;
+            value_types.push(ty);
+        }
+
+        let mut used = vec![false; values.len()];
+        for placeholder in format_string.placeholders() {
+            let ty = match &placeholder.arg {
+                FormatArg::Positional(index) => {
+                    let Some(ty) = value_types.get(*index) else {
+                        return Err(SemanticError::format_argument_missing(
+                            *index,
+                            values.len(),
+                            format_expr.span,
+                        ));
+                    };
+                    used[*index] = true;
+                    ty.clone()
+                }
+                FormatArg::Named(name) => self
+                    .symbols
+                    .lookup_variable(name)
+                    .ok_or_else(|| SemanticError::undefined_variable(name, format_expr.span))?
+                    .ty
+                    .clone(),
+            };
+            if ty.is_tuple() {
+                return Err(SemanticError::format_tuple_argument(
+                    &ty.to_string(),
+                    format_expr.span,
+                ));
+            }
+            if ty.is_collection() {
+                return Err(SemanticError::format_collection_argument(
+                    &ty.to_string(),
+                    format_expr.span,
+                ));
+            }
+            if ty.is_option() {
+                return Err(SemanticError::format_option_argument(
+                    &ty.to_string(),
+                    format_expr.span,
+                ));
+            }
+            if ty == Type::Rng {
+                return Err(SemanticError::format_rng_argument(
+                    &ty.to_string(),
+                    format_expr.span,
+                ));
+            }
+            validate_format_spec(&placeholder.spec, &ty, format_expr.span)?;
+        }
+
+        if let Some(index) = used.iter().position(|used| !used) {
+            return Err(SemanticError::format_argument_unused(
+                index,
+                values[index].span,
+            ));
+        }
+
+        Ok(Type::String)
+    }
+}
+
+/// Checks that every part of `spec` applies to a value of type `ty`.
+fn validate_format_spec(spec: &FormatSpec, ty: &Type, span: Span) -> Result<(), SemanticError> {
+    if let Some(type_char) = spec.kind.type_char() {
+        if spec.kind.is_radix() && !ty.is_integer() {
+            return Err(SemanticError::format_type_mismatch(
+                type_char,
+                "an integer",
+                &ty.to_string(),
+                span,
+            ));
+        }
+        if spec.kind.is_exponent() && !ty.is_float() {
+            return Err(SemanticError::format_type_mismatch(
+                type_char,
+                "a float",
+                &ty.to_string(),
+                span,
+            ));
+        }
+    }
+    if spec.sign_plus && !ty.is_numeric() {
+        return Err(SemanticError::format_flag_requires_numeric(
+            '+',
+            &ty.to_string(),
+            span,
+        ));
+    }
+    if spec.zero_pad && !ty.is_numeric() {
+        return Err(SemanticError::format_flag_requires_numeric(
+            '0',
+            &ty.to_string(),
+            span,
+        ));
+    }
+    if spec.alternate && !spec.kind.is_radix() {
+        return Err(SemanticError::format_alternate_requires_radix(span));
+    }
+    if spec.precision.is_some() && !(ty.is_float() || *ty == Type::String) {
+        return Err(SemanticError::format_precision_unsupported(
+            &ty.to_string(),
+            span,
+        ));
+    }
+    Ok(())
+}
@@ -9,6 +9,11 @@
 //!
 //! - **Name resolution**: Checks for duplicate/undefined functions and variables
 //! - **Type checking**: Validates type consistency in assignments and expressions
+//! - **Pattern checking**: Ensures destructuring patterns irrefutably match their value type
+//! - **Format checking**: Validates `format` placeholders against their argument types
+//! - **Collection checking**: Validates `map`/`set` constructors and method calls
+//! - **Option checking**: Validates `Option` method calls
+//! - **Rng checking**: Validates `rng` method calls
 //! - **Structural validation**: Ensures main function exists with correct signature
 //!
 //! # Pipeline Position
@@ -21,8 +26,13 @@
 //! an AST and either returns success (allowing codegen to proceed) or an error
 //! describing the semantic problem.
 
+mod collections;
 mod error;
+mod format;
 mod module_table;
+mod options;
+mod patterns;
+mod rng;
 mod symbol;
 mod symbols;
 mod typecheck_expr;
@@ -32,10 +42,10 @@ mod typecheck_stmt;
 mod tests;
 
 pub use error::{SemanticError, SemanticErrorKind};
-pub use module_table::ModuleTable;
+pub use module_table::{FunctionExport, ModuleExports, ModuleTable};
 use symbol::SymbolTable;
 
-use crate::ast::{Program, Type};
+use crate::ast::{Program, ReturnType, Type};
 use crate::token::Span;
 use std::collections::HashMap;
 
@@ -63,8 +73,10 @@ enum AnalysisMode {
 pub struct SemanticAnalyzer {
     symbols: SymbolTable,
     mode: AnalysisMode,
-    current_function_return_type: Option<String>,
+    current_function_return_type: Option<ReturnType>,
     loop_depth: usize,
+    /// Number of enclosing `defer` bodies; control flow may not leave them.
+    defer_depth: usize,
     inferred_binding_types: HashMap<Span, Type>,
 }
 
@@ -76,6 +88,7 @@ impl SemanticAnalyzer {
             mode: AnalysisMode::SingleFile,
             current_function_return_type: None,
             loop_depth: 0,
+            defer_depth: 0,
             inferred_binding_types: HashMap::new(),
         }
     }
@@ -107,6 +120,7 @@ impl SemanticAnalyzer {
         self.mode = mode;
         self.current_function_return_type = None;
         self.loop_depth = 0;
+        self.defer_depth = 0;
         self.inferred_binding_types.clear();
     }
 
@@ -3,9 +3,10 @@
 //! This module provides [`ModuleTable`], which collects and provides access
 //! to public symbols exported by imported modules.
 
-use crate::ast::{Type, Visibility};
+use crate::ast::{ReturnType, Type, Visibility};
 use crate::resolver::ResolvedModule;
 use crate::semantic::SemanticError;
+use crate::stdlib::NumericFunction;
 use crate::token::Span;
 
 use std::collections::HashMap;
@@ -15,37 +16,51 @@ use std::collections::HashMap;
 pub struct FunctionExport {
     /// The function name.
     name: String,
-    /// The parameter types in declaration order.
+    /// The parameter names in declaration order.
+    param_names: Vec<String>,
+    /// The parameter types in declaration order. Empty for numeric
+    /// functions, whose parameters all have the generic type `T`.
     param_types: Vec<Type>,
-    /// The return type.
-    return_type: String,
+    /// The return type (`T` for numeric functions).
+    return_type: ReturnType,
     /// The span of the function definition.
     definition_span: Span,
+    /// The doc comment attached to the function definition, if any.
+    doc: Option<String>,
+    /// The definition of a numeric function of a native module.
+    numeric: Option<&'static NumericFunction>,
 }
 
 impl FunctionExport {
     /// Creates a new FunctionExport.
+    ///
+    /// `param_names` and `param_types` must have the same length.
     fn new(
         name: String,
+        param_names: Vec<String>,
         param_types: Vec<Type>,
-        return_type: String,
+        return_type: ReturnType,
         definition_span: Span,
+        doc: Option<String>,
     ) -> Result<Self, SemanticError> {
         if name.is_empty() {
             return Err(SemanticError::internal_function_export_empty_name(
                 definition_span,
             ));
         }
-        if return_type.is_empty() {
+        if matches!(&return_type, ReturnType::Unknown(name) if name.is_empty()) {
             return Err(SemanticError::internal_function_export_empty_return_type(
                 definition_span,
             ));
         }
         Ok(FunctionExport {
             name,
+            param_names,
             param_types,
             return_type,
             definition_span,
+            doc,
+            numeric: None,
         })
     }
 
@@ -54,13 +69,18 @@ impl FunctionExport {
         &self.name
     }
 
+    /// Returns the parameter names.
+    pub fn param_names(&self) -> &[String] {
+        &self.param_names
+    }
+
     /// Returns the parameter types.
     pub fn param_types(&self) -> &[Type] {
         &self.param_types
     }
 
     /// Returns the return type.
-    pub fn return_type(&self) -> &str {
+    pub fn return_type(&self) -> &ReturnType {
         &self.return_type
     }
 
@@ -68,6 +88,17 @@ impl FunctionExport {
     pub fn definition_span(&self) -> Span {
         self.definition_span
     }
+
+    /// Returns the doc comment attached to the function definition, if any.
+    pub fn doc(&self) -> Option<&str> {
+        self.doc.as_deref()
+    }
+
+    /// Returns the definition if this is a numeric function of a native
+    /// module, which is defined for several types.
+    pub fn numeric(&self) -> Option<&'static NumericFunction> {
+        self.numeric
+    }
 }
 
 /// Information about a module's public exports.
@@ -87,11 +118,57 @@ impl ModuleExports {
             functions: HashMap::new(),
         };
 
+        // Native modules export their runtime function table. The spans only
+        // record the table order, which `lak doc` uses as definition order.
+        if let Some(native) = module.native_module() {
+            for (index, function) in native.functions.iter().enumerate() {
+                let export = FunctionExport::new(
+                    function.name.to_string(),
+                    function
+                        .params
+                        .iter()
+                        .map(|(name, _)| name.to_string())
+                        .collect(),
+                    function.params.iter().map(|(_, ty)| ty.clone()).collect(),
+                    function.return_type().into(),
+                    Span::new(index, index, 1, 1),
+                    Some(function.doc.to_string()),
+                )?;
+                exports.functions.insert(function.name.to_string(), export);
+            }
+            for (index, function) in native.numeric_functions.iter().enumerate() {
+                let index = native.functions.len() + index;
+                let mut export = FunctionExport::new(
+                    function.name.to_string(),
+                    function
+                        .params
+                        .iter()
+                        .map(|name| name.to_string())
+                        .collect(),
+                    Vec::new(),
+                    ReturnType::Unknown("T".to_string()),
+                    Span::new(index, index, 1, 1),
+                    Some(format!(
+                        "{}\n\n`T` is {}.",
+                        function.doc,
+                        function.types.describe()
+                    )),
+                )?;
+                export.numeric = Some(function);
+                exports.functions.insert(function.name.to_string(), export);
+            }
+        }
+
         // Extract public functions
         for function in &module.program().functions {
             if function.visibility == Visibility::Public {
                 let export = FunctionExport::new(
                     function.name.clone(),
+                    function
+                        .params
+                        .iter()
+                        .map(|param| param.name.clone())
+                        .collect(),
                     function
                         .params
                         .iter()
@@ -99,6 +176,7 @@ impl ModuleExports {
                         .collect(),
                     function.return_type.clone(),
                     function.span,
+                    function.doc.clone(),
                 )?;
                 exports.functions.insert(function.name.clone(), export);
             }
@@ -270,7 +348,17 @@ impl ModuleExports {
     ) -> Result<Self, SemanticError> {
         let mut map = HashMap::new();
         for (fn_name, param_types, ret_type, span) in functions {
-            let export = FunctionExport::new(fn_name.clone(), param_types, ret_type, span)?;
+            let param_names = (0..param_types.len())
+                .map(|i| format!("arg{}", i))
+                .collect();
+            let export = FunctionExport::new(
+                fn_name.clone(),
+                param_names,
+                param_types,
+                ReturnType::from_name(&ret_type),
+                span,
+                None,
+            )?;
             map.insert(fn_name, export);
         }
         Ok(ModuleExports {
@@ -283,7 +371,7 @@ impl ModuleExports {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ast::{FnDef, ImportDecl, Program, Visibility};
+    use crate::ast::{FnDef, ImportDecl, Program, ReturnType, Visibility};
     use crate::token::Span;
 
     fn dummy_span() -> Span {
@@ -295,19 +383,28 @@ mod tests {
         let result = FunctionExport::new(
             "greet".to_string(),
             vec![],
-            "void".to_string(),
+            vec![],
+            ReturnType::Void,
             dummy_span(),
+            None,
         );
         assert!(result.is_ok());
         let export = result.unwrap();
         assert_eq!(export.name(), "greet");
         assert!(export.param_types().is_empty());
-        assert_eq!(export.return_type(), "void");
+        assert_eq!(*export.return_type(), ReturnType::Void);
     }
 
     #[test]
     fn test_function_export_empty_name_fails() {
-        let result = FunctionExport::new("".to_string(), vec![], "void".to_string(), dummy_span());
+        let result = FunctionExport::new(
+            "".to_string(),
+            vec![],
+            vec![],
+            ReturnType::Void,
+            dummy_span(),
+            None,
+        );
         assert!(result.is_err());
         let err = result.unwrap_err();
         assert_eq!(
@@ -322,7 +419,14 @@ mod tests {
 
     #[test]
     fn test_function_export_empty_return_type_fails() {
-        let result = FunctionExport::new("greet".to_string(), vec![], "".to_string(), dummy_span());
+        let result = FunctionExport::new(
+            "greet".to_string(),
+            vec![],
+            vec![],
+            ReturnType::Unknown(String::new()),
+            dummy_span(),
+            None,
+        );
         assert!(result.is_err());
         let err = result.unwrap_err();
         assert_eq!(
@@ -339,23 +443,26 @@ mod tests {
     fn test_module_exports_filters_private_functions() {
         // Create a module with one public and one private function
         let public_fn = FnDef {
+            doc: Some("Greets someone.".to_string()),
             visibility: Visibility::Public,
             name: "greet".to_string(),
             params: vec![crate::ast::FnParam {
                 name: "name".to_string(),
+                pattern: None,
                 ty: crate::ast::Type::String,
                 span: dummy_span(),
             }],
-            return_type: "void".to_string(),
+            return_type: ReturnType::Void,
             return_type_span: dummy_span(),
             body: Vec::new(),
             span: dummy_span(),
         };
         let private_fn = FnDef {
+            doc: None,
             visibility: Visibility::Private,
             name: "helper".to_string(),
             params: vec![],
-            return_type: "void".to_string(),
+            return_type: ReturnType::Void,
             return_type_span: dummy_span(),
             body: Vec::new(),
             span: dummy_span(),
@@ -383,6 +490,8 @@ mod tests {
         let greet = exports.get_function("greet").unwrap();
         assert_eq!(greet.param_types().len(), 1);
         assert_eq!(greet.param_types()[0], crate::ast::Type::String);
+        assert_eq!(greet.param_names(), ["name".to_string()]);
+        assert_eq!(greet.doc(), Some("Greets someone."));
     }
 
     // =========================================================================
@@ -396,10 +505,11 @@ mod tests {
         let imported_program = Program {
             imports: Vec::new(),
             functions: vec![FnDef {
+                doc: None,
                 visibility: Visibility::Public,
                 name: "greet".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: Vec::new(),
                 span: dummy_span(),
@@ -421,10 +531,11 @@ mod tests {
                 span: dummy_span(),
             }],
             functions: vec![FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: Vec::new(),
                 span: dummy_span(),
@@ -463,10 +574,11 @@ mod tests {
                 span: dummy_span(),
             }],
             functions: vec![FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: Vec::new(),
                 span: dummy_span(),
@@ -511,10 +623,11 @@ mod tests {
         let program = Program {
             imports: Vec::new(),
             functions: vec![FnDef {
+                doc: None,
                 visibility: Visibility::Public,
                 name: "greet".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: Vec::new(),
                 span: dummy_span(),
@@ -550,10 +663,11 @@ mod tests {
         let imported_program = Program {
             imports: Vec::new(),
             functions: vec![FnDef {
+                doc: None,
                 visibility: Visibility::Public,
                 name: "greet".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: Vec::new(),
                 span: dummy_span(),
@@ -575,10 +689,11 @@ mod tests {
                 span: dummy_span(),
             }],
             functions: vec![FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: Vec::new(),
                 span: dummy_span(),
@@ -610,10 +725,11 @@ mod tests {
         let utils_program = Program {
             imports: Vec::new(),
             functions: vec![FnDef {
+                doc: None,
                 visibility: Visibility::Public,
                 name: "greet".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: Vec::new(),
                 span: dummy_span(),
@@ -626,10 +742,11 @@ mod tests {
         let utils_program2 = Program {
             imports: Vec::new(),
             functions: vec![FnDef {
+                doc: None,
                 visibility: Visibility::Public,
                 name: "greet".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: Vec::new(),
                 span: dummy_span(),
@@ -664,10 +781,11 @@ mod tests {
                 },
             ],
             functions: vec![FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: Vec::new(),
                 span: dummy_span(),
@@ -704,10 +822,11 @@ mod tests {
         let imported_program = Program {
             imports: Vec::new(),
             functions: vec![FnDef {
+                doc: None,
                 visibility: Visibility::Public,
                 name: "greet".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: Vec::new(),
                 span: dummy_span(),
@@ -729,10 +848,11 @@ mod tests {
                 span: dummy_span(),
             }],
             functions: vec![FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: Vec::new(),
                 span: dummy_span(),
@@ -0,0 +1,60 @@
+//! `Option<T>` checking.
+//!
+//! Options are used through method calls on variables (see
+//! [`crate::options`]). None of the methods change the option.
+
+use super::{SemanticAnalyzer, SemanticError};
+
+use crate::ast::{Expr, Type};
+use crate::options::OptionMethod;
+use crate::token::Span;
+
+impl SemanticAnalyzer {
+    /// Returns the type of `name` if it is a variable holding an `Option`,
+    /// so that `name.f(...)` is a method call.
+    pub(super) fn option_variable(&self, name: &str) -> Option<Type> {
+        let var = self.symbols.lookup_variable(name)?;
+        var.ty.is_option().then(|| var.ty.clone())
+    }
+
+    /// Type-checks the method call `variable.method(args)` on an `Option`
+    /// variable and returns the method's return type.
+    pub(super) fn analyze_option_method(
+        &mut self,
+        variable: &str,
+        option_ty: &Type,
+        method_name: &str,
+        args: &[Expr],
+        span: Span,
+    ) -> Result<Type, SemanticError> {
+        let method = OptionMethod::lookup(option_ty, method_name).ok_or_else(|| {
+            SemanticError::undefined_collection_method(
+                variable,
+                &option_ty.to_string(),
+                method_name,
+                &OptionMethod::names(),
+                span,
+            )
+        })?;
+
+        let callee = format!("{}.{}", variable, method.name());
+        let param_types = method.param_types(option_ty);
+        if args.len() != param_types.len() {
+            return Err(if param_types.is_empty() {
+                SemanticError::invalid_argument_fn_expects_no_args(&callee, args.len(), span)
+            } else {
+                SemanticError::invalid_argument_fn_expects_args(
+                    &callee,
+                    param_types.len(),
+                    args.len(),
+                    span,
+                )
+            });
+        }
+        for (arg, param_ty) in args.iter().zip(&param_types) {
+            self.check_expr_type(arg, param_ty)?;
+        }
+
+        Ok(method.return_type(option_ty))
+    }
+}
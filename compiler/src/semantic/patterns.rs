@@ -0,0 +1,57 @@
+//! Destructuring pattern checking.
+//!
+//! Patterns are checked against the type of the value they destructure.
+//! Every supported pattern form is irrefutable once its shape matches the
+//! type, so a shape mismatch is reported as an error instead of being
+//! deferred to runtime.
+
+use super::symbol::VariableInfo;
+use super::{SemanticAnalyzer, SemanticError};
+
+use crate::ast::{Pattern, PatternKind, Type};
+
+impl SemanticAnalyzer {
+    /// Checks that `pattern` irrefutably matches a value of type `ty` and
+    /// registers each binding it introduces in the current scope.
+    ///
+    /// Bindings are registered as ordinary [`VariableInfo`] entries, so
+    /// repeated names within one pattern are reported as duplicate variables.
+    pub(super) fn bind_pattern(
+        &mut self,
+        pattern: &Pattern,
+        ty: &Type,
+        is_mutable: bool,
+    ) -> Result<(), SemanticError> {
+        match &pattern.kind {
+            PatternKind::Binding(name) => self.symbols.define_variable(VariableInfo {
+                name: name.clone(),
+                is_mutable,
+                ty: ty.clone(),
+                definition_span: pattern.span,
+            }),
+            PatternKind::Wildcard => Ok(()),
+            PatternKind::Tuple(elements) => {
+                let Type::Tuple(element_types) = ty else {
+                    return Err(SemanticError::pattern_type_mismatch(
+                        &pattern.to_string(),
+                        &ty.to_string(),
+                        pattern.span,
+                    ));
+                };
+                if elements.len() != element_types.len() {
+                    return Err(SemanticError::tuple_pattern_arity_mismatch(
+                        &pattern.to_string(),
+                        element_types.len(),
+                        &ty.to_string(),
+                        elements.len(),
+                        pattern.span,
+                    ));
+                }
+                for (element, element_ty) in elements.iter().zip(element_types) {
+                    self.bind_pattern(element, element_ty, is_mutable)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
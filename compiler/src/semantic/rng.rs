@@ -0,0 +1,84 @@
+//! `rng` checking.
+//!
+//! Generators are created by the `random` module and used through method
+//! calls on variables (see [`crate::rng`]). Every method advances the
+//! generator, so all of them require a `let mut` variable.
+
+use super::{SemanticAnalyzer, SemanticError};
+
+use crate::ast::{Expr, Type};
+use crate::rng::RngMethod;
+use crate::token::Span;
+
+impl SemanticAnalyzer {
+    /// Returns the mutability of `name` if it is a variable holding an
+    /// `rng`, so that `name.f(...)` is a method call.
+    pub(super) fn rng_variable(&self, name: &str) -> Option<bool> {
+        let var = self.symbols.lookup_variable(name)?;
+        (var.ty == Type::Rng).then_some(var.is_mutable)
+    }
+
+    /// Type-checks the method call `variable.method(args)` on an `rng`
+    /// variable and returns the method's return type.
+    pub(super) fn analyze_rng_method(
+        &mut self,
+        variable: &str,
+        is_mutable: bool,
+        method_name: &str,
+        args: &[Expr],
+        span: Span,
+    ) -> Result<Type, SemanticError> {
+        let method = RngMethod::lookup(method_name).ok_or_else(|| {
+            SemanticError::undefined_collection_method(
+                variable,
+                &Type::Rng.to_string(),
+                method_name,
+                &RngMethod::names(),
+                span,
+            )
+        })?;
+        if !is_mutable {
+            return Err(SemanticError::immutable_collection_mutation(
+                variable,
+                method.name(),
+                span,
+            ));
+        }
+
+        let callee = format!("{}.{}", variable, method.name());
+        if args.len() != method.arg_count() {
+            return Err(if method.arg_count() == 0 {
+                SemanticError::invalid_argument_fn_expects_no_args(&callee, args.len(), span)
+            } else {
+                SemanticError::invalid_argument_fn_expects_args(
+                    &callee,
+                    method.arg_count(),
+                    args.len(),
+                    span,
+                )
+            });
+        }
+
+        match (method.param_types(), method.return_type()) {
+            (Some(param_types), Some(return_ty)) => {
+                for (arg, param_ty) in args.iter().zip(&param_types) {
+                    self.check_expr_type(arg, param_ty)?;
+                }
+                Ok(return_ty)
+            }
+            _ => {
+                // `shuffle` returns a shuffled copy of its map or set argument.
+                let items_ty = self.infer_expr_type(&args[0])?;
+                if !items_ty.is_collection() {
+                    return Err(SemanticError::invalid_argument_rng_shuffle(
+                        &callee,
+                        &items_ty.to_string(),
+                        args[0].span,
+                    ));
+                }
+                self.check_expr_type(&args[0], &items_ty)?;
+                Ok(items_ty)
+            }
+        }
+    }
+}
@@ -4,7 +4,7 @@
 //! definitions during semantic analysis, with support for scoped variable lookup.
 
 use super::error::SemanticError;
-use crate::ast::{Type, Visibility};
+use crate::ast::{ReturnType, Type, Visibility};
 use crate::token::Span;
 use std::collections::HashMap;
 
@@ -15,8 +15,8 @@ pub struct FunctionInfo {
     pub name: String,
     /// The parameter types in declaration order.
     pub param_types: Vec<Type>,
-    /// The return type annotation (e.g. "void", "i32", "i64", "string", "bool").
-    pub return_type: String,
+    /// The declared return type.
+    pub return_type: ReturnType,
     /// The span of the return type token (for error reporting).
     pub return_type_span: Span,
     /// The span of the function definition (for "previously defined here" messages).
@@ -1,7 +1,9 @@
 use super::symbol::FunctionInfo;
 use super::{AnalysisMode, SemanticAnalyzer, SemanticError};
 
-use crate::ast::{Expr, Program, Type};
+use crate::ast::{Expr, Program, ReturnType, Type};
+use crate::prelude::{RuntimeFunction, reserved_function_names};
+use crate::stdlib::NumericFunction;
 use crate::token::Span;
 
 impl SemanticAnalyzer {
@@ -9,7 +11,7 @@ impl SemanticAnalyzer {
 
     pub(super) fn collect_functions(&mut self, program: &Program) -> Result<(), SemanticError> {
         for function in &program.functions {
-            if matches!(function.name.as_str(), "println" | "panic") {
+            if reserved_function_names().any(|name| name == function.name) {
                 return Err(SemanticError::reserved_prelude_function_name(
                     &function.name,
                     function.span,
@@ -55,9 +57,12 @@ impl SemanticAnalyzer {
             ));
         }
 
-        if main_fn.return_type != "void" {
+        // A non-void return value becomes the process exit status.
+        let returns_exit_status =
+            matches!(main_fn.return_type, ReturnType::Value(Type::I32 | Type::U8));
+        if main_fn.return_type != ReturnType::Void && !returns_exit_status {
             return Err(SemanticError::invalid_main_signature(
-                &main_fn.return_type,
+                &main_fn.return_type.to_string(),
                 main_fn.return_type_span,
             ));
         }
@@ -65,13 +70,17 @@ impl SemanticAnalyzer {
         Ok(())
     }
 
-    pub(super) fn return_type_name_to_type(
+    pub(super) fn return_type_to_type(
         &self,
-        name: &str,
+        return_type: &ReturnType,
         span: Span,
     ) -> Result<Type, SemanticError> {
-        Type::from_source_name(name)
-            .ok_or_else(|| SemanticError::invalid_function_return_type(name, span))
+        match return_type {
+            ReturnType::Value(ty) => Ok(ty.clone()),
+            ReturnType::Void | ReturnType::Unknown(_) => Err(
+                SemanticError::invalid_function_return_type(&return_type.to_string(), span),
+            ),
+        }
     }
 
     pub(super) fn resolve_user_call(
@@ -79,7 +88,7 @@ impl SemanticAnalyzer {
         callee: &str,
         args: &[Expr],
         span: Span,
-    ) -> Result<String, SemanticError> {
+    ) -> Result<ReturnType, SemanticError> {
         let (param_types, return_type) = {
             let func_info = self
                 .symbols
@@ -113,14 +122,47 @@ impl SemanticAnalyzer {
         Ok(return_type)
     }
 
+    /// Type-checks the arguments of a call to a runtime-backed prelude function.
+    pub(super) fn check_runtime_function_args(
+        &mut self,
+        function: &RuntimeFunction,
+        args: &[Expr],
+        span: Span,
+    ) -> Result<(), SemanticError> {
+        let expected_arg_count = function.params.len();
+        if args.len() != expected_arg_count {
+            return Err(if expected_arg_count == 0 {
+                SemanticError::invalid_argument_fn_expects_no_args(function.name, args.len(), span)
+            } else {
+                SemanticError::invalid_argument_fn_expects_args(
+                    function.name,
+                    expected_arg_count,
+                    args.len(),
+                    span,
+                )
+            });
+        }
+
+        for (arg, (_, expected_ty)) in args.iter().zip(function.params) {
+            self.check_expr_type(arg, expected_ty)?;
+        }
+
+        Ok(())
+    }
+
+    /// Type-checks a module-qualified call and returns its return type.
+    ///
+    /// `expected_ty` is the type the call's value must have, if known. It
+    /// decides the operand type of numeric functions.
     pub(super) fn resolve_module_call(
         &mut self,
         module_name: &str,
         function_name: &str,
         args: &[Expr],
+        expected_ty: Option<&Type>,
         span: Span,
-    ) -> Result<String, SemanticError> {
-        let (param_types, return_type) = {
+    ) -> Result<ReturnType, SemanticError> {
+        let (param_types, return_type, numeric) = {
             let module_table = match &self.mode {
                 AnalysisMode::EntryWithModules(table) => table,
                 AnalysisMode::ImportedModule(Some(table)) => table,
@@ -150,11 +192,17 @@ impl SemanticAnalyzer {
 
             (
                 func_export.param_types().to_vec(),
-                func_export.return_type().to_string(),
+                func_export.return_type().clone(),
+                func_export.numeric(),
             )
         };
 
         let full_function_name = format!("{}.{}", module_name, function_name);
+        if let Some(function) = numeric {
+            let operand_ty =
+                self.check_numeric_call(&full_function_name, function, args, expected_ty, span)?;
+            return Ok(ReturnType::Value(operand_ty));
+        }
         let expected_arg_count = param_types.len();
         if args.len() != expected_arg_count {
             return Err(if expected_arg_count == 0 {
@@ -179,4 +227,57 @@ impl SemanticAnalyzer {
 
         Ok(return_type)
     }
+
+    /// Type-checks a call to a numeric function and returns its operand
+    /// type `T`, which is also the type of the result.
+    ///
+    /// `T` is the expected type if known, otherwise the type of the argument
+    /// chosen by [`Expr::numeric_operand_index`].
+    fn check_numeric_call(
+        &mut self,
+        callee: &str,
+        function: &NumericFunction,
+        args: &[Expr],
+        expected_ty: Option<&Type>,
+        span: Span,
+    ) -> Result<Type, SemanticError> {
+        if args.len() != function.params.len() {
+            return Err(SemanticError::invalid_argument_fn_expects_args(
+                callee,
+                function.params.len(),
+                args.len(),
+                span,
+            ));
+        }
+
+        let operand_ty = match expected_ty {
+            Some(ty) => ty.clone(),
+            None => {
+                let index = Expr::numeric_operand_index(args).ok_or_else(|| {
+                    SemanticError::invalid_argument_fn_expects_args(
+                        callee,
+                        function.params.len(),
+                        0,
+                        span,
+                    )
+                })?;
+                self.infer_expr_type(&args[index])?
+            }
+        };
+
+        if !function.types.contains(&operand_ty) {
+            return Err(SemanticError::numeric_function_type_not_supported(
+                callee,
+                &operand_ty.to_string(),
+                function.types.describe(),
+                span,
+            ));
+        }
+
+        for arg in args {
+            self.check_expr_type(arg, &operand_ty)?;
+        }
+
+        Ok(operand_ty)
+    }
 }
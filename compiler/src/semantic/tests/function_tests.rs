@@ -20,19 +20,21 @@ fn test_duplicate_function_error() {
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![],
                 span: span_at(1, 1),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![],
                 span: span_at(5, 1),
@@ -54,28 +56,31 @@ fn test_duplicate_non_main_function_error() {
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![],
                 span: dummy_span(),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "helper".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![],
                 span: span_at(5, 1),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "helper".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![],
                 span: span_at(10, 1),
@@ -97,19 +102,21 @@ fn test_reserved_prelude_function_println_error() {
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "println".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![],
                 span: span_at(1, 1),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![],
                 span: span_at(3, 1),
@@ -134,19 +141,21 @@ fn test_reserved_prelude_function_panic_error() {
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "panic".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![],
                 span: span_at(1, 1),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![],
                 span: span_at(3, 1),
@@ -192,10 +201,11 @@ fn test_missing_main_function_with_other_functions() {
     let program = Program {
         imports: vec![],
         functions: vec![FnDef {
+            doc: None,
             visibility: Visibility::Private,
             name: "helper".to_string(),
             params: vec![],
-            return_type: "void".to_string(),
+            return_type: ReturnType::Void,
             return_type_span: dummy_span(),
             body: vec![],
             span: dummy_span(),
@@ -222,10 +232,11 @@ fn test_invalid_main_signature() {
     let program = Program {
         imports: vec![],
         functions: vec![FnDef {
+            doc: None,
             visibility: Visibility::Private,
             name: "main".to_string(),
             params: vec![],
-            return_type: "int".to_string(),
+            return_type: ReturnType::Unknown("int".to_string()),
             return_type_span: span_at(1, 15),
             body: vec![],
             span: dummy_span(),
@@ -239,10 +250,39 @@ fn test_invalid_main_signature() {
     assert_eq!(err.kind(), SemanticErrorKind::InvalidMainSignature);
     assert_eq!(
         err.message(),
-        "main function must return void, but found return type 'int'"
+        "main function must return void, i32 or u8, but found return type 'int'"
     );
 }
 
+#[test]
+fn test_main_with_exit_code_return_types() {
+    for return_type in ["i32", "u8", "byte"] {
+        let program = Program {
+            imports: vec![],
+            functions: vec![FnDef {
+                doc: None,
+                visibility: Visibility::Private,
+                name: "main".to_string(),
+                params: vec![],
+                return_type: ReturnType::from_name(return_type),
+                return_type_span: span_at(1, 14),
+                body: vec![Stmt::new(
+                    StmtKind::Return(Some(Expr::new(ExprKind::IntLiteral(1), span_at(2, 12)))),
+                    span_at(2, 5),
+                )],
+                span: span_at(1, 1),
+            }],
+        };
+
+        let mut analyzer = SemanticAnalyzer::new();
+        assert!(
+            analyzer.analyze(&program).is_ok(),
+            "main returning {} should be accepted",
+            return_type
+        );
+    }
+}
+
 #[test]
 fn test_invalid_non_main_return_type_uses_return_type_span() {
     let invalid_return_type_span = span_at(1, 16);
@@ -250,10 +290,11 @@ fn test_invalid_non_main_return_type_uses_return_type_span() {
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "helper".to_string(),
                 params: vec![],
-                return_type: "int".to_string(),
+                return_type: ReturnType::Unknown("int".to_string()),
                 return_type_span: invalid_return_type_span,
                 body: vec![Stmt::new(
                     StmtKind::Return(Some(Expr::new(ExprKind::IntLiteral(1), span_at(2, 12)))),
@@ -262,10 +303,11 @@ fn test_invalid_non_main_return_type_uses_return_type_span() {
                 span: span_at(1, 1),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: span_at(4, 14),
                 body: vec![],
                 span: span_at(4, 1),
@@ -321,19 +363,21 @@ fn test_call_user_defined_function() {
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "helper".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![],
                 span: dummy_span(),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![Stmt::new(
                     StmtKind::Expr(Expr::new(
@@ -362,28 +406,31 @@ fn test_call_multiple_user_defined_functions() {
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "foo".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![],
                 span: dummy_span(),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "bar".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![],
                 span: dummy_span(),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![
                     Stmt::new(
@@ -423,21 +470,24 @@ fn test_call_user_defined_function_with_params() {
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "helper".to_string(),
                 params: vec![
                     FnParam {
                         name: "name".to_string(),
+                        pattern: None,
                         ty: Type::String,
                         span: dummy_span(),
                     },
                     FnParam {
                         name: "age".to_string(),
+                        pattern: None,
                         ty: Type::I32,
                         span: dummy_span(),
                     },
                 ],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![
                     Stmt::new(
@@ -470,10 +520,11 @@ fn test_call_user_defined_function_with_params() {
                 span: dummy_span(),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![Stmt::new(
                     StmtKind::Expr(Expr::new(
@@ -507,23 +558,26 @@ fn test_call_user_defined_function_with_param_type_mismatch() {
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "helper".to_string(),
                 params: vec![FnParam {
                     name: "name".to_string(),
+                    pattern: None,
                     ty: Type::String,
                     span: dummy_span(),
                 }],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![],
                 span: dummy_span(),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![Stmt::new(
                     StmtKind::Expr(Expr::new(
@@ -556,14 +610,16 @@ fn test_main_function_with_params_error() {
     let program = Program {
         imports: vec![],
         functions: vec![FnDef {
+            doc: None,
             visibility: Visibility::Private,
             name: "main".to_string(),
             params: vec![FnParam {
                 name: "x".to_string(),
+                pattern: None,
                 ty: Type::I32,
                 span: dummy_span(),
             }],
-            return_type: "void".to_string(),
+            return_type: ReturnType::Void,
             return_type_span: dummy_span(),
             body: vec![],
             span: span_at(1, 1),
@@ -592,10 +648,11 @@ fn test_function_variable_scope_isolation() {
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "helper".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![Stmt::new(
                     StmtKind::Let {
@@ -609,10 +666,11 @@ fn test_function_variable_scope_isolation() {
                 span: dummy_span(),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![Stmt::new(
                     StmtKind::Let {
@@ -640,10 +698,11 @@ fn test_function_scope_isolation_different_types() {
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "helper".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![Stmt::new(
                     StmtKind::Let {
@@ -657,10 +716,11 @@ fn test_function_scope_isolation_different_types() {
                 span: dummy_span(),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![Stmt::new(
                     StmtKind::Let {
@@ -691,19 +751,21 @@ fn test_valid_multiple_functions() {
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![],
                 span: dummy_span(),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "helper".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![],
                 span: dummy_span(),
@@ -752,10 +814,11 @@ fn test_module_call_in_imported_module_without_table() {
     let program = Program {
         imports: vec![],
         functions: vec![FnDef {
+            doc: None,
             visibility: Visibility::Public,
             name: "helper".to_string(),
             params: vec![],
-            return_type: "void".to_string(),
+            return_type: ReturnType::Void,
             return_type_span: dummy_span(),
             body: vec![Stmt::new(
                 StmtKind::Expr(Expr::new(
@@ -891,10 +954,11 @@ fn test_module_call_in_imported_module_with_table() {
     let program = Program {
         imports: vec![],
         functions: vec![FnDef {
+            doc: None,
             visibility: Visibility::Public,
             name: "helper".to_string(),
             params: vec![],
-            return_type: "void".to_string(),
+            return_type: ReturnType::Void,
             return_type_span: dummy_span(),
             body: vec![Stmt::new(
                 StmtKind::Expr(Expr::new(
@@ -939,10 +1003,11 @@ fn test_module_call_success_in_entry() {
     let program = Program {
         imports: vec![],
         functions: vec![FnDef {
+            doc: None,
             visibility: Visibility::Private,
             name: "main".to_string(),
             params: vec![],
-            return_type: "void".to_string(),
+            return_type: ReturnType::Void,
             return_type_span: dummy_span(),
             body: vec![Stmt::new(
                 StmtKind::Expr(Expr::new(
@@ -982,10 +1047,11 @@ fn test_module_call_success_with_arguments_in_entry() {
     let program = Program {
         imports: vec![],
         functions: vec![FnDef {
+            doc: None,
             visibility: Visibility::Private,
             name: "main".to_string(),
             params: vec![],
-            return_type: "void".to_string(),
+            return_type: ReturnType::Void,
             return_type_span: dummy_span(),
             body: vec![Stmt::new(
                 StmtKind::Expr(Expr::new(
@@ -1028,10 +1094,11 @@ fn test_module_call_argument_type_mismatch_in_entry() {
     let program = Program {
         imports: vec![],
         functions: vec![FnDef {
+            doc: None,
             visibility: Visibility::Private,
             name: "main".to_string(),
             params: vec![],
-            return_type: "void".to_string(),
+            return_type: ReturnType::Void,
             return_type_span: dummy_span(),
             body: vec![Stmt::new(
                 StmtKind::Expr(Expr::new(
@@ -1065,10 +1132,11 @@ fn test_analyze_module_success() {
     let program = Program {
         imports: vec![],
         functions: vec![FnDef {
+            doc: None,
             visibility: Visibility::Public,
             name: "greet".to_string(),
             params: vec![],
-            return_type: "void".to_string(),
+            return_type: ReturnType::Void,
             return_type_span: dummy_span(),
             body: vec![],
             span: dummy_span(),
@@ -1101,10 +1169,11 @@ fn test_analyze_module_reuse_does_not_leak_function_symbols() {
     let program = Program {
         imports: vec![],
         functions: vec![FnDef {
+            doc: None,
             visibility: Visibility::Public,
             name: "helper".to_string(),
             params: vec![],
-            return_type: "void".to_string(),
+            return_type: ReturnType::Void,
             return_type_span: dummy_span(),
             body: vec![],
             span: dummy_span(),
@@ -1323,10 +1392,11 @@ fn test_non_void_function_with_while_true_and_return_is_valid() {
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "helper".to_string(),
                 params: vec![],
-                return_type: "i64".to_string(),
+                return_type: ReturnType::Value(Type::I64),
                 return_type_span: span_at(1, 16),
                 body: vec![Stmt::new(
                     StmtKind::While {
@@ -1344,10 +1414,11 @@ fn test_non_void_function_with_while_true_and_return_is_valid() {
                 span: span_at(1, 1),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: span_at(6, 14),
                 body: vec![],
                 span: span_at(6, 1),
@@ -1366,10 +1437,11 @@ fn test_non_void_function_with_while_false_still_requires_return() {
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "helper".to_string(),
                 params: vec![],
-                return_type: "i64".to_string(),
+                return_type: ReturnType::Value(Type::I64),
                 return_type_span: span_at(1, 16),
                 body: vec![Stmt::new(
                     StmtKind::While {
@@ -1387,10 +1459,11 @@ fn test_non_void_function_with_while_false_still_requires_return() {
                 span: span_at(1, 1),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: span_at(6, 14),
                 body: vec![],
                 span: span_at(6, 1),
@@ -1415,10 +1488,11 @@ fn test_non_void_function_with_if_true_and_return_is_valid() {
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "helper".to_string(),
                 params: vec![],
-                return_type: "i64".to_string(),
+                return_type: ReturnType::Value(Type::I64),
                 return_type_span: span_at(1, 16),
                 body: vec![Stmt::new(
                     StmtKind::If {
@@ -1437,10 +1511,11 @@ fn test_non_void_function_with_if_true_and_return_is_valid() {
                 span: span_at(1, 1),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: span_at(6, 14),
                 body: vec![],
                 span: span_at(6, 1),
@@ -1459,10 +1534,11 @@ fn test_non_void_function_with_if_not_false_and_return_is_valid() {
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "helper".to_string(),
                 params: vec![],
-                return_type: "i64".to_string(),
+                return_type: ReturnType::Value(Type::I64),
                 return_type_span: span_at(1, 16),
                 body: vec![Stmt::new(
                     StmtKind::If {
@@ -1490,10 +1566,11 @@ fn test_non_void_function_with_if_not_false_and_return_is_valid() {
                 span: span_at(1, 1),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: span_at(6, 14),
                 body: vec![],
                 span: span_at(6, 1),
@@ -1512,10 +1589,11 @@ fn test_non_void_function_with_if_true_and_true_and_return_is_valid() {
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "helper".to_string(),
                 params: vec![],
-                return_type: "i64".to_string(),
+                return_type: ReturnType::Value(Type::I64),
                 return_type_span: span_at(1, 16),
                 body: vec![Stmt::new(
                     StmtKind::If {
@@ -1547,10 +1625,11 @@ fn test_non_void_function_with_if_true_and_true_and_return_is_valid() {
                 span: span_at(1, 1),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: span_at(6, 14),
                 body: vec![],
                 span: span_at(6, 1),
@@ -1569,10 +1648,11 @@ fn test_non_void_function_with_if_false_and_no_else_still_requires_return() {
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "helper".to_string(),
                 params: vec![],
-                return_type: "i64".to_string(),
+                return_type: ReturnType::Value(Type::I64),
                 return_type_span: span_at(1, 16),
                 body: vec![Stmt::new(
                     StmtKind::If {
@@ -1591,10 +1671,11 @@ fn test_non_void_function_with_if_false_and_no_else_still_requires_return() {
                 span: span_at(1, 1),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: span_at(6, 14),
                 body: vec![],
                 span: span_at(6, 1),
@@ -1619,10 +1700,11 @@ fn test_non_void_function_with_if_false_and_else_return_is_valid() {
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "helper".to_string(),
                 params: vec![],
-                return_type: "i64".to_string(),
+                return_type: ReturnType::Value(Type::I64),
                 return_type_span: span_at(1, 16),
                 body: vec![Stmt::new(
                     StmtKind::If {
@@ -1641,10 +1723,11 @@ fn test_non_void_function_with_if_false_and_else_return_is_valid() {
                 span: span_at(1, 1),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: span_at(8, 14),
                 body: vec![],
                 span: span_at(8, 1),
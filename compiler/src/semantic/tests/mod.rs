@@ -30,10 +30,11 @@ pub fn program_with_main(body: Vec<Stmt>) -> Program {
     Program {
         imports: vec![],
         functions: vec![FnDef {
+            doc: None,
             visibility: Visibility::Private,
             name: "main".to_string(),
             params: vec![],
-            return_type: "void".to_string(),
+            return_type: ReturnType::Void,
             return_type_span: dummy_span(),
             body,
             span: dummy_span(),
@@ -198,10 +199,13 @@ fn test_internal_check_integer_range_unexpected_inferred_constructor() {
 }
 
 #[test]
-fn test_invalid_argument_println_count_constructor() {
-    let err = SemanticError::invalid_argument_println_count(span_at(5, 5));
+fn test_invalid_argument_print_count_constructor() {
+    let err = SemanticError::invalid_argument_print_count("println", span_at(5, 5));
     assert_eq!(err.kind(), SemanticErrorKind::InvalidArgument);
     assert_eq!(err.message(), "println expects exactly 1 argument");
+
+    let err = SemanticError::invalid_argument_print_count("eprint", span_at(5, 5));
+    assert_eq!(err.message(), "eprint expects exactly 1 argument");
 }
 
 #[test]
@@ -252,7 +256,13 @@ fn test_reserved_prelude_function_name_constructor() {
     );
     assert_eq!(
         err.help(),
-        Some("use a different name; prelude names 'println' and 'panic' are reserved")
+        Some(
+            "use a different name; prelude names 'print', 'println', 'eprint', 'eprintln', \
+             'format', 'panic', 'exit', 'assert', 'assert_eq', 'assert_ne', 'map', 'set', \
+             'arg_count', 'arg', 'read_line', 'read_all', 'parse_i8', 'parse_i16', 'parse_i32', \
+             'parse_i64', 'parse_u8', 'parse_u16', 'parse_u32', 'parse_u64', 'parse_f32' \
+             and 'parse_f64' are reserved"
+        )
     );
 }
 
@@ -266,13 +276,35 @@ fn test_invalid_expression_string_literal_constructor() {
     );
 }
 
+#[test]
+fn test_invalid_expression_block_constructor() {
+    let err = SemanticError::invalid_expression_block(span_at(2, 5));
+    assert_eq!(err.kind(), SemanticErrorKind::InvalidExpression);
+    assert_eq!(err.message(), "Block expression value is not used");
+    assert_eq!(
+        err.help(),
+        Some("assign the block to a variable: `let result = { ... }`")
+    );
+}
+
+#[test]
+fn test_invalid_expression_tuple_constructor() {
+    let err = SemanticError::invalid_expression_tuple(span_at(2, 5));
+    assert_eq!(err.kind(), SemanticErrorKind::InvalidExpression);
+    assert_eq!(err.message(), "Tuple expression value is not used");
+    assert_eq!(
+        err.help(),
+        Some("assign the tuple to a variable: `let (a, b) = (..., ...)`")
+    );
+}
+
 #[test]
 fn test_invalid_main_signature_constructor() {
-    let err = SemanticError::invalid_main_signature("i32", span_at(1, 20));
+    let err = SemanticError::invalid_main_signature("string", span_at(1, 20));
     assert_eq!(err.kind(), SemanticErrorKind::InvalidMainSignature);
     assert_eq!(
         err.message(),
-        "main function must return void, but found return type 'i32'"
+        "main function must return void, i32 or u8, but found return type 'string'"
     );
     // InvalidMainSignature has a span (pointing to return type)
     assert!(err.span().is_some());
@@ -38,10 +38,11 @@ fn module_with_public_void_fn(name: &str) -> Program {
     Program {
         imports: vec![],
         functions: vec![FnDef {
+            doc: None,
             visibility: Visibility::Public,
             name: name.to_string(),
             params: vec![],
-            return_type: "void".to_string(),
+            return_type: ReturnType::Void,
             return_type_span: span_at(1, 20),
             body: vec![],
             span: span_at(1, 1),
@@ -65,19 +66,21 @@ fn duplicate_main_program() -> Program {
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: span_at(1, 15),
                 body: vec![],
                 span: span_at(1, 1),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Private,
                 name: "main".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: span_at(3, 15),
                 body: vec![],
                 span: span_at(3, 1),
@@ -91,19 +94,21 @@ fn duplicate_helper_module_program() -> Program {
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 visibility: Visibility::Public,
                 name: "helper".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: span_at(1, 17),
                 body: vec![],
                 span: span_at(1, 1),
             },
             FnDef {
+                doc: None,
                 visibility: Visibility::Public,
                 name: "helper".to_string(),
                 params: vec![],
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: span_at(3, 17),
                 body: vec![],
                 span: span_at(3, 1),
@@ -29,7 +29,7 @@ fn test_define_and_lookup_function() {
     let info = FunctionInfo {
         name: "test_fn".to_string(),
         param_types: vec![],
-        return_type: "void".to_string(),
+        return_type: ReturnType::Void,
         return_type_span: dummy_span(),
         definition_span: span_at(1, 1),
         visibility: Visibility::Private,
@@ -46,7 +46,7 @@ fn test_duplicate_function_error() {
     let info1 = FunctionInfo {
         name: "dup".to_string(),
         param_types: vec![],
-        return_type: "void".to_string(),
+        return_type: ReturnType::Void,
         return_type_span: dummy_span(),
         definition_span: span_at(1, 1),
         visibility: Visibility::Private,
@@ -54,7 +54,7 @@ fn test_duplicate_function_error() {
     let info2 = FunctionInfo {
         name: "dup".to_string(),
         param_types: vec![],
-        return_type: "void".to_string(),
+        return_type: ReturnType::Void,
         return_type_span: dummy_span(),
         definition_span: span_at(5, 1),
         visibility: Visibility::Private,
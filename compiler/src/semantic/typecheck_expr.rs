@@ -1,6 +1,7 @@
 use super::{SemanticAnalyzer, SemanticError, SemanticErrorKind};
 
 use crate::ast::{BinaryOperator, Expr, ExprKind, IfExprBlock, Type, UnaryOperator};
+use crate::collections;
 use crate::token::Span;
 
 impl SemanticAnalyzer {
@@ -25,7 +26,13 @@ impl SemanticAnalyzer {
                 if *expected_ty == Type::Bool {
                     return Err(SemanticError::type_mismatch_int_to_bool(*value, expr.span));
                 }
-                if expected_ty.is_float() {
+                if expected_ty.is_float()
+                    || *expected_ty == Type::Char
+                    || expected_ty.is_tuple()
+                    || expected_ty.is_collection()
+                    || expected_ty.is_option()
+                    || *expected_ty == Type::Rng
+                {
                     return Err(SemanticError::type_mismatch_int_to_type(
                         *value,
                         &expected_ty.to_string(),
@@ -34,6 +41,18 @@ impl SemanticAnalyzer {
                 }
                 self.check_integer_range(*value, expected_ty, expr.span)
             }
+            ExprKind::SuffixedIntLiteral { value, ty } => {
+                self.check_integer_range(*value, ty, expr.span)?;
+                if ty != expected_ty {
+                    return Err(SemanticError::type_mismatch_suffixed_int(
+                        *value,
+                        &ty.to_string(),
+                        &expected_ty.to_string(),
+                        expr.span,
+                    ));
+                }
+                Ok(())
+            }
             ExprKind::FloatLiteral(_) => {
                 if !expected_ty.is_float() {
                     return Err(SemanticError::type_mismatch_float_to_type(
@@ -43,6 +62,16 @@ impl SemanticAnalyzer {
                 }
                 Ok(())
             }
+            ExprKind::SuffixedFloatLiteral { ty, .. } => {
+                if ty != expected_ty {
+                    return Err(SemanticError::type_mismatch_suffixed_float(
+                        &ty.to_string(),
+                        &expected_ty.to_string(),
+                        expr.span,
+                    ));
+                }
+                Ok(())
+            }
             ExprKind::Identifier(name) => {
                 let var_info = self
                     .symbols
@@ -78,6 +107,37 @@ impl SemanticAnalyzer {
                 }
                 Ok(())
             }
+            ExprKind::CharLiteral(_) => {
+                if *expected_ty != Type::Char {
+                    return Err(SemanticError::type_mismatch_char_to_type(
+                        &expected_ty.to_string(),
+                        expr.span,
+                    ));
+                }
+                Ok(())
+            }
+            ExprKind::Tuple(elements) => {
+                let Type::Tuple(element_types) = expected_ty else {
+                    return Err(SemanticError::type_mismatch_tuple_to_type(
+                        &expected_ty.to_string(),
+                        expr.span,
+                    ));
+                };
+                if elements.len() != element_types.len() {
+                    return Err(SemanticError::type_mismatch_tuple_arity(
+                        &expected_ty.to_string(),
+                        elements.len(),
+                        expr.span,
+                    ));
+                }
+                for (element, element_ty) in elements.iter().zip(element_types) {
+                    self.check_expr_type(element, element_ty)?;
+                }
+                Ok(())
+            }
+            ExprKind::Call { callee, args } if collections::is_constructor(callee) => {
+                self.check_collection_constructor(callee, args, expected_ty, expr.span)
+            }
             ExprKind::Call { callee, args } => {
                 let actual_ty = self.analyze_call_value(callee, args, expr.span)?;
                 if actual_ty != *expected_ty {
@@ -150,6 +210,10 @@ impl SemanticAnalyzer {
                 else_contextual?;
                 Ok(())
             }
+            ExprKind::Block(block) => {
+                self.analyze_if_expr_block(block, Some(expected_ty))?;
+                Ok(())
+            }
             ExprKind::MemberAccess { .. } => {
                 Err(SemanticError::module_access_not_implemented(expr.span))
             }
@@ -158,8 +222,13 @@ impl SemanticAnalyzer {
                 function,
                 args,
             } => {
-                let actual_ty =
-                    self.analyze_module_call_value(module, function, args, expr.span)?;
+                let actual_ty = self.analyze_module_call_value(
+                    module,
+                    function,
+                    args,
+                    Some(expected_ty),
+                    expr.span,
+                )?;
                 if actual_ty != *expected_ty {
                     return Err(SemanticError::type_mismatch_call_return(
                         &format!("{}.{}", module, function),
@@ -251,7 +320,7 @@ impl SemanticAnalyzer {
         ))
     }
 
-    fn infer_comparison_operand_type(
+    pub(super) fn infer_comparison_operand_type(
         &mut self,
         left: &Expr,
         op: BinaryOperator,
@@ -279,10 +348,40 @@ impl SemanticAnalyzer {
             ));
         };
 
+        if operand_ty.is_tuple() {
+            return Err(SemanticError::invalid_tuple_comparison(
+                op,
+                &operand_ty.to_string(),
+                span,
+            ));
+        }
+        if operand_ty.is_collection() {
+            return Err(SemanticError::invalid_collection_comparison(
+                op,
+                &operand_ty.to_string(),
+                span,
+            ));
+        }
+        if operand_ty.is_option() {
+            return Err(SemanticError::invalid_option_comparison(
+                op,
+                &operand_ty.to_string(),
+                span,
+            ));
+        }
+        if operand_ty == Type::Rng {
+            return Err(SemanticError::invalid_rng_comparison(
+                op,
+                &operand_ty.to_string(),
+                span,
+            ));
+        }
+
         if !(op.is_equality()
             || operand_ty.is_integer()
             || operand_ty.is_float()
-            || operand_ty == Type::String)
+            || operand_ty == Type::String
+            || operand_ty == Type::Char)
         {
             return Err(SemanticError::invalid_ordering_op_type(
                 op,
@@ -307,9 +406,12 @@ impl SemanticAnalyzer {
     pub(super) fn infer_expr_type(&mut self, expr: &Expr) -> Result<Type, SemanticError> {
         match &expr.kind {
             ExprKind::IntLiteral(_) => Ok(Type::I64),
+            ExprKind::SuffixedIntLiteral { ty, .. } => Ok(ty.clone()),
             ExprKind::FloatLiteral(_) => Ok(Type::F64),
+            ExprKind::SuffixedFloatLiteral { ty, .. } => Ok(ty.clone()),
             ExprKind::StringLiteral(_) => Ok(Type::String),
             ExprKind::BoolLiteral(_) => Ok(Type::Bool),
+            ExprKind::CharLiteral(_) => Ok(Type::Char),
             ExprKind::Identifier(name) => {
                 let var = self
                     .symbols
@@ -357,6 +459,13 @@ impl SemanticAnalyzer {
                 }
                 Ok(then_ty)
             }
+            ExprKind::Block(block) => self.analyze_if_expr_block(block, None),
+            ExprKind::Tuple(elements) => Ok(Type::Tuple(
+                elements
+                    .iter()
+                    .map(|element| self.infer_expr_type(element))
+                    .collect::<Result<Vec<_>, _>>()?,
+            )),
             ExprKind::Call { callee, args } => self.analyze_call_value(callee, args, expr.span),
             ExprKind::MemberAccess { .. } => {
                 Err(SemanticError::module_access_not_implemented(expr.span))
@@ -365,7 +474,7 @@ impl SemanticAnalyzer {
                 module,
                 function,
                 args,
-            } => self.analyze_module_call_value(module, function, args, expr.span),
+            } => self.analyze_module_call_value(module, function, args, None, expr.span),
         }
     }
 
@@ -511,13 +620,49 @@ impl SemanticAnalyzer {
         }
     }
 
-    /// Validates an expression for use in println.
+    /// Validates an expression for use as the argument of a print builtin
+    /// (`print`, `println`, `eprint`, `eprintln`).
     ///
     /// Validation is done via:
     /// 1. `infer_expr_type` for contextual type inference (including literal adaptation)
     /// 2. `check_expr_type` for deep structural type validation and precise diagnostics
-    pub(super) fn validate_expr_for_println(&mut self, expr: &Expr) -> Result<(), SemanticError> {
+    ///
+    /// Tuple, map, set, `Option` and `rng` values are rejected because the
+    /// runtime has no printer for them.
+    pub(super) fn validate_expr_for_print(
+        &mut self,
+        callee: &str,
+        expr: &Expr,
+    ) -> Result<(), SemanticError> {
         let inferred_ty = self.infer_expr_type(expr)?;
+        if inferred_ty.is_tuple() {
+            return Err(SemanticError::invalid_argument_print_tuple(
+                callee,
+                &inferred_ty.to_string(),
+                expr.span,
+            ));
+        }
+        if inferred_ty.is_collection() {
+            return Err(SemanticError::invalid_argument_print_collection(
+                callee,
+                &inferred_ty.to_string(),
+                expr.span,
+            ));
+        }
+        if inferred_ty.is_option() {
+            return Err(SemanticError::invalid_argument_print_option(
+                callee,
+                &inferred_ty.to_string(),
+                expr.span,
+            ));
+        }
+        if inferred_ty == Type::Rng {
+            return Err(SemanticError::invalid_argument_print_rng(
+                callee,
+                &inferred_ty.to_string(),
+                expr.span,
+            ));
+        }
         self.check_expr_type(expr, &inferred_ty)?;
         Ok(())
     }
@@ -642,6 +787,21 @@ impl SemanticAnalyzer {
                     value, span,
                 ));
             }
+            Type::Char
+            | Type::Tuple(_)
+            | Type::Map(..)
+            | Type::Set(_)
+            | Type::Option(_)
+            | Type::Rng => {
+                // This branch should never be reached because check_expr_type
+                // rejects integer literals against char, tuple, collection,
+                // option and rng types before range checks.
+                return Err(SemanticError::internal_check_integer_range_unexpected_type(
+                    value,
+                    &ty.to_string(),
+                    span,
+                ));
+            }
             Type::Inferred => {
                 // This branch should never be reached because inferred binding
                 // types are resolved before integer range checks.
@@ -653,9 +813,11 @@ impl SemanticAnalyzer {
         Ok(())
     }
 
-    /// Analyzes an if-expression branch block and returns its result type.
+    /// Analyzes an if-expression branch or block expression and returns its
+    /// result type.
     ///
-    /// If `expected_ty` is provided, the branch value is checked directly against
+    /// The block gets its own variable scope. If `expected_ty` is provided, the
+    /// block value is checked directly against
     /// that type to preserve contextual typing (e.g. i32 integer literals).
     fn analyze_if_expr_block(
         &mut self,
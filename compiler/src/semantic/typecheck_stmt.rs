@@ -1,7 +1,11 @@
 use super::symbol::VariableInfo;
 use super::{SemanticAnalyzer, SemanticError, SemanticErrorKind};
 
-use crate::ast::{BinaryOperator, Expr, ExprKind, FnDef, Stmt, StmtKind, Type, UnaryOperator};
+use crate::ast::{
+    BinaryOperator, Expr, ExprKind, FnDef, Pattern, ReturnType, Stmt, StmtKind, Type, UnaryOperator,
+};
+use crate::collections;
+use crate::prelude::runtime_function;
 use crate::token::Span;
 
 impl SemanticAnalyzer {
@@ -11,11 +15,15 @@ impl SemanticAnalyzer {
         self.symbols.enter_scope();
 
         let result = (|| -> Result<(), SemanticError> {
-            if function.return_type != "void" {
-                self.return_type_name_to_type(&function.return_type, function.return_type_span)?;
+            if function.return_type != ReturnType::Void {
+                self.return_type_to_type(&function.return_type, function.return_type_span)?;
             }
 
             for param in &function.params {
+                if let Some(pattern) = &param.pattern {
+                    self.bind_pattern(pattern, &param.ty, false)?;
+                    continue;
+                }
                 let info = VariableInfo {
                     name: param.name.clone(),
                     is_mutable: false,
@@ -35,10 +43,10 @@ impl SemanticAnalyzer {
                 }
             }
 
-            if function.return_type != "void" && !always_returns {
+            if function.return_type != ReturnType::Void && !always_returns {
                 return Err(SemanticError::missing_return_in_non_void_function(
                     &function.name,
-                    &function.return_type,
+                    &function.return_type.to_string(),
                     function.return_type_span,
                 ));
             }
@@ -66,6 +74,15 @@ impl SemanticAnalyzer {
                 self.analyze_let(*is_mutable, name, ty, init, stmt.span)?;
                 Ok(false)
             }
+            StmtKind::LetPattern {
+                is_mutable,
+                pattern,
+                ty,
+                init,
+            } => {
+                self.analyze_let_pattern(*is_mutable, pattern, ty, init, stmt.span)?;
+                Ok(false)
+            }
             StmtKind::Assign { name, value } => {
                 self.analyze_assign(name, value, stmt.span)?;
                 Ok(false)
@@ -83,6 +100,10 @@ impl SemanticAnalyzer {
             StmtKind::While { condition, body } => self.analyze_while(condition, body),
             StmtKind::Break => self.analyze_break(stmt.span),
             StmtKind::Continue => self.analyze_continue(stmt.span),
+            StmtKind::Defer(body) => {
+                self.analyze_defer(body)?;
+                Ok(false)
+            }
         }
     }
 
@@ -150,6 +171,9 @@ impl SemanticAnalyzer {
 
     fn analyze_break(&self, span: Span) -> Result<bool, SemanticError> {
         if self.loop_depth == 0 {
+            if self.defer_depth > 0 {
+                return Err(SemanticError::control_flow_out_of_defer("break", span));
+            }
             return Err(SemanticError::break_outside_loop(span));
         }
         Ok(false)
@@ -157,11 +181,27 @@ impl SemanticAnalyzer {
 
     fn analyze_continue(&self, span: Span) -> Result<bool, SemanticError> {
         if self.loop_depth == 0 {
+            if self.defer_depth > 0 {
+                return Err(SemanticError::control_flow_out_of_defer("continue", span));
+            }
             return Err(SemanticError::continue_outside_loop(span));
         }
         Ok(false)
     }
 
+    /// Analyzes a deferred body in its own scope.
+    ///
+    /// Loops enclosing the `defer` are hidden while analyzing the body so that
+    /// `break`/`continue` cannot jump out of it; `return` is rejected as well.
+    fn analyze_defer(&mut self, body: &[Stmt]) -> Result<(), SemanticError> {
+        let saved_loop_depth = std::mem::replace(&mut self.loop_depth, 0);
+        self.defer_depth += 1;
+        let result = self.analyze_block_scoped(body);
+        self.defer_depth -= 1;
+        self.loop_depth = saved_loop_depth;
+        result.map(|_| ())
+    }
+
     fn analyze_block_scoped(&mut self, stmts: &[Stmt]) -> Result<bool, SemanticError> {
         self.symbols.enter_scope();
         let result = (|| -> Result<bool, SemanticError> {
@@ -191,7 +231,7 @@ impl SemanticAnalyzer {
                 function,
                 args,
             } => {
-                self.analyze_module_call_value(module, function, args, expr.span)?;
+                self.analyze_module_call_value(module, function, args, None, expr.span)?;
                 Ok(())
             }
             _ => Err(SemanticError::invalid_discard_target(span)),
@@ -199,21 +239,25 @@ impl SemanticAnalyzer {
     }
 
     fn analyze_return(&mut self, value: Option<&Expr>, span: Span) -> Result<bool, SemanticError> {
-        let return_type_name = self
+        if self.defer_depth > 0 {
+            return Err(SemanticError::control_flow_out_of_defer("return", span));
+        }
+
+        let return_type = self
             .current_function_return_type
-            .as_deref()
+            .clone()
             .ok_or_else(|| SemanticError::internal_return_outside_function(span))?;
 
-        if return_type_name == "void" {
+        if return_type == ReturnType::Void {
             if value.is_some() {
                 return Err(SemanticError::return_value_in_void_function(span));
             }
             return Ok(true);
         }
 
-        let expected_ty = self.return_type_name_to_type(return_type_name, span)?;
-        let value =
-            value.ok_or_else(|| SemanticError::return_value_required(return_type_name, span))?;
+        let expected_ty = self.return_type_to_type(&return_type, span)?;
+        let value = value
+            .ok_or_else(|| SemanticError::return_value_required(&return_type.to_string(), span))?;
 
         // For integer return types, try contextual checking first so wide literals
         // (e.g. u64::MAX) are validated against the declared return type rather
@@ -252,11 +296,65 @@ impl SemanticAnalyzer {
         args: &[Expr],
         span: Span,
     ) -> Result<(), SemanticError> {
-        if callee == "println" {
+        if matches!(callee, "print" | "println" | "eprint" | "eprintln") {
+            if args.len() != 1 {
+                return Err(SemanticError::invalid_argument_print_count(callee, span));
+            }
+            self.validate_expr_for_print(callee, &args[0])?;
+            return Ok(());
+        }
+
+        if callee == "format" {
+            self.analyze_format_call(args, span)?;
+            return Err(SemanticError::type_mismatch_non_void_fn_as_stmt(
+                callee, "string", span,
+            ));
+        }
+
+        if collections::is_constructor(callee) {
+            return Err(SemanticError::collection_constructor_needs_type(
+                callee, span,
+            ));
+        }
+
+        if callee == "assert" {
+            if args.len() != 1 {
+                return Err(SemanticError::invalid_argument_assert_count(
+                    callee, 1, span,
+                ));
+            }
+            let condition_ty = self.infer_expr_type(&args[0])?;
+            if condition_ty != Type::Bool {
+                return Err(SemanticError::invalid_argument_assert_type(
+                    &condition_ty.to_string(),
+                    args[0].span,
+                ));
+            }
+            self.check_expr_type(&args[0], &Type::Bool)?;
+            return Ok(());
+        }
+
+        if matches!(callee, "assert_eq" | "assert_ne") {
+            if args.len() != 2 {
+                return Err(SemanticError::invalid_argument_assert_count(
+                    callee, 2, span,
+                ));
+            }
+            // The operands must be comparable exactly as with `==` / `!=`.
+            let op = if callee == "assert_eq" {
+                BinaryOperator::Equal
+            } else {
+                BinaryOperator::NotEqual
+            };
+            self.infer_comparison_operand_type(&args[0], op, &args[1], span)?;
+            return Ok(());
+        }
+
+        if callee == "exit" {
             if args.len() != 1 {
-                return Err(SemanticError::invalid_argument_println_count(span));
+                return Err(SemanticError::invalid_argument_exit_count(span));
             }
-            self.validate_expr_for_println(&args[0])?;
+            self.check_expr_type(&args[0], &Type::I32)?;
             return Ok(());
         }
 
@@ -280,13 +378,13 @@ impl SemanticAnalyzer {
                         ));
                     }
                 }
-                ExprKind::IntLiteral(_) => {
+                ExprKind::IntLiteral(_) | ExprKind::SuffixedIntLiteral { .. } => {
                     return Err(SemanticError::invalid_argument_panic_type(
                         "integer literal",
                         args[0].span,
                     ));
                 }
-                ExprKind::FloatLiteral(_) => {
+                ExprKind::FloatLiteral(_) | ExprKind::SuffixedFloatLiteral { .. } => {
                     return Err(SemanticError::invalid_argument_panic_type(
                         "float literal",
                         args[0].span,
@@ -298,12 +396,24 @@ impl SemanticAnalyzer {
                         args[0].span,
                     ));
                 }
+                ExprKind::CharLiteral(_) => {
+                    return Err(SemanticError::invalid_argument_panic_type(
+                        "character literal",
+                        args[0].span,
+                    ));
+                }
                 ExprKind::BinaryOp { .. } | ExprKind::UnaryOp { .. } => {
                     return Err(SemanticError::invalid_argument_panic_type(
                         "expression",
                         args[0].span,
                     ));
                 }
+                ExprKind::Tuple(_) => {
+                    return Err(SemanticError::invalid_argument_panic_type(
+                        "tuple expression",
+                        args[0].span,
+                    ));
+                }
                 ExprKind::IfExpr { .. } => {
                     let arg_ty = self.infer_expr_type(&args[0])?;
                     if arg_ty != Type::String {
@@ -313,6 +423,15 @@ impl SemanticAnalyzer {
                         ));
                     }
                 }
+                ExprKind::Block(_) => {
+                    let arg_ty = self.infer_expr_type(&args[0])?;
+                    if arg_ty != Type::String {
+                        return Err(SemanticError::invalid_argument_panic_type(
+                            "block expression",
+                            args[0].span,
+                        ));
+                    }
+                }
                 ExprKind::Call { .. } | ExprKind::ModuleCall { .. } => {
                     let arg_ty = self.infer_expr_type(&args[0])?;
                     if arg_ty != Type::String {
@@ -329,11 +448,23 @@ impl SemanticAnalyzer {
             return Ok(());
         }
 
+        if let Some(function) = runtime_function(callee) {
+            self.check_runtime_function_args(function, args, span)?;
+            if function.return_type().is_some() {
+                return Err(SemanticError::type_mismatch_non_void_fn_as_stmt(
+                    callee,
+                    &function.return_type_name(),
+                    span,
+                ));
+            }
+            return Ok(());
+        }
+
         let return_type = self.resolve_user_call(callee, args, span)?;
-        if return_type != "void" {
+        if return_type != ReturnType::Void {
             return Err(SemanticError::type_mismatch_non_void_fn_as_stmt(
                 callee,
-                &return_type,
+                &return_type.to_string(),
                 span,
             ));
         }
@@ -347,17 +478,47 @@ impl SemanticAnalyzer {
         args: &[Expr],
         span: Span,
     ) -> Result<Type, SemanticError> {
-        if callee == "println" || callee == "panic" {
+        if matches!(
+            callee,
+            "print"
+                | "println"
+                | "eprint"
+                | "eprintln"
+                | "panic"
+                | "assert"
+                | "assert_eq"
+                | "assert_ne"
+                | "exit"
+        ) {
             self.analyze_call_stmt(callee, args, span)?;
             return Err(SemanticError::void_function_call_as_value(callee, span));
         }
 
+        if callee == "format" {
+            return self.analyze_format_call(args, span);
+        }
+
+        // Constructors with a known expected type are checked by
+        // `check_collection_constructor`.
+        if collections::is_constructor(callee) {
+            return Err(SemanticError::collection_constructor_needs_type(
+                callee, span,
+            ));
+        }
+
+        if let Some(function) = runtime_function(callee) {
+            self.check_runtime_function_args(function, args, span)?;
+            return function
+                .return_type()
+                .ok_or_else(|| SemanticError::void_function_call_as_value(callee, span));
+        }
+
         let return_type = self.resolve_user_call(callee, args, span)?;
-        if return_type == "void" {
+        if return_type == ReturnType::Void {
             return Err(SemanticError::void_function_call_as_value(callee, span));
         }
 
-        self.return_type_name_to_type(&return_type, span)
+        self.return_type_to_type(&return_type, span)
     }
 
     pub(super) fn analyze_module_call_stmt(
@@ -367,11 +528,42 @@ impl SemanticAnalyzer {
         args: &[Expr],
         span: Span,
     ) -> Result<(), SemanticError> {
-        let return_type = self.resolve_module_call(module_name, function_name, args, span)?;
-        if return_type != "void" {
+        if let Some(collection) = self.collection_variable(module_name) {
+            let return_ty =
+                self.analyze_collection_method(module_name, collection, function_name, args, span)?;
+            if let Some(return_ty) = return_ty {
+                return Err(SemanticError::type_mismatch_non_void_fn_as_stmt(
+                    &format!("{}.{}", module_name, function_name),
+                    &return_ty.to_string(),
+                    span,
+                ));
+            }
+            return Ok(());
+        }
+        if let Some(is_mutable) = self.rng_variable(module_name) {
+            let return_ty =
+                self.analyze_rng_method(module_name, is_mutable, function_name, args, span)?;
+            return Err(SemanticError::type_mismatch_non_void_fn_as_stmt(
+                &format!("{}.{}", module_name, function_name),
+                &return_ty.to_string(),
+                span,
+            ));
+        }
+        if let Some(option_ty) = self.option_variable(module_name) {
+            let return_ty =
+                self.analyze_option_method(module_name, &option_ty, function_name, args, span)?;
             return Err(SemanticError::type_mismatch_non_void_fn_as_stmt(
                 &format!("{}.{}", module_name, function_name),
-                &return_type,
+                &return_ty.to_string(),
+                span,
+            ));
+        }
+
+        let return_type = self.resolve_module_call(module_name, function_name, args, None, span)?;
+        if return_type != ReturnType::Void {
+            return Err(SemanticError::type_mismatch_non_void_fn_as_stmt(
+                &format!("{}.{}", module_name, function_name),
+                &return_type.to_string(),
                 span,
             ));
         }
@@ -384,10 +576,26 @@ impl SemanticAnalyzer {
         module_name: &str,
         function_name: &str,
         args: &[Expr],
+        expected_ty: Option<&Type>,
         span: Span,
     ) -> Result<Type, SemanticError> {
-        let return_type = self.resolve_module_call(module_name, function_name, args, span)?;
-        if return_type == "void" {
+        if let Some(collection) = self.collection_variable(module_name) {
+            return self
+                .analyze_collection_method(module_name, collection, function_name, args, span)?
+                .ok_or_else(|| {
+                    SemanticError::void_module_call_as_value(module_name, function_name, span)
+                });
+        }
+        if let Some(option_ty) = self.option_variable(module_name) {
+            return self.analyze_option_method(module_name, &option_ty, function_name, args, span);
+        }
+        if let Some(is_mutable) = self.rng_variable(module_name) {
+            return self.analyze_rng_method(module_name, is_mutable, function_name, args, span);
+        }
+
+        let return_type =
+            self.resolve_module_call(module_name, function_name, args, expected_ty, span)?;
+        if return_type == ReturnType::Void {
             return Err(SemanticError::void_module_call_as_value(
                 module_name,
                 function_name,
@@ -395,7 +603,7 @@ impl SemanticAnalyzer {
             ));
         }
 
-        self.return_type_name_to_type(&return_type, span)
+        self.return_type_to_type(&return_type, span)
     }
 
     fn analyze_let(
@@ -420,31 +628,7 @@ impl SemanticAnalyzer {
         // Type check initializer before introducing the new binding.
         // This rejects self-referential initializers like `let x: i32 = x`
         // and `let x = x`.
-        let resolved_ty = if !ty.is_resolved() {
-            let inferred_ty = self.infer_expr_type(init)?;
-            if !inferred_ty.is_resolved() {
-                return Err(SemanticError::internal_define_variable_unexpected_inferred(
-                    name, span,
-                ));
-            }
-            // Re-validate the initializer under the inferred concrete type so
-            // structural checks (for example integer range validation) still run.
-            self.check_expr_type(init, &inferred_ty)?;
-            if let Some(existing_ty) = self.inferred_binding_types.get(&span) {
-                if *existing_ty != inferred_ty {
-                    return Err(SemanticError::internal_inferred_binding_span_collision(
-                        name, span,
-                    ));
-                }
-            } else {
-                self.inferred_binding_types
-                    .insert(span, inferred_ty.clone());
-            }
-            inferred_ty
-        } else {
-            self.check_expr_type(init, ty)?;
-            ty.clone()
-        };
+        let resolved_ty = self.resolve_let_init_type(name, ty, init, span)?;
 
         let info = VariableInfo {
             name: name.to_string(),
@@ -457,6 +641,62 @@ impl SemanticAnalyzer {
         Ok(())
     }
 
+    /// Analyzes a destructuring `let` statement.
+    ///
+    /// The initializer is typed first (so it cannot see the new bindings),
+    /// then the pattern is checked against that type and its bindings are
+    /// introduced.
+    fn analyze_let_pattern(
+        &mut self,
+        is_mutable: bool,
+        pattern: &Pattern,
+        ty: &Type,
+        init: &Expr,
+        span: Span,
+    ) -> Result<(), SemanticError> {
+        let resolved_ty = self.resolve_let_init_type(&pattern.to_string(), ty, init, span)?;
+        self.bind_pattern(pattern, &resolved_ty, is_mutable)
+    }
+
+    /// Type-checks a `let` initializer and returns the binding type.
+    ///
+    /// For `Type::Inferred` annotations, the inferred type is recorded in the
+    /// semantic side channel keyed by the statement span so codegen does not
+    /// re-infer it.
+    fn resolve_let_init_type(
+        &mut self,
+        name: &str,
+        ty: &Type,
+        init: &Expr,
+        span: Span,
+    ) -> Result<Type, SemanticError> {
+        if ty.is_resolved() {
+            self.check_expr_type(init, ty)?;
+            return Ok(ty.clone());
+        }
+
+        let inferred_ty = self.infer_expr_type(init)?;
+        if !inferred_ty.is_resolved() {
+            return Err(SemanticError::internal_define_variable_unexpected_inferred(
+                name, span,
+            ));
+        }
+        // Re-validate the initializer under the inferred concrete type so
+        // structural checks (for example integer range validation) still run.
+        self.check_expr_type(init, &inferred_ty)?;
+        if let Some(existing_ty) = self.inferred_binding_types.get(&span) {
+            if *existing_ty != inferred_ty {
+                return Err(SemanticError::internal_inferred_binding_span_collision(
+                    name, span,
+                ));
+            }
+        } else {
+            self.inferred_binding_types
+                .insert(span, inferred_ty.clone());
+        }
+        Ok(inferred_ty)
+    }
+
     fn analyze_assign(
         &mut self,
         name: &str,
@@ -485,15 +725,18 @@ impl SemanticAnalyzer {
             ExprKind::StringLiteral(_) => {
                 Err(SemanticError::invalid_expression_string_literal(expr.span))
             }
-            ExprKind::IntLiteral(_) => {
+            ExprKind::IntLiteral(_) | ExprKind::SuffixedIntLiteral { .. } => {
                 Err(SemanticError::invalid_expression_int_literal(expr.span))
             }
-            ExprKind::FloatLiteral(_) => {
+            ExprKind::FloatLiteral(_) | ExprKind::SuffixedFloatLiteral { .. } => {
                 Err(SemanticError::invalid_expression_float_literal(expr.span))
             }
             ExprKind::BoolLiteral(_) => {
                 Err(SemanticError::invalid_expression_bool_literal(expr.span))
             }
+            ExprKind::CharLiteral(_) => {
+                Err(SemanticError::invalid_expression_char_literal(expr.span))
+            }
             ExprKind::Identifier(name) => Err(SemanticError::invalid_expression_identifier(
                 name, expr.span,
             )),
@@ -502,6 +745,8 @@ impl SemanticAnalyzer {
             }
             ExprKind::UnaryOp { .. } => Err(SemanticError::invalid_expression_unary_op(expr.span)),
             ExprKind::IfExpr { .. } => Err(SemanticError::invalid_expression_binary_op(expr.span)),
+            ExprKind::Tuple(_) => Err(SemanticError::invalid_expression_tuple(expr.span)),
+            ExprKind::Block(_) => Err(SemanticError::invalid_expression_block(expr.span)),
             ExprKind::MemberAccess { .. } => {
                 Err(SemanticError::module_access_not_implemented(expr.span))
             }
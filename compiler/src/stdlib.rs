@@ -0,0 +1,516 @@
+//! Standard library modules imported by name (e.g. `import "fs"`).
+//!
+//! A non-relative import is looked up in this order:
+//!
+//! 1. `<name>.lak` in the directory named by the `LAK_STDLIB_DIR`
+//!    environment variable, if set (for developing the standard library)
+//! 2. Lak sources embedded into the compiler from `compiler/stdlib`
+//!    ([`SOURCE_MODULES`])
+//! 3. Native modules implemented by the runtime ([`NATIVE_MODULES`])
+//!
+//! Anything else is an unknown standard module.
+//!
+//! Source modules are ordinary Lak modules. Their virtual path is
+//! `<std>/<name>.lak`, which is also the file name shown in diagnostics.
+//!
+//! Native modules have no Lak source: every function is a
+//! [`RuntimeFunction`] implemented in `lak-runtime`. The resolver turns an
+//! import of a native module into a [`ResolvedModule`] with an empty program
+//! whose exports come from the function table, so semantic analysis, `lak
+//! doc` and module-qualified calls treat it like any other module. Codegen
+//! lowers calls to its functions to direct runtime calls.
+//!
+//! Fallible functions return an error message that is empty on success,
+//! either alone or as the second element of a `(value, error)` tuple.
+//!
+//! A native module may also export [`NumericFunction`]s, which are defined
+//! for several numeric types and lowered inline by codegen instead of
+//! calling the runtime.
+//!
+//! [`ResolvedModule`]: crate::resolver::ResolvedModule
+
+use crate::ast::Type;
+use crate::prelude::{RuntimeFunction, RuntimeReturn};
+
+/// Environment variable naming a directory whose `.lak` files take
+/// precedence over the bundled standard library.
+pub const STDLIB_DIR_ENV: &str = "LAK_STDLIB_DIR";
+
+/// A standard library module written in Lak.
+#[derive(Debug)]
+pub struct SourceModule {
+    /// Module name, used both as the import path and the default alias.
+    pub name: &'static str,
+    /// The Lak source code.
+    pub source: &'static str,
+}
+
+/// All standard library modules written in Lak.
+pub const SOURCE_MODULES: &[SourceModule] = &[SourceModule {
+    name: "strings",
+    source: include_str!("../stdlib/strings.lak"),
+}];
+
+/// Looks up a standard library module written in Lak by its import path.
+pub fn source_module(name: &str) -> Option<&'static SourceModule> {
+    SOURCE_MODULES.iter().find(|module| module.name == name)
+}
+
+/// Returns the names of all bundled standard library modules, sorted.
+pub fn module_names() -> Vec<&'static str> {
+    let mut names: Vec<&str> = SOURCE_MODULES
+        .iter()
+        .map(|module| module.name)
+        .chain(NATIVE_MODULES.iter().map(|module| module.name))
+        .collect();
+    names.sort_unstable();
+    names
+}
+
+/// A standard library module implemented by the runtime.
+#[derive(Debug)]
+pub struct NativeModule {
+    /// Module name, used both as the import path and the default alias.
+    pub name: &'static str,
+    /// Functions exported by the module.
+    pub functions: &'static [RuntimeFunction],
+    /// Functions defined for several numeric types.
+    pub numeric_functions: &'static [NumericFunction],
+}
+
+impl NativeModule {
+    /// Looks up an exported function by name.
+    pub fn function(&self, name: &str) -> Option<&'static RuntimeFunction> {
+        self.functions.iter().find(|function| function.name == name)
+    }
+
+    /// Looks up an exported numeric function by name.
+    pub fn numeric_function(&self, name: &str) -> Option<&'static NumericFunction> {
+        self.numeric_functions
+            .iter()
+            .find(|function| function.name == name)
+    }
+}
+
+/// A function defined for every numeric type `T` in [`NumericTypes`].
+///
+/// All parameters and the result have type `T`, which is the common type of
+/// the arguments (integer literals adapt to the other arguments, as with
+/// binary operators) or the expected type of the call. Codegen lowers each
+/// call inline, using LLVM intrinsics for floating-point types.
+#[derive(Debug)]
+pub struct NumericFunction {
+    /// Name of the function in Lak source code.
+    pub name: &'static str,
+    /// Parameter names, in order.
+    pub params: &'static [&'static str],
+    /// The types the function is defined for.
+    pub types: NumericTypes,
+    /// The operation performed.
+    pub op: NumericOp,
+    /// Doc comment shown by `lak doc`.
+    pub doc: &'static str,
+}
+
+/// The set of types a [`NumericFunction`] is defined for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumericTypes {
+    /// `f32` and `f64`.
+    Float,
+    /// Signed integers, `f32` and `f64`.
+    Signed,
+    /// All integer and floating-point types.
+    All,
+}
+
+impl NumericTypes {
+    /// Returns true if the function is defined for `ty`.
+    pub fn contains(self, ty: &Type) -> bool {
+        match self {
+            NumericTypes::Float => ty.is_float(),
+            NumericTypes::Signed => ty.is_signed_integer() || ty.is_float(),
+            NumericTypes::All => ty.is_numeric(),
+        }
+    }
+
+    /// Describes the set for diagnostics and documentation.
+    pub fn describe(self) -> &'static str {
+        match self {
+            NumericTypes::Float => "f32 or f64",
+            NumericTypes::Signed => "a signed integer type, f32 or f64",
+            NumericTypes::All => "an integer type, f32 or f64",
+        }
+    }
+}
+
+/// The operation performed by a [`NumericFunction`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumericOp {
+    /// `llvm.sqrt`
+    Sqrt,
+    /// `llvm.pow`
+    Pow,
+    /// `llvm.sin`
+    Sin,
+    /// `llvm.cos`
+    Cos,
+    /// `llvm.exp`
+    Exp,
+    /// `llvm.log`
+    Log,
+    /// `llvm.floor`
+    Floor,
+    /// `llvm.ceil`
+    Ceil,
+    /// `llvm.fabs` for floats, overflow-checked negation for integers.
+    Abs,
+    /// `llvm.minnum` for floats, `llvm.smin`/`llvm.umin` for integers.
+    Min,
+    /// `llvm.maxnum` for floats, `llvm.smax`/`llvm.umax` for integers.
+    Max,
+    /// `Max` followed by `Min`, after checking that `low <= high`.
+    Clamp,
+}
+
+/// File system access.
+const FS: NativeModule = NativeModule {
+    name: "fs",
+    numeric_functions: &[],
+    functions: &[
+        RuntimeFunction {
+            name: "read_file",
+            symbol: "lak_fs_read_file",
+            params: &[("path", Type::String)],
+            returns: RuntimeReturn::Result(Type::String),
+            takes_location: false,
+            doc: "Reads the whole file at `path`.\n\
+                  Returns the contents and an error message, empty on success.",
+        },
+        RuntimeFunction {
+            name: "write_file",
+            symbol: "lak_fs_write_file",
+            params: &[("path", Type::String), ("contents", Type::String)],
+            returns: RuntimeReturn::Error,
+            takes_location: false,
+            doc: "Writes `contents` to `path`, replacing the file if it exists.\n\
+                  Returns an error message, empty on success.",
+        },
+        RuntimeFunction {
+            name: "append_file",
+            symbol: "lak_fs_append_file",
+            params: &[("path", Type::String), ("contents", Type::String)],
+            returns: RuntimeReturn::Error,
+            takes_location: false,
+            doc: "Appends `contents` to `path`, creating the file if needed.\n\
+                  Returns an error message, empty on success.",
+        },
+        RuntimeFunction {
+            name: "exists",
+            symbol: "lak_fs_exists",
+            params: &[("path", Type::String)],
+            returns: RuntimeReturn::Value(Type::Bool),
+            takes_location: false,
+            doc: "Returns true if a file or directory exists at `path`.",
+        },
+        RuntimeFunction {
+            name: "remove",
+            symbol: "lak_fs_remove",
+            params: &[("path", Type::String)],
+            returns: RuntimeReturn::Error,
+            takes_location: false,
+            doc: "Removes the file or empty directory at `path`.\n\
+                  Returns an error message, empty on success.",
+        },
+        RuntimeFunction {
+            name: "list_dir",
+            symbol: "lak_fs_list_dir",
+            params: &[("path", Type::String)],
+            returns: RuntimeReturn::Result(Type::String),
+            takes_location: false,
+            doc: "Lists the entries of the directory at `path`, sorted by name and\n\
+                  separated by newlines. Returns the entries and an error message,\n\
+                  empty on success. An entry whose name contains a newline is\n\
+                  reported as an error.",
+        },
+        RuntimeFunction {
+            name: "create_dir",
+            symbol: "lak_fs_create_dir",
+            params: &[("path", Type::String)],
+            returns: RuntimeReturn::Error,
+            takes_location: false,
+            doc: "Creates the directory `path` and any missing parents.\n\
+                  Returns an error message, empty on success.",
+        },
+    ],
+};
+
+/// Environment variables and child processes.
+const PROCESS: NativeModule = NativeModule {
+    name: "process",
+    numeric_functions: &[],
+    functions: &[
+        RuntimeFunction {
+            name: "get_env",
+            symbol: "lak_process_get_env",
+            params: &[("name", Type::String)],
+            returns: RuntimeReturn::Optional(Type::String),
+            takes_location: false,
+            doc: "Returns the value of the environment variable `name`.\n\
+                  Returns `(\"\", false)` if it is not set.",
+        },
+        RuntimeFunction {
+            name: "set_env",
+            symbol: "lak_process_set_env",
+            params: &[("name", Type::String), ("value", Type::String)],
+            returns: RuntimeReturn::Error,
+            takes_location: false,
+            doc: "Sets the environment variable `name` for this process and the\n\
+                  processes it runs. Returns an error message, empty on success.",
+        },
+        RuntimeFunction {
+            name: "current_dir",
+            symbol: "lak_process_current_dir",
+            params: &[],
+            returns: RuntimeReturn::Result(Type::String),
+            takes_location: false,
+            doc: "Returns the current working directory and an error message,\n\
+                  empty on success.",
+        },
+        RuntimeFunction {
+            name: "run",
+            symbol: "lak_process_run",
+            params: &[("command", Type::String), ("args", Type::String)],
+            returns: RuntimeReturn::Outputs(Type::I32),
+            takes_location: false,
+            doc: "Runs `command` with `args`, one argument per line, and waits for it.\n\
+                  `\"\"` passes no arguments and `\"\\n\"` one empty argument; an argument\n\
+                  cannot contain a newline. Returns the exit code and the captured\n\
+                  stdout and stderr. The exit code is -1 if the command could not be\n\
+                  started or its output contains a NUL byte, in which case stderr\n\
+                  holds the reason, or if it was terminated by a signal.",
+        },
+    ],
+};
+
+/// Seedable pseudo-random number generators.
+///
+/// The functions create `rng` values; numbers are drawn with methods on the
+/// generator (see [`crate::rng`]).
+const RANDOM: NativeModule = NativeModule {
+    name: "random",
+    numeric_functions: &[],
+    functions: &[
+        RuntimeFunction {
+            name: "seeded",
+            symbol: "lak_random_seeded",
+            params: &[("seed", Type::U64)],
+            returns: RuntimeReturn::Value(Type::Rng),
+            takes_location: false,
+            doc: "Returns a generator started from `seed`. The same seed always yields\n\
+                  the same numbers.",
+        },
+        RuntimeFunction {
+            name: "from_entropy",
+            symbol: "lak_random_from_entropy",
+            params: &[],
+            returns: RuntimeReturn::Value(Type::Rng),
+            takes_location: false,
+            doc: "Returns a generator started from a seed taken from OS entropy.",
+        },
+    ],
+};
+
+/// Clocks, sleeping and duration formatting.
+const TIME: NativeModule = NativeModule {
+    name: "time",
+    numeric_functions: &[],
+    functions: &[
+        RuntimeFunction {
+            name: "now_ns",
+            symbol: "lak_time_now_ns",
+            params: &[],
+            returns: RuntimeReturn::Value(Type::I64),
+            takes_location: false,
+            doc: "Returns monotonic nanoseconds since an unspecified starting point.\n\
+                  Only the difference between two calls is meaningful.",
+        },
+        RuntimeFunction {
+            name: "unix_seconds",
+            symbol: "lak_time_unix_seconds",
+            params: &[],
+            returns: RuntimeReturn::Value(Type::I64),
+            takes_location: false,
+            doc: "Returns the wall-clock time in seconds since the Unix epoch.",
+        },
+        RuntimeFunction {
+            name: "sleep_ms",
+            symbol: "lak_time_sleep_ms",
+            params: &[("ms", Type::I64)],
+            returns: RuntimeReturn::Void,
+            takes_location: true,
+            doc: "Suspends the program for `ms` milliseconds. Panics if `ms` is negative.",
+        },
+        RuntimeFunction {
+            name: "format_duration",
+            symbol: "lak_time_format_duration",
+            params: &[("ns", Type::I64)],
+            returns: RuntimeReturn::Value(Type::String),
+            takes_location: false,
+            doc: "Formats a duration of `ns` nanoseconds, e.g. `1.5ms` or `1h2m3.5s`.",
+        },
+    ],
+};
+
+/// Mathematical functions.
+const MATH: NativeModule = NativeModule {
+    name: "math",
+    functions: &[],
+    numeric_functions: &[
+        NumericFunction {
+            name: "sqrt",
+            params: &["x"],
+            types: NumericTypes::Float,
+            op: NumericOp::Sqrt,
+            doc: "Returns the square root of `x`, or NaN if `x` is negative.",
+        },
+        NumericFunction {
+            name: "pow",
+            params: &["base", "exponent"],
+            types: NumericTypes::Float,
+            op: NumericOp::Pow,
+            doc: "Returns `base` raised to the power `exponent`.",
+        },
+        NumericFunction {
+            name: "sin",
+            params: &["x"],
+            types: NumericTypes::Float,
+            op: NumericOp::Sin,
+            doc: "Returns the sine of `x` radians.",
+        },
+        NumericFunction {
+            name: "cos",
+            params: &["x"],
+            types: NumericTypes::Float,
+            op: NumericOp::Cos,
+            doc: "Returns the cosine of `x` radians.",
+        },
+        NumericFunction {
+            name: "exp",
+            params: &["x"],
+            types: NumericTypes::Float,
+            op: NumericOp::Exp,
+            doc: "Returns e raised to the power `x`.",
+        },
+        NumericFunction {
+            name: "log",
+            params: &["x"],
+            types: NumericTypes::Float,
+            op: NumericOp::Log,
+            doc: "Returns the natural logarithm of `x`.",
+        },
+        NumericFunction {
+            name: "floor",
+            params: &["x"],
+            types: NumericTypes::Float,
+            op: NumericOp::Floor,
+            doc: "Returns the largest integral value not greater than `x`.",
+        },
+        NumericFunction {
+            name: "ceil",
+            params: &["x"],
+            types: NumericTypes::Float,
+            op: NumericOp::Ceil,
+            doc: "Returns the smallest integral value not less than `x`.",
+        },
+        NumericFunction {
+            name: "abs",
+            params: &["x"],
+            types: NumericTypes::Signed,
+            op: NumericOp::Abs,
+            doc: "Returns the absolute value of `x`.\n\
+                  Panics on overflow (the minimum value of a signed integer type).",
+        },
+        NumericFunction {
+            name: "min",
+            params: &["a", "b"],
+            types: NumericTypes::All,
+            op: NumericOp::Min,
+            doc: "Returns the smaller of `a` and `b`. For floats, NaN is ignored.",
+        },
+        NumericFunction {
+            name: "max",
+            params: &["a", "b"],
+            types: NumericTypes::All,
+            op: NumericOp::Max,
+            doc: "Returns the larger of `a` and `b`. For floats, NaN is ignored.",
+        },
+        NumericFunction {
+            name: "clamp",
+            params: &["x", "low", "high"],
+            types: NumericTypes::All,
+            op: NumericOp::Clamp,
+            doc: "Returns `x` restricted to the range `low..=high`.\n\
+                  Panics if `low` is greater than `high`.",
+        },
+    ],
+};
+
+/// All native standard library modules.
+pub const NATIVE_MODULES: &[NativeModule] = &[FS, MATH, PROCESS, RANDOM, TIME];
+
+/// Looks up a native standard library module by its import path.
+pub fn native_module(name: &str) -> Option<&'static NativeModule> {
+    NATIVE_MODULES.iter().find(|module| module.name == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_runtime_symbols_are_unique() {
+        let mut symbols: Vec<&str> = NATIVE_MODULES
+            .iter()
+            .flat_map(|module| module.functions)
+            .chain(crate::prelude::RUNTIME_FUNCTIONS)
+            .map(|function| function.symbol)
+            .collect();
+        let count = symbols.len();
+        symbols.sort();
+        symbols.dedup();
+        assert_eq!(symbols.len(), count);
+    }
+
+    #[test]
+    fn test_module_names_are_unique_and_sorted() {
+        let names = module_names();
+        let mut deduped = names.clone();
+        deduped.dedup();
+        assert_eq!(names, deduped);
+        assert!(names.contains(&"fs"));
+        assert!(names.contains(&"strings"));
+    }
+
+    #[test]
+    fn test_native_module_lookup() {
+        let fs = native_module("fs").unwrap();
+        assert_eq!(fs.function("read_file").unwrap().symbol, "lak_fs_read_file");
+        assert!(fs.function("missing").is_none());
+        assert!(native_module("./fs").is_none());
+    }
+
+    #[test]
+    fn test_numeric_function_types() {
+        let math = native_module("math").unwrap();
+        let sqrt = math.numeric_function("sqrt").unwrap();
+        assert!(sqrt.types.contains(&Type::F32));
+        assert!(!sqrt.types.contains(&Type::I64));
+        let abs = math.numeric_function("abs").unwrap();
+        assert!(abs.types.contains(&Type::I8));
+        assert!(!abs.types.contains(&Type::U8));
+        let max = math.numeric_function("max").unwrap();
+        assert!(max.types.contains(&Type::U64));
+        assert!(!max.types.contains(&Type::String));
+        assert!(math.function("sqrt").is_none());
+    }
+}
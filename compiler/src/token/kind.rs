@@ -23,6 +23,11 @@ pub enum TokenKind {
     /// like `\n` are already converted to their actual characters).
     StringLiteral(String),
 
+    /// A character literal enclosed in single quotes (e.g., `'a'`, `'\n'`).
+    ///
+    /// The contained `char` is the unescaped value.
+    CharLiteral(char),
+
     /// The `fn` keyword for function definitions.
     Fn,
 
@@ -50,6 +55,9 @@ pub enum TokenKind {
     /// The `continue` keyword for continuing to the next loop iteration.
     Continue,
 
+    /// The `defer` keyword for scope-exit cleanup statements.
+    Defer,
+
     /// The `pub` keyword for public visibility.
     Pub,
 
@@ -128,12 +136,23 @@ pub enum TokenKind {
     /// Greater than or equal `>=` for comparison.
     GreaterEqual,
 
-    /// An integer literal (e.g., 42, 100).
+    /// An integer literal (e.g., 42, 100, 0xFF, 1_000).
     IntLiteral(u64),
 
-    /// A floating-point literal (e.g., 3.14, 0.5).
+    /// An integer literal with a type suffix (e.g., `255u8`, `1_000i64`).
+    ///
+    /// The suffix is always one of the integer type names (`i8` through `u64`);
+    /// the lexer rejects any other suffix.
+    SuffixedIntLiteral(u64, String),
+
+    /// A floating-point literal (e.g., 3.14, 0.5, 1e-9, `inf`, `nan`).
     FloatLiteral(f64),
 
+    /// A floating-point literal with a type suffix (e.g., `1.5f32`, `2f64`).
+    ///
+    /// The suffix is always `f32` or `f64`; the lexer rejects any other suffix.
+    SuffixedFloatLiteral(f64, String),
+
     /// A boolean literal (`true` or `false`).
     BoolLiteral(bool),
 
@@ -145,6 +164,13 @@ pub enum TokenKind {
     /// produce tokens.
     Newline,
 
+    /// A `///` doc comment, holding the comment text without the marker
+    /// and a single following space (e.g., `/// Adds.` → `"Adds."`).
+    ///
+    /// Doc comments never affect newline emission. The parser removes them
+    /// from the token stream and attaches them to the following declaration.
+    DocComment(String),
+
     /// End of file marker.
     ///
     /// The lexer always appends this as the final token, ensuring
@@ -208,6 +234,11 @@ mod tests {
         assert!(matches!(TokenKind::Continue, TokenKind::Continue));
     }
 
+    #[test]
+    fn test_token_kind_defer() {
+        assert!(matches!(TokenKind::Defer, TokenKind::Defer));
+    }
+
     #[test]
     fn test_token_kind_colon() {
         assert!(matches!(TokenKind::Colon, TokenKind::Colon));
@@ -224,12 +255,26 @@ mod tests {
         assert!(matches!(kind, TokenKind::IntLiteral(42)));
     }
 
+    #[test]
+    fn test_token_kind_suffixed_int_literal() {
+        let kind = TokenKind::SuffixedIntLiteral(255, "u8".to_string());
+        assert!(matches!(kind, TokenKind::SuffixedIntLiteral(255, ref s) if s == "u8"));
+    }
+
     #[test]
     fn test_token_kind_float_literal() {
         let kind = TokenKind::FloatLiteral(2.5);
         assert!(matches!(kind, TokenKind::FloatLiteral(v) if v == 2.5));
     }
 
+    #[test]
+    fn test_token_kind_suffixed_float_literal() {
+        let kind = TokenKind::SuffixedFloatLiteral(2.5, "f32".to_string());
+        assert!(
+            matches!(kind, TokenKind::SuffixedFloatLiteral(v, ref s) if v == 2.5 && s == "f32")
+        );
+    }
+
     #[test]
     fn test_token_kind_eof() {
         assert!(matches!(TokenKind::Eof, TokenKind::Eof));
@@ -16,11 +16,18 @@ use lak::token::Span;
 
 use inkwell::context::Context;
 use std::fs;
+use std::io::{ErrorKind, Write};
 use std::path::Path;
 use std::path::PathBuf;
-use std::process::Command;
+use std::process::{Command, Output, Stdio};
 use tempfile::tempdir;
 
+/// Environment variables read by `lak` or the runtime.
+///
+/// [`run_lak`] removes them from the inherited environment so that only
+/// the variables a test passes explicitly take effect.
+const LAK_ENV_VARS: &[&str] = &["LAK_LEAK_CHECK", "LAK_STDLIB_DIR"];
+
 /// Returns the runtime static library filename for the current target.
 pub fn runtime_library_filename() -> &'static str {
     linker::runtime_library_filename()
@@ -73,6 +80,90 @@ pub fn lak_binary() -> String {
     env!("CARGO_BIN_EXE_lak").to_string()
 }
 
+/// Writes `source` to `main.lak` and runs it with `lak run`.
+///
+/// - `env`: variables to set for the program
+/// - `args`: arguments passed to the program after `--`
+/// - `stdin`: written to the program's stdin, which is then closed
+/// - `cwd`: directory that holds `main.lak` and becomes the working
+///   directory; a fresh temporary directory is used if `None`
+///
+/// The returned output holds the exit status, stdout and stderr.
+pub fn run_lak(
+    source: &str,
+    env: &[(&str, &str)],
+    args: &[&str],
+    stdin: &str,
+    cwd: Option<&Path>,
+) -> Output {
+    let temp = tempdir().unwrap();
+    let dir = cwd.unwrap_or(temp.path());
+    fs::write(dir.join("main.lak"), source).unwrap();
+
+    let mut command = Command::new(lak_binary());
+    command
+        .current_dir(dir)
+        .args(["run", "main.lak"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    if !args.is_empty() {
+        command.arg("--").args(args);
+    }
+    for name in LAK_ENV_VARS {
+        command.env_remove(name);
+    }
+    command.envs(env.iter().copied());
+
+    let mut child = command.spawn().unwrap();
+    let mut child_stdin = child.stdin.take().unwrap();
+    // The program may exit without reading all of its input.
+    if let Err(e) = child_stdin.write_all(stdin.as_bytes()) {
+        assert_eq!(e.kind(), ErrorKind::BrokenPipe, "{}", e);
+    }
+    drop(child_stdin);
+    child.wait_with_output().unwrap()
+}
+
+/// Runs `source` with `lak run`, asserting that it succeeded, and returns
+/// its stdout.
+pub fn run_ok(source: &str) -> String {
+    let output = run_lak(source, &[], &[], "", None);
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+/// Runs `source` with `lak run`, asserting that it exited with code 1 as a
+/// panic or failed assertion does, and returns its stderr.
+pub fn run_failing(source: &str) -> String {
+    let output = run_lak(source, &[], &[], "", None);
+    let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+    assert_eq!(output.status.code(), Some(1), "stderr: {}", stderr);
+    stderr
+}
+
+/// Runs `source` with the leak check enabled and returns its stdout,
+/// asserting that it succeeded without leaks.
+pub fn run_ok_leak_checked(source: &str) -> String {
+    run_ok_leak_checked_in(None, source, &[])
+}
+
+/// Like [`run_ok_leak_checked`], but runs in `cwd` (see [`run_lak`]) with
+/// the extra environment variables `env`.
+pub fn run_ok_leak_checked_in(cwd: Option<&Path>, source: &str, env: &[(&str, &str)]) -> String {
+    let mut vars = vec![("LAK_LEAK_CHECK", "1")];
+    vars.extend_from_slice(env);
+    let output = run_lak(source, &vars, &[], "", cwd);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {}", stderr);
+    assert_eq!(stderr, "leak check: no leaked objects\n");
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
 /// Creates a dummy span for test AST construction.
 pub fn dummy_span() -> Span {
     Span::new(0, 0, 1, 1)
@@ -0,0 +1,111 @@
+//! End-to-end tests for command-line arguments: the `arg_count` and `arg`
+//! built-in functions.
+//!
+//! These tests verify that arguments given after `--` to `lak run`, or
+//! directly to a built executable, are visible to the program.
+
+mod common;
+
+use common::{lak_binary, run_lak};
+use std::fs;
+use std::process::Command;
+use tempfile::tempdir;
+
+const ECHO_ARGS: &str = r#"fn main() -> void {
+    println(format("{} args", arg_count()))
+    let mut i: i64 = 0
+    while i < arg_count() {
+        println(arg(i))
+        i = i + 1
+    }
+}"#;
+
+#[test]
+fn test_run_forwards_args() {
+    let output = run_lak(ECHO_ARGS, &[], &["a", "b c", "--flag"], "", None);
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "3 args\na\nb c\n--flag\n"
+    );
+}
+
+#[test]
+fn test_run_without_args() {
+    let output = run_lak(ECHO_ARGS, &[], &[], "", None);
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "0 args\n");
+}
+
+#[test]
+fn test_built_executable_reads_args() {
+    let temp = tempdir().unwrap();
+    let source_path = temp.path().join("echo.lak");
+    let executable_path = temp
+        .path()
+        .join(format!("echo{}", std::env::consts::EXE_SUFFIX));
+    fs::write(&source_path, ECHO_ARGS).unwrap();
+
+    let build = Command::new(lak_binary())
+        .args([
+            "build",
+            source_path.to_str().unwrap(),
+            "-o",
+            executable_path.to_str().unwrap(),
+        ])
+        .output()
+        .unwrap();
+    assert!(build.status.success(), "{:?}", build);
+
+    let output = Command::new(&executable_path)
+        .args(["x", "y"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "2 args\nx\ny\n");
+}
+
+#[test]
+fn test_arg_out_of_range_panics_with_location() {
+    let output = run_lak(
+        r#"fn main() -> void {
+    let first: string = arg(0)
+    println(first)
+    let second: string = arg(1)
+    println(second)
+}"#,
+        &[],
+        &["only"],
+        "",
+        None,
+    );
+    assert!(!output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "only\n");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("argument index 1 out of range (arg_count is 1)"),
+        "stderr: {}",
+        stderr
+    );
+    assert!(stderr.contains("main.lak:4:26"), "stderr: {}", stderr);
+}
+
+#[test]
+fn test_arg_negative_index_panics() {
+    let output = run_lak(
+        r#"fn main() -> void {
+    println(arg(-1))
+}"#,
+        &[],
+        &["a"],
+        "",
+        None,
+    );
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("argument index -1 out of range (arg_count is 1)"),
+        "stderr: {}",
+        stderr
+    );
+}
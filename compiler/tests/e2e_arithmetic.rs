@@ -895,7 +895,7 @@ const LAK_RUN_FAILURE_CASES: &[LakRunFailureCase] = &[
     let x: i32 = 10 / 0
     println(x)
 }"#,
-        expected_stderr: "panic: division by zero\n",
+        expected_stderr: "panic: division by zero\n    at div_zero_lit.lak:2:18\n",
         non_success_message: "division by zero should panic",
     },
     LakRunFailureCase {
@@ -907,7 +907,7 @@ const LAK_RUN_FAILURE_CASES: &[LakRunFailureCase] = &[
     let z: i32 = x / y
     println(z)
 }"#,
-        expected_stderr: "panic: division by zero\n",
+        expected_stderr: "panic: division by zero\n    at div_zero_var.lak:4:18\n",
         non_success_message: "case should fail",
     },
     LakRunFailureCase {
@@ -917,7 +917,7 @@ const LAK_RUN_FAILURE_CASES: &[LakRunFailureCase] = &[
     let x: i32 = 10 % 0
     println(x)
 }"#,
-        expected_stderr: "panic: modulo by zero\n",
+        expected_stderr: "panic: modulo by zero\n    at mod_zero_lit.lak:2:18\n",
         non_success_message: "modulo by zero should panic",
     },
     LakRunFailureCase {
@@ -929,7 +929,7 @@ const LAK_RUN_FAILURE_CASES: &[LakRunFailureCase] = &[
     let c: i32 = a % b
     println(c)
 }"#,
-        expected_stderr: "panic: modulo by zero\n",
+        expected_stderr: "panic: modulo by zero\n    at mod_zero_var.lak:4:18\n",
         non_success_message: "case should fail",
     },
     LakRunFailureCase {
@@ -941,7 +941,7 @@ const LAK_RUN_FAILURE_CASES: &[LakRunFailureCase] = &[
     let z: i64 = x / y
     println(z)
 }"#,
-        expected_stderr: "panic: division by zero\n",
+        expected_stderr: "panic: division by zero\n    at div_zero_i64.lak:4:18\n",
         non_success_message: "case should fail",
     },
     LakRunFailureCase {
@@ -951,7 +951,7 @@ const LAK_RUN_FAILURE_CASES: &[LakRunFailureCase] = &[
     let x: i32 = 10 + 20 / 0
     println(x)
 }"#,
-        expected_stderr: "panic: division by zero\n",
+        expected_stderr: "panic: division by zero\n    at nested_div.lak:2:23\n",
         non_success_message: "case should fail",
     },
     LakRunFailureCase {
@@ -961,7 +961,7 @@ const LAK_RUN_FAILURE_CASES: &[LakRunFailureCase] = &[
     let x: i32 = 100 / 10 / 0
     println(x)
 }"#,
-        expected_stderr: "panic: division by zero\n",
+        expected_stderr: "panic: division by zero\n    at chained_div.lak:2:18\n",
         non_success_message: "case should fail",
     },
     LakRunFailureCase {
@@ -973,7 +973,7 @@ const LAK_RUN_FAILURE_CASES: &[LakRunFailureCase] = &[
     let z: i64 = x % y
     println(z)
 }"#,
-        expected_stderr: "panic: modulo by zero\n",
+        expected_stderr: "panic: modulo by zero\n    at mod_zero_i64.lak:4:18\n",
         non_success_message: "case should fail",
     },
     LakRunFailureCase {
@@ -984,7 +984,7 @@ const LAK_RUN_FAILURE_CASES: &[LakRunFailureCase] = &[
     let y: i32 = -x
     println(y)
 }"#,
-        expected_stderr: "panic: integer overflow\n",
+        expected_stderr: "panic: integer overflow\n    at neg_i32_min.lak:3:18\n",
         non_success_message: "negation overflow should panic",
     },
     LakRunFailureCase {
@@ -995,7 +995,7 @@ const LAK_RUN_FAILURE_CASES: &[LakRunFailureCase] = &[
     let y: i64 = -x
     println(y)
 }"#,
-        expected_stderr: "panic: integer overflow\n",
+        expected_stderr: "panic: integer overflow\n    at neg_i64_min.lak:3:18\n",
         non_success_message: "negation overflow should panic",
     },
     LakRunFailureCase {
@@ -1006,7 +1006,7 @@ const LAK_RUN_FAILURE_CASES: &[LakRunFailureCase] = &[
     let y: i32 = x + 1
     println(y)
 }"#,
-        expected_stderr: "panic: integer overflow\n",
+        expected_stderr: "panic: integer overflow\n    at add_overflow_i32.lak:3:18\n",
         non_success_message: "addition overflow should panic",
     },
     LakRunFailureCase {
@@ -1017,7 +1017,7 @@ const LAK_RUN_FAILURE_CASES: &[LakRunFailureCase] = &[
     let y: i64 = x + 1
     println(y)
 }"#,
-        expected_stderr: "panic: integer overflow\n",
+        expected_stderr: "panic: integer overflow\n    at add_overflow_i64.lak:3:18\n",
         non_success_message: "addition overflow should panic",
     },
     LakRunFailureCase {
@@ -1028,7 +1028,7 @@ const LAK_RUN_FAILURE_CASES: &[LakRunFailureCase] = &[
     let y: i32 = x - 1
     println(y)
 }"#,
-        expected_stderr: "panic: integer overflow\n",
+        expected_stderr: "panic: integer overflow\n    at sub_overflow_i32.lak:3:18\n",
         non_success_message: "subtraction overflow should panic",
     },
     LakRunFailureCase {
@@ -1039,7 +1039,7 @@ const LAK_RUN_FAILURE_CASES: &[LakRunFailureCase] = &[
     let y: i64 = x - 1
     println(y)
 }"#,
-        expected_stderr: "panic: integer overflow\n",
+        expected_stderr: "panic: integer overflow\n    at sub_overflow_i64.lak:3:18\n",
         non_success_message: "subtraction overflow should panic",
     },
     LakRunFailureCase {
@@ -1050,7 +1050,7 @@ const LAK_RUN_FAILURE_CASES: &[LakRunFailureCase] = &[
     let y: i32 = x * x
     println(y)
 }"#,
-        expected_stderr: "panic: integer overflow\n",
+        expected_stderr: "panic: integer overflow\n    at mul_overflow_i32.lak:3:18\n",
         non_success_message: "multiplication overflow should panic",
     },
     LakRunFailureCase {
@@ -1061,7 +1061,7 @@ const LAK_RUN_FAILURE_CASES: &[LakRunFailureCase] = &[
     let y: i64 = x * x
     println(y)
 }"#,
-        expected_stderr: "panic: integer overflow\n",
+        expected_stderr: "panic: integer overflow\n    at mul_overflow_i64.lak:3:18\n",
         non_success_message: "multiplication overflow should panic",
     },
     LakRunFailureCase {
@@ -1073,7 +1073,7 @@ const LAK_RUN_FAILURE_CASES: &[LakRunFailureCase] = &[
     let z: i32 = x + y
     println(z)
 }"#,
-        expected_stderr: "panic: integer overflow\n",
+        expected_stderr: "panic: integer overflow\n    at add_neg_overflow_i32.lak:4:18\n",
         non_success_message: "negative addition overflow should panic",
     },
     LakRunFailureCase {
@@ -1085,7 +1085,7 @@ const LAK_RUN_FAILURE_CASES: &[LakRunFailureCase] = &[
     let z: i64 = x + y
     println(z)
 }"#,
-        expected_stderr: "panic: integer overflow\n",
+        expected_stderr: "panic: integer overflow\n    at add_neg_overflow_i64.lak:4:18\n",
         non_success_message: "negative addition overflow should panic",
     },
     LakRunFailureCase {
@@ -1096,7 +1096,7 @@ const LAK_RUN_FAILURE_CASES: &[LakRunFailureCase] = &[
     let y: i32 = x + 1 - 1
     println(y)
 }"#,
-        expected_stderr: "panic: integer overflow\n",
+        expected_stderr: "panic: integer overflow\n    at chained_overflow_i32.lak:3:18\n",
         non_success_message: "chained expression should panic at first overflow",
     },
     LakRunFailureCase {
@@ -1108,7 +1108,7 @@ const LAK_RUN_FAILURE_CASES: &[LakRunFailureCase] = &[
     let z: i32 = x / y
     println(z)
 }"#,
-        expected_stderr: "panic: integer overflow\n",
+        expected_stderr: "panic: integer overflow\n    at div_overflow_i32.lak:4:18\n",
         non_success_message: "i32 MIN / -1 should panic with overflow",
     },
     LakRunFailureCase {
@@ -1120,7 +1120,7 @@ const LAK_RUN_FAILURE_CASES: &[LakRunFailureCase] = &[
     let z: i64 = x / y
     println(z)
 }"#,
-        expected_stderr: "panic: integer overflow\n",
+        expected_stderr: "panic: integer overflow\n    at div_overflow_i64.lak:4:18\n",
         non_success_message: "i64 MIN / -1 should panic with overflow",
     },
     LakRunFailureCase {
@@ -1132,7 +1132,7 @@ const LAK_RUN_FAILURE_CASES: &[LakRunFailureCase] = &[
     let z: i32 = x % y
     println(z)
 }"#,
-        expected_stderr: "panic: integer overflow\n",
+        expected_stderr: "panic: integer overflow\n    at mod_overflow_i32.lak:4:18\n",
         non_success_message: "i32 MIN % -1 should panic with overflow",
     },
     LakRunFailureCase {
@@ -1144,7 +1144,7 @@ const LAK_RUN_FAILURE_CASES: &[LakRunFailureCase] = &[
     let z: i64 = x % y
     println(z)
 }"#,
-        expected_stderr: "panic: integer overflow\n",
+        expected_stderr: "panic: integer overflow\n    at mod_overflow_i64.lak:4:18\n",
         non_success_message: "i64 MIN % -1 should panic with overflow",
     },
     LakRunFailureCase {
@@ -1155,7 +1155,7 @@ const LAK_RUN_FAILURE_CASES: &[LakRunFailureCase] = &[
     let y: i8 = x + 1
     println(y)
 }"#,
-        expected_stderr: "panic: integer overflow\n",
+        expected_stderr: "panic: integer overflow\n    at add_overflow_i8.lak:3:17\n",
         non_success_message: "i8 addition overflow should panic",
     },
     LakRunFailureCase {
@@ -1166,7 +1166,7 @@ const LAK_RUN_FAILURE_CASES: &[LakRunFailureCase] = &[
     let y: i16 = x - 1
     println(y)
 }"#,
-        expected_stderr: "panic: integer overflow\n",
+        expected_stderr: "panic: integer overflow\n    at sub_overflow_i16.lak:3:18\n",
         non_success_message: "i16 subtraction overflow should panic",
     },
     LakRunFailureCase {
@@ -1177,7 +1177,7 @@ const LAK_RUN_FAILURE_CASES: &[LakRunFailureCase] = &[
     let y: u8 = x - 1
     println(y)
 }"#,
-        expected_stderr: "panic: integer overflow\n",
+        expected_stderr: "panic: integer overflow\n    at sub_underflow_u8.lak:3:17\n",
         non_success_message: "u8 subtraction underflow should panic",
     },
     LakRunFailureCase {
@@ -1188,7 +1188,7 @@ const LAK_RUN_FAILURE_CASES: &[LakRunFailureCase] = &[
     let y: u16 = x * 2
     println(y)
 }"#,
-        expected_stderr: "panic: integer overflow\n",
+        expected_stderr: "panic: integer overflow\n    at mul_overflow_u16.lak:3:18\n",
         non_success_message: "u16 multiplication overflow should panic",
     },
 ];
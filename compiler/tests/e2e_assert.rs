@@ -0,0 +1,109 @@
+//! End-to-end tests for the `assert`, `assert_eq` and `assert_ne` built-in functions.
+//!
+//! These tests verify that passing assertions have no effect and that failing
+//! assertions exit with code 1, reporting the values and source location.
+
+mod common;
+
+use common::{compile_and_run, run_failing, run_lak};
+use std::fs;
+use tempfile::tempdir;
+
+#[test]
+fn test_passing_assertions() {
+    let output = compile_and_run(
+        r#"fn main() -> void {
+    let x: i32 = 3
+    let name = format("lak{}", 1)
+    assert(x > 2)
+    assert(true && name == "lak1")
+    assert_eq(x, 3)
+    assert_eq(x + 1, 4)
+    assert_eq(name, "lak1")
+    assert_eq(0.5, 0.5)
+    assert_eq(x > 1, true)
+    assert_ne(x, 4)
+    assert_ne(name, "lak2")
+    assert_ne(1.5f32, 2.5f32)
+    println("ok")
+}"#,
+    )
+    .unwrap();
+    assert_eq!(output, "ok\n");
+}
+
+#[test]
+fn test_assert_failure_reports_location() {
+    let stderr = run_failing(
+        r#"fn main() -> void {
+    let x = 1
+    assert(x > 2)
+}"#,
+    );
+    assert_eq!(stderr, "panic: assertion failed\n    at main.lak:3:5\n");
+}
+
+#[test]
+fn test_assert_eq_failure_reports_values() {
+    let stderr = run_failing(
+        r#"fn main() -> void {
+    println("before")
+    let x: u8 = 200
+    assert_eq(x, 100)
+    println("after")
+}"#,
+    );
+    assert_eq!(
+        stderr,
+        "panic: assertion `left == right` failed\n  left: 200\n right: 100\n    at main.lak:4:5\n"
+    );
+}
+
+#[test]
+fn test_assert_ne_failure_reports_values() {
+    let stderr = run_failing(
+        r#"fn main() -> void {
+    let greeting = format("hi {}", "there")
+    assert_ne(greeting, "hi there")
+}"#,
+    );
+    assert_eq!(
+        stderr,
+        "panic: assertion `left != right` failed\n  left: hi there\n right: hi there\n    at main.lak:3:5\n"
+    );
+}
+
+#[test]
+fn test_assert_in_imported_module_reports_module_path() {
+    let temp = tempdir().unwrap();
+    fs::create_dir(temp.path().join("lib")).unwrap();
+    fs::write(
+        temp.path().join("lib/check.lak"),
+        r#"pub fn positive(n: i64) -> void {
+    assert(n > 0)
+}"#,
+    )
+    .unwrap();
+
+    let output = run_lak(
+        r#"import "./lib/check"
+
+fn main() -> void {
+    check.positive(1)
+    check.positive(-1)
+}"#,
+        &[],
+        &[],
+        "",
+        Some(temp.path()),
+    );
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        format!(
+            "panic: assertion failed\n    at {}:2:5\n",
+            std::path::Path::new("lib").join("check.lak").display()
+        )
+    );
+}
@@ -0,0 +1,137 @@
+//! End-to-end tests for panic backtraces (`--backtrace`).
+//!
+//! These tests verify that programs compiled with `--backtrace` list the
+//! active Lak calls, innermost first, when they panic, and that programs
+//! compiled without the flag do not.
+
+mod common;
+
+use common::{executable_name, lak_binary};
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+use tempfile::tempdir;
+
+const MAIN: &str = r#"import "./lib/math"
+
+fn check(n: i64) -> i64 {
+    let half = math.halve(n)
+    return half
+}
+
+fn main() -> void {
+    println(check(4))
+    let _ = check(0)
+}"#;
+
+const MATH: &str = r#"pub fn halve(n: i64) -> i64 {
+    return div(100, n)
+}
+
+fn div(a: i64, b: i64) -> i64 {
+    return a / b
+}"#;
+
+/// Writes the test program into `dir` and returns the entry file path.
+fn write_program(dir: &Path) -> String {
+    fs::create_dir(dir.join("lib")).unwrap();
+    fs::write(dir.join("lib/math.lak"), MATH).unwrap();
+    let source_path = dir.join("main.lak");
+    fs::write(&source_path, MAIN).unwrap();
+    source_path.to_str().unwrap().to_string()
+}
+
+fn math_path() -> String {
+    Path::new("lib").join("math.lak").display().to_string()
+}
+
+fn expected_backtrace() -> String {
+    let math = math_path();
+    format!(
+        "panic: division by zero\n    at {math}:6:12\nbacktrace:\n  0: div at {math}:6:12\n  1: halve at {math}:2:12\n  2: check at main.lak:4:16\n  3: main at main.lak:10:13\n"
+    )
+}
+
+fn run(args: &[&str]) -> Output {
+    Command::new(lak_binary()).args(args).output().unwrap()
+}
+
+#[test]
+fn test_run_with_backtrace_lists_calls() {
+    let temp = tempdir().unwrap();
+    let source = write_program(temp.path());
+
+    let output = run(&["run", "--backtrace", &source]);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "25\n");
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        expected_backtrace()
+    );
+}
+
+#[test]
+fn test_run_without_backtrace_flag_has_no_backtrace() {
+    let temp = tempdir().unwrap();
+    let source = write_program(temp.path());
+
+    let output = run(&["run", &source]);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        format!("panic: division by zero\n    at {}:6:12\n", math_path())
+    );
+}
+
+#[test]
+fn test_build_with_backtrace() {
+    let temp = tempdir().unwrap();
+    let source = write_program(temp.path());
+    let executable = temp.path().join(executable_name("program"));
+
+    let build = run(&[
+        "build",
+        "--backtrace",
+        &source,
+        "-o",
+        executable.to_str().unwrap(),
+    ]);
+    assert!(
+        build.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&build.stderr)
+    );
+
+    let output = Command::new(&executable).output().unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        expected_backtrace()
+    );
+}
+
+#[test]
+fn test_backtrace_for_panic_in_main() {
+    let temp = tempdir().unwrap();
+    let source_path = temp.path().join("main.lak");
+    fs::write(
+        &source_path,
+        r#"fn noop() -> void {}
+
+fn main() -> void {
+    noop()
+    panic("stop")
+}"#,
+    )
+    .unwrap();
+
+    let output = run(&["run", "--backtrace", source_path.to_str().unwrap()]);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "panic: stop\n    at main.lak:5:5\nbacktrace:\n  0: main at main.lak:5:5\n"
+    );
+}
@@ -0,0 +1,160 @@
+//! End-to-end tests for block expressions.
+
+mod common;
+
+use common::compile_and_run;
+
+#[test]
+fn test_block_expression_basic() {
+    let output = compile_and_run(
+        r#"
+fn main() -> void {
+    let value: i64 = {
+        let x: i64 = 20
+        let y: i64 = 22
+        x + y
+    }
+    println(value)
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "42\n");
+}
+
+#[test]
+fn test_block_expression_result_assigned_to_inferred_variable() {
+    let output = compile_and_run(
+        r#"
+fn main() -> void {
+    let value = {
+        let s = "hello"
+        s
+    }
+    println(value)
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "hello\n");
+}
+
+#[test]
+fn test_block_expression_contextual_integer_type() {
+    let output = compile_and_run(
+        r#"
+fn main() -> void {
+    let value: u8 = { 255 }
+    println(value)
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "255\n");
+}
+
+#[test]
+fn test_block_expression_shadowing_is_scoped() {
+    let output = compile_and_run(
+        r#"
+fn main() -> void {
+    let x: i64 = 1
+    let y: i64 = {
+        let x: i64 = 10
+        x * 2
+    }
+    println(x)
+    println(y)
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "1\n20\n");
+}
+
+#[test]
+fn test_block_expression_side_effects_run_in_order() {
+    let output = compile_and_run(
+        r#"
+fn main() -> void {
+    let mut counter: i64 = 0
+    let value: i64 = {
+        println("inside")
+        counter = counter + 5
+        counter
+    }
+    println(value)
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "inside\n5\n");
+}
+
+#[test]
+fn test_block_expression_in_println_argument() {
+    let output = compile_and_run(
+        r#"
+fn main() -> void {
+    println({
+        let flag: bool = 1 < 2
+        flag
+    })
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "true\n");
+}
+
+#[test]
+fn test_block_expression_as_binary_operands() {
+    let output = compile_and_run(
+        r#"
+fn main() -> void {
+    let value: i32 = { 40 } + { 2 }
+    println(value)
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "42\n");
+}
+
+#[test]
+fn test_block_expression_as_function_argument_and_return_value() {
+    let output = compile_and_run(
+        r#"
+fn double(n: i64) -> i64 {
+    return {
+        let result: i64 = n * 2
+        result
+    }
+}
+
+fn main() -> void {
+    println(double({ 21 }))
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "42\n");
+}
+
+#[test]
+fn test_block_expression_nested_in_if_expression() {
+    let output = compile_and_run(
+        r#"
+fn main() -> void {
+    let value: string = if true {
+        { "nested" }
+    } else {
+        "other"
+    }
+    println(value)
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "nested\n");
+}
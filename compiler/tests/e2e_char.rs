@@ -0,0 +1,72 @@
+//! End-to-end tests for the `char` type.
+//!
+//! Tests character literals and escapes, printing, formatting, comparison,
+//! and chars passed to and returned from functions.
+
+mod common;
+
+use common::run_ok;
+
+#[test]
+fn test_print_char_literals() {
+    let stdout = run_ok(
+        r#"fn main() -> void {
+    let letter: char = 'a'
+    println(letter)
+    println('λ')
+    println('😀')
+    print('\'')
+    print('"')
+    println('\\')
+    eprintln('x')
+}"#,
+    );
+    assert_eq!(stdout, "a\nλ\n😀\n'\"\\\n");
+}
+
+#[test]
+fn test_char_inferred_and_returned() {
+    let stdout = run_ok(
+        r#"fn grade(score: i64) -> char {
+    if score >= 90 {
+        return 'A'
+    }
+    return 'B'
+}
+
+fn main() -> void {
+    let c = grade(95)
+    let d: char = if c == 'A' { 'B' } else { 'C' }
+    println(c)
+    println(d)
+}"#,
+    );
+    assert_eq!(stdout, "A\nB\n");
+}
+
+#[test]
+fn test_char_comparison() {
+    let stdout = run_ok(
+        r#"fn main() -> void {
+    let c = 'm'
+    println(c == 'm')
+    println(c != 'm')
+    println('a' < c)
+    println(c > 'z')
+    println('z' < 'é')
+    println(c <= 'm' && c >= 'm')
+}"#,
+    );
+    assert_eq!(stdout, "true\nfalse\ntrue\nfalse\ntrue\ntrue\n");
+}
+
+#[test]
+fn test_format_char() {
+    let stdout = run_ok(
+        r#"fn main() -> void {
+    let c = 'é'
+    println(format("[{}] [{:>3}] [{:*^5}] [{c}]", 'a', c, 'x'))
+}"#,
+    );
+    assert_eq!(stdout, "[a] [  é] [**x**] [é]\n");
+}
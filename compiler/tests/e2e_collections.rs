@@ -0,0 +1,215 @@
+//! End-to-end tests for the `map<K, V>` and `set<T>` collection types.
+
+mod common;
+
+use common::{run_failing, run_ok_leak_checked};
+
+#[test]
+fn test_map_insert_get_remove() {
+    let stdout = run_ok_leak_checked(
+        r#"fn main() -> void {
+    let mut ages: map<string, i64> = map()
+    ages.insert("alice", 30)
+    ages.insert("bob", 25)
+    ages.insert("alice", 31)
+    println(ages.len())
+    let alice = ages.get("alice")
+    println(alice.unwrap_or(-1))
+    println(alice.is_some())
+    let carol: Option<i64> = ages.get("carol")
+    println(carol.unwrap_or(0))
+    println(carol.is_some())
+    println(ages.contains("bob"))
+    ages.remove("bob")
+    ages.remove("bob")
+    println(ages.contains("bob"))
+    println(ages.len())
+}"#,
+    );
+    assert_eq!(stdout, "2\n31\ntrue\n0\nfalse\ntrue\nfalse\n1\n");
+}
+
+#[test]
+fn test_map_iteration_in_insertion_order() {
+    let stdout = run_ok_leak_checked(
+        r#"fn main() -> void {
+    let mut counts: map<string, i32> = map()
+    counts.insert("b", 2)
+    counts.insert("a", 3)
+    counts.insert("c", 1)
+    counts.insert("d", 4)
+    let mut i = 0
+    while i < counts.len() {
+        println(format("{}={}", counts.key_at(i), counts.value_at(i)))
+        i = i + 1
+    }
+    counts.remove("b")
+    counts.insert("a", 5)
+    i = 0
+    while i < counts.len() {
+        println(format("{}={}", counts.key_at(i), counts.value_at(i)))
+        i = i + 1
+    }
+}"#,
+    );
+    assert_eq!(stdout, "b=2\na=3\nc=1\nd=4\na=5\nc=1\nd=4\n");
+}
+
+#[test]
+fn test_map_key_and_value_types() {
+    let stdout = run_ok_leak_checked(
+        r#"fn main() -> void {
+    let mut names: map<i8, string> = map()
+    names.insert(-1i8, format("minus {}", 1))
+    names.insert(2i8, "two")
+    names.insert(-1i8, "negative one")
+    let name = names.get(-1i8)
+    println(name.unwrap_or(""))
+    let none = names.get(3i8)
+    println(format("[{}] {}", none.unwrap_or(""), none.is_none()))
+
+    let mut big: map<u64, bool> = map()
+    big.insert(18446744073709551615u64, true)
+    println(big.key_at(0))
+    println(big.value_at(0))
+
+    let mut flags: map<bool, f32> = map()
+    flags.insert(true, 1.5f32)
+    flags.insert(false, -0.25f32)
+    let half = flags.get(false)
+    println(half.unwrap_or(0.0f32))
+
+    let mut ratios: map<i64, f64> = map()
+    ratios.insert(7, 0.1)
+    println(ratios.value_at(0) * 10.0)
+
+    let mut counts: map<char, i64> = map()
+    counts.insert('é', 1)
+    counts.insert('a', 2)
+    counts.insert('é', 3)
+    let accented = counts.get('é')
+    println(accented.unwrap_or(0))
+    println(counts.key_at(1))
+}"#,
+    );
+    assert_eq!(
+        stdout,
+        "negative one\n[] true\n18446744073709551615\ntrue\n-0.25\n1\n3\na\n"
+    );
+}
+
+#[test]
+fn test_set_operations() {
+    let stdout = run_ok_leak_checked(
+        r#"fn main() -> void {
+    let mut seen: set<string> = set()
+    seen.insert("x")
+    seen.insert(format("{}", "y"))
+    seen.insert("x")
+    println(seen.len())
+    println(seen.contains("y"))
+    seen.insert("z")
+    seen.remove("x")
+    println(seen.contains("x"))
+    println(seen.item_at(0))
+    println(seen.item_at(1))
+
+    let mut primes: set<u16> = set()
+    primes.insert(2u16)
+    primes.insert(3u16)
+    primes.insert(5u16)
+    let mut total = 0u16
+    let mut i = 0
+    while i < primes.len() {
+        total = total + primes.item_at(i)
+        i = i + 1
+    }
+    println(total)
+
+    let mut vowels: set<char> = set()
+    vowels.insert('a')
+    vowels.insert('e')
+    vowels.insert('a')
+    println(vowels.len())
+    println(vowels.contains('e'))
+    println(vowels.contains('z'))
+}"#,
+    );
+    assert_eq!(stdout, "2\ntrue\nfalse\ny\nz\n10\n2\ntrue\nfalse\n");
+}
+
+#[test]
+fn test_collections_are_copied_on_write() {
+    let stdout = run_ok_leak_checked(
+        r#"fn make_set() -> set<i64> {
+    let mut items: set<i64> = set()
+    items.insert(1)
+    return items
+}
+
+fn count(items: set<i64>) -> i64 {
+    return items.len()
+}
+
+fn main() -> void {
+    let mut a = make_set()
+    let b = a
+    a.insert(2)
+    println(count(b))
+    a = set()
+    println(count(a))
+    println(b.contains(2))
+    let mut c = b
+    c.insert(3)
+    c.remove(1)
+    println(count(b))
+    println(c.contains(1))
+    println(c.item_at(0))
+}"#,
+    );
+    assert_eq!(stdout, "1\n0\nfalse\n1\nfalse\n3\n");
+}
+
+#[test]
+fn test_map_get_returns_option() {
+    let stdout = run_ok_leak_checked(
+        r#"fn lookup(names: map<i64, string>, id: i64) -> Option<string> {
+    return names.get(id)
+}
+
+fn describe(name: Option<string>) -> string {
+    if name.is_none() {
+        return "nobody"
+    }
+    return format("{}!", name.unwrap_or(""))
+}
+
+fn main() -> void {
+    let mut names: map<i64, string> = map()
+    names.insert(1, format("{}{}", "ada", "lovelace"))
+    let mut found = lookup(names, 1)
+    names.remove(1)
+    println(describe(found))
+    found = lookup(names, 1)
+    println(describe(found))
+    println(found.unwrap_or(format("{}", "default")))
+}"#,
+    );
+    assert_eq!(stdout, "adalovelace!\nnobody\ndefault\n");
+}
+
+#[test]
+fn test_map_index_out_of_range_panics() {
+    let stderr = run_failing(
+        r#"fn main() -> void {
+    let scores: map<string, i64> = map()
+    println(scores.key_at(0))
+}"#,
+    );
+    assert!(
+        stderr.contains("map index 0 out of range for length 0"),
+        "stderr: {}",
+        stderr
+    );
+    assert!(stderr.contains("main.lak:3:13"), "stderr: {}", stderr);
+}
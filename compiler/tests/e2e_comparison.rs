@@ -271,6 +271,27 @@ const SUCCESS_CASES: &[SuccessCase] = &[
 }"#,
         expected_stdout: "true\n",
     },
+    SuccessCase {
+        id: "test_float_comparison_nan_is_unordered",
+        source: r#"fn main() -> void {
+    let x: f64 = nan
+    println(x == x)
+    println(x != x)
+    println(x < 1.0)
+    println(x >= 1.0)
+}"#,
+        expected_stdout: "false\ntrue\nfalse\nfalse\n",
+    },
+    SuccessCase {
+        id: "test_float_comparison_infinity",
+        source: r#"fn main() -> void {
+    let big: f64 = 1e308
+    println(inf > big)
+    println(-inf < -big)
+    println(inf == inf)
+}"#,
+        expected_stdout: "true\ntrue\ntrue\n",
+    },
     SuccessCase {
         id: "test_comparison_with_i32_variables",
         source: r#"fn main() -> void {
@@ -0,0 +1,275 @@
+//! End-to-end tests for `defer` statements.
+
+mod common;
+
+use common::compile_and_run;
+
+#[test]
+fn test_defer_runs_at_end_of_function() {
+    let output = compile_and_run(
+        r#"
+fn main() -> void {
+    defer println("deferred")
+    println("body")
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "body\ndeferred\n");
+}
+
+#[test]
+fn test_defer_runs_in_reverse_order() {
+    let output = compile_and_run(
+        r#"
+fn main() -> void {
+    defer println("first")
+    defer println("second")
+    defer println("third")
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "third\nsecond\nfirst\n");
+}
+
+#[test]
+fn test_defer_block_form() {
+    let output = compile_and_run(
+        r#"
+fn main() -> void {
+    defer {
+        let msg: string = "cleanup"
+        println(msg)
+        println("done")
+    }
+    println("work")
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "work\ncleanup\ndone\n");
+}
+
+#[test]
+fn test_defer_runs_at_end_of_enclosing_block() {
+    let output = compile_and_run(
+        r#"
+fn main() -> void {
+    if true {
+        defer println("inner cleanup")
+        println("inner")
+    }
+    println("outer")
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "inner\ninner cleanup\nouter\n");
+}
+
+#[test]
+fn test_defer_runs_before_return() {
+    let output = compile_and_run(
+        r#"
+fn work(flag: bool) -> void {
+    defer println("cleanup")
+    if flag {
+        defer println("early cleanup")
+        println("early")
+        return
+    }
+    println("late")
+}
+
+fn main() -> void {
+    work(true)
+    work(false)
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "early\nearly cleanup\ncleanup\nlate\ncleanup\n");
+}
+
+#[test]
+fn test_defer_return_value_is_computed_before_defer_runs() {
+    let output = compile_and_run(
+        r#"
+fn compute() -> i64 {
+    let mut x: i64 = 1
+    defer {
+        x = 100
+        println(x)
+    }
+    return x + 1
+}
+
+fn main() -> void {
+    println(compute())
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "100\n2\n");
+}
+
+#[test]
+fn test_defer_sees_latest_variable_value() {
+    let output = compile_and_run(
+        r#"
+fn main() -> void {
+    let mut count: i64 = 0
+    defer println(count)
+    count = 42
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "42\n");
+}
+
+#[test]
+fn test_defer_runs_each_loop_iteration() {
+    let output = compile_and_run(
+        r#"
+fn main() -> void {
+    let mut i: i64 = 0
+    while i < 3 {
+        defer println("end of iteration")
+        i = i + 1
+        println(i)
+    }
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(
+        output,
+        "1\nend of iteration\n2\nend of iteration\n3\nend of iteration\n"
+    );
+}
+
+#[test]
+fn test_defer_runs_on_break_and_continue() {
+    let output = compile_and_run(
+        r#"
+fn main() -> void {
+    defer println("main done")
+    let mut i: i64 = 0
+    while true {
+        i = i + 1
+        defer println("iteration cleanup")
+        if i == 1 {
+            defer println("continue cleanup")
+            continue
+        }
+        if i == 2 {
+            defer println("break cleanup")
+            break
+        }
+    }
+    println("after loop")
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(
+        output,
+        "continue cleanup\niteration cleanup\nbreak cleanup\niteration cleanup\nafter loop\nmain done\n"
+    );
+}
+
+#[test]
+fn test_defer_not_registered_when_not_reached() {
+    let output = compile_and_run(
+        r#"
+fn work() -> void {
+    defer println("registered")
+    return
+}
+
+fn main() -> void {
+    work()
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "registered\n");
+}
+
+#[test]
+fn test_defer_uses_bindings_visible_at_defer() {
+    let output = compile_and_run(
+        r#"
+fn main() -> void {
+    let name: string = "outer"
+    if true {
+        defer println(name)
+        let name: i64 = 7
+        println(name)
+    }
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "7\nouter\n");
+}
+
+#[test]
+fn test_defer_with_loop_inside_body() {
+    let output = compile_and_run(
+        r#"
+fn main() -> void {
+    defer {
+        let mut n: i64 = 0
+        while true {
+            n = n + 1
+            if n == 3 {
+                break
+            }
+        }
+        println(n)
+    }
+    println("start")
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "start\n3\n");
+}
+
+#[test]
+fn test_nested_defer_inside_defer_block() {
+    let output = compile_and_run(
+        r#"
+fn main() -> void {
+    defer {
+        defer println("nested")
+        println("outer defer")
+    }
+    println("body")
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "body\nouter defer\nnested\n");
+}
+
+#[test]
+fn test_defer_in_block_expression() {
+    let output = compile_and_run(
+        r#"
+fn main() -> void {
+    let value: i64 = {
+        defer println("block cleanup")
+        println("block body")
+        5
+    }
+    println(value)
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "block body\nblock cleanup\n5\n");
+}
@@ -0,0 +1,233 @@
+//! End-to-end tests for tuples and destructuring patterns.
+
+mod common;
+
+use common::compile_and_run;
+
+#[test]
+fn test_let_tuple_destructuring() {
+    let output = compile_and_run(
+        r#"
+fn main() -> void {
+    let pair: (i64, string) = (42, "answer")
+    let (n, label) = pair
+    println(label)
+    println(n)
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "answer\n42\n");
+}
+
+#[test]
+fn test_let_tuple_destructuring_inferred_literal() {
+    let output = compile_and_run(
+        r#"
+fn main() -> void {
+    let (a, b, c) = (1, true, "three")
+    println(a)
+    println(b)
+    println(c)
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "1\ntrue\nthree\n");
+}
+
+#[test]
+fn test_let_tuple_destructuring_with_annotation_adapts_literals() {
+    let output = compile_and_run(
+        r#"
+fn main() -> void {
+    let (small, big): (u8, u64) = (255, 18446744073709551615)
+    println(small)
+    println(big)
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "255\n18446744073709551615\n");
+}
+
+#[test]
+fn test_wildcard_in_tuple_pattern() {
+    let output = compile_and_run(
+        r#"
+fn main() -> void {
+    let (_, second, _) = (1, 2, 3)
+    println(second)
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "2\n");
+}
+
+#[test]
+fn test_nested_tuple_pattern() {
+    let output = compile_and_run(
+        r#"
+fn main() -> void {
+    let nested: (i32, (string, bool)) = (7, ("inner", false))
+    let (num, (text, flag)) = nested
+    println(num)
+    println(text)
+    println(flag)
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "7\ninner\nfalse\n");
+}
+
+#[test]
+fn test_mutable_tuple_pattern_bindings() {
+    let output = compile_and_run(
+        r#"
+fn main() -> void {
+    let mut (x, y) = (1, 2)
+    x = x + 10
+    y = y + 20
+    println(x)
+    println(y)
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "11\n22\n");
+}
+
+#[test]
+fn test_function_returning_tuple() {
+    let output = compile_and_run(
+        r#"
+fn div_mod(a: i64, b: i64) -> (i64, i64) {
+    return (a / b, a % b)
+}
+
+fn main() -> void {
+    let (q, r) = div_mod(17, 5)
+    println(q)
+    println(r)
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "3\n2\n");
+}
+
+#[test]
+fn test_destructured_function_parameter() {
+    let output = compile_and_run(
+        r#"
+fn describe((name, age): (string, i32), suffix: string) -> void {
+    println(name)
+    println(age)
+    println(suffix)
+}
+
+fn main() -> void {
+    describe(("lak", 3), "done")
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "lak\n3\ndone\n");
+}
+
+#[test]
+fn test_tuple_passed_through_variables_and_calls() {
+    let output = compile_and_run(
+        r#"
+fn swap((a, b): (i64, string)) -> (string, i64) {
+    return (b, a)
+}
+
+fn main() -> void {
+    let original: (i64, string) = (5, "five")
+    let swapped = swap(original)
+    let (s, n) = swapped
+    println(s)
+    println(n)
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "five\n5\n");
+}
+
+#[test]
+fn test_tuple_from_if_expression() {
+    let output = compile_and_run(
+        r#"
+fn main() -> void {
+    let flag: bool = false
+    let (a, b) = if flag { (1, "yes") } else { (2, "no") }
+    println(a)
+    println(b)
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "2\nno\n");
+}
+
+#[test]
+fn test_pattern_bindings_in_block_expression() {
+    let output = compile_and_run(
+        r#"
+fn main() -> void {
+    let total: i64 = {
+        let (x, y) = (40, 2)
+        x + y
+    }
+    println(total)
+    println({
+        let (_, label) = (0, "block")
+        label
+    })
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "42\nblock\n");
+}
+
+#[test]
+fn test_pattern_bindings_shadow_outer_variables() {
+    let output = compile_and_run(
+        r#"
+fn main() -> void {
+    let a: string = "outer"
+    if true {
+        let (a, _) = (1, 2)
+        println(a)
+    }
+    println(a)
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "1\nouter\n");
+}
+
+#[test]
+fn test_tuple_elements_evaluated_left_to_right() {
+    let output = compile_and_run(
+        r#"
+fn trace(label: string, value: i64) -> i64 {
+    println(label)
+    return value
+}
+
+fn main() -> void {
+    let (a, b) = (trace("first", 1), trace("second", 2))
+    println(a + b)
+}
+"#,
+    )
+    .unwrap();
+    assert_eq!(output, "first\nsecond\n3\n");
+}
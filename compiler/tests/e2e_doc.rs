@@ -0,0 +1,118 @@
+//! End-to-end tests for the `lak doc` command.
+//!
+//! These tests verify that the `doc` command walks the module graph and
+//! writes cross-linked HTML or Markdown pages.
+
+mod common;
+
+use common::lak_binary;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use tempfile::tempdir;
+
+fn write_project(dir: &Path) {
+    fs::write(
+        dir.join("main.lak"),
+        r#"import "./utils"
+
+fn main() -> void {
+    utils.greet("world")
+}
+"#,
+    )
+    .unwrap();
+    fs::write(
+        dir.join("utils.lak"),
+        r#"/// Prints a greeting for `name`.
+pub fn greet(name: string) -> void {
+    println(name)
+}
+
+/* Not public, so not documented. */
+fn hidden() -> void {}
+"#,
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_doc_html() {
+    let temp = tempdir().unwrap();
+    write_project(temp.path());
+
+    let output = Command::new(lak_binary())
+        .current_dir(temp.path())
+        .args(["doc", "main.lak"])
+        .output()
+        .unwrap();
+
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout).trim(),
+        format!(
+            "Documented: {}",
+            Path::new("doc").join("index.html").display()
+        )
+    );
+
+    let doc_dir = temp.path().join("doc");
+    let index = fs::read_to_string(doc_dir.join("index.html")).unwrap();
+    assert!(index.contains("<a href=\"utils.html#fn.greet\">"));
+
+    let main = fs::read_to_string(doc_dir.join("main.html")).unwrap();
+    assert!(main.contains("<li><a href=\"utils.html\">utils</a></li>"));
+
+    let utils = fs::read_to_string(doc_dir.join("utils.html")).unwrap();
+    assert!(utils.contains("<pre><code>pub fn greet(name: string) -&gt; void</code></pre>"));
+    assert!(utils.contains("<p>Prints a greeting for `name`.</p>"));
+    assert!(!utils.contains("hidden"));
+}
+
+#[test]
+fn test_doc_markdown_custom_output() {
+    let temp = tempdir().unwrap();
+    write_project(temp.path());
+
+    let output = Command::new(lak_binary())
+        .current_dir(temp.path())
+        .args(["doc", "main.lak", "-o", "api", "--format", "markdown"])
+        .output()
+        .unwrap();
+
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let api_dir = temp.path().join("api");
+    assert!(api_dir.join("index.md").exists());
+    assert!(api_dir.join("main.md").exists());
+    let utils = fs::read_to_string(api_dir.join("utils.md")).unwrap();
+    assert!(utils.contains("```lak\npub fn greet(name: string) -> void\n```"));
+    assert!(utils.contains("Prints a greeting for `name`."));
+}
+
+#[test]
+fn test_doc_missing_import() {
+    let temp = tempdir().unwrap();
+    fs::write(
+        temp.path().join("main.lak"),
+        "import \"./missing\"\n\nfn main() -> void {}\n",
+    )
+    .unwrap();
+
+    let output = Command::new(lak_binary())
+        .current_dir(temp.path())
+        .args(["doc", "main.lak"])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(!temp.path().join("doc").exists());
+}
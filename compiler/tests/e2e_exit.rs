@@ -0,0 +1,168 @@
+//! End-to-end tests for exit codes: `main` returning `i32`/`u8` and the
+//! `exit` built-in function.
+//!
+//! These tests verify that the value returned by `main` or passed to `exit`
+//! becomes the exit status of the program, also when forwarded by `lak run`.
+
+mod common;
+
+use common::run_lak;
+
+#[test]
+fn test_main_returning_i32_sets_exit_code() {
+    let output = run_lak(
+        r#"fn main() -> i32 {
+    println("failing")
+    return 3
+}"#,
+        &[],
+        &[],
+        "",
+        None,
+    );
+    assert_eq!(output.status.code(), Some(3));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "failing\n");
+}
+
+#[test]
+fn test_main_returning_zero_succeeds() {
+    let output = run_lak(
+        r#"fn main() -> i32 {
+    return 0
+}"#,
+        &[],
+        &[],
+        "",
+        None,
+    );
+    assert_eq!(output.status.code(), Some(0));
+}
+
+#[test]
+fn test_main_returning_u8_sets_exit_code() {
+    let output = run_lak(
+        r#"fn main() -> u8 {
+    let code: u8 = 200
+    return code
+}"#,
+        &[],
+        &[],
+        "",
+        None,
+    );
+    assert_eq!(output.status.code(), Some(200));
+}
+
+#[test]
+fn test_main_returning_byte_alias_sets_exit_code() {
+    let output = run_lak(
+        r#"fn main() -> byte {
+    return 7
+}"#,
+        &[],
+        &[],
+        "",
+        None,
+    );
+    assert_eq!(output.status.code(), Some(7));
+}
+
+#[test]
+fn test_main_return_value_from_function_and_branch() {
+    let output = run_lak(
+        r#"fn check(n: i32) -> bool {
+    return n > 10
+}
+
+fn main() -> i32 {
+    defer println("cleanup")
+    if check(5) {
+        return 0
+    }
+    return 42
+}"#,
+        &[],
+        &[],
+        "",
+        None,
+    );
+    assert_eq!(output.status.code(), Some(42));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "cleanup\n");
+}
+
+#[test]
+fn test_exit_from_main() {
+    let output = run_lak(
+        r#"fn main() -> void {
+    println("before")
+    exit(7)
+    println("after")
+}"#,
+        &[],
+        &[],
+        "",
+        None,
+    );
+    assert_eq!(output.status.code(), Some(7));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "before\n");
+}
+
+#[test]
+fn test_exit_from_nested_function_skips_defer() {
+    let output = run_lak(
+        r#"fn fail(code: i32) -> void {
+    print("exiting")
+    exit(code)
+}
+
+fn main() -> i32 {
+    defer println("deferred")
+    fail(5)
+    return 0
+}"#,
+        &[],
+        &[],
+        "",
+        None,
+    );
+    assert_eq!(output.status.code(), Some(5));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "exiting");
+}
+
+#[test]
+fn test_exit_zero_in_branch() {
+    let output = run_lak(
+        r#"fn main() -> void {
+    let done = true
+    if done {
+        exit(0)
+    }
+    panic("unreachable")
+}"#,
+        &[],
+        &[],
+        "",
+        None,
+    );
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "");
+}
+
+#[test]
+fn test_exit_skips_leak_report() {
+    let output = run_lak(
+        r#"fn main() -> void {
+    let name = format("{}-{}", "heap", 1)
+    println(name)
+    exit(3)
+}"#,
+        &[("LAK_LEAK_CHECK", "1")],
+        &[],
+        "",
+        None,
+    );
+
+    assert_eq!(output.status.code(), Some(3));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "heap-1\n");
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "");
+}
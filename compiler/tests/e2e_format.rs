@@ -0,0 +1,186 @@
+//! End-to-end tests for the `format` built-in function.
+//!
+//! Tests width, alignment, precision, radix and sign specifiers, named
+//! placeholders, and using formatted strings with other builtins.
+
+mod common;
+
+use common::compile_and_run;
+
+// =============================================================================
+// Basic placeholders
+// =============================================================================
+
+#[test]
+fn test_format_positional_arguments() {
+    let output = compile_and_run(
+        r#"fn main() -> void {
+    let name: string = "lak"
+    println(format("{} is {} years old, {}", name, 3, true))
+}"#,
+    )
+    .unwrap();
+    assert_eq!(output, "lak is 3 years old, true\n");
+}
+
+#[test]
+fn test_format_explicit_indices_and_escaped_braces() {
+    let output = compile_and_run(
+        r#"fn main() -> void {
+    println(format("{{{1}, {0}, {1}}}", "a", "b"))
+}"#,
+    )
+    .unwrap();
+    assert_eq!(output, "{b, a, b}\n");
+}
+
+#[test]
+fn test_format_named_placeholders() {
+    let output = compile_and_run(
+        r#"fn main() -> void {
+    let x: f64 = 2.0 / 3.0
+    let n: i32 = 255
+    println(format("{x:8.3}|{n:x}|{n:08b}"))
+}"#,
+    )
+    .unwrap();
+    assert_eq!(output, "   0.667|ff|11111111\n");
+}
+
+// =============================================================================
+// Specifiers
+// =============================================================================
+
+#[test]
+fn test_format_width_and_alignment() {
+    let output = compile_and_run(
+        r#"fn main() -> void {
+    println(format("[{:<6}][{:^6}][{:>6}]", "ab", "ab", "ab"))
+    println(format("[{:6}][{:<6}][{:*^7}]", 42, 42, 42))
+}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        output,
+        "[ab    ][  ab  ][    ab]\n[    42][42    ][**42***]\n"
+    );
+}
+
+#[test]
+fn test_format_float_precision_and_exponent() {
+    let output = compile_and_run(
+        r#"fn main() -> void {
+    let small: f32 = 0.1
+    println(format("{:.2} {:.0} {} {:e} {:.2E}", 3.14159, 2.5, small, 1500.0, 0.000123))
+}"#,
+    )
+    .unwrap();
+    assert_eq!(output, "3.14 2 0.1 1.5e3 1.23E-4\n");
+}
+
+#[test]
+fn test_format_special_floats() {
+    let output = compile_and_run(
+        r#"fn main() -> void {
+    let half: f32 = nan
+    println(format("{} {:.2} {:>5} {} {}", nan, nan, half, inf, -inf))
+}"#,
+    )
+    .unwrap();
+    assert_eq!(output, "nan nan   nan inf -inf\n");
+}
+
+#[test]
+fn test_format_radix_sign_and_zero_padding() {
+    let output = compile_and_run(
+        r#"fn main() -> void {
+    let byte: u8 = 10
+    let negative: i16 = -42
+    println(format("{:x} {:X} {:o} {:b} {:#x} {:#010b}", 255, 255, 8, byte, 255, byte))
+    println(format("{:+} {:+} {:05} {:+07.2}", 7, negative, negative, 1.5))
+}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        output,
+        "ff FF 10 1010 0xff 0b00001010\n+7 -42 -0042 +001.50\n"
+    );
+}
+
+#[test]
+fn test_format_unsigned_max_and_string_truncation() {
+    let output = compile_and_run(
+        r#"fn main() -> void {
+    let big: u64 = 18446744073709551615
+    println(format("{} {:x}", big, big))
+    println(format("[{:.3}] [{:5.2}]", "abcdef", "abcdef"))
+}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        output,
+        "18446744073709551615 ffffffffffffffff\n[abc] [ab   ]\n"
+    );
+}
+
+// =============================================================================
+// Using formatted strings
+// =============================================================================
+
+#[test]
+fn test_format_table_rows() {
+    let output = compile_and_run(
+        r#"fn row(label: string, value: f64) -> string {
+    return format("{:<8}|{:>8.2}", label, value)
+}
+
+fn main() -> void {
+    println(row("alpha", 1.5))
+    println(row("beta", 22.126))
+}"#,
+    )
+    .unwrap();
+    assert_eq!(output, "alpha   |    1.50\nbeta    |   22.13\n");
+}
+
+#[test]
+fn test_format_result_in_variable_and_comparison() {
+    let output = compile_and_run(
+        r#"fn main() -> void {
+    let s = format("{}-{}", 1, 2)
+    print(s)
+    println(format("{}", s == "1-2"))
+}"#,
+    )
+    .unwrap();
+    assert_eq!(output, "1-2true\n");
+}
+
+#[test]
+fn test_format_without_placeholders_and_discard() {
+    let output = compile_and_run(
+        r#"fn main() -> void {
+    let _ = format("ignored {}", 1)
+    println(format("plain"))
+    println(format(""))
+}"#,
+    )
+    .unwrap();
+    assert_eq!(output, "plain\n\n");
+}
+
+#[test]
+fn test_format_arguments_evaluated_once_in_order() {
+    let output = compile_and_run(
+        r#"fn next(n: i64) -> i64 {
+    print(n)
+    return n
+}
+
+fn main() -> void {
+    println(format(" {1}{0}{1}", next(1), next(2)))
+}"#,
+    )
+    .unwrap();
+    assert_eq!(output, "12 212\n");
+}
@@ -0,0 +1,152 @@
+//! End-to-end tests for the `fs` standard module.
+//!
+//! These tests import `fs` and run programs with `lak run` inside a
+//! temporary directory, so relative paths refer to that directory.
+
+mod common;
+
+use common::run_ok_leak_checked_in;
+use std::fs;
+use tempfile::tempdir;
+
+#[test]
+fn test_fs_write_read_append() {
+    let temp = tempdir().unwrap();
+    let stdout = run_ok_leak_checked_in(
+        Some(temp.path()),
+        r#"import "fs"
+
+fn main() -> void {
+    let err = fs.write_file("notes.txt", "one\n")
+    println(err == "")
+    let _ = fs.append_file("notes.txt", "two\n")
+    let (text, read_err) = fs.read_file("notes.txt")
+    print(text)
+    println(read_err == "")
+}"#,
+        &[],
+    );
+    assert_eq!(stdout, "true\none\ntwo\ntrue\n");
+    assert_eq!(
+        fs::read_to_string(temp.path().join("notes.txt")).unwrap(),
+        "one\ntwo\n"
+    );
+}
+
+#[test]
+fn test_fs_read_missing_file_reports_error() {
+    let temp = tempdir().unwrap();
+    let stdout = run_ok_leak_checked_in(
+        Some(temp.path()),
+        r#"import "fs"
+
+fn main() -> void {
+    let (text, err) = fs.read_file("missing.txt")
+    println(text == "")
+    println(err)
+}"#,
+        &[],
+    );
+    assert!(
+        stdout.starts_with("true\nmissing.txt: "),
+        "stdout: {}",
+        stdout
+    );
+}
+
+#[test]
+fn test_fs_read_file_with_nul_byte_reports_error() {
+    let temp = tempdir().unwrap();
+    fs::write(temp.path().join("binary.dat"), b"ab\0c").unwrap();
+    let stdout = run_ok_leak_checked_in(
+        Some(temp.path()),
+        r#"import "fs"
+
+fn main() -> void {
+    let (text, err) = fs.read_file("binary.dat")
+    println(text == "")
+    println(err)
+}"#,
+        &[],
+    );
+    assert_eq!(
+        stdout,
+        "true\nbinary.dat: contains a NUL byte at offset 2\n"
+    );
+}
+
+#[test]
+fn test_fs_directories() {
+    let temp = tempdir().unwrap();
+    let stdout = run_ok_leak_checked_in(
+        Some(temp.path()),
+        r#"import "fs"
+
+fn main() -> void {
+    println(fs.create_dir("data/nested"))
+    let _ = fs.write_file("data/b.txt", "b")
+    let _ = fs.write_file("data/a.txt", "a")
+    let (entries, err) = fs.list_dir("data")
+    println(entries)
+    println(err)
+    println(fs.exists("data/a.txt"))
+    println(fs.remove("data/a.txt"))
+    println(fs.exists("data/a.txt"))
+    println(fs.remove("data") != "")
+}"#,
+        &[],
+    );
+    assert_eq!(stdout, "\na.txt\nb.txt\nnested\n\ntrue\n\nfalse\ntrue\n");
+    assert!(temp.path().join("data/nested").is_dir());
+}
+
+#[test]
+fn test_fs_list_dir_rejects_newline_in_entry_name() {
+    let temp = tempdir().unwrap();
+    fs::create_dir(temp.path().join("data")).unwrap();
+    fs::write(temp.path().join("data/ok.txt"), "").unwrap();
+    fs::write(temp.path().join("data/two\nlines.txt"), "").unwrap();
+    let stdout = run_ok_leak_checked_in(
+        Some(temp.path()),
+        r#"import "fs"
+
+fn main() -> void {
+    let (entries, err) = fs.list_dir("data")
+    println(entries == "")
+    println(err)
+}"#,
+        &[],
+    );
+    assert_eq!(
+        stdout,
+        "true\ndata: entry name \"two\\nlines.txt\" contains a newline\n"
+    );
+}
+
+#[test]
+fn test_fs_with_alias_in_imported_module() {
+    let temp = tempdir().unwrap();
+    fs::write(
+        temp.path().join("store.lak"),
+        r#"import "fs" as files
+
+pub fn save(text: string) -> bool {
+    return files.write_file("saved.txt", text) == ""
+}
+"#,
+    )
+    .unwrap();
+    let stdout = run_ok_leak_checked_in(
+        Some(temp.path()),
+        r#"import "./store"
+import "fs"
+
+fn main() -> void {
+    println(store.save("hello"))
+    let (text, _) = fs.read_file("saved.txt")
+    println(text)
+}"#,
+        &[],
+    );
+    assert_eq!(stdout, "true\nhello\n");
+}
@@ -0,0 +1,152 @@
+//! End-to-end tests for the `math` standard module.
+//!
+//! These tests verify that math functions work for each numeric type, with
+//! the operand type taken from the expected type or from the arguments.
+
+mod common;
+
+use common::{run_failing, run_ok};
+
+#[test]
+fn test_math_float_functions_f64() {
+    let stdout = run_ok(
+        r#"import "math"
+
+fn main() -> void {
+    println(math.sqrt(16.0))
+    println(math.pow(2.0, 10.0))
+    println(math.sin(0.0))
+    println(math.cos(0.0))
+    println(math.exp(0.0))
+    println(math.log(1.0))
+    println(math.floor(2.7))
+    println(math.ceil(2.2))
+    println(math.floor(-2.5))
+}"#,
+    );
+    assert_eq!(stdout, "4\n1024\n0\n1\n1\n0\n2\n3\n-3\n");
+}
+
+#[test]
+fn test_math_float_functions_f32() {
+    let stdout = run_ok(
+        r#"import "math"
+
+fn main() -> void {
+    let x: f32 = 2.25
+    let root = math.sqrt(x)
+    let y: f32 = math.pow(root, 2.0)
+    println(root)
+    println(y)
+    let z: f32 = math.abs(-1.5)
+    println(z)
+}"#,
+    );
+    assert_eq!(stdout, "1.5\n2.25\n1.5\n");
+}
+
+#[test]
+fn test_math_abs_signed_integers() {
+    let stdout = run_ok(
+        r#"import "math"
+
+fn main() -> void {
+    let a: i8 = -7
+    println(math.abs(a))
+    let b: i32 = math.abs(-40)
+    println(b)
+    println(math.abs(-9000000000))
+    println(math.abs(5))
+}"#,
+    );
+    assert_eq!(stdout, "7\n40\n9000000000\n5\n");
+}
+
+#[test]
+fn test_math_min_max_clamp_integers() {
+    let stdout = run_ok(
+        r#"import "math"
+
+fn main() -> void {
+    let a: u8 = 200
+    println(math.max(a, 100))
+    println(math.min(a, 100))
+    let b: i16 = -3
+    println(math.min(b, 2))
+    println(math.max(1, b))
+    let big: u64 = 18446744073709551615
+    println(math.max(big, 1))
+    println(math.clamp(15, 0, 10))
+    println(math.clamp(-5, 0, 10))
+    let c: u32 = 7
+    println(math.clamp(c, 1, 5))
+}"#,
+    );
+    assert_eq!(stdout, "200\n100\n-3\n1\n18446744073709551615\n10\n0\n5\n");
+}
+
+#[test]
+fn test_math_min_max_clamp_floats() {
+    let stdout = run_ok(
+        r#"import "math"
+
+fn main() -> void {
+    println(math.min(1.5, -2.0))
+    println(math.max(1.5, -2.0))
+    let x: f32 = 3.5
+    println(math.clamp(x, 0.0, 1.0))
+    println(math.clamp(0.25, 0.0, 1.0))
+}"#,
+    );
+    assert_eq!(stdout, "-2\n1.5\n1\n0.25\n");
+}
+
+#[test]
+fn test_math_with_alias_in_expressions() {
+    let stdout = run_ok(
+        r#"import "math" as m
+
+fn hypot(a: f64, b: f64) -> f64 {
+    return m.sqrt(a * a + b * b)
+}
+
+fn main() -> void {
+    println(hypot(3.0, 4.0))
+    if m.max(2, 3) > 2 {
+        println("max ok")
+    }
+    let n = m.abs(-4) + 1
+    println(n)
+}"#,
+    );
+    assert_eq!(stdout, "5\nmax ok\n5\n");
+}
+
+#[test]
+fn test_math_abs_overflow_panics() {
+    let stderr = run_failing(
+        r#"import "math"
+
+fn main() -> void {
+    let x: i8 = -128
+    println(math.abs(x))
+}"#,
+    );
+    assert!(stderr.contains("integer overflow"), "stderr: {}", stderr);
+}
+
+#[test]
+fn test_math_clamp_invalid_range_panics() {
+    let stderr = run_failing(
+        r#"import "math"
+
+fn main() -> void {
+    println(math.clamp(5, 10, 0))
+}"#,
+    );
+    assert!(
+        stderr.contains("clamp called with low greater than high"),
+        "stderr: {}",
+        stderr
+    );
+}
@@ -0,0 +1,159 @@
+//! End-to-end tests for reference-counted strings.
+//!
+//! These tests run programs with the runtime's leak check enabled
+//! (`LAK_LEAK_CHECK=1`) and verify that every heap string is released by
+//! the time the program exits.
+
+mod common;
+
+use common::{run_lak, run_ok_leak_checked};
+
+#[test]
+fn test_leak_check_disabled_by_default() {
+    let output = run_lak(
+        r#"fn main() -> void {
+    let s = format("{}", 1)
+    println(s)
+}"#,
+        &[],
+        &[],
+        "",
+        None,
+    );
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "1\n");
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "");
+}
+
+#[test]
+fn test_leak_check_reports_without_allocations() {
+    let stdout = run_ok_leak_checked(
+        r#"fn main() -> void {
+    println(1 + 2)
+}"#,
+    );
+    assert_eq!(stdout, "3\n");
+}
+
+#[test]
+fn test_no_leaks_for_variables_and_reassignment() {
+    let stdout = run_ok_leak_checked(
+        r#"fn main() -> void {
+    let mut s = format("a{}", 1)
+    s = format("{}b", s)
+    s = s
+    s = "literal"
+    let t = s
+    println(t)
+    println(format("{} {}", 2, 3))
+    let _ = format("discarded")
+}"#,
+    );
+    assert_eq!(stdout, "literal\n2 3\n");
+}
+
+#[test]
+fn test_no_leaks_across_function_calls() {
+    let stdout = run_ok_leak_checked(
+        r#"fn label(n: i64) -> string {
+    let text = format("n{}", n)
+    return text
+}
+
+fn echo(s: string) -> string {
+    return s
+}
+
+fn pair(n: i64) -> (string, i64) {
+    return (label(n), n)
+}
+
+fn main() -> void {
+    println(echo(label(1)))
+    let _ = label(2)
+    let (name, n) = pair(3)
+    println(name)
+    println(n)
+    let whole = pair(4)
+    let (_, m) = whole
+    println(m)
+}"#,
+    );
+    assert_eq!(stdout, "n1\nn3\n3\n4\n");
+}
+
+#[test]
+fn test_no_leaks_in_loops_and_early_exits() {
+    let stdout = run_ok_leak_checked(
+        r#"fn find(limit: i64) -> string {
+    let mut i = 0
+    while true {
+        let s = format("item {}", i)
+        if i == limit {
+            return s
+        }
+        i = i + 1
+    }
+    return "none"
+}
+
+fn main() -> void {
+    let mut i = 0
+    while format("{}", i) != "5" {
+        let s = format("{}", i)
+        i = i + 1
+        if s == "1" {
+            continue
+        }
+        if s == "3" {
+            break
+        }
+        println(s)
+    }
+    println(find(2))
+}"#,
+    );
+    assert_eq!(stdout, "0\n2\nitem 2\n");
+}
+
+#[test]
+fn test_no_leaks_for_expression_values_and_defer() {
+    let stdout = run_ok_leak_checked(
+        r#"fn main() -> void {
+    let flag = true
+    let a = if flag { format("yes {}", 1) } else { "no" }
+    let b = {
+        let inner = format("inner")
+        inner
+    }
+    defer println(format("deferred {}", a))
+    if flag && format("{}", b) == "inner" {
+        println(b)
+    }
+    println(a)
+}"#,
+    );
+    assert_eq!(stdout, "inner\nyes 1\ndeferred yes 1\n");
+}
+
+#[test]
+fn test_panic_skips_leak_report() {
+    let output = run_lak(
+        r#"fn main() -> void {
+    let name = format("{}-{}", "heap", 1)
+    println(name)
+    assert_eq(name, "other")
+}"#,
+        &[("LAK_LEAK_CHECK", "1")],
+        &[],
+        "",
+        None,
+    );
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "heap-1\n");
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "panic: assertion `left == right` failed\n  left: heap-1\n right: other\n    at main.lak:4:5\n"
+    );
+}
@@ -1,7 +1,8 @@
 //! End-to-end tests for the `panic` built-in function.
 //!
 //! These tests verify that `panic` correctly terminates the program
-//! with exit code 1 and outputs the error message to stderr.
+//! with exit code 1 and outputs the error message and its source location
+//! to stderr.
 
 mod common;
 
@@ -36,7 +37,7 @@ fn test_panic_string_literal() {
     );
     assert_eq!(
         String::from_utf8_lossy(&output.stderr),
-        "panic: something went wrong\n"
+        "panic: something went wrong\n    at panic.lak:2:5\n"
     );
 }
 
@@ -63,7 +64,7 @@ fn test_panic_string_variable() {
     assert_eq!(output.status.code(), Some(1));
     assert_eq!(
         String::from_utf8_lossy(&output.stderr),
-        "panic: error message\n"
+        "panic: error message\n    at panic_var.lak:3:5\n"
     );
 }
 
@@ -92,7 +93,10 @@ fn test_panic_before_println() {
     // "before" should be printed to stdout
     assert_eq!(String::from_utf8_lossy(&output.stdout), "before\n");
     // panic message should be on stderr
-    assert_eq!(String::from_utf8_lossy(&output.stderr), "panic: abort\n");
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "panic: abort\n    at panic_before.lak:3:5\n"
+    );
 }
 
 #[test]
@@ -115,7 +119,10 @@ fn test_panic_empty_message() {
 
     assert!(!output.status.success());
     assert_eq!(output.status.code(), Some(1));
-    assert_eq!(String::from_utf8_lossy(&output.stderr), "panic: \n");
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "panic: \n    at panic_empty.lak:2:5\n"
+    );
 }
 
 #[test]
@@ -140,7 +147,7 @@ fn test_panic_with_escape_sequences() {
     assert_eq!(output.status.code(), Some(1));
     assert_eq!(
         String::from_utf8_lossy(&output.stderr),
-        "panic: line1\nline2\ttab\n"
+        "panic: line1\nline2\ttab\n    at panic_escape.lak:2:5\n"
     );
 }
 
@@ -165,17 +172,49 @@ fn test_panic_if_expression_string() {
 
     assert!(!output.status.success());
     assert_eq!(output.status.code(), Some(1));
-    assert_eq!(String::from_utf8_lossy(&output.stderr), "panic: left\n");
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "panic: left\n    at panic_if_expr.lak:3:5\n"
+    );
 }
 
 #[test]
-fn test_user_function_name_lak_panic_does_not_collide_with_builtin() {
+fn test_panic_block_expression_string() {
+    let temp = tempdir().unwrap();
+    let source_path = temp.path().join("panic_block_expr.lak");
+
+    fs::write(
+        &source_path,
+        r#"fn main() -> void {
+    panic({
+        let msg: string = "from block"
+        msg
+    })
+}"#,
+    )
+    .unwrap();
+
+    let output = Command::new(lak_binary())
+        .args(["run", source_path.to_str().unwrap()])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "panic: from block\n    at panic_block_expr.lak:2:5\n"
+    );
+}
+
+#[test]
+fn test_user_function_name_lak_panic_at_does_not_collide_with_builtin() {
     let temp = tempdir().unwrap();
     let source_path = temp.path().join("panic_collision.lak");
 
     fs::write(
         &source_path,
-        r#"fn lak_panic() -> void {
+        r#"fn lak_panic_at() -> void {
     println("fake panic")
 }
 
@@ -197,5 +236,50 @@ fn main() -> void {
         "panic should exit with code 1"
     );
     assert_eq!(String::from_utf8_lossy(&output.stdout), "");
-    assert_eq!(String::from_utf8_lossy(&output.stderr), "panic: x\n");
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "panic: x\n    at panic_collision.lak:6:5\n"
+    );
+}
+
+#[test]
+fn test_panic_in_imported_module_reports_module_path() {
+    let temp = tempdir().unwrap();
+    fs::create_dir(temp.path().join("lib")).unwrap();
+    fs::write(
+        temp.path().join("lib/math.lak"),
+        r#"pub fn halve(n: i64) -> i64 {
+    if n < 0 {
+        panic("negative input")
+    }
+    return 100 / n
+}"#,
+    )
+    .unwrap();
+    let source_path = temp.path().join("main.lak");
+    fs::write(
+        &source_path,
+        r#"import "./lib/math"
+
+fn main() -> void {
+    println(math.halve(4))
+    println(math.halve(0))
+}"#,
+    )
+    .unwrap();
+
+    let output = Command::new(lak_binary())
+        .args(["run", source_path.to_str().unwrap()])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "25\n");
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        format!(
+            "panic: division by zero\n    at {}:5:12\n",
+            std::path::Path::new("lib").join("math.lak").display()
+        )
+    );
 }
@@ -0,0 +1,111 @@
+//! End-to-end tests for the `parse_*` built-in functions, which convert
+//! strings to numbers.
+
+mod common;
+
+use common::run_ok_leak_checked;
+
+#[test]
+fn test_parse_integers() {
+    let stdout = run_ok_leak_checked(
+        r#"fn main() -> void {
+    let (n, err) = parse_i64("-42")
+    println(n)
+    println(err == "")
+    let (small, _) = parse_i8("-128")
+    println(small)
+    let (byte, _) = parse_u8("255")
+    println(byte)
+    let (wide, _) = parse_u64("18446744073709551615")
+    println(wide)
+    let (mid, _) = parse_i32("+7")
+    println(mid + 1)
+}"#,
+    );
+    assert_eq!(stdout, "-42\ntrue\n-128\n255\n18446744073709551615\n8\n");
+}
+
+#[test]
+fn test_parse_integer_errors() {
+    let stdout = run_ok_leak_checked(
+        r#"fn main() -> void {
+    let (a, empty) = parse_i64("")
+    println(a)
+    println(empty)
+    let (_, invalid) = parse_i64("12x")
+    println(invalid)
+    let (_, overflow) = parse_u8("256")
+    println(overflow)
+    let (_, negative) = parse_u32("-1")
+    println(negative)
+    let (_, spaced) = parse_i16(" 1")
+    println(spaced)
+}"#,
+    );
+    assert_eq!(
+        stdout,
+        "0\n\
+         cannot parse \"\" as i64: empty string\n\
+         cannot parse \"12x\" as i64: invalid digit\n\
+         cannot parse \"256\" as u8: number out of range\n\
+         cannot parse \"-1\" as u32: invalid digit\n\
+         cannot parse \" 1\" as i16: invalid digit\n"
+    );
+}
+
+#[test]
+fn test_parse_floats() {
+    let stdout = run_ok_leak_checked(
+        r#"fn main() -> void {
+    let (x, err) = parse_f64("2.5")
+    println(x * 2.0)
+    println(err == "")
+    let (y, _) = parse_f32("-1e2")
+    println(y)
+    let (z, bad) = parse_f64("1.5.2")
+    println(z)
+    println(bad)
+    let (big, overflow) = parse_f64("1e999")
+    println(big)
+    println(overflow)
+    let (_, narrow) = parse_f32("1e39")
+    println(narrow)
+    let (infinite, none) = parse_f64("inf")
+    println(infinite > 1e308)
+    println(none == "")
+}"#,
+    );
+    assert_eq!(
+        stdout,
+        "5\n\
+         true\n\
+         -100\n\
+         0\n\
+         cannot parse \"1.5.2\" as f64: invalid float\n\
+         0\n\
+         cannot parse \"1e999\" as f64: number out of range\n\
+         cannot parse \"1e39\" as f32: number out of range\n\
+         true\n\
+         true\n"
+    );
+}
+
+#[test]
+fn test_parse_in_loop_reads_numbers() {
+    let stdout = run_ok_leak_checked(
+        r#"fn main() -> void {
+    let mut total: i64 = 0
+    let mut i = 0
+    while i < 3 {
+        let (n, err) = parse_i64(format("{}", i * 10))
+        if err != "" {
+            panic(err)
+        }
+        total = total + n
+        i = i + 1
+    }
+    println(total)
+}"#,
+    );
+    assert_eq!(stdout, "30\n");
+}
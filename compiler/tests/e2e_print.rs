@@ -0,0 +1,182 @@
+//! End-to-end tests for the `print`, `eprint` and `eprintln` built-in functions.
+//!
+//! These tests run programs through `lak run` so that stdout and stderr can
+//! be checked separately.
+
+mod common;
+
+use common::{compile_and_run, run_lak};
+
+// =============================================================================
+// print
+// =============================================================================
+
+#[test]
+fn test_print_without_newline() {
+    let output = compile_and_run(
+        r#"fn main() -> void {
+    print("a")
+    print("b")
+    println("c")
+}"#,
+    )
+    .unwrap();
+    assert_eq!(output, "abc\n");
+}
+
+#[test]
+fn test_print_all_printable_types() {
+    let output = compile_and_run(
+        r#"fn main() -> void {
+    let small: i8 = -8
+    let short: u16 = 16
+    let word: i32 = 32
+    let big: u64 = 64
+    let half: f32 = 0.5
+    print(small)
+    print(" ")
+    print(short)
+    print(" ")
+    print(word)
+    print(" ")
+    print(big)
+    print(" ")
+    print(42)
+    print(" ")
+    print(half)
+    print(" ")
+    print(2.25)
+    print(" ")
+    print(1 < 2)
+}"#,
+    )
+    .unwrap();
+    assert_eq!(output, "-8 16 32 64 42 0.5 2.25 true");
+}
+
+#[test]
+fn test_print_expression_arguments() {
+    let output = compile_and_run(
+        r#"fn label() -> string {
+    return "n="
+}
+
+fn main() -> void {
+    let n: i64 = 20
+    print(label())
+    print(n + 1)
+    print(if n > 10 { "!" } else { "?" })
+    println("")
+}"#,
+    )
+    .unwrap();
+    assert_eq!(output, "n=21!\n");
+}
+
+#[test]
+fn test_print_progress_on_one_line() {
+    let output = compile_and_run(
+        r#"fn main() -> void {
+    let mut i: i64 = 0
+    while i < 3 {
+        print(i)
+        print(".")
+        i = i + 1
+    }
+    println("done")
+}"#,
+    )
+    .unwrap();
+    assert_eq!(output, "0.1.2.done\n");
+}
+
+// =============================================================================
+// eprint / eprintln
+// =============================================================================
+
+#[test]
+fn test_eprintln_writes_to_stderr() {
+    let output = run_lak(
+        r#"fn main() -> void {
+    println("out")
+    eprintln("err")
+}"#,
+        &[],
+        &[],
+        "",
+        None,
+    );
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "out\n");
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "err\n");
+}
+
+#[test]
+fn test_eprint_without_newline() {
+    let output = run_lak(
+        r#"fn main() -> void {
+    eprint("warning: ")
+    eprint(3)
+    eprintln(" issues")
+}"#,
+        &[],
+        &[],
+        "",
+        None,
+    );
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "");
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "warning: 3 issues\n"
+    );
+}
+
+#[test]
+fn test_eprintln_all_printable_types() {
+    let output = run_lak(
+        r#"fn main() -> void {
+    let flag: bool = false
+    let code: u8 = 255
+    let ratio: f64 = 1.5
+    eprintln(flag)
+    eprintln(code)
+    eprintln(ratio)
+    eprintln(-7)
+}"#,
+        &[],
+        &[],
+        "",
+        None,
+    );
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "");
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "false\n255\n1.5\n-7\n"
+    );
+}
+
+#[test]
+fn test_print_flushed_before_panic() {
+    let output = run_lak(
+        r#"fn main() -> void {
+    print("partial")
+    panic("stop")
+}"#,
+        &[],
+        &[],
+        "",
+        None,
+    );
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "partial");
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "panic: stop\n    at main.lak:3:5\n"
+    );
+}
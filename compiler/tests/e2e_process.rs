@@ -0,0 +1,133 @@
+//! End-to-end tests for the `process` standard module.
+//!
+//! These tests import `process` and run programs with `lak run` inside a
+//! temporary directory.
+
+mod common;
+
+use common::run_ok_leak_checked_in;
+use tempfile::tempdir;
+
+#[test]
+fn test_process_get_and_set_env() {
+    let temp = tempdir().unwrap();
+    let stdout = run_ok_leak_checked_in(
+        Some(temp.path()),
+        r#"import "process"
+
+fn main() -> void {
+    let (value, ok) = process.get_env("LAK_TEST_VALUE")
+    println(value)
+    println(ok)
+    let (missing, found) = process.get_env("LAK_TEST_MISSING")
+    println(missing == "")
+    println(found)
+    let err = process.set_env("LAK_TEST_MISSING", "set")
+    println(err == "")
+    let (now_set, now_found) = process.get_env("LAK_TEST_MISSING")
+    println(now_set)
+    println(now_found)
+    println(process.set_env("A=B", "x"))
+}"#,
+        &[("LAK_TEST_VALUE", "from parent")],
+    );
+    assert_eq!(
+        stdout,
+        "from parent\ntrue\ntrue\nfalse\ntrue\nset\ntrue\ninvalid environment variable name 'A=B'\n"
+    );
+}
+
+#[test]
+fn test_process_current_dir() {
+    let temp = tempdir().unwrap();
+    let stdout = run_ok_leak_checked_in(
+        Some(temp.path()),
+        r#"import "process"
+
+fn main() -> void {
+    let (dir, err) = process.current_dir()
+    println(dir)
+    println(err == "")
+}"#,
+        &[("LAK_TEST_VALUE", "from parent")],
+    );
+    let dir = temp.path().canonicalize().unwrap();
+    assert_eq!(stdout, format!("{}\ntrue\n", dir.display()));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_process_run_captures_output_and_exit_code() {
+    let temp = tempdir().unwrap();
+    let stdout = run_ok_leak_checked_in(
+        Some(temp.path()),
+        r#"import "process"
+
+fn main() -> void {
+    let (code, out, err) = process.run("sh", "-c\necho hi; echo err >&2; exit 3")
+    println(code)
+    print(out)
+    print(err)
+}"#,
+        &[("LAK_TEST_VALUE", "from parent")],
+    );
+    assert_eq!(stdout, "3\nhi\nerr\n");
+}
+
+#[cfg(unix)]
+#[test]
+fn test_process_run_sees_set_env() {
+    let temp = tempdir().unwrap();
+    let stdout = run_ok_leak_checked_in(
+        Some(temp.path()),
+        r#"import "process"
+
+fn main() -> void {
+    let _ = process.set_env("LAK_TEST_MISSING", "inherited")
+    let (code, out, _) = process.run("sh", "-c\necho $LAK_TEST_MISSING")
+    println(code)
+    print(out)
+}"#,
+        &[("LAK_TEST_VALUE", "from parent")],
+    );
+    assert_eq!(stdout, "0\ninherited\n");
+}
+
+#[cfg(unix)]
+#[test]
+fn test_process_run_argument_lines() {
+    let temp = tempdir().unwrap();
+    let stdout = run_ok_leak_checked_in(
+        Some(temp.path()),
+        r#"import "process"
+
+fn main() -> void {
+    let (_, none, _) = process.run("sh", "-c\necho $#\nsh")
+    print(none)
+    let (_, empty, _) = process.run("sh", "-c\necho \"$# [$1]\"\nsh\n\n")
+    print(empty)
+    let (_, split, _) = process.run("sh", "-c\necho \"$# [$1] [$2]\"\nsh\na\nb\n")
+    print(split)
+}"#,
+        &[],
+    );
+    assert_eq!(stdout, "0\n1 []\n2 [a] [b]\n");
+}
+
+#[test]
+fn test_process_run_missing_command() {
+    let temp = tempdir().unwrap();
+    let stdout = run_ok_leak_checked_in(
+        Some(temp.path()),
+        r#"import "process"
+
+fn main() -> void {
+    let (code, out, err) = process.run("lak-no-such-command", "")
+    println(code)
+    println(out == "")
+    println(err != "")
+}"#,
+        &[("LAK_TEST_VALUE", "from parent")],
+    );
+    assert_eq!(stdout, "-1\ntrue\ntrue\n");
+}
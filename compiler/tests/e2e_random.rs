@@ -0,0 +1,198 @@
+//! End-to-end tests for the `random` standard module and `rng` values.
+
+mod common;
+
+use common::{run_failing, run_ok_leak_checked};
+
+#[test]
+fn test_random_seeded_is_deterministic() {
+    let source = r#"import "random"
+
+fn main() -> void {
+    let mut rng = random.seeded(0)
+    let n = rng.next_u64()
+    let m = rng.int_range(0, 1000000)
+    println(n)
+    println(m)
+}"#;
+    assert_eq!(run_ok_leak_checked(source), run_ok_leak_checked(source));
+}
+
+#[test]
+fn test_random_generators_are_independent() {
+    let stdout = run_ok_leak_checked(
+        r#"import "random"
+
+fn main() -> void {
+    let mut a = random.seeded(42)
+    let mut b: rng = random.seeded(42)
+    let first = a.next_u64()
+    let n = a.int_range(-5, 5)
+    let b_first = b.next_u64()
+    let b_n = b.int_range(-5, 5)
+    println(b_first == first)
+    println(b_n == n)
+
+    let mut copy = a
+    let from_a = a.next_u64()
+    let from_copy = copy.next_u64()
+    println(from_a == from_copy)
+
+    let mut other = random.from_entropy()
+    let _ = other.float()
+}"#,
+    );
+    assert_eq!(stdout, "true\ntrue\ntrue\n");
+}
+
+#[test]
+fn test_random_generator_as_parameter() {
+    let stdout = run_ok_leak_checked(
+        r#"import "random"
+
+fn roll(dice: rng) -> i64 {
+    let mut copy = dice
+    return copy.int_range(1, 7)
+}
+
+fn make() -> rng {
+    return random.seeded(3)
+}
+
+fn main() -> void {
+    let mut dice = make()
+    let first = roll(dice)
+    let again = roll(dice)
+    println(first == again)
+    let drawn = dice.int_range(1, 7)
+    println(drawn == first)
+}"#,
+    );
+    assert_eq!(stdout, "true\ntrue\n");
+}
+
+#[test]
+fn test_random_ranges() {
+    let stdout = run_ok_leak_checked(
+        r#"import "random"
+
+fn main() -> void {
+    let mut rng = random.seeded(7)
+    let mut i = 0
+    let mut ok = true
+    while i < 1000 {
+        let n = rng.int_range(-3, 4)
+        let f = rng.float()
+        let g = rng.float_range(2.5, 3.0)
+        if n < -3 || n >= 4 || f < 0.0 || f >= 1.0 || g < 2.5 || g >= 3.0 {
+            ok = false
+        }
+        i = i + 1
+    }
+    println(ok)
+    let last = rng.int_range(9, 10)
+    println(last)
+}"#,
+    );
+    assert_eq!(stdout, "true\n9\n");
+}
+
+#[test]
+fn test_random_shuffle_set() {
+    let stdout = run_ok_leak_checked(
+        r#"import "random"
+
+fn main() -> void {
+    let mut rng = random.seeded(1)
+    let mut names: set<string> = set()
+    names.insert("a")
+    names.insert("b")
+    names.insert("c")
+    names.insert("d")
+    names.insert("e")
+    let shuffled = rng.shuffle(names)
+    let mut i = 0
+    while i < shuffled.len() {
+        let name = shuffled.item_at(i)
+        println(name)
+        i = i + 1
+    }
+    let first = names.item_at(0)
+    println(first)
+    let empty: set<string> = set()
+    let none = rng.shuffle(empty)
+    println(none.len())
+}"#,
+    );
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines[5..], ["a", "0"]);
+    let mut shuffled = lines[..5].to_vec();
+    shuffled.sort_unstable();
+    assert_eq!(shuffled, ["a", "b", "c", "d", "e"]);
+}
+
+#[test]
+fn test_random_shuffle_map_keeps_pairs() {
+    let stdout = run_ok_leak_checked(
+        r#"import "random"
+
+fn main() -> void {
+    let mut rng = random.seeded(5)
+    let mut ages: map<string, i64> = map()
+    ages.insert("alice", 30)
+    ages.insert("bob", 25)
+    ages.insert("carol", 41)
+    let mut shuffled = rng.shuffle(ages)
+    let mut i = 0
+    while i < shuffled.len() {
+        let name = shuffled.key_at(i)
+        let age = shuffled.value_at(i)
+        println(format("{}={}", name, age))
+        i = i + 1
+    }
+    shuffled.insert("dave", 19)
+    println(ages.len())
+    println(shuffled.len())
+}"#,
+    );
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines[3..], ["3", "4"]);
+    let mut pairs = lines[..3].to_vec();
+    pairs.sort_unstable();
+    assert_eq!(pairs, ["alice=30", "bob=25", "carol=41"]);
+}
+
+#[test]
+fn test_random_empty_int_range_panics() {
+    let stderr = run_failing(
+        r#"import "random"
+
+fn main() -> void {
+    let mut rng = random.seeded(0)
+    let _ = rng.int_range(3, 3)
+}"#,
+    );
+    assert!(
+        stderr.starts_with("panic: int_range called with empty range 3..3\n    at "),
+        "stderr: {}",
+        stderr
+    );
+    assert!(stderr.contains("main.lak:5:13"), "stderr: {}", stderr);
+}
+
+#[test]
+fn test_random_invalid_float_range_panics() {
+    let stderr = run_failing(
+        r#"import "random"
+
+fn main() -> void {
+    let mut rng = random.seeded(0)
+    let _ = rng.float_range(1.0, 1.0)
+}"#,
+    );
+    assert!(
+        stderr.starts_with("panic: float_range called with invalid range 1..1\n"),
+        "stderr: {}",
+        stderr
+    );
+}
@@ -0,0 +1,142 @@
+//! End-to-end tests for standard input: the `read_line` and `read_all`
+//! built-in functions.
+//!
+//! These tests pipe input into `lak run`, which connects its own stdin to
+//! the program.
+
+mod common;
+
+use common::run_lak;
+
+const NUMBER_LINES: &str = r#"fn main() -> void {
+    let mut n: i64 = 0
+    while true {
+        let (line, ok) = read_line()
+        if !ok {
+            break
+        }
+        n = n + 1
+        println(format("{}: {}", n, line))
+    }
+    println(format("{} lines", n))
+}"#;
+
+#[test]
+fn test_read_line_until_eof() {
+    let output = run_lak(NUMBER_LINES, &[], &[], "alpha\nbeta\r\n\ngamma", None);
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "1: alpha\n2: beta\n3: \n4: gamma\n4 lines\n"
+    );
+}
+
+#[test]
+fn test_read_line_empty_input() {
+    let output = run_lak(NUMBER_LINES, &[], &[], "", None);
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "0 lines\n");
+}
+
+#[test]
+fn test_read_line_after_eof_keeps_returning_false() {
+    let output = run_lak(
+        r#"fn main() -> void {
+    let (first, ok1) = read_line()
+    let (second, ok2) = read_line()
+    println(ok1)
+    println(ok2)
+    println(second == "")
+    println(first)
+}"#,
+        &[],
+        &[],
+        "only\n",
+        None,
+    );
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "true\nfalse\ntrue\nonly\n"
+    );
+}
+
+#[test]
+fn test_read_all() {
+    let output = run_lak(
+        r#"fn main() -> void {
+    let text = read_all()
+    print(text)
+    println(read_all() == "")
+}"#,
+        &[],
+        &[],
+        "one\ntwo\n",
+        None,
+    );
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "one\ntwo\ntrue\n");
+}
+
+#[test]
+fn test_read_line_then_read_all() {
+    let output = run_lak(
+        r#"fn main() -> void {
+    let (header, _) = read_line()
+    let rest: string = read_all()
+    println(format("header={}", header))
+    print(rest)
+}"#,
+        &[],
+        &[],
+        "h\nx\ny\n",
+        None,
+    );
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "header=h\nx\ny\n");
+}
+
+#[test]
+fn test_read_line_and_read_all_no_leaks() {
+    let output = run_lak(
+        r#"fn main() -> void {
+    let (first, _) = read_line()
+    let (second, _) = read_line()
+    let rest = read_all()
+    println(format("{}|{}|{}", first, second, rest))
+}"#,
+        &[("LAK_LEAK_CHECK", "1")],
+        &[],
+        "a\nb\nc",
+        None,
+    );
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "a|b|c\n");
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "leak check: no leaked objects\n"
+    );
+}
+
+#[test]
+fn test_read_all_with_nul_byte_panics() {
+    let output = run_lak(
+        r#"fn main() -> void {
+    let (first, _) = read_line()
+    println(first)
+    println(read_all())
+}"#,
+        &[],
+        &[],
+        "ok\nab\0c\n",
+        None,
+    );
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "ok\n");
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "panic: failed to read from stdin: contains a NUL byte at offset 2\n    at main.lak:4:13\n"
+    );
+}
@@ -0,0 +1,139 @@
+//! End-to-end tests for standard library module resolution.
+//!
+//! These tests import modules by name (e.g. `import "strings"`) and check
+//! that bundled sources, the `LAK_STDLIB_DIR` override and unknown module
+//! errors behave as documented.
+
+mod common;
+
+use common::run_lak;
+use std::fs;
+use tempfile::tempdir;
+
+#[test]
+fn test_bundled_strings_module() {
+    let temp = tempdir().unwrap();
+    let output = run_lak(
+        r#"import "strings"
+
+fn main() -> void {
+    println(strings.concat("ab", "cd"))
+    println(strings.repeat("ha", 3))
+    println(strings.is_empty(""))
+    println(strings.quote("lak"))
+}"#,
+        &[("LAK_LEAK_CHECK", "1")],
+        &[],
+        "",
+        Some(temp.path()),
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {}", stderr);
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "abcd\nhahaha\ntrue\n\"lak\"\n"
+    );
+    assert_eq!(stderr, "leak check: no leaked objects\n");
+}
+
+#[test]
+fn test_standard_module_imported_from_two_modules() {
+    let temp = tempdir().unwrap();
+    fs::write(
+        temp.path().join("greet.lak"),
+        r#"import "strings" as s
+
+pub fn shout(name: string) -> string {
+    return s.concat(name, "!")
+}"#,
+    )
+    .unwrap();
+    let output = run_lak(
+        r#"import "./greet"
+import "strings"
+
+fn main() -> void {
+    println(strings.quote(greet.shout("hi")))
+}"#,
+        &[("LAK_LEAK_CHECK", "1")],
+        &[],
+        "",
+        Some(temp.path()),
+    );
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "\"hi!\"\n");
+}
+
+#[test]
+fn test_stdlib_dir_overrides_bundled_module() {
+    let temp = tempdir().unwrap();
+    let stdlib_dir = temp.path().join("stdlib");
+    fs::create_dir(&stdlib_dir).unwrap();
+    fs::write(
+        stdlib_dir.join("strings.lak"),
+        r#"pub fn quote(text: string) -> string {
+    return format("<{}>", text)
+}"#,
+    )
+    .unwrap();
+    let output = run_lak(
+        r#"import "strings"
+
+fn main() -> void {
+    println(strings.quote("lak"))
+}"#,
+        &[
+            ("LAK_LEAK_CHECK", "1"),
+            ("LAK_STDLIB_DIR", stdlib_dir.to_str().unwrap()),
+        ],
+        &[],
+        "",
+        Some(temp.path()),
+    );
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "<lak>\n");
+}
+
+#[test]
+fn test_stdlib_dir_adds_new_module() {
+    let temp = tempdir().unwrap();
+    let stdlib_dir = temp.path().join("stdlib");
+    fs::create_dir(&stdlib_dir).unwrap();
+    fs::write(
+        stdlib_dir.join("extra.lak"),
+        r#"pub fn answer() -> i64 {
+    return 42
+}"#,
+    )
+    .unwrap();
+    let output = run_lak(
+        r#"import "extra"
+import "fs"
+
+fn main() -> void {
+    println(extra.answer())
+    println(fs.exists("main.lak"))
+}"#,
+        &[
+            ("LAK_LEAK_CHECK", "1"),
+            ("LAK_STDLIB_DIR", stdlib_dir.to_str().unwrap()),
+        ],
+        &[],
+        "",
+        Some(temp.path()),
+    );
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "42\ntrue\n");
+}
@@ -275,3 +275,43 @@ fn test_multiple_string_variables_reuse() {
     .unwrap();
     assert_eq!(output, "first\nsecond\nthird\nfirst\n");
 }
+
+#[test]
+fn test_hex_and_unicode_escapes() {
+    let output = compile_and_run(
+        r#"fn main() -> void {
+    println("\x48\x69 \u{48}\u{e9}\u{1F600}")
+}"#,
+    )
+    .unwrap();
+    assert_eq!(output, "Hi H\u{e9}\u{1F600}\n");
+}
+
+#[test]
+fn test_raw_string() {
+    let output = compile_and_run(
+        r###"fn main() -> void {
+    println(r"C:\path\to\file")
+    println(r#"match "\d+" here"#)
+}"###,
+    )
+    .unwrap();
+    assert_eq!(output, "C:\\path\\to\\file\nmatch \"\\d+\" here\n");
+}
+
+#[test]
+fn test_multiline_string() {
+    let output = compile_and_run(
+        r#"fn main() -> void {
+    let text = """
+        first
+          indented\tline
+
+        last
+        """
+    println(text)
+}"#,
+    )
+    .unwrap();
+    assert_eq!(output, "first\n  indented\tline\n\nlast\n");
+}
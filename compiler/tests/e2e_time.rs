@@ -0,0 +1,76 @@
+//! End-to-end tests for the `time` standard module.
+
+mod common;
+
+use common::{run_failing, run_ok_leak_checked};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[test]
+fn test_time_sleep_and_now_ns() {
+    let stdout = run_ok_leak_checked(
+        r#"import "time"
+
+fn main() -> void {
+    let start = time.now_ns()
+    time.sleep_ms(20)
+    let elapsed = time.now_ns() - start
+    println(elapsed >= 20000000)
+    println(elapsed < 5000000000)
+}"#,
+    );
+    assert_eq!(stdout, "true\ntrue\n");
+}
+
+#[test]
+fn test_time_unix_seconds() {
+    let before = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let stdout = run_ok_leak_checked(
+        r#"import "time"
+
+fn main() -> void {
+    println(time.unix_seconds())
+}"#,
+    );
+    let after = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let seconds: u64 = stdout.trim().parse().unwrap();
+    assert!(before <= seconds && seconds <= after, "{}", seconds);
+}
+
+#[test]
+fn test_time_format_duration() {
+    let stdout = run_ok_leak_checked(
+        r#"import "time"
+
+fn main() -> void {
+    println(time.format_duration(0))
+    println(time.format_duration(750))
+    println(time.format_duration(1500000))
+    println(time.format_duration(-2250000000))
+    println(format("took {}", time.format_duration(3723500000000)))
+}"#,
+    );
+    assert_eq!(stdout, "0s\n750ns\n1.5ms\n-2.25s\ntook 1h2m3.5s\n");
+}
+
+#[test]
+fn test_time_sleep_negative_panics() {
+    let stderr = run_failing(
+        r#"import "time"
+
+fn main() -> void {
+    time.sleep_ms(-5)
+}"#,
+    );
+    assert!(
+        stderr.starts_with("panic: sleep_ms called with negative duration -5\n    at "),
+        "stderr: {}",
+        stderr
+    );
+    assert!(stderr.contains("main.lak:4:5"), "stderr: {}", stderr);
+}
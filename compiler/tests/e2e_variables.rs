@@ -486,3 +486,61 @@ fn test_i32_min_value_valid() {
     .unwrap();
     assert_eq!(output, "-2147483648\n");
 }
+
+#[test]
+fn test_radix_and_separated_integer_literals() {
+    let output = compile_and_run(
+        r#"fn main() -> void {
+    let mask: u32 = 0xFF_00
+    let perms: i32 = 0o755
+    let bits: u8 = 0b1010_1010
+    let big: i64 = 1_000_000
+    println(mask)
+    println(perms)
+    println(bits)
+    println(big)
+}"#,
+    )
+    .unwrap();
+    assert_eq!(output, "65280\n493\n170\n1000000\n");
+}
+
+#[test]
+fn test_suffixed_integer_literals_infer_type() {
+    let output = compile_and_run(
+        r#"fn main() -> void {
+    let small = 255u8
+    let negative = -128i8
+    let wide = 4_000_000_000u32
+    println(format("{}", small))
+    println(negative)
+    println(wide)
+    println(small - 5)
+}"#,
+    )
+    .unwrap();
+    assert_eq!(output, "255\n-128\n4000000000\n250\n");
+}
+
+#[test]
+fn test_scientific_and_suffixed_float_literals() {
+    let output = compile_and_run(
+        r#"fn main() -> void {
+    let tiny = 1e-9
+    let avogadro = 6.02e23
+    let half = 0.5f32
+    let scaled: f32 = half * 4f32
+    println(tiny)
+    println(avogadro)
+    println(scaled)
+    println(inf)
+    println(-inf)
+    println(nan)
+}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        output,
+        "0.000000001\n602000000000000000000000\n2\ninf\n-inf\nnan\n"
+    );
+}
@@ -46,6 +46,125 @@ fn test_compile_error_invalid_escape() {
     );
 }
 
+#[test]
+fn test_compile_error_hex_escape_out_of_range() {
+    let result = compile_error_with_kind(r#"fn main() -> void { println("\x80") }"#);
+    let (stage, msg, short_msg, kind) = result.expect("Expected compilation to fail");
+    assert!(
+        matches!(stage, CompileStage::Lex),
+        "Expected Lex error, got {:?}: {}",
+        stage,
+        msg
+    );
+    assert_eq!(
+        msg,
+        "Hex escape '\\x80' is out of range; only '\\x00' to '\\x7F' are allowed, use '\\u{...}' for other characters"
+    );
+    assert_eq!(short_msg, "Invalid escape sequence");
+    assert_eq!(
+        kind,
+        CompileErrorKind::Lex(LexErrorKind::InvalidEscapeSequence),
+        "Expected InvalidEscapeSequence error kind"
+    );
+}
+
+#[test]
+fn test_compile_error_nul_escape() {
+    let result = compile_error_with_kind(r#"fn main() -> void { println("a\0b") }"#);
+    let (stage, msg, short_msg, kind) = result.expect("Expected compilation to fail");
+    assert!(
+        matches!(stage, CompileStage::Lex),
+        "Expected Lex error, got {:?}: {}",
+        stage,
+        msg
+    );
+    assert_eq!(
+        msg,
+        "Strings cannot contain NUL characters, since runtime strings are NUL-terminated"
+    );
+    assert_eq!(short_msg, "NUL character in string");
+    assert_eq!(
+        kind,
+        CompileErrorKind::Lex(LexErrorKind::NulInString),
+        "Expected NulInString error kind"
+    );
+}
+
+#[test]
+fn test_compile_error_char_literal_with_several_characters() {
+    let result = compile_error_with_kind(r#"fn main() -> void { println('ab') }"#);
+    let (stage, msg, short_msg, kind) = result.expect("Expected compilation to fail");
+    assert!(
+        matches!(stage, CompileStage::Lex),
+        "Expected Lex error, got {:?}: {}",
+        stage,
+        msg
+    );
+    assert_eq!(
+        msg,
+        "Character literal must contain exactly one character; use double quotes for strings"
+    );
+    assert_eq!(short_msg, "Invalid character literal");
+    assert_eq!(
+        kind,
+        CompileErrorKind::Lex(LexErrorKind::InvalidCharLiteral),
+        "Expected InvalidCharLiteral error kind"
+    );
+}
+
+#[test]
+fn test_compile_error_multiline_string_indentation() {
+    let result = compile_error_with_kind(
+        r#"fn main() -> void {
+    println("""
+        ok
+      short
+        """)
+}"#,
+    );
+    let (stage, msg, short_msg, kind) = result.expect("Expected compilation to fail");
+    assert!(
+        matches!(stage, CompileStage::Lex),
+        "Expected Lex error, got {:?}: {}",
+        stage,
+        msg
+    );
+    assert_eq!(
+        msg,
+        "Multi-line string line is indented less than the closing '\"\"\"'"
+    );
+    assert_eq!(short_msg, "Invalid multi-line string");
+    assert_eq!(
+        kind,
+        CompileErrorKind::Lex(LexErrorKind::InvalidMultilineString),
+        "Expected InvalidMultilineString error kind"
+    );
+}
+
+#[test]
+fn test_compile_error_unterminated_block_comment() {
+    let result = compile_error_with_kind(
+        r#"fn main() -> void {
+    /* outer /* inner */
+    println("unreachable")
+}"#,
+    );
+    let (stage, msg, short_msg, kind) = result.expect("Expected compilation to fail");
+    assert!(
+        matches!(stage, CompileStage::Lex),
+        "Expected Lex error, got {:?}: {}",
+        stage,
+        msg
+    );
+    assert_eq!(msg, "Unterminated block comment (expected '*/')");
+    assert_eq!(short_msg, "Unterminated block comment");
+    assert_eq!(
+        kind,
+        CompileErrorKind::Lex(LexErrorKind::UnterminatedBlockComment),
+        "Expected UnterminatedBlockComment error kind"
+    );
+}
+
 #[test]
 fn test_compile_error_u64_overflow() {
     let result = compile_error_with_kind(
@@ -296,14 +296,14 @@ fn main() -> void {
 }
 
 #[test]
-fn test_error_standard_library_not_supported() {
+fn test_error_unknown_standard_module() {
     let temp = tempdir().unwrap();
 
-    // Create main.lak that tries to import a standard library
+    // Create main.lak that imports a module missing from the standard library
     let main_path = temp.path().join("main.lak");
     fs::write(
         &main_path,
-        r#"import "math"
+        r#"import "json"
 
 fn main() -> void {}
 "#,
@@ -320,19 +320,19 @@ fn main() -> void {}
     let stderr = String::from_utf8_lossy(&output.stderr);
     // Verify short_message in report title
     assert!(
-        stderr.contains("\x1b[31mError:\x1b[0m Standard library not supported"),
-        "Expected 'Standard library not supported' error, got: {}",
+        stderr.contains("\x1b[31mError:\x1b[0m Unknown standard module"),
+        "Expected 'Unknown standard module' error, got: {}",
         stderr
     );
     // Verify detailed message in label
     assert!(
-        stderr.contains("Standard library imports are not yet supported: 'math'"),
+        stderr.contains("Unknown standard library module: 'json'"),
         "Expected error message to mention the library name, got: {}",
         stderr
     );
     // Verify help text
     assert!(
-        stderr.contains("\x1b[38;5;115mHelp\x1b[0m: use relative paths like './module' instead"),
+        stderr.contains("\x1b[38;5;115mHelp\x1b[0m: available standard modules: fs, math, process, random, strings, time; use relative paths like './module' for your own modules"),
         "Expected help text about using relative paths, got: {}",
         stderr
     );
@@ -988,3 +988,181 @@ fn test_error_invalid_entry_module_name() {
         stderr
     );
 }
+
+#[test]
+fn test_error_fs_function_wrong_argument_type() {
+    let temp = tempdir().unwrap();
+    let main_path = temp.path().join("main.lak");
+    fs::write(
+        &main_path,
+        r#"import "fs"
+
+fn main() -> void {
+    let (text, err) = fs.read_file(42)
+}
+"#,
+    )
+    .unwrap();
+
+    let output = Command::new(lak_binary())
+        .current_dir(temp.path())
+        .args(["build", "main.lak"])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Type mismatch: integer literal '42' cannot be assigned to type 'string'"),
+        "Expected a type mismatch for the path argument, got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_error_fs_undefined_function() {
+    let temp = tempdir().unwrap();
+    let main_path = temp.path().join("main.lak");
+    fs::write(
+        &main_path,
+        r#"import "fs"
+
+fn main() -> void {
+    fs.delete("a.txt")
+}
+"#,
+    )
+    .unwrap();
+
+    let output = Command::new(lak_binary())
+        .current_dir(temp.path())
+        .args(["build", "main.lak"])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Function 'delete' not found in module 'fs'"),
+        "got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_error_fs_result_ignored_as_statement() {
+    let temp = tempdir().unwrap();
+    let main_path = temp.path().join("main.lak");
+    fs::write(
+        &main_path,
+        r#"import "fs"
+
+fn main() -> void {
+    fs.write_file("a.txt", "a")
+}
+"#,
+    )
+    .unwrap();
+
+    let output = Command::new(lak_binary())
+        .current_dir(temp.path())
+        .args(["build", "main.lak"])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Function 'fs.write_file' returns 'string'"),
+        "got: {}",
+        stderr
+    );
+}
+
+/// Builds `source` as `main.lak` and returns stderr, asserting failure.
+fn build_error(source: &str) -> String {
+    let temp = tempdir().unwrap();
+    fs::write(temp.path().join("main.lak"), source).unwrap();
+
+    let output = Command::new(lak_binary())
+        .current_dir(temp.path())
+        .args(["build", "main.lak"])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[test]
+fn test_error_math_float_function_with_integer() {
+    let stderr = build_error(
+        r#"import "math"
+
+fn main() -> void {
+    let x: i64 = 16
+    println(math.sqrt(x))
+}
+"#,
+    );
+    assert!(
+        stderr.contains("Function 'math.sqrt' is not defined for type 'i64'"),
+        "got: {}",
+        stderr
+    );
+    assert!(
+        stderr.contains("'math.sqrt' accepts f32 or f64"),
+        "got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_error_math_abs_unsigned() {
+    let stderr = build_error(
+        r#"import "math"
+
+fn main() -> void {
+    let x: u32 = 3
+    let y: u32 = math.abs(x)
+}
+"#,
+    );
+    assert!(
+        stderr.contains("Function 'math.abs' is not defined for type 'u32'"),
+        "got: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_error_math_mixed_argument_types() {
+    let stderr = build_error(
+        r#"import "math"
+
+fn main() -> void {
+    let a: i32 = 1
+    let b: i64 = 2
+    println(math.max(a, b))
+}
+"#,
+    );
+    assert!(stderr.contains("Type mismatch"), "got: {}", stderr);
+}
+
+#[test]
+fn test_error_math_wrong_argument_count() {
+    let stderr = build_error(
+        r#"import "math"
+
+fn main() -> void {
+    println(math.clamp(1, 2))
+}
+"#,
+    );
+    assert!(
+        stderr.contains("Function 'math.clamp' expects 3 arguments, but got 2"),
+        "got: {}",
+        stderr
+    );
+}
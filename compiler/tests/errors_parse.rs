@@ -295,6 +295,79 @@ fn test_compile_error_if_expression_missing_branch_value() {
     );
 }
 
+#[test]
+fn test_compile_error_block_expression_missing_value() {
+    let result = compile_error_with_kind(
+        r#"fn main() -> void {
+    let value: i64 = { let x: i64 = 1 }
+}"#,
+    );
+    let (stage, msg, short_msg, kind) = result.expect("Expected compilation to fail");
+    assert!(
+        matches!(stage, CompileStage::Parse),
+        "Expected Parse error, got {:?}: {}",
+        stage,
+        msg
+    );
+    assert_eq!(msg, "block expression must end with a value expression");
+    assert_eq!(short_msg, "Missing value in block expression");
+    assert_eq!(
+        kind,
+        CompileErrorKind::Parse(ParseErrorKind::MissingBlockExpressionValue),
+        "Expected MissingBlockExpressionValue error kind"
+    );
+}
+
+#[test]
+fn test_compile_error_single_element_tuple_pattern() {
+    let result = compile_error_with_kind(
+        r#"fn main() -> void {
+    let (a,) = (1, 2)
+}"#,
+    );
+    let (stage, msg, short_msg, kind) = result.expect("Expected compilation to fail");
+    assert!(
+        matches!(stage, CompileStage::Parse),
+        "Expected Parse error, got {:?}: {}",
+        stage,
+        msg
+    );
+    assert_eq!(msg, "A tuple pattern must have at least two elements");
+    assert_eq!(short_msg, "Invalid tuple arity");
+    assert_eq!(
+        kind,
+        CompileErrorKind::Parse(ParseErrorKind::InvalidTupleArity),
+        "Expected InvalidTupleArity error kind"
+    );
+}
+
+#[test]
+fn test_compile_error_struct_pattern_not_supported() {
+    let result = compile_error_with_kind(
+        r#"fn main() -> void {
+    let p: (i64, i64) = (1, 2)
+    let Point { x, y } = p
+}"#,
+    );
+    let (stage, msg, short_msg, kind) = result.expect("Expected compilation to fail");
+    assert!(
+        matches!(stage, CompileStage::Parse),
+        "Expected Parse error, got {:?}: {}",
+        stage,
+        msg
+    );
+    assert_eq!(
+        msg,
+        "Expected ':' for type annotation or '=' for initializer, found '{'"
+    );
+    assert_eq!(short_msg, "Unexpected token");
+    assert_eq!(
+        kind,
+        CompileErrorKind::Parse(ParseErrorKind::UnexpectedToken),
+        "Expected UnexpectedToken error kind"
+    );
+}
+
 // ========================================
 // Import syntax error tests
 // ========================================
@@ -414,6 +487,27 @@ fn main() -> void {}"#,
     );
 }
 
+#[test]
+fn test_compile_error_special_float_literal_as_variable_name() {
+    // `inf` and `nan` are literals, so they cannot be used as names
+    for (name, found) in [("nan", "'nan' literal"), ("inf", "'inf' literal")] {
+        let result =
+            compile_error_with_kind(&format!("fn main() -> void {{\n    let {} = 1\n}}", name));
+        let (stage, msg, _, kind) = result.expect("Expected compilation to fail");
+        assert!(
+            matches!(stage, CompileStage::Parse),
+            "Expected Parse error, got {:?}: {}",
+            stage,
+            msg
+        );
+        assert_eq!(msg, format!("Expected identifier, found {}", found));
+        assert_eq!(
+            kind,
+            CompileErrorKind::Parse(ParseErrorKind::ExpectedIdentifier)
+        );
+    }
+}
+
 // ========================================
 // Member access error tests
 // ========================================
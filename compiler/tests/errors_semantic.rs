@@ -5,19 +5,33 @@
 
 mod common;
 
+#[path = "errors_semantic/args_builtin.rs"]
+mod args_builtin;
+#[path = "errors_semantic/assert_builtin.rs"]
+mod assert_builtin;
 #[path = "errors_semantic/bindings_and_types.rs"]
 mod bindings_and_types;
 #[path = "errors_semantic/calls_and_expressions.rs"]
 mod calls_and_expressions;
+#[path = "errors_semantic/collections.rs"]
+mod collections;
 #[path = "errors_semantic/comparisons_and_logical.rs"]
 mod comparisons_and_logical;
+#[path = "errors_semantic/exit_builtin.rs"]
+mod exit_builtin;
+#[path = "errors_semantic/format_builtin.rs"]
+mod format_builtin;
 #[path = "errors_semantic/helpers.rs"]
 mod helpers;
 #[path = "errors_semantic/module_access.rs"]
 mod module_access;
 #[path = "errors_semantic/panic_builtin.rs"]
 mod panic_builtin;
+#[path = "errors_semantic/patterns.rs"]
+mod patterns;
 #[path = "errors_semantic/returns_and_discard.rs"]
 mod returns_and_discard;
+#[path = "errors_semantic/stdin_builtin.rs"]
+mod stdin_builtin;
 #[path = "errors_semantic/unary_and_boolean.rs"]
 mod unary_and_boolean;
@@ -0,0 +1,89 @@
+use crate::helpers::assert_semantic_error;
+use lak::semantic::SemanticErrorKind;
+
+// ========================================
+// arg_count() and arg() built-in function error tests
+// ========================================
+
+#[test]
+fn test_compile_error_arg_count_with_args() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let n = arg_count(1)
+}"#,
+        "Function 'arg_count' expects 0 arguments, but got 1",
+        "Invalid argument",
+        SemanticErrorKind::InvalidArgument,
+    );
+}
+
+#[test]
+fn test_compile_error_arg_no_args() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let a = arg()
+}"#,
+        "Function 'arg' expects 1 arguments, but got 0",
+        "Invalid argument",
+        SemanticErrorKind::InvalidArgument,
+    );
+}
+
+#[test]
+fn test_compile_error_arg_string_index() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let a = arg("0")
+}"#,
+        "Type mismatch: string literal cannot be assigned to type 'i64'",
+        "Type mismatch",
+        SemanticErrorKind::TypeMismatch,
+    );
+}
+
+#[test]
+fn test_compile_error_arg_result_wrong_type() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let a: i64 = arg(0)
+}"#,
+        "Type mismatch: function 'arg' returns 'string', expected 'i64'",
+        "Type mismatch",
+        SemanticErrorKind::TypeMismatch,
+    );
+}
+
+#[test]
+fn test_compile_error_arg_as_statement() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    arg(0)
+}"#,
+        "Function 'arg' returns 'string', but only void functions can be called as statements",
+        "Type mismatch",
+        SemanticErrorKind::TypeMismatch,
+    );
+}
+
+#[test]
+fn test_compile_error_arg_redefined() {
+    assert_semantic_error(
+        r#"fn arg(index: i64) -> string {
+    return "x"
+}
+fn main() -> void {}"#,
+        "Function name 'arg' is reserved by the prelude and cannot be redefined",
+        "Invalid argument",
+        SemanticErrorKind::InvalidArgument,
+    );
+}
+
+#[test]
+fn test_compile_error_main_with_params_suggests_args() {
+    assert_semantic_error(
+        r#"fn main(args: string) -> void {}"#,
+        "main function must not have parameters, but found 1",
+        "Invalid main signature",
+        SemanticErrorKind::InvalidMainSignature,
+    );
+}
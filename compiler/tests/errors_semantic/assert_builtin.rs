@@ -0,0 +1,89 @@
+use crate::helpers::assert_semantic_error;
+use lak::semantic::SemanticErrorKind;
+
+// ========================================
+// assert(), assert_eq() and assert_ne() built-in function error tests
+// ========================================
+
+#[test]
+fn test_compile_error_assert_no_args() {
+    assert_semantic_error(
+        r#"fn main() -> void { assert() }"#,
+        "assert expects exactly 1 argument",
+        "Invalid argument",
+        SemanticErrorKind::InvalidArgument,
+    );
+}
+
+#[test]
+fn test_compile_error_assert_non_bool_condition() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let x: i32 = 1
+    assert(x)
+}"#,
+        "assert requires a bool condition, but got 'i32'",
+        "Invalid argument",
+        SemanticErrorKind::InvalidArgument,
+    );
+}
+
+#[test]
+fn test_compile_error_assert_eq_one_arg() {
+    assert_semantic_error(
+        r#"fn main() -> void { assert_eq(1) }"#,
+        "assert_eq expects exactly 2 arguments",
+        "Invalid argument",
+        SemanticErrorKind::InvalidArgument,
+    );
+}
+
+#[test]
+fn test_compile_error_assert_ne_mismatched_types() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let x: i32 = 5
+    let y: i64 = 10
+    assert_ne(x, y)
+}"#,
+        "Type mismatch: variable 'y' has type 'i64', expected 'i32'",
+        "Type mismatch",
+        SemanticErrorKind::TypeMismatch,
+    );
+}
+
+#[test]
+fn test_compile_error_assert_eq_tuples() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let a = (1, 2)
+    assert_eq(a, a)
+}"#,
+        "Operator '==' cannot be used with '(i64, i64)' type",
+        "Type mismatch",
+        SemanticErrorKind::TypeMismatch,
+    );
+}
+
+#[test]
+fn test_compile_error_assert_used_as_value() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let x = assert(true)
+}"#,
+        "Function call 'assert' returns 'void' and cannot be used as a value",
+        "Type mismatch",
+        SemanticErrorKind::TypeMismatch,
+    );
+}
+
+#[test]
+fn test_compile_error_reserved_prelude_function_assert_eq() {
+    assert_semantic_error(
+        r#"fn assert_eq(a: i64, b: i64) -> void {}
+fn main() -> void {}"#,
+        "Function name 'assert_eq' is reserved by the prelude and cannot be redefined",
+        "Invalid argument",
+        SemanticErrorKind::InvalidArgument,
+    );
+}
@@ -35,7 +35,7 @@ fn test_compile_error_missing_main_with_other_functions() {
 fn test_compile_error_main_wrong_return_type() {
     assert_semantic_error(
         "fn main() -> int {}",
-        "main function must return void, but found return type 'int'",
+        "main function must return void, i32 or u8, but found return type 'int'",
         "Invalid main signature",
         SemanticErrorKind::InvalidMainSignature,
     );
@@ -204,6 +204,55 @@ fn test_compile_error_type_mismatch() {
     );
 }
 
+#[test]
+fn test_compile_error_char_literal_to_string() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let s: string = 'a'
+}"#,
+        "Type mismatch: character literal cannot be assigned to type 'string'",
+        "Type mismatch",
+        SemanticErrorKind::TypeMismatch,
+    );
+}
+
+#[test]
+fn test_compile_error_int_literal_to_char() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let c: char = 97
+}"#,
+        "Type mismatch: integer literal '97' cannot be assigned to type 'char'",
+        "Type mismatch",
+        SemanticErrorKind::TypeMismatch,
+    );
+}
+
+#[test]
+fn test_compile_error_char_arithmetic() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let c = 'a'
+    println(c + 'b')
+}"#,
+        "Operator '+' cannot be used with 'char' type",
+        "Type mismatch",
+        SemanticErrorKind::TypeMismatch,
+    );
+}
+
+#[test]
+fn test_compile_error_char_literal_as_statement() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    'a'
+}"#,
+        "Character literal as a statement has no effect",
+        "Invalid expression",
+        SemanticErrorKind::InvalidExpression,
+    );
+}
+
 #[test]
 fn test_compile_error_if_condition_must_be_bool() {
     assert_semantic_error(
@@ -268,6 +317,58 @@ fn test_compile_error_if_expression_branch_i32_overflow_is_preserved() {
     );
 }
 
+#[test]
+fn test_compile_error_block_expression_type_mismatch() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let value: i64 = { "hello" }
+}"#,
+        "Type mismatch: string literal cannot be assigned to type 'i64'",
+        "Type mismatch",
+        SemanticErrorKind::TypeMismatch,
+    );
+}
+
+#[test]
+fn test_compile_error_block_expression_local_out_of_scope() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let value: i64 = {
+        let inner: i64 = 1
+        inner
+    }
+    println(inner)
+}"#,
+        "Undefined variable: 'inner'",
+        "Undefined variable",
+        SemanticErrorKind::UndefinedVariable,
+    );
+}
+
+#[test]
+fn test_compile_error_block_expression_as_statement() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    { 1 }
+}"#,
+        "Block expression value is not used",
+        "Invalid expression",
+        SemanticErrorKind::InvalidExpression,
+    );
+}
+
+#[test]
+fn test_compile_error_tuple_expression_as_statement() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    (1, "a")
+}"#,
+        "Tuple expression value is not used",
+        "Invalid expression",
+        SemanticErrorKind::InvalidExpression,
+    );
+}
+
 #[test]
 fn test_compile_error_i32_overflow() {
     // i32::MAX + 1 = 2147483648 should overflow i32
@@ -398,3 +499,52 @@ fn test_compile_error_int_literal_to_string() {
         SemanticErrorKind::TypeMismatch,
     );
 }
+
+#[test]
+fn test_compile_error_suffixed_literal_out_of_range() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let x = 256u8
+}"#,
+        "Integer literal '256' is out of range for u8 (valid range: 0 to 255)",
+        "Integer overflow",
+        SemanticErrorKind::IntegerOverflow,
+    );
+}
+
+#[test]
+fn test_compile_error_suffixed_literal_type_mismatch() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let x: i32 = 5u8
+}"#,
+        "Type mismatch: integer literal '5u8' has type 'u8', expected 'i32'",
+        "Type mismatch",
+        SemanticErrorKind::TypeMismatch,
+    );
+}
+
+#[test]
+fn test_compile_error_suffixed_literal_mixed_with_other_integer_type() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let x: i64 = 1
+    println(x + 2u8)
+}"#,
+        "Type mismatch: integer literal '2u8' has type 'u8', expected 'i64'",
+        "Type mismatch",
+        SemanticErrorKind::TypeMismatch,
+    );
+}
+
+#[test]
+fn test_compile_error_suffixed_float_type_mismatch() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let x: f64 = 1.5f32
+}"#,
+        "Type mismatch: float literal with suffix 'f32' has type 'f32', expected 'f64'",
+        "Type mismatch",
+        SemanticErrorKind::TypeMismatch,
+    );
+}
@@ -34,6 +34,59 @@ fn main() -> void {}"#,
     );
 }
 
+#[test]
+fn test_compile_error_reserved_prelude_function_eprintln() {
+    assert_semantic_error(
+        r#"fn eprintln(msg: string) -> void {}
+fn main() -> void {}"#,
+        "Function name 'eprintln' is reserved by the prelude and cannot be redefined",
+        "Invalid argument",
+        SemanticErrorKind::InvalidArgument,
+    );
+}
+
+#[test]
+fn test_compile_error_print_no_args() {
+    assert_semantic_error(
+        r#"fn main() -> void { print() }"#,
+        "print expects exactly 1 argument",
+        "Invalid argument",
+        SemanticErrorKind::InvalidArgument,
+    );
+}
+
+#[test]
+fn test_compile_error_eprint_multiple_args() {
+    assert_semantic_error(
+        r#"fn main() -> void { eprint("a", "b") }"#,
+        "eprint expects exactly 1 argument",
+        "Invalid argument",
+        SemanticErrorKind::InvalidArgument,
+    );
+}
+
+#[test]
+fn test_compile_error_eprintln_used_as_value() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let x: i64 = eprintln("a")
+}"#,
+        "Function call 'eprintln' returns 'void' and cannot be used as a value",
+        "Type mismatch",
+        SemanticErrorKind::TypeMismatch,
+    );
+}
+
+#[test]
+fn test_compile_error_print_undefined_variable() {
+    assert_semantic_error(
+        r#"fn main() -> void { print(missing) }"#,
+        "Undefined variable: 'missing'",
+        "Undefined variable",
+        SemanticErrorKind::UndefinedVariable,
+    );
+}
+
 #[test]
 fn test_compile_error_function_call_with_args() {
     // Calling a parameterless function with arguments should error
@@ -0,0 +1,277 @@
+use crate::helpers::assert_semantic_error;
+use lak::semantic::SemanticErrorKind;
+
+// ========================================
+// map<K, V> and set<T> error tests
+// ========================================
+
+#[test]
+fn test_compile_error_collection_constructor_without_type() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let scores = map()
+}"#,
+        "Cannot infer the type of 'map()'",
+        "Type mismatch",
+        SemanticErrorKind::TypeMismatch,
+    );
+}
+
+#[test]
+fn test_compile_error_collection_constructor_wrong_type() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let seen: map<string, i64> = set()
+}"#,
+        "Type mismatch: 'set()' creates a set and cannot be assigned to type 'map<string, i64>'",
+        "Type mismatch",
+        SemanticErrorKind::TypeMismatch,
+    );
+}
+
+#[test]
+fn test_compile_error_collection_insert_on_immutable_variable() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let seen: set<i64> = set()
+    seen.insert(1)
+}"#,
+        "Cannot call 'insert' on immutable variable 'seen'",
+        "Invalid assignment",
+        SemanticErrorKind::ImmutableVariableReassignment,
+    );
+}
+
+#[test]
+fn test_compile_error_collection_unknown_method() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let seen: set<i64> = set()
+    let (value, found) = seen.get(1)
+}"#,
+        "Variable 'seen' of type 'set<i64>' has no method 'get'",
+        "Undefined function",
+        SemanticErrorKind::UndefinedFunction,
+    );
+}
+
+#[test]
+fn test_compile_error_collection_method_wrong_key_type() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let mut scores: map<string, i64> = map()
+    scores.insert(1, 2)
+}"#,
+        "Type mismatch: integer literal '1' cannot be assigned to type 'string'",
+        "Type mismatch",
+        SemanticErrorKind::TypeMismatch,
+    );
+}
+
+#[test]
+fn test_compile_error_collection_method_arg_count() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let scores: map<string, i64> = map()
+    let n = scores.len(1)
+}"#,
+        "Function 'scores.len' expects 0 arguments, but got 1",
+        "Invalid argument",
+        SemanticErrorKind::InvalidArgument,
+    );
+}
+
+#[test]
+fn test_compile_error_collection_contains_as_statement() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let seen: set<string> = set()
+    seen.contains("x")
+}"#,
+        "Function 'seen.contains' returns 'bool', but only void functions can be called as statements",
+        "Type mismatch",
+        SemanticErrorKind::TypeMismatch,
+    );
+}
+
+#[test]
+fn test_compile_error_println_collection() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let seen: set<string> = set()
+    println(seen)
+}"#,
+        "println cannot print a value of type 'set<string>'",
+        "Invalid argument",
+        SemanticErrorKind::InvalidArgument,
+    );
+}
+
+#[test]
+fn test_compile_error_compare_collections() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let a: set<i64> = set()
+    let b: set<i64> = set()
+    let same = a == b
+}"#,
+        "Operator '==' cannot be used with 'set<i64>' type",
+        "Type mismatch",
+        SemanticErrorKind::TypeMismatch,
+    );
+}
+
+// ========================================
+// Option<T> error tests
+// ========================================
+
+#[test]
+fn test_compile_error_destructure_map_get() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let scores: map<string, i64> = map()
+    let (score, found) = scores.get("alice")
+}"#,
+        "Pattern '(score, found)' cannot destructure a value of type 'Option<i64>'",
+        "Invalid pattern",
+        SemanticErrorKind::InvalidPattern,
+    );
+}
+
+#[test]
+fn test_compile_error_option_unknown_method() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let scores: map<string, i64> = map()
+    let score = scores.get("alice")
+    let value = score.value()
+}"#,
+        "Variable 'score' of type 'Option<i64>' has no method 'value'",
+        "Undefined function",
+        SemanticErrorKind::UndefinedFunction,
+    );
+}
+
+#[test]
+fn test_compile_error_option_unwrap_or_wrong_type() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let names: map<i64, string> = map()
+    let name = names.get(1)
+    println(name.unwrap_or(0))
+}"#,
+        "Type mismatch: integer literal '0' cannot be assigned to type 'string'",
+        "Type mismatch",
+        SemanticErrorKind::TypeMismatch,
+    );
+}
+
+#[test]
+fn test_compile_error_println_option() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let names: map<i64, string> = map()
+    let name = names.get(1)
+    println(name)
+}"#,
+        "println cannot print a value of type 'Option<string>'",
+        "Invalid argument",
+        SemanticErrorKind::InvalidArgument,
+    );
+}
+
+#[test]
+fn test_compile_error_compare_options() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let scores: map<string, i64> = map()
+    let a = scores.get("a")
+    let b = scores.get("b")
+    let same = a == b
+}"#,
+        "Operator '==' cannot be used with 'Option<i64>' type",
+        "Type mismatch",
+        SemanticErrorKind::TypeMismatch,
+    );
+}
+
+// ========================================
+// rng error tests
+// ========================================
+
+#[test]
+fn test_compile_error_rng_method_on_immutable_variable() {
+    assert_semantic_error(
+        r#"fn roll(dice: rng) -> i64 {
+    return dice.int_range(1, 7)
+}
+
+fn main() -> void {
+}"#,
+        "Cannot call 'int_range' on immutable variable 'dice'",
+        "Invalid assignment",
+        SemanticErrorKind::ImmutableVariableReassignment,
+    );
+}
+
+#[test]
+fn test_compile_error_rng_unknown_method() {
+    assert_semantic_error(
+        r#"fn reseed(dice: rng) -> void {
+    let mut copy = dice
+    let n = copy.seed(1)
+}
+
+fn main() -> void {
+}"#,
+        "Variable 'copy' of type 'rng' has no method 'seed'",
+        "Undefined function",
+        SemanticErrorKind::UndefinedFunction,
+    );
+}
+
+#[test]
+fn test_compile_error_rng_shuffle_non_collection() {
+    assert_semantic_error(
+        r#"fn deal(dice: rng) -> void {
+    let mut copy = dice
+    let cards = copy.shuffle("a\nb\n")
+}
+
+fn main() -> void {
+}"#,
+        "Function 'copy.shuffle' expects a map or set argument, but got 'string'",
+        "Type mismatch",
+        SemanticErrorKind::TypeMismatch,
+    );
+}
+
+#[test]
+fn test_compile_error_println_rng() {
+    assert_semantic_error(
+        r#"fn show(dice: rng) -> void {
+    println(dice)
+}
+
+fn main() -> void {
+}"#,
+        "println cannot print a value of type 'rng'",
+        "Invalid argument",
+        SemanticErrorKind::InvalidArgument,
+    );
+}
+
+#[test]
+fn test_compile_error_compare_rngs() {
+    assert_semantic_error(
+        r#"fn same(a: rng, b: rng) -> bool {
+    return a == b
+}
+
+fn main() -> void {
+}"#,
+        "Operator '==' cannot be used with 'rng' type",
+        "Type mismatch",
+        SemanticErrorKind::TypeMismatch,
+    );
+}
@@ -433,3 +433,65 @@ fn test_continue_outside_loop_error() {
         SemanticErrorKind::InvalidControlFlow,
     );
 }
+
+#[test]
+fn test_return_inside_defer_error() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    defer {
+        return
+    }
+}"#,
+        "return statement cannot leave a deferred block",
+        "Invalid control flow",
+        SemanticErrorKind::InvalidControlFlow,
+    );
+}
+
+#[test]
+fn test_break_inside_defer_error() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    while true {
+        defer break
+    }
+}"#,
+        "break statement cannot leave a deferred block",
+        "Invalid control flow",
+        SemanticErrorKind::InvalidControlFlow,
+    );
+}
+
+#[test]
+fn test_continue_inside_defer_error() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    while true {
+        defer {
+            if true {
+                continue
+            }
+        }
+        break
+    }
+}"#,
+        "continue statement cannot leave a deferred block",
+        "Invalid control flow",
+        SemanticErrorKind::InvalidControlFlow,
+    );
+}
+
+#[test]
+fn test_defer_body_variable_not_visible_after_defer() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    defer {
+        let inner: i64 = 1
+    }
+    println(inner)
+}"#,
+        "Undefined variable: 'inner'",
+        "Undefined variable",
+        SemanticErrorKind::UndefinedVariable,
+    );
+}
@@ -0,0 +1,82 @@
+use crate::helpers::assert_semantic_error;
+use lak::semantic::SemanticErrorKind;
+
+// ========================================
+// exit() built-in function error tests
+// ========================================
+
+#[test]
+fn test_compile_error_exit_no_args() {
+    assert_semantic_error(
+        r#"fn main() -> void { exit() }"#,
+        "exit expects exactly 1 argument",
+        "Invalid argument",
+        SemanticErrorKind::InvalidArgument,
+    );
+}
+
+#[test]
+fn test_compile_error_exit_string_arg() {
+    assert_semantic_error(
+        r#"fn main() -> void { exit("1") }"#,
+        "Type mismatch: string literal cannot be assigned to type 'i32'",
+        "Type mismatch",
+        SemanticErrorKind::TypeMismatch,
+    );
+}
+
+#[test]
+fn test_compile_error_exit_i64_variable() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let code: i64 = 1
+    exit(code)
+}"#,
+        "Type mismatch: variable 'code' has type 'i64', expected 'i32'",
+        "Type mismatch",
+        SemanticErrorKind::TypeMismatch,
+    );
+}
+
+#[test]
+fn test_compile_error_exit_as_value() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let x = exit(1)
+}"#,
+        "Function call 'exit' returns 'void' and cannot be used as a value",
+        "Type mismatch",
+        SemanticErrorKind::TypeMismatch,
+    );
+}
+
+#[test]
+fn test_compile_error_exit_redefined() {
+    assert_semantic_error(
+        r#"fn exit(code: i32) -> void {}
+fn main() -> void {}"#,
+        "Function name 'exit' is reserved by the prelude and cannot be redefined",
+        "Invalid argument",
+        SemanticErrorKind::InvalidArgument,
+    );
+}
+
+#[test]
+fn test_compile_error_main_returns_i64() {
+    assert_semantic_error(
+        "fn main() -> i64 {\n    return 0\n}",
+        "main function must return void, i32 or u8, but found return type 'i64'",
+        "Invalid main signature",
+        SemanticErrorKind::InvalidMainSignature,
+    );
+}
+
+#[test]
+fn test_compile_error_main_i32_missing_return() {
+    assert_semantic_error(
+        "fn main() -> i32 {\n    println(\"hi\")\n}",
+        "Function 'main' with return type 'i32' must return a value on all code paths",
+        "Type mismatch",
+        SemanticErrorKind::TypeMismatch,
+    );
+}
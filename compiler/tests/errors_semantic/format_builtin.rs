@@ -0,0 +1,187 @@
+use crate::helpers::assert_semantic_error;
+use lak::semantic::SemanticErrorKind;
+
+// ========================================
+// format() built-in function error tests
+// ========================================
+
+#[test]
+fn test_compile_error_format_no_args() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let s = format()
+}"#,
+        "format expects a format string as its first argument",
+        "Invalid argument",
+        SemanticErrorKind::InvalidArgument,
+    );
+}
+
+#[test]
+fn test_compile_error_format_string_not_literal() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let fmt: string = "{}"
+    let s = format(fmt, 1)
+}"#,
+        "format string must be a string literal",
+        "Invalid argument",
+        SemanticErrorKind::InvalidArgument,
+    );
+}
+
+#[test]
+fn test_compile_error_format_unmatched_brace() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let s = format("value: {", 1)
+}"#,
+        "Invalid format string: unterminated placeholder; use '{{' for a literal brace",
+        "Invalid format string",
+        SemanticErrorKind::InvalidFormatString,
+    );
+}
+
+#[test]
+fn test_compile_error_format_unknown_type() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let s = format("{:q}", 1)
+}"#,
+        "Invalid format string: unknown format type 'q' in spec 'q'",
+        "Invalid format string",
+        SemanticErrorKind::InvalidFormatString,
+    );
+}
+
+#[test]
+fn test_compile_error_format_missing_argument() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let s = format("{} {}", 1)
+}"#,
+        "Format string refers to argument 1, but only 1 argument was given",
+        "Invalid argument",
+        SemanticErrorKind::InvalidArgument,
+    );
+}
+
+#[test]
+fn test_compile_error_format_unused_argument() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let s = format("{0}", 1, 2)
+}"#,
+        "Format argument 1 is never used",
+        "Invalid argument",
+        SemanticErrorKind::InvalidArgument,
+    );
+}
+
+#[test]
+fn test_compile_error_format_named_undefined_variable() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let s = format("{missing}")
+}"#,
+        "Undefined variable: 'missing'",
+        "Undefined variable",
+        SemanticErrorKind::UndefinedVariable,
+    );
+}
+
+#[test]
+fn test_compile_error_format_radix_on_float() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let s = format("{:x}", 1.5)
+}"#,
+        "Format type 'x' requires an integer value, but the argument has type 'f64'",
+        "Invalid format string",
+        SemanticErrorKind::InvalidFormatString,
+    );
+}
+
+#[test]
+fn test_compile_error_format_exponent_on_integer() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let n: i32 = 5
+    let s = format("{n:e}")
+}"#,
+        "Format type 'e' requires a float value, but the argument has type 'i32'",
+        "Invalid format string",
+        SemanticErrorKind::InvalidFormatString,
+    );
+}
+
+#[test]
+fn test_compile_error_format_precision_on_integer() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let s = format("{:8.3}", 42)
+}"#,
+        "Format precision cannot be used with a value of type 'i64'",
+        "Invalid format string",
+        SemanticErrorKind::InvalidFormatString,
+    );
+}
+
+#[test]
+fn test_compile_error_format_zero_flag_on_string() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let s = format("{:05}", "ab")
+}"#,
+        "Format flag '0' requires a numeric value, but the argument has type 'string'",
+        "Invalid format string",
+        SemanticErrorKind::InvalidFormatString,
+    );
+}
+
+#[test]
+fn test_compile_error_format_alternate_without_radix() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let s = format("{:#}", 1)
+}"#,
+        "Format flag '#' requires a radix format type ('x', 'X', 'o' or 'b')",
+        "Invalid format string",
+        SemanticErrorKind::InvalidFormatString,
+    );
+}
+
+#[test]
+fn test_compile_error_format_tuple_argument() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let s = format("{}", (1, 2))
+}"#,
+        "format cannot format a value of tuple type '(i64, i64)'",
+        "Invalid format string",
+        SemanticErrorKind::InvalidFormatString,
+    );
+}
+
+#[test]
+fn test_compile_error_format_as_statement() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    format("{}", 1)
+}"#,
+        "Function 'format' returns 'string', but only void functions can be called as statements",
+        "Type mismatch",
+        SemanticErrorKind::TypeMismatch,
+    );
+}
+
+#[test]
+fn test_compile_error_reserved_prelude_function_format() {
+    assert_semantic_error(
+        r#"fn format() -> void {}
+fn main() -> void {}"#,
+        "Function name 'format' is reserved by the prelude and cannot be redefined",
+        "Invalid argument",
+        SemanticErrorKind::InvalidArgument,
+    );
+}
@@ -0,0 +1,150 @@
+use crate::helpers::assert_semantic_error;
+use lak::semantic::SemanticErrorKind;
+
+// ============================================================================
+// Destructuring pattern errors
+// ============================================================================
+
+#[test]
+fn test_tuple_pattern_arity_mismatch() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let (a, b) = (1, 2, 3)
+}"#,
+        "Tuple pattern '(a, b)' has 2 elements, but type '(i64, i64, i64)' has 3",
+        "Invalid pattern",
+        SemanticErrorKind::InvalidPattern,
+    );
+}
+
+#[test]
+fn test_tuple_pattern_against_non_tuple() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let value: i64 = 5
+    let (a, b) = value
+}"#,
+        "Pattern '(a, b)' cannot destructure a value of type 'i64'",
+        "Invalid pattern",
+        SemanticErrorKind::InvalidPattern,
+    );
+}
+
+#[test]
+fn test_nested_tuple_pattern_against_non_tuple_element() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let (a, (b, c)) = (1, 2)
+}"#,
+        "Pattern '(b, c)' cannot destructure a value of type 'i64'",
+        "Invalid pattern",
+        SemanticErrorKind::InvalidPattern,
+    );
+}
+
+#[test]
+fn test_duplicate_binding_in_pattern() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let (a, a) = (1, 2)
+}"#,
+        "Variable 'a' is already defined at 2:10",
+        "Duplicate variable",
+        SemanticErrorKind::DuplicateVariable,
+    );
+}
+
+#[test]
+fn test_pattern_binding_not_visible_in_initializer() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let (a, b) = (1, a)
+}"#,
+        "Undefined variable: 'a'",
+        "Undefined variable",
+        SemanticErrorKind::UndefinedVariable,
+    );
+}
+
+#[test]
+fn test_immutable_pattern_binding_reassignment() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let (a, b) = (1, 2)
+    a = 5
+}"#,
+        "Cannot reassign immutable variable 'a'",
+        "Invalid assignment",
+        SemanticErrorKind::ImmutableVariableReassignment,
+    );
+}
+
+#[test]
+fn test_destructured_parameter_arity_mismatch() {
+    assert_semantic_error(
+        r#"fn first((a, b, c): (i64, i64)) -> i64 {
+    return a
+}
+
+fn main() -> void {
+    println(first((1, 2)))
+}"#,
+        "Tuple pattern '(a, b, c)' has 3 elements, but type '(i64, i64)' has 2",
+        "Invalid pattern",
+        SemanticErrorKind::InvalidPattern,
+    );
+}
+
+// ============================================================================
+// Tuple type errors
+// ============================================================================
+
+#[test]
+fn test_tuple_expression_arity_mismatch() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let pair: (i64, i64) = (1, 2, 3)
+}"#,
+        "Type mismatch: tuple expression with 3 elements cannot be assigned to type '(i64, i64)'",
+        "Type mismatch",
+        SemanticErrorKind::TypeMismatch,
+    );
+}
+
+#[test]
+fn test_tuple_expression_to_non_tuple_type() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let value: i64 = (1, 2)
+}"#,
+        "Type mismatch: tuple expression cannot be assigned to type 'i64'",
+        "Type mismatch",
+        SemanticErrorKind::TypeMismatch,
+    );
+}
+
+#[test]
+fn test_println_tuple_rejected() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    println((1, "two"))
+}"#,
+        "println cannot print a value of tuple type '(i64, string)'",
+        "Invalid argument",
+        SemanticErrorKind::InvalidArgument,
+    );
+}
+
+#[test]
+fn test_tuple_equality_rejected() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let a: (i64, i64) = (1, 2)
+    let b: (i64, i64) = (1, 2)
+    let same: bool = a == b
+}"#,
+        "Operator '==' cannot be used with '(i64, i64)' type",
+        "Type mismatch",
+        SemanticErrorKind::TypeMismatch,
+    );
+}
@@ -0,0 +1,55 @@
+use crate::helpers::assert_semantic_error;
+use lak::semantic::SemanticErrorKind;
+
+// ========================================
+// read_line() and read_all() built-in function error tests
+// ========================================
+
+#[test]
+fn test_compile_error_read_line_with_args() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let (line, ok) = read_line(1)
+}"#,
+        "Function 'read_line' expects 0 arguments, but got 1",
+        "Invalid argument",
+        SemanticErrorKind::InvalidArgument,
+    );
+}
+
+#[test]
+fn test_compile_error_read_line_as_string() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    let line: string = read_line()
+}"#,
+        "Type mismatch: function 'read_line' returns '(string, bool)', expected 'string'",
+        "Type mismatch",
+        SemanticErrorKind::TypeMismatch,
+    );
+}
+
+#[test]
+fn test_compile_error_read_all_as_statement() {
+    assert_semantic_error(
+        r#"fn main() -> void {
+    read_all()
+}"#,
+        "Function 'read_all' returns 'string', but only void functions can be called as statements",
+        "Type mismatch",
+        SemanticErrorKind::TypeMismatch,
+    );
+}
+
+#[test]
+fn test_compile_error_read_line_redefined() {
+    assert_semantic_error(
+        r#"fn read_line() -> string {
+    return ""
+}
+fn main() -> void {}"#,
+        "Function name 'read_line' is reserved by the prelude and cannot be redefined",
+        "Invalid argument",
+        SemanticErrorKind::InvalidArgument,
+    );
+}
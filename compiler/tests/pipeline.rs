@@ -7,7 +7,7 @@ mod common;
 
 use common::dummy_span;
 
-use lak::ast::{Expr, ExprKind, FnDef, Program, Stmt, StmtKind, Type, Visibility};
+use lak::ast::{Expr, ExprKind, FnDef, Program, ReturnType, Stmt, StmtKind, Type, Visibility};
 use lak::codegen::Codegen;
 use lak::lexer::Lexer;
 use lak::parser::Parser;
@@ -36,10 +36,11 @@ fn test_ast_to_codegen() {
     let program = Program {
         imports: vec![],
         functions: vec![FnDef {
+            doc: None,
             name: "main".to_string(),
             params: vec![],
             visibility: Visibility::Private,
-            return_type: "void".to_string(),
+            return_type: ReturnType::Void,
             return_type_span: dummy_span(),
             body: vec![Stmt::new(
                 StmtKind::Expr(Expr::new(
@@ -77,10 +78,11 @@ fn test_ast_let_to_codegen() {
     let program = Program {
         imports: vec![],
         functions: vec![FnDef {
+            doc: None,
             name: "main".to_string(),
             params: vec![],
             visibility: Visibility::Private,
-            return_type: "void".to_string(),
+            return_type: ReturnType::Void,
             return_type_span: dummy_span(),
             body: vec![
                 Stmt::new(
@@ -126,10 +128,11 @@ fn test_error_string_literal_as_int_value() {
     let program = Program {
         imports: vec![],
         functions: vec![FnDef {
+            doc: None,
             name: "main".to_string(),
             params: vec![],
             visibility: Visibility::Private,
-            return_type: "void".to_string(),
+            return_type: ReturnType::Void,
             return_type_span: dummy_span(),
             body: vec![Stmt::new(
                 StmtKind::Let {
@@ -160,19 +163,21 @@ fn test_error_function_call_as_int_value() {
         imports: vec![],
         functions: vec![
             FnDef {
+                doc: None,
                 name: "some_func".to_string(),
                 params: vec![],
                 visibility: Visibility::Private,
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![],
                 span: dummy_span(),
             },
             FnDef {
+                doc: None,
                 name: "main".to_string(),
                 params: vec![],
                 visibility: Visibility::Private,
-                return_type: "void".to_string(),
+                return_type: ReturnType::Void,
                 return_type_span: dummy_span(),
                 body: vec![Stmt::new(
                     StmtKind::Let {
@@ -209,10 +214,11 @@ fn test_error_int_literal_as_statement() {
     let program = Program {
         imports: vec![],
         functions: vec![FnDef {
+            doc: None,
             name: "main".to_string(),
             params: vec![],
             visibility: Visibility::Private,
-            return_type: "void".to_string(),
+            return_type: ReturnType::Void,
             return_type_span: dummy_span(),
             body: vec![Stmt::new(
                 StmtKind::Expr(Expr::new(ExprKind::IntLiteral(42), dummy_span())),
@@ -237,10 +243,11 @@ fn test_error_identifier_as_statement() {
     let program = Program {
         imports: vec![],
         functions: vec![FnDef {
+            doc: None,
             name: "main".to_string(),
             params: vec![],
             visibility: Visibility::Private,
-            return_type: "void".to_string(),
+            return_type: ReturnType::Void,
             return_type_span: dummy_span(),
             body: vec![
                 Stmt::new(
@@ -279,10 +286,11 @@ fn test_error_string_literal_as_statement() {
     let program = Program {
         imports: vec![],
         functions: vec![FnDef {
+            doc: None,
             name: "main".to_string(),
             params: vec![],
             visibility: Visibility::Private,
-            return_type: "void".to_string(),
+            return_type: ReturnType::Void,
             return_type_span: dummy_span(),
             body: vec![Stmt::new(
                 StmtKind::Expr(Expr::new(
@@ -312,10 +320,11 @@ fn test_error_i32_underflow_via_ast() {
     let program = Program {
         imports: vec![],
         functions: vec![FnDef {
+            doc: None,
             name: "main".to_string(),
             params: vec![],
             visibility: Visibility::Private,
-            return_type: "void".to_string(),
+            return_type: ReturnType::Void,
             return_type_span: dummy_span(),
             body: vec![Stmt::new(
                 StmtKind::Let {
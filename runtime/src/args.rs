@@ -0,0 +1,112 @@
+//! Command-line arguments exposed by the `arg_count` and `arg` builtins.
+//!
+//! The generated `main` passes its `argc` and `argv` to `lak_args_init`
+//! before running any Lak code. The program name (`argv[0]`) is not
+//! included, so `arg(0)` is the first argument given by the user.
+
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+use std::sync::OnceLock;
+
+use crate::lak_panic_at;
+use crate::rc::new_string;
+
+/// Arguments recorded by `lak_args_init`, without the program name.
+static ARGS: OnceLock<Vec<Vec<u8>>> = OnceLock::new();
+
+/// Copies `argv[1..argc]` into owned byte strings.
+///
+/// # Safety
+///
+/// `argv` must point to `argc` valid C strings, or be null.
+unsafe fn collect_args(argc: i32, argv: *const *const c_char) -> Vec<Vec<u8>> {
+    if argv.is_null() {
+        return Vec::new();
+    }
+    (1..argc.max(0) as usize)
+        .map(|i| {
+            // SAFETY: The caller guarantees `argv` holds `argc` valid C strings.
+            let arg = unsafe { *argv.add(i) };
+            if arg.is_null() {
+                Vec::new()
+            } else {
+                // SAFETY: Same as above.
+                unsafe { CStr::from_ptr(arg) }.to_bytes().to_vec()
+            }
+        })
+        .collect()
+}
+
+fn args() -> &'static [Vec<u8>] {
+    ARGS.get().map_or(&[], Vec::as_slice)
+}
+
+/// Records the process arguments. Called once from the generated `main`.
+///
+/// Later calls are ignored.
+///
+/// # Safety
+///
+/// `argv` must point to `argc` valid C strings, or be null.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_args_init(argc: i32, argv: *const *const c_char) {
+    // SAFETY: Forwarded from the caller.
+    let args = unsafe { collect_args(argc, argv) };
+    let _ = ARGS.set(args);
+}
+
+/// Returns the number of command-line arguments, excluding the program name.
+#[unsafe(no_mangle)]
+pub extern "C" fn lak_arg_count() -> i64 {
+    args().len() as i64
+}
+
+/// Returns the command-line argument at `index` as a new string.
+///
+/// The string is a reference-counted object owned by the caller. Panics
+/// with the call-site location if `index` is out of range.
+///
+/// # Safety
+///
+/// `file` must be null or a valid C string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_arg(
+    index: i64,
+    file: *const c_char,
+    line: u32,
+    column: u32,
+) -> *const c_char {
+    let args = args();
+    match usize::try_from(index).ok().and_then(|i| args.get(i)) {
+        Some(arg) => new_string(arg),
+        None => {
+            let message = CString::new(format!(
+                "argument index {} out of range (arg_count is {})",
+                index,
+                args.len()
+            ))
+            .expect("message has no NUL bytes");
+            // SAFETY: `message` is a valid C string; `file` is forwarded from the caller.
+            unsafe { lak_panic_at(message.as_ptr(), file, line, column) }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collect_args_skips_program_name() {
+        let argv = [c"prog".as_ptr(), c"a".as_ptr(), c"b c".as_ptr()];
+        let args = unsafe { collect_args(3, argv.as_ptr()) };
+        assert_eq!(args, vec![b"a".to_vec(), b"b c".to_vec()]);
+    }
+
+    #[test]
+    fn test_collect_args_handles_empty_and_null() {
+        let argv = [c"prog".as_ptr()];
+        assert!(unsafe { collect_args(1, argv.as_ptr()) }.is_empty());
+        assert!(unsafe { collect_args(0, std::ptr::null()) }.is_empty());
+    }
+}
@@ -0,0 +1,202 @@
+//! Shadow call stack used to print backtraces on panic.
+//!
+//! Programs built with `lak build --backtrace` (or run with
+//! `lak run --backtrace`) maintain a stack of active Lak calls:
+//!
+//! - `main` pushes a root frame on entry
+//! - every call to a Lak function is surrounded by `lak_backtrace_push`
+//!   (callee name and call-site location) and `lak_backtrace_pop`
+//!
+//! When the program panics, `lak_panic_at` prints the frames innermost first.
+//! The innermost frame is reported at the panic location and every other
+//! frame at the location of the call into the frame above it:
+//!
+//! ```text
+//! backtrace:
+//!   0: halve at lib/math.lak:5:12
+//!   1: main at main.lak:5:13
+//! ```
+//!
+//! Programs built without the flag never push frames, so no backtrace is
+//! printed.
+
+use std::cell::RefCell;
+use std::ffi::CStr;
+use std::io::Write;
+use std::os::raw::c_char;
+
+use crate::cstr_to_lossy_str;
+
+/// One active Lak call.
+///
+/// All strings are static data emitted by the compiler.
+#[derive(Clone, Copy)]
+pub(crate) struct Frame {
+    /// Name of the called function.
+    function: *const c_char,
+    /// File containing the call expression, or null for the root frame.
+    file: *const c_char,
+    /// Line of the call expression.
+    line: u32,
+    /// Column of the call expression.
+    column: u32,
+}
+
+thread_local! {
+    static STACK: RefCell<Vec<Frame>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Pushes a frame for a call to `function` made at `file:line:column`.
+///
+/// # Safety
+///
+/// `function` and `file` must be null or valid null-terminated C strings that
+/// outlive the frame.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_backtrace_push(
+    function: *const c_char,
+    file: *const c_char,
+    line: u32,
+    column: u32,
+) {
+    STACK.with_borrow_mut(|stack| {
+        stack.push(Frame {
+            function,
+            file,
+            line,
+            column,
+        })
+    });
+}
+
+/// Pops the innermost frame after its call returns.
+#[unsafe(no_mangle)]
+pub extern "C" fn lak_backtrace_pop() {
+    STACK.with_borrow_mut(|stack| {
+        stack.pop();
+    });
+}
+
+/// Formats a nullable C string, substituting `fallback` for null.
+///
+/// # Safety
+///
+/// If non-null, `ptr` must point to a valid null-terminated C string.
+unsafe fn lossy_or(ptr: *const c_char, fallback: &str) -> String {
+    if ptr.is_null() {
+        return fallback.to_string();
+    }
+    // SAFETY: The caller guarantees `ptr` is a valid C string.
+    cstr_to_lossy_str(unsafe { CStr::from_ptr(ptr) })
+}
+
+/// Writes the backtrace for `frames` (outermost first), with the innermost
+/// frame reported at `location`. Writes nothing when `frames` is empty.
+///
+/// # Safety
+///
+/// The strings referenced by `frames` and `location` must be null or valid
+/// null-terminated C strings.
+pub(crate) unsafe fn write_frames(
+    out: &mut impl Write,
+    frames: &[Frame],
+    location: (*const c_char, u32, u32),
+) -> std::io::Result<()> {
+    if frames.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(out, "backtrace:")?;
+    let mut position = location;
+    for (index, frame) in frames.iter().rev().enumerate() {
+        // SAFETY: The caller guarantees the strings are valid.
+        let function = unsafe { lossy_or(frame.function, "<unknown>") };
+        // SAFETY: Same as above.
+        let file = unsafe { lossy_or(position.0, "<unknown>") };
+        writeln!(
+            out,
+            "  {}: {} at {}:{}:{}",
+            index, function, file, position.1, position.2
+        )?;
+        position = (frame.file, frame.line, frame.column);
+    }
+    Ok(())
+}
+
+/// Writes the current thread's backtrace, with the innermost frame reported
+/// at the panic location.
+///
+/// # Safety
+///
+/// `file` must be null or a valid null-terminated C string.
+pub(crate) unsafe fn write_backtrace(
+    out: &mut impl Write,
+    file: *const c_char,
+    line: u32,
+    column: u32,
+) -> std::io::Result<()> {
+    let frames = STACK.with_borrow(|stack| stack.clone());
+    // SAFETY: Frames are pushed by compiled code with static strings, and the
+    // caller guarantees `file` is valid.
+    unsafe { write_frames(out, &frames, (file, line, column)) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(function: &CStr, file: Option<&CStr>, line: u32, column: u32) -> Frame {
+        Frame {
+            function: function.as_ptr(),
+            file: file.map_or(std::ptr::null(), CStr::as_ptr),
+            line,
+            column,
+        }
+    }
+
+    fn render(frames: &[Frame], location: (&CStr, u32, u32)) -> String {
+        let mut out = Vec::new();
+        unsafe {
+            write_frames(
+                &mut out,
+                frames,
+                (location.0.as_ptr(), location.1, location.2),
+            )
+        }
+        .unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_frames_are_reported_innermost_first() {
+        let frames = [
+            frame(c"main", None, 0, 0),
+            frame(c"parse", Some(c"main.lak"), 5, 13),
+            frame(c"halve", Some(c"lib/math.lak"), 9, 20),
+        ];
+        assert_eq!(
+            render(&frames, (c"lib/math.lak", 2, 12)),
+            "backtrace:\n  0: halve at lib/math.lak:2:12\n  1: parse at lib/math.lak:9:20\n  2: main at main.lak:5:13\n"
+        );
+    }
+
+    #[test]
+    fn test_no_frames_writes_nothing() {
+        assert_eq!(render(&[], (c"main.lak", 1, 1)), "");
+    }
+
+    #[test]
+    fn test_push_and_pop() {
+        unsafe { lak_backtrace_push(c"main".as_ptr(), std::ptr::null(), 0, 0) };
+        unsafe { lak_backtrace_push(c"f".as_ptr(), c"main.lak".as_ptr(), 2, 5) };
+        lak_backtrace_pop();
+
+        let mut out = Vec::new();
+        unsafe { write_backtrace(&mut out, c"main.lak".as_ptr(), 3, 1) }.unwrap();
+        lak_backtrace_pop();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "backtrace:\n  0: main at main.lak:3:1\n"
+        );
+    }
+}
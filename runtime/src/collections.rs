@@ -0,0 +1,881 @@
+//! Hash maps and sets behind Lak's `map<K, V>` and `set<T>` types.
+//!
+//! A map or set is a reference-counted object (see [`crate::rc`]) whose
+//! payload is a pointer to a [`Table`]. Compiled code passes keys and values
+//! as 64-bit slots:
+//!
+//! - integers are extended to 64 bits, and bools are 0 or 1
+//! - floats are passed as the bits of the value converted to `f64`
+//! - strings are passed as object pointers
+//!
+//! Every operation taking a key has an `_int` entry point, which hashes and
+//! compares the slot itself and serves integer and bool keys, and a
+//! `_string` entry point, which hashes and compares the string contents.
+//! Whether keys and values are strings is fixed when the collection is
+//! created, so the table knows which slots it holds references to.
+//!
+//! Entries are kept in insertion order, which is the order of `key_at`,
+//! `value_at` and `item_at`. Removing an entry shifts the entries after it
+//! down by one position, so the order of the others is unchanged.
+//!
+//! Strings returned to compiled code are owned by the caller.
+//!
+//! Maps and sets are values: compiled code calls `lak_map_unique` or
+//! `lak_set_unique` before changing one, which copies the table first if
+//! another variable still refers to it.
+//!
+//! `lak_map_shuffle` and `lak_set_shuffle` return a copy whose entries are
+//! in an order drawn from a generator of the `random` module.
+
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+
+use crate::lak_panic_at;
+use crate::random::{Rng, generator};
+use crate::rc::{is_shared, lak_rc_alloc, lak_release, lak_retain, release_with};
+
+/// A key passed by compiled code.
+enum Key<'a> {
+    /// An integer or bool key.
+    Int(u64),
+    /// A string key: the object pointer and its contents.
+    String(*const c_char, &'a [u8]),
+}
+
+impl Key<'_> {
+    /// Returns the slot stored for this key.
+    fn slot(&self) -> u64 {
+        match self {
+            Key::Int(value) => *value,
+            Key::String(ptr, _) => *ptr as u64,
+        }
+    }
+}
+
+/// Contents of a map or set.
+struct Table {
+    /// Whether keys are string objects.
+    key_is_string: bool,
+    /// Whether values are string objects. Always false for sets.
+    value_is_string: bool,
+    /// Keys in iteration order.
+    keys: Vec<u64>,
+    /// Values in the order of `keys`, or `None` for sets.
+    values: Option<Vec<u64>>,
+    /// Position in `keys` of each integer key.
+    int_index: HashMap<u64, usize>,
+    /// Position in `keys` of each string key, by contents.
+    string_index: HashMap<Box<[u8]>, usize>,
+}
+
+impl Table {
+    fn new(key_is_string: bool, value_is_string: bool, values: Option<Vec<u64>>) -> Table {
+        Table {
+            key_is_string,
+            value_is_string,
+            keys: Vec::new(),
+            values,
+            int_index: HashMap::new(),
+            string_index: HashMap::new(),
+        }
+    }
+
+    /// Returns a copy of the table holding its own references to strings.
+    fn duplicate(&self) -> Table {
+        for &key in &self.keys {
+            retain_slot(self.key_is_string, key);
+        }
+        for &value in self.values.iter().flatten() {
+            retain_slot(self.value_is_string, value);
+        }
+        Table {
+            key_is_string: self.key_is_string,
+            value_is_string: self.value_is_string,
+            keys: self.keys.clone(),
+            values: self.values.clone(),
+            int_index: self.int_index.clone(),
+            string_index: self.string_index.clone(),
+        }
+    }
+
+    /// Returns a copy of the table with its entries in random order.
+    fn shuffled(&self, rng: &mut Rng) -> Table {
+        let mut order: Vec<usize> = (0..self.keys.len()).collect();
+        for i in (1..order.len()).rev() {
+            let j = rng.below(i as u64 + 1) as usize;
+            order.swap(i, j);
+        }
+        let mut copy = self.duplicate();
+        copy.keys = order.iter().map(|&position| self.keys[position]).collect();
+        copy.values = self
+            .values
+            .as_ref()
+            .map(|values| order.iter().map(|&position| values[position]).collect());
+        for (position, &key) in copy.keys.iter().enumerate() {
+            if copy.key_is_string {
+                // SAFETY: String keys held by the table are live objects.
+                let bytes = unsafe { string_bytes(key as *const c_char) };
+                copy.string_index.insert(bytes.into(), position);
+            } else {
+                copy.int_index.insert(key, position);
+            }
+        }
+        copy
+    }
+
+    fn find(&self, key: &Key) -> Option<usize> {
+        match key {
+            Key::Int(value) => self.int_index.get(value).copied(),
+            Key::String(_, bytes) => self.string_index.get(*bytes).copied(),
+        }
+    }
+
+    /// Returns the position of `key`, adding it if it is not present.
+    ///
+    /// Returns `true` as the second element when the key was added, in which
+    /// case the caller must push its value for maps.
+    fn find_or_insert(&mut self, key: &Key) -> (usize, bool) {
+        if let Some(position) = self.find(key) {
+            return (position, false);
+        }
+        let position = self.keys.len();
+        match key {
+            Key::Int(value) => {
+                self.int_index.insert(*value, position);
+            }
+            Key::String(ptr, bytes) => {
+                // SAFETY: String keys are live objects; the table keeps a reference.
+                unsafe { lak_retain(*ptr as *mut u8) };
+                self.string_index.insert((*bytes).into(), position);
+            }
+        }
+        self.keys.push(key.slot());
+        (position, true)
+    }
+
+    /// Sets the value of `key` in a map, releasing the value it replaces.
+    fn insert(&mut self, key: &Key, value: u64) {
+        let (position, added) = self.find_or_insert(key);
+        if self.value_is_string {
+            // SAFETY: String values are live objects; the table keeps a reference.
+            unsafe { lak_retain(value as *mut u8) };
+        }
+        let value_is_string = self.value_is_string;
+        let values = self.values.as_mut().expect("insert is only called on maps");
+        if added {
+            values.push(value);
+        } else {
+            let old = std::mem::replace(&mut values[position], value);
+            release_slot(value_is_string, old);
+        }
+    }
+
+    /// Removes `key` and its value. Returns whether the key was present.
+    ///
+    /// This takes time linear in the number of entries after `key`, whose
+    /// positions move down by one.
+    fn remove(&mut self, key: &Key) -> bool {
+        let position = match key {
+            Key::Int(value) => self.int_index.remove(value),
+            Key::String(_, bytes) => self.string_index.remove(*bytes),
+        };
+        let Some(position) = position else {
+            return false;
+        };
+
+        let old_key = self.keys.remove(position);
+        release_slot(self.key_is_string, old_key);
+        if let Some(values) = &mut self.values {
+            let old_value = values.remove(position);
+            release_slot(self.value_is_string, old_value);
+        }
+
+        for (moved_position, &moved) in self.keys.iter().enumerate().skip(position) {
+            if self.key_is_string {
+                // SAFETY: String keys held by the table are live objects.
+                let bytes = unsafe { string_bytes(moved as *const c_char) };
+                self.string_index.insert(bytes.into(), moved_position);
+            } else {
+                self.int_index.insert(moved, moved_position);
+            }
+        }
+        true
+    }
+}
+
+impl Drop for Table {
+    fn drop(&mut self) {
+        for &key in &self.keys {
+            release_slot(self.key_is_string, key);
+        }
+        for &value in self.values.iter().flatten() {
+            release_slot(self.value_is_string, value);
+        }
+    }
+}
+
+/// Releases the reference held by a slot if it is a string.
+fn release_slot(is_string: bool, slot: u64) {
+    if is_string {
+        // SAFETY: The table owned a reference to this string object.
+        unsafe { lak_release(slot as *mut u8) };
+    }
+}
+
+/// Retains a slot returned to compiled code if it is a string.
+fn retain_slot(is_string: bool, slot: u64) -> u64 {
+    if is_string {
+        // SAFETY: String slots held by the table are live objects.
+        unsafe { lak_retain(slot as *mut u8) };
+    }
+    slot
+}
+
+/// Returns the contents of a string object.
+///
+/// # Safety
+///
+/// `ptr` must be a valid C string that outlives the returned slice.
+unsafe fn string_bytes<'a>(ptr: *const c_char) -> &'a [u8] {
+    // SAFETY: Guaranteed by the caller.
+    unsafe { CStr::from_ptr(ptr) }.to_bytes()
+}
+
+/// Allocates a collection object owning `table`.
+fn new_object(table: Table) -> *mut u8 {
+    let ptr = lak_rc_alloc(size_of::<*mut Table>() as u64);
+    // SAFETY: The payload has room for one pointer and is suitably aligned.
+    unsafe { (ptr as *mut *mut Table).write(Box::into_raw(Box::new(table))) };
+    ptr
+}
+
+/// Returns the table of a collection object.
+///
+/// # Safety
+///
+/// `ptr` must be a live map or set object.
+unsafe fn table<'a>(ptr: *mut u8) -> &'a mut Table {
+    // SAFETY: The payload of a collection object is a pointer to its table.
+    unsafe { &mut **(ptr as *mut *mut Table) }
+}
+
+/// Removes an owner from a collection object, dropping its table when no
+/// owners remain.
+///
+/// # Safety
+///
+/// Same as [`lak_release`].
+unsafe fn release_collection(ptr: *mut u8) {
+    // SAFETY: Forwarded from the caller.
+    unsafe {
+        release_with(ptr, |payload| {
+            drop(Box::from_raw(*(payload as *mut *mut Table)));
+        })
+    }
+}
+
+/// Returns a collection object that the caller is the only owner of.
+///
+/// If `ptr` has other owners, the caller's reference is released and a copy
+/// owned by the caller is returned; otherwise `ptr` itself is returned.
+///
+/// # Safety
+///
+/// `ptr` must be a live map or set object owned by the caller.
+unsafe fn make_unique(ptr: *mut u8) -> *mut u8 {
+    // SAFETY: Guaranteed by the caller.
+    unsafe {
+        if !is_shared(ptr) {
+            return ptr;
+        }
+        let copy = new_object(table(ptr).duplicate());
+        release_collection(ptr);
+        copy
+    }
+}
+
+/// Returns the position for `index`, panicking at the call site if it is
+/// out of range.
+///
+/// # Safety
+///
+/// `file` must be null or a valid C string.
+unsafe fn checked_index(
+    kind: &str,
+    index: i64,
+    len: usize,
+    file: *const c_char,
+    line: u32,
+    column: u32,
+) -> usize {
+    match usize::try_from(index) {
+        Ok(position) if position < len => position,
+        _ => {
+            let message = format!("{} index {} out of range for length {}", kind, index, len);
+            let message = CString::new(message).expect("message has no NUL bytes");
+            // SAFETY: `message` is a valid C string; `file` is forwarded from the caller.
+            unsafe { lak_panic_at(message.as_ptr(), file, line, column) }
+        }
+    }
+}
+
+/// Creates an empty map owned by the caller.
+#[unsafe(no_mangle)]
+pub extern "C" fn lak_map_new(key_is_string: bool, value_is_string: bool) -> *mut u8 {
+    new_object(Table::new(key_is_string, value_is_string, Some(Vec::new())))
+}
+
+/// Sets the value of an integer or bool key.
+///
+/// # Safety
+///
+/// `map` must be a live map with non-string keys; a string `value` must be a
+/// live string object.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_map_insert_int(map: *mut u8, key: u64, value: u64) {
+    // SAFETY: Guaranteed by the caller.
+    unsafe { table(map) }.insert(&Key::Int(key), value);
+}
+
+/// Sets the value of a string key.
+///
+/// # Safety
+///
+/// `map` must be a live map with string keys, `key` a live string object,
+/// and a string `value` a live string object.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_map_insert_string(map: *mut u8, key: *const c_char, value: u64) {
+    // SAFETY: Guaranteed by the caller.
+    let key = Key::String(key, unsafe { string_bytes(key) });
+    unsafe { table(map) }.insert(&key, value);
+}
+
+/// Looks up a key and stores its value, or 0 if it is absent, in `value`.
+fn map_get(table: &Table, key: &Key, value: &mut u64) -> bool {
+    let values = table
+        .values
+        .as_ref()
+        .expect("map_get is only called on maps");
+    match table.find(key) {
+        Some(position) => {
+            *value = retain_slot(table.value_is_string, values[position]);
+            true
+        }
+        None => {
+            *value = 0;
+            false
+        }
+    }
+}
+
+/// Looks up an integer or bool key.
+///
+/// Stores the value, or 0 if the key is absent, in `value` and returns
+/// whether the key was present.
+///
+/// # Safety
+///
+/// `map` must be a live map with non-string keys and `value` a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_map_get_int(map: *mut u8, key: u64, value: *mut u64) -> bool {
+    // SAFETY: Guaranteed by the caller.
+    unsafe { map_get(table(map), &Key::Int(key), &mut *value) }
+}
+
+/// Looks up a string key. See [`lak_map_get_int`].
+///
+/// # Safety
+///
+/// `map` must be a live map with string keys, `key` a live string object,
+/// and `value` a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_map_get_string(
+    map: *mut u8,
+    key: *const c_char,
+    value: *mut u64,
+) -> bool {
+    // SAFETY: Guaranteed by the caller.
+    unsafe {
+        let key = Key::String(key, string_bytes(key));
+        map_get(table(map), &key, &mut *value)
+    }
+}
+
+/// Removes an integer or bool key and its value. Returns whether the key
+/// was present.
+///
+/// # Safety
+///
+/// `map` must be a live map with non-string keys.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_map_remove_int(map: *mut u8, key: u64) -> bool {
+    // SAFETY: Guaranteed by the caller.
+    unsafe { table(map) }.remove(&Key::Int(key))
+}
+
+/// Removes a string key and its value. Returns whether the key was present.
+///
+/// # Safety
+///
+/// `map` must be a live map with string keys and `key` a live string object.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_map_remove_string(map: *mut u8, key: *const c_char) -> bool {
+    // SAFETY: Guaranteed by the caller.
+    unsafe { table(map).remove(&Key::String(key, string_bytes(key))) }
+}
+
+/// Returns whether a map contains an integer or bool key.
+///
+/// # Safety
+///
+/// `map` must be a live map with non-string keys.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_map_contains_int(map: *mut u8, key: u64) -> bool {
+    // SAFETY: Guaranteed by the caller.
+    unsafe { table(map) }.find(&Key::Int(key)).is_some()
+}
+
+/// Returns whether a map contains a string key.
+///
+/// # Safety
+///
+/// `map` must be a live map with string keys and `key` a live string object.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_map_contains_string(map: *mut u8, key: *const c_char) -> bool {
+    // SAFETY: Guaranteed by the caller.
+    unsafe { table(map).find(&Key::String(key, string_bytes(key))) }.is_some()
+}
+
+/// Returns the number of entries in a map.
+///
+/// # Safety
+///
+/// `map` must be a live map.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_map_len(map: *mut u8) -> i64 {
+    // SAFETY: Guaranteed by the caller.
+    unsafe { table(map) }.keys.len() as i64
+}
+
+/// Returns the key of the entry at `index` in iteration order.
+///
+/// Panics with the call-site location if `index` is out of range.
+///
+/// # Safety
+///
+/// `map` must be a live map and `file` null or a valid C string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_map_key_at(
+    map: *mut u8,
+    index: i64,
+    file: *const c_char,
+    line: u32,
+    column: u32,
+) -> u64 {
+    // SAFETY: Guaranteed by the caller.
+    let table = unsafe { table(map) };
+    // SAFETY: `file` is forwarded from the caller.
+    let position = unsafe { checked_index("map", index, table.keys.len(), file, line, column) };
+    retain_slot(table.key_is_string, table.keys[position])
+}
+
+/// Returns the value of the entry at `index` in iteration order.
+///
+/// Panics with the call-site location if `index` is out of range.
+///
+/// # Safety
+///
+/// `map` must be a live map and `file` null or a valid C string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_map_value_at(
+    map: *mut u8,
+    index: i64,
+    file: *const c_char,
+    line: u32,
+    column: u32,
+) -> u64 {
+    // SAFETY: Guaranteed by the caller.
+    let table = unsafe { table(map) };
+    let values = table.values.as_ref().expect("map has values");
+    // SAFETY: `file` is forwarded from the caller.
+    let position = unsafe { checked_index("map", index, values.len(), file, line, column) };
+    retain_slot(table.value_is_string, values[position])
+}
+
+/// Removes an owner from a map, releasing its entries when no owners remain.
+///
+/// # Safety
+///
+/// Same as [`lak_release`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_map_release(map: *mut u8) {
+    // SAFETY: Forwarded from the caller.
+    unsafe { release_collection(map) }
+}
+
+/// Prepares a map owned by the caller for a change. See
+/// [`lak_set_unique`].
+///
+/// # Safety
+///
+/// `map` must be a live map owned by the caller. It must not be used by the
+/// caller afterwards; the returned map replaces it.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_map_unique(map: *mut u8) -> *mut u8 {
+    // SAFETY: Forwarded from the caller.
+    unsafe { make_unique(map) }
+}
+
+/// Returns a copy of `map` with its entries in random order, owned by the
+/// caller. Keys keep their values.
+///
+/// # Safety
+///
+/// `map` must be a live map and `rng` a live generator.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_map_shuffle(map: *mut u8, rng: *mut u8) -> *mut u8 {
+    // SAFETY: Guaranteed by the caller.
+    new_object(unsafe { table(map).shuffled(generator(rng)) })
+}
+
+/// Creates an empty set owned by the caller.
+#[unsafe(no_mangle)]
+pub extern "C" fn lak_set_new(item_is_string: bool) -> *mut u8 {
+    new_object(Table::new(item_is_string, false, None))
+}
+
+/// Adds an integer or bool item if it is not present.
+///
+/// # Safety
+///
+/// `set` must be a live set with non-string items.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_set_insert_int(set: *mut u8, item: u64) {
+    // SAFETY: Guaranteed by the caller.
+    unsafe { table(set) }.find_or_insert(&Key::Int(item));
+}
+
+/// Adds a string item if it is not present.
+///
+/// # Safety
+///
+/// `set` must be a live set with string items and `item` a live string object.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_set_insert_string(set: *mut u8, item: *const c_char) {
+    // SAFETY: Guaranteed by the caller.
+    unsafe { table(set).find_or_insert(&Key::String(item, string_bytes(item))) };
+}
+
+/// Removes an integer or bool item. Returns whether it was present.
+///
+/// # Safety
+///
+/// `set` must be a live set with non-string items.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_set_remove_int(set: *mut u8, item: u64) -> bool {
+    // SAFETY: Guaranteed by the caller.
+    unsafe { table(set) }.remove(&Key::Int(item))
+}
+
+/// Removes a string item. Returns whether it was present.
+///
+/// # Safety
+///
+/// `set` must be a live set with string items and `item` a live string object.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_set_remove_string(set: *mut u8, item: *const c_char) -> bool {
+    // SAFETY: Guaranteed by the caller.
+    unsafe { table(set).remove(&Key::String(item, string_bytes(item))) }
+}
+
+/// Returns whether a set contains an integer or bool item.
+///
+/// # Safety
+///
+/// `set` must be a live set with non-string items.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_set_contains_int(set: *mut u8, item: u64) -> bool {
+    // SAFETY: Guaranteed by the caller.
+    unsafe { table(set) }.find(&Key::Int(item)).is_some()
+}
+
+/// Returns whether a set contains a string item.
+///
+/// # Safety
+///
+/// `set` must be a live set with string items and `item` a live string object.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_set_contains_string(set: *mut u8, item: *const c_char) -> bool {
+    // SAFETY: Guaranteed by the caller.
+    unsafe { table(set).find(&Key::String(item, string_bytes(item))) }.is_some()
+}
+
+/// Returns the number of items in a set.
+///
+/// # Safety
+///
+/// `set` must be a live set.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_set_len(set: *mut u8) -> i64 {
+    // SAFETY: Guaranteed by the caller.
+    unsafe { table(set) }.keys.len() as i64
+}
+
+/// Returns the item at `index` in iteration order.
+///
+/// Panics with the call-site location if `index` is out of range.
+///
+/// # Safety
+///
+/// `set` must be a live set and `file` null or a valid C string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_set_item_at(
+    set: *mut u8,
+    index: i64,
+    file: *const c_char,
+    line: u32,
+    column: u32,
+) -> u64 {
+    // SAFETY: Guaranteed by the caller.
+    let table = unsafe { table(set) };
+    // SAFETY: `file` is forwarded from the caller.
+    let position = unsafe { checked_index("set", index, table.keys.len(), file, line, column) };
+    retain_slot(table.key_is_string, table.keys[position])
+}
+
+/// Removes an owner from a set, releasing its items when no owners remain.
+///
+/// # Safety
+///
+/// Same as [`lak_release`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_set_release(set: *mut u8) {
+    // SAFETY: Forwarded from the caller.
+    unsafe { release_collection(set) }
+}
+
+/// Prepares a set owned by the caller for a change.
+///
+/// Returns `set` itself if the caller is its only owner. Otherwise releases
+/// the caller's reference and returns a copy owned by the caller, so the
+/// other owners do not see the change.
+///
+/// # Safety
+///
+/// `set` must be a live set owned by the caller. It must not be used by the
+/// caller afterwards; the returned set replaces it.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_set_unique(set: *mut u8) -> *mut u8 {
+    // SAFETY: Forwarded from the caller.
+    unsafe { make_unique(set) }
+}
+
+/// Returns a copy of `set` with its items in random order, owned by the
+/// caller.
+///
+/// # Safety
+///
+/// `set` must be a live set and `rng` a live generator.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_set_shuffle(set: *mut u8, rng: *mut u8) -> *mut u8 {
+    // SAFETY: Guaranteed by the caller.
+    new_object(unsafe { table(set).shuffled(generator(rng)) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rc::new_string;
+
+    fn string(text: &str) -> *const c_char {
+        new_string(text.as_bytes())
+    }
+
+    fn text(slot: u64) -> String {
+        let text = unsafe { string_bytes(slot as *const c_char) };
+        String::from_utf8(text.to_vec()).unwrap()
+    }
+
+    #[test]
+    fn test_map_int_keys() {
+        let map = lak_map_new(false, false);
+        unsafe {
+            lak_map_insert_int(map, 1, 10);
+            lak_map_insert_int(map, (-2i64) as u64, 20);
+            lak_map_insert_int(map, 1, 11);
+            assert_eq!(lak_map_len(map), 2);
+
+            let mut value = 99;
+            assert!(lak_map_get_int(map, 1, &mut value));
+            assert_eq!(value, 11);
+            assert!(!lak_map_get_int(map, 3, &mut value));
+            assert_eq!(value, 0);
+
+            assert!(lak_map_contains_int(map, (-2i64) as u64));
+            assert!(lak_map_remove_int(map, 1));
+            assert!(!lak_map_remove_int(map, 1));
+            assert!(!lak_map_contains_int(map, 1));
+            assert_eq!(lak_map_len(map), 1);
+            assert_eq!(
+                lak_map_key_at(map, 0, std::ptr::null(), 0, 0),
+                (-2i64) as u64
+            );
+            assert_eq!(lak_map_value_at(map, 0, std::ptr::null(), 0, 0), 20);
+            lak_map_release(map);
+        }
+    }
+
+    #[test]
+    fn test_map_string_keys_compare_contents() {
+        let map = lak_map_new(true, true);
+        let key = string("alice");
+        let same_key = string("alice");
+        let value = string("thirty");
+        unsafe {
+            lak_map_insert_string(map, key, value as u64);
+            lak_release(value as *mut u8);
+            assert!(lak_map_contains_string(map, same_key));
+
+            let mut slot = 0;
+            assert!(lak_map_get_string(map, same_key, &mut slot));
+            assert_eq!(text(slot), "thirty");
+            lak_release(slot as *mut u8);
+
+            assert_eq!(
+                text(lak_map_key_at(map, 0, std::ptr::null(), 0, 0)),
+                "alice"
+            );
+            lak_release(key as *mut u8);
+            assert!(lak_map_remove_string(map, same_key));
+            assert_eq!(lak_map_len(map), 0);
+            lak_release(same_key as *mut u8);
+            lak_map_release(map);
+        }
+    }
+
+    #[test]
+    fn test_remove_keeps_insertion_order() {
+        let map = lak_map_new(true, false);
+        let keys = [string("a"), string("b"), string("c"), string("d")];
+        unsafe {
+            for (value, &key) in keys.iter().enumerate() {
+                lak_map_insert_string(map, key, value as u64);
+            }
+            assert!(lak_map_remove_string(map, keys[1]));
+            let order: Vec<String> = (0..lak_map_len(map))
+                .map(|index| {
+                    let key = lak_map_key_at(map, index, std::ptr::null(), 0, 0);
+                    let name = text(key);
+                    lak_release(key as *mut u8);
+                    name
+                })
+                .collect();
+            assert_eq!(order, ["a", "c", "d"]);
+            let mut value = 0;
+            assert!(lak_map_get_string(map, keys[3], &mut value));
+            assert_eq!(value, 3);
+            assert!(lak_map_remove_string(map, keys[2]));
+            assert_eq!(lak_map_value_at(map, 1, std::ptr::null(), 0, 0), 3);
+            lak_map_release(map);
+            for key in keys {
+                lak_release(key as *mut u8);
+            }
+        }
+    }
+
+    #[test]
+    fn test_unique_copies_shared_map() {
+        let map = lak_map_new(true, false);
+        let key = string("a");
+        unsafe {
+            lak_map_insert_string(map, key, 1);
+            assert_eq!(lak_map_unique(map), map);
+
+            lak_retain(map);
+            let copy = lak_map_unique(map);
+            assert_ne!(copy, map);
+            lak_map_insert_string(copy, key, 2);
+            let mut value = 0;
+            assert!(lak_map_get_string(map, key, &mut value));
+            assert_eq!(value, 1);
+            assert!(lak_map_get_string(copy, key, &mut value));
+            assert_eq!(value, 2);
+            lak_map_release(copy);
+            lak_map_release(map);
+            lak_release(key as *mut u8);
+        }
+    }
+
+    #[test]
+    fn test_shuffle_keeps_entries() {
+        let map = lak_map_new(true, false);
+        let keys: Vec<_> = (0..20).map(|i| string(&i.to_string())).collect();
+        let rng = crate::random::lak_random_seeded(5);
+        unsafe {
+            for (value, &key) in keys.iter().enumerate() {
+                lak_map_insert_string(map, key, value as u64);
+            }
+            let shuffled = lak_map_shuffle(map, rng);
+            assert_eq!(lak_map_len(shuffled), 20);
+            let order: Vec<u64> = (0..20)
+                .map(|i| lak_map_value_at(shuffled, i, std::ptr::null(), 0, 0))
+                .collect();
+            assert_ne!(order, (0..20).collect::<Vec<u64>>());
+            for (value, &key) in keys.iter().enumerate() {
+                let mut found = 0;
+                assert!(lak_map_get_string(shuffled, key, &mut found));
+                assert_eq!(found, value as u64);
+                let position = order.iter().position(|&v| v == value as u64).unwrap();
+                let moved = lak_map_key_at(shuffled, position as i64, std::ptr::null(), 0, 0);
+                assert_eq!(string_bytes(moved as *const c_char), string_bytes(key));
+                lak_release(moved as *mut u8);
+            }
+            assert_eq!(lak_map_value_at(map, 3, std::ptr::null(), 0, 0), 3);
+
+            let set = lak_set_new(false);
+            let empty = lak_set_shuffle(set, rng);
+            assert_eq!(lak_set_len(empty), 0);
+
+            lak_set_release(empty);
+            lak_set_release(set);
+            lak_map_release(shuffled);
+            lak_map_release(map);
+            lak_release(rng);
+            for key in keys {
+                lak_release(key as *mut u8);
+            }
+        }
+    }
+
+    #[test]
+    fn test_set() {
+        let set = lak_set_new(false);
+        unsafe {
+            lak_set_insert_int(set, 1);
+            lak_set_insert_int(set, 0);
+            lak_set_insert_int(set, 1);
+            assert_eq!(lak_set_len(set), 2);
+            assert!(lak_set_contains_int(set, 0));
+            assert_eq!(lak_set_item_at(set, 1, std::ptr::null(), 0, 0), 0);
+            assert!(lak_set_remove_int(set, 1));
+            assert!(!lak_set_contains_int(set, 1));
+            lak_set_release(set);
+        }
+    }
+
+    #[test]
+    fn test_string_set() {
+        let set = lak_set_new(true);
+        let item = string("x");
+        unsafe {
+            lak_set_insert_string(set, item);
+            lak_set_insert_string(set, item);
+            assert_eq!(lak_set_len(set), 1);
+            assert!(lak_set_contains_string(set, item));
+            assert!(lak_set_remove_string(set, item));
+            lak_release(item as *mut u8);
+            lak_set_release(set);
+        }
+    }
+}
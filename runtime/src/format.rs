@@ -0,0 +1,492 @@
+//! Runtime support for Lak's `format` builtin.
+//!
+//! The compiler parses and validates format strings at compile time and
+//! lowers each `format` call to a sequence of calls on a heap-allocated
+//! buffer:
+//!
+//! 1. `lak_fmt_new` creates the buffer
+//! 2. `lak_fmt_push_literal` appends literal text
+//! 3. `lak_fmt_push_{str,i64,u64,f32,f64,bool,char}` append a value formatted
+//!    according to a [`LakFormatSpec`]
+//! 4. `lak_fmt_finish` consumes the buffer and returns a reference-counted
+//!    C string
+//!
+//! Signed integers are widened to `i64` and unsigned integers to `u64`
+//! before the call. Radix output (`x`, `X`, `o`, `b`) of negative numbers
+//! uses a leading minus sign followed by the magnitude, e.g. `-ff`.
+
+use super::rc::new_string;
+use super::{CharDisplay, cstr_from_nullable_ptr, cstr_to_lossy_str};
+use std::fmt::{Display, LowerExp, UpperExp};
+use std::os::raw::c_char;
+
+const ALIGN_LEFT: u8 = 1;
+const ALIGN_CENTER: u8 = 2;
+const ALIGN_RIGHT: u8 = 3;
+
+const FLAG_PLUS: u8 = 1;
+const FLAG_ALTERNATE: u8 = 2;
+const FLAG_ZERO: u8 = 4;
+
+const KIND_LOWER_HEX: u8 = 1;
+const KIND_UPPER_HEX: u8 = 2;
+const KIND_OCTAL: u8 = 3;
+const KIND_BINARY: u8 = 4;
+const KIND_LOWER_EXP: u8 = 5;
+const KIND_UPPER_EXP: u8 = 6;
+
+/// A compiled placeholder spec, emitted by the compiler as a constant.
+///
+/// Field encoding:
+/// - `fill`: Unicode scalar value of the padding character
+/// - `width`: minimum field width in characters (`0` for none)
+/// - `precision`: float digits or maximum string length (`-1` for none)
+/// - `align`: `0` default, `1` left, `2` center, `3` right
+/// - `flags`: bit 0 `+`, bit 1 `#`, bit 2 `0`
+/// - `kind`: `0` display, `1` `x`, `2` `X`, `3` `o`, `4` `b`, `5` `e`, `6` `E`
+#[repr(C)]
+pub struct LakFormatSpec {
+    pub fill: u32,
+    pub width: u32,
+    pub precision: i32,
+    pub align: u8,
+    pub flags: u8,
+    pub kind: u8,
+}
+
+impl LakFormatSpec {
+    fn has_flag(&self, flag: u8) -> bool {
+        self.flags & flag != 0
+    }
+
+    fn precision(&self) -> Option<usize> {
+        usize::try_from(self.precision).ok()
+    }
+
+    fn fill(&self) -> char {
+        char::from_u32(self.fill).unwrap_or(' ')
+    }
+}
+
+/// Pads `text` to the spec width using the fill character and alignment.
+fn pad(text: &str, spec: &LakFormatSpec, default_align: u8) -> String {
+    let len = text.chars().count();
+    let width = spec.width as usize;
+    if len >= width {
+        return text.to_string();
+    }
+
+    let padding = width - len;
+    let align = if spec.align == 0 {
+        default_align
+    } else {
+        spec.align
+    };
+    let (before, after) = match align {
+        ALIGN_LEFT => (0, padding),
+        ALIGN_CENTER => (padding / 2, padding - padding / 2),
+        _ => (padding, 0),
+    };
+
+    let fill = spec.fill();
+    let mut out = String::with_capacity(text.len() + padding * fill.len_utf8());
+    out.extend(std::iter::repeat_n(fill, before));
+    out.push_str(text);
+    out.extend(std::iter::repeat_n(fill, after));
+    out
+}
+
+/// Lays out a number from its sign, radix prefix and digits.
+///
+/// With the `0` flag, zeros are inserted between the sign/prefix and the
+/// digits and any alignment is ignored; otherwise the whole number is padded
+/// like any other value (right-aligned by default).
+fn pad_number(negative: bool, prefix: &str, digits: &str, spec: &LakFormatSpec) -> String {
+    let sign = if negative {
+        "-"
+    } else if spec.has_flag(FLAG_PLUS) {
+        "+"
+    } else {
+        ""
+    };
+
+    if spec.has_flag(FLAG_ZERO) {
+        let len = sign.len() + prefix.len() + digits.chars().count();
+        let zeros = (spec.width as usize).saturating_sub(len);
+        return format!("{sign}{prefix}{}{digits}", "0".repeat(zeros));
+    }
+
+    pad(&format!("{sign}{prefix}{digits}"), spec, ALIGN_RIGHT)
+}
+
+fn format_integer(negative: bool, magnitude: u64, spec: &LakFormatSpec) -> String {
+    let alternate = spec.has_flag(FLAG_ALTERNATE);
+    let (prefix, digits) = match spec.kind {
+        KIND_LOWER_HEX => ("0x", format!("{magnitude:x}")),
+        KIND_UPPER_HEX => ("0x", format!("{magnitude:X}")),
+        KIND_OCTAL => ("0o", format!("{magnitude:o}")),
+        KIND_BINARY => ("0b", format!("{magnitude:b}")),
+        _ => ("", magnitude.to_string()),
+    };
+    let prefix = if alternate { prefix } else { "" };
+    pad_number(negative, prefix, &digits, spec)
+}
+
+fn format_float<T>(value: T, negative: bool, spec: &LakFormatSpec) -> String
+where
+    T: Display + LowerExp + UpperExp + Copy + Into<f64>,
+{
+    if value.into().is_nan() {
+        // Written like Lak's `nan` literal, without sign or zero padding,
+        // regardless of precision or kind.
+        return pad("nan", spec, ALIGN_RIGHT);
+    }
+    let text = match (spec.kind, spec.precision()) {
+        (KIND_LOWER_EXP, Some(precision)) => format!("{value:.precision$e}"),
+        (KIND_LOWER_EXP, None) => format!("{value:e}"),
+        (KIND_UPPER_EXP, Some(precision)) => format!("{value:.precision$E}"),
+        (KIND_UPPER_EXP, None) => format!("{value:E}"),
+        (_, Some(precision)) => format!("{value:.precision$}"),
+        (_, None) => value.to_string(),
+    };
+    let digits = text.strip_prefix('-').unwrap_or(&text);
+    pad_number(negative, "", digits, spec)
+}
+
+fn format_text(text: &str, spec: &LakFormatSpec) -> String {
+    match spec.precision() {
+        Some(max_chars) => {
+            let truncated: String = text.chars().take(max_chars).collect();
+            pad(&truncated, spec, ALIGN_LEFT)
+        }
+        None => pad(text, spec, ALIGN_LEFT),
+    }
+}
+
+/// Appends formatted text to a buffer created by [`lak_fmt_new`].
+///
+/// # Safety
+///
+/// `buffer` must come from `lak_fmt_new` and not yet be finished.
+unsafe fn push(buffer: *mut String, text: &str) {
+    // SAFETY: The caller guarantees `buffer` is a live buffer from `lak_fmt_new`.
+    unsafe { &mut *buffer }.push_str(text);
+}
+
+/// Creates an empty format buffer.
+#[unsafe(no_mangle)]
+pub extern "C" fn lak_fmt_new() -> *mut String {
+    Box::into_raw(Box::default())
+}
+
+/// Appends literal text to a format buffer.
+///
+/// # Safety
+///
+/// `buffer` must come from `lak_fmt_new` and not yet be finished, and `s`
+/// must be a valid null-terminated C string (or null, which appends nothing).
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_fmt_push_literal(buffer: *mut String, s: *const c_char) {
+    // SAFETY: The caller guarantees non-null pointers are valid C strings.
+    if let Some(c_str) = unsafe { cstr_from_nullable_ptr(s) } {
+        // SAFETY: The caller guarantees `buffer` is live.
+        unsafe { push(buffer, &cstr_to_lossy_str(c_str)) };
+    }
+}
+
+/// Appends a formatted string value to a format buffer.
+///
+/// # Safety
+///
+/// `buffer` must come from `lak_fmt_new` and not yet be finished, `s` must
+/// be a valid null-terminated C string (or null, formatted as empty), and
+/// `spec` must point to a valid [`LakFormatSpec`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_fmt_push_str(
+    buffer: *mut String,
+    s: *const c_char,
+    spec: *const LakFormatSpec,
+) {
+    // SAFETY: The caller guarantees non-null pointers are valid C strings.
+    let text = unsafe { cstr_from_nullable_ptr(s) }
+        .map(cstr_to_lossy_str)
+        .unwrap_or_default();
+    // SAFETY: The caller guarantees `spec` and `buffer` are valid.
+    unsafe { push(buffer, &format_text(&text, &*spec)) };
+}
+
+/// Appends a formatted bool value to a format buffer.
+///
+/// # Safety
+///
+/// `buffer` must come from `lak_fmt_new` and not yet be finished, and
+/// `spec` must point to a valid [`LakFormatSpec`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_fmt_push_bool(
+    buffer: *mut String,
+    value: bool,
+    spec: *const LakFormatSpec,
+) {
+    let text = if value { "true" } else { "false" };
+    // SAFETY: The caller guarantees `spec` and `buffer` are valid.
+    unsafe { push(buffer, &format_text(text, &*spec)) };
+}
+
+/// Appends a formatted char value (a Unicode scalar value) to a format buffer.
+///
+/// # Safety
+///
+/// `buffer` must come from `lak_fmt_new` and not yet be finished, and
+/// `spec` must point to a valid [`LakFormatSpec`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_fmt_push_char(
+    buffer: *mut String,
+    value: u32,
+    spec: *const LakFormatSpec,
+) {
+    let text = CharDisplay(value).to_string();
+    // SAFETY: The caller guarantees `spec` and `buffer` are valid.
+    unsafe { push(buffer, &format_text(&text, &*spec)) };
+}
+
+/// Appends a formatted signed integer (widened to `i64`) to a format buffer.
+///
+/// # Safety
+///
+/// `buffer` must come from `lak_fmt_new` and not yet be finished, and
+/// `spec` must point to a valid [`LakFormatSpec`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_fmt_push_i64(
+    buffer: *mut String,
+    value: i64,
+    spec: *const LakFormatSpec,
+) {
+    // SAFETY: The caller guarantees `spec` and `buffer` are valid.
+    let text = format_integer(value < 0, value.unsigned_abs(), unsafe { &*spec });
+    unsafe { push(buffer, &text) };
+}
+
+/// Appends a formatted unsigned integer (widened to `u64`) to a format buffer.
+///
+/// # Safety
+///
+/// `buffer` must come from `lak_fmt_new` and not yet be finished, and
+/// `spec` must point to a valid [`LakFormatSpec`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_fmt_push_u64(
+    buffer: *mut String,
+    value: u64,
+    spec: *const LakFormatSpec,
+) {
+    // SAFETY: The caller guarantees `spec` and `buffer` are valid.
+    let text = format_integer(false, value, unsafe { &*spec });
+    unsafe { push(buffer, &text) };
+}
+
+/// Appends a formatted `f32` value to a format buffer.
+///
+/// # Safety
+///
+/// `buffer` must come from `lak_fmt_new` and not yet be finished, and
+/// `spec` must point to a valid [`LakFormatSpec`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_fmt_push_f32(
+    buffer: *mut String,
+    value: f32,
+    spec: *const LakFormatSpec,
+) {
+    let negative = value.is_sign_negative() && !value.is_nan();
+    // SAFETY: The caller guarantees `spec` and `buffer` are valid.
+    let text = format_float(value.abs(), negative, unsafe { &*spec });
+    unsafe { push(buffer, &text) };
+}
+
+/// Appends a formatted `f64` value to a format buffer.
+///
+/// # Safety
+///
+/// `buffer` must come from `lak_fmt_new` and not yet be finished, and
+/// `spec` must point to a valid [`LakFormatSpec`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_fmt_push_f64(
+    buffer: *mut String,
+    value: f64,
+    spec: *const LakFormatSpec,
+) {
+    let negative = value.is_sign_negative() && !value.is_nan();
+    // SAFETY: The caller guarantees `spec` and `buffer` are valid.
+    let text = format_float(value.abs(), negative, unsafe { &*spec });
+    unsafe { push(buffer, &text) };
+}
+
+/// Consumes a format buffer and returns its contents as a C string.
+///
+/// The string is a reference-counted object owned by the caller, who must
+/// release it with `lak_release`.
+///
+/// # Safety
+///
+/// `buffer` must come from `lak_fmt_new` and not yet be finished.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_fmt_finish(buffer: *mut String) -> *const c_char {
+    // SAFETY: The caller guarantees `buffer` is a live buffer from `lak_fmt_new`.
+    let text = *unsafe { Box::from_raw(buffer) };
+    new_string(text.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rc::lak_release;
+    use std::ffi::{CStr, CString};
+
+    fn spec(
+        fill: char,
+        align: u8,
+        flags: u8,
+        width: u32,
+        precision: i32,
+        kind: u8,
+    ) -> LakFormatSpec {
+        LakFormatSpec {
+            fill: fill as u32,
+            width,
+            precision,
+            align,
+            flags,
+            kind,
+        }
+    }
+
+    fn plain() -> LakFormatSpec {
+        spec(' ', 0, 0, 0, -1, 0)
+    }
+
+    fn finish(buffer: *mut String) -> String {
+        let ptr = unsafe { lak_fmt_finish(buffer) };
+        let text = unsafe { CStr::from_ptr(ptr) }.to_str().unwrap().to_string();
+        unsafe { lak_release(ptr as *mut u8) };
+        text
+    }
+
+    #[test]
+    fn test_integer_display_and_padding() {
+        assert_eq!(format_integer(false, 42, &plain()), "42");
+        assert_eq!(
+            format_integer(true, 42, &spec(' ', 0, 0, 6, -1, 0)),
+            "   -42"
+        );
+        assert_eq!(
+            format_integer(false, 42, &spec('*', ALIGN_LEFT, 0, 5, -1, 0)),
+            "42***"
+        );
+        assert_eq!(
+            format_integer(false, 42, &spec(' ', 0, FLAG_PLUS, 0, -1, 0)),
+            "+42"
+        );
+        assert_eq!(
+            format_integer(true, 7, &spec(' ', 0, FLAG_ZERO, 5, -1, 0)),
+            "-0007"
+        );
+    }
+
+    #[test]
+    fn test_integer_radix() {
+        assert_eq!(
+            format_integer(false, 255, &spec(' ', 0, 0, 0, -1, KIND_LOWER_HEX)),
+            "ff"
+        );
+        assert_eq!(
+            format_integer(false, 255, &spec(' ', 0, 0, 0, -1, KIND_UPPER_HEX)),
+            "FF"
+        );
+        assert_eq!(
+            format_integer(false, 8, &spec(' ', 0, 0, 0, -1, KIND_OCTAL)),
+            "10"
+        );
+        assert_eq!(
+            format_integer(false, 5, &spec(' ', 0, FLAG_ZERO, 8, -1, KIND_BINARY)),
+            "00000101"
+        );
+        assert_eq!(
+            format_integer(
+                false,
+                5,
+                &spec(' ', 0, FLAG_ALTERNATE | FLAG_ZERO, 8, -1, KIND_BINARY)
+            ),
+            "0b000101"
+        );
+        assert_eq!(
+            format_integer(true, 255, &spec(' ', 0, 0, 0, -1, KIND_LOWER_HEX)),
+            "-ff"
+        );
+    }
+
+    #[test]
+    fn test_float_nan_and_inf() {
+        assert_eq!(format_float(f64::NAN, false, &plain()), "nan");
+        assert_eq!(
+            format_float(f32::NAN, false, &spec(' ', 0, 0, 5, 2, KIND_LOWER_EXP)),
+            "  nan"
+        );
+        assert_eq!(format_float(f64::INFINITY, true, &plain()), "-inf");
+    }
+
+    #[test]
+    fn test_float_precision_and_exponent() {
+        assert_eq!(
+            format_float(1.23456_f64, false, &spec(' ', 0, 0, 8, 3, 0)),
+            "   1.235"
+        );
+        assert_eq!(
+            format_float(2.5_f64, true, &spec(' ', 0, 0, 0, 2, 0)),
+            "-2.50"
+        );
+        assert_eq!(format_float(0.1_f32, false, &plain()), "0.1");
+        assert_eq!(
+            format_float(1234.5_f64, false, &spec(' ', 0, 0, 0, 2, KIND_LOWER_EXP)),
+            "1.23e3"
+        );
+        assert_eq!(
+            format_float(1500.0_f64, false, &spec(' ', 0, 0, 0, -1, KIND_UPPER_EXP)),
+            "1.5E3"
+        );
+        assert_eq!(
+            format_float(
+                1.5_f64,
+                false,
+                &spec(' ', 0, FLAG_PLUS | FLAG_ZERO, 6, -1, 0)
+            ),
+            "+001.5"
+        );
+    }
+
+    #[test]
+    fn test_text_alignment_and_truncation() {
+        assert_eq!(format_text("ab", &spec(' ', 0, 0, 4, -1, 0)), "ab  ");
+        assert_eq!(
+            format_text("ab", &spec('-', ALIGN_CENTER, 0, 5, -1, 0)),
+            "-ab--"
+        );
+        assert_eq!(
+            format_text("ab", &spec(' ', ALIGN_RIGHT, 0, 4, -1, 0)),
+            "  ab"
+        );
+        assert_eq!(format_text("héllo", &spec(' ', 0, 0, 0, 2, 0)), "hé");
+    }
+
+    #[test]
+    fn test_buffer_round_trip() {
+        let buffer = lak_fmt_new();
+        let literal = CString::new("x = ").unwrap();
+        unsafe {
+            lak_fmt_push_literal(buffer, literal.as_ptr());
+            lak_fmt_push_i64(buffer, -3, &spec(' ', 0, 0, 3, -1, 0));
+            lak_fmt_push_literal(buffer, std::ptr::null());
+            lak_fmt_push_bool(buffer, true, &spec(' ', ALIGN_RIGHT, 0, 5, -1, 0));
+            lak_fmt_push_f64(buffer, 0.5, &spec(' ', 0, 0, 0, 1, 0));
+            lak_fmt_push_char(buffer, 'é' as u32, &spec('*', ALIGN_CENTER, 0, 3, -1, 0));
+        }
+        assert_eq!(finish(buffer), "x =  -3 true0.5*é*");
+    }
+}
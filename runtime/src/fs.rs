@@ -0,0 +1,240 @@
+//! File system functions of the `fs` standard module.
+//!
+//! Fallible functions report failures as owned error strings (see
+//! `RuntimeReturn` in the compiler): either as the return value, or through
+//! a trailing `error` out-parameter when they also return a value. Error
+//! messages name the path, e.g. `missing.txt: No such file or directory
+//! (os error 2)`.
+
+use std::ffi::CStr;
+use std::fs;
+use std::io::{self, Write};
+use std::os::raw::c_char;
+use std::path::PathBuf;
+
+use crate::rc::{check_string_bytes, new_string};
+
+/// Converts a path argument to a `PathBuf`.
+///
+/// # Safety
+///
+/// `path` must be a valid C string.
+unsafe fn path_arg(path: *const c_char) -> PathBuf {
+    // SAFETY: The caller guarantees `path` is a valid C string.
+    let bytes = unsafe { CStr::from_ptr(path) }.to_bytes();
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt;
+        PathBuf::from(std::ffi::OsStr::from_bytes(bytes))
+    }
+    #[cfg(not(unix))]
+    {
+        PathBuf::from(String::from_utf8_lossy(bytes).into_owned())
+    }
+}
+
+/// Formats an I/O error about `path` as an owned string.
+fn error_string(path: &std::path::Path, error: &io::Error) -> *const c_char {
+    new_string(format!("{}: {}", path.display(), error).as_bytes())
+}
+
+/// Returns null on success or an owned error string.
+fn status(path: &std::path::Path, result: io::Result<()>) -> *const c_char {
+    match result {
+        Ok(()) => std::ptr::null(),
+        Err(error) => error_string(path, &error),
+    }
+}
+
+/// Stores the outcome of a fallible function returning a string.
+///
+/// # Safety
+///
+/// `error` must be valid for writes.
+unsafe fn string_result(
+    path: &std::path::Path,
+    result: io::Result<Vec<u8>>,
+    error: *mut *const c_char,
+) -> *const c_char {
+    let (value, message) = match result {
+        Ok(bytes) => (new_string(&bytes), std::ptr::null()),
+        Err(e) => (std::ptr::null(), error_string(path, &e)),
+    };
+    // SAFETY: The caller guarantees `error` is valid for writes.
+    unsafe { error.write(message) };
+    value
+}
+
+/// Returns the names of the entries of `path`, sorted and newline-separated.
+///
+/// A name containing a newline would read as two entries, so it is reported
+/// as an error instead.
+fn list_dir(path: &std::path::Path) -> io::Result<Vec<u8>> {
+    let mut names = fs::read_dir(path)?
+        .map(|entry| entry.map(|entry| entry.file_name().to_string_lossy().into_owned()))
+        .collect::<io::Result<Vec<_>>>()?;
+    if let Some(name) = names.iter().find(|name| name.contains('\n')) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("entry name {:?} contains a newline", name),
+        ));
+    }
+    names.sort();
+    Ok(names.join("\n").into_bytes())
+}
+
+/// Removes a file, or a directory if it is empty.
+fn remove(path: &std::path::Path) -> io::Result<()> {
+    if fs::symlink_metadata(path)?.is_dir() {
+        fs::remove_dir(path)
+    } else {
+        fs::remove_file(path)
+    }
+}
+
+/// Reads the whole file at `path`.
+///
+/// Returns the contents, or null with an error stored in `error`. A file
+/// containing a NUL byte cannot be a Lak string and is reported as an error.
+///
+/// # Safety
+///
+/// `path` must be a valid C string and `error` valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_fs_read_file(
+    path: *const c_char,
+    error: *mut *const c_char,
+) -> *const c_char {
+    // SAFETY: The caller guarantees `path` is a valid C string.
+    let path = unsafe { path_arg(path) };
+    let contents = fs::read(&path).and_then(|bytes| check_string_bytes(&bytes).map(|()| bytes));
+    // SAFETY: The caller guarantees `error` is valid for writes.
+    unsafe { string_result(&path, contents, error) }
+}
+
+/// Writes `contents` to `path`, replacing the file if it exists.
+///
+/// # Safety
+///
+/// `path` and `contents` must be valid C strings.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_fs_write_file(
+    path: *const c_char,
+    contents: *const c_char,
+) -> *const c_char {
+    // SAFETY: The caller guarantees both arguments are valid C strings.
+    let (path, contents) = unsafe { (path_arg(path), CStr::from_ptr(contents)) };
+    status(&path, fs::write(&path, contents.to_bytes()))
+}
+
+/// Appends `contents` to `path`, creating the file if needed.
+///
+/// # Safety
+///
+/// `path` and `contents` must be valid C strings.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_fs_append_file(
+    path: *const c_char,
+    contents: *const c_char,
+) -> *const c_char {
+    // SAFETY: The caller guarantees both arguments are valid C strings.
+    let (path, contents) = unsafe { (path_arg(path), CStr::from_ptr(contents)) };
+    let result = fs::OpenOptions::new()
+        .append(true)
+        .create(true)
+        .open(&path)
+        .and_then(|mut file| file.write_all(contents.to_bytes()));
+    status(&path, result)
+}
+
+/// Returns true if a file or directory exists at `path`.
+///
+/// # Safety
+///
+/// `path` must be a valid C string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_fs_exists(path: *const c_char) -> bool {
+    // SAFETY: The caller guarantees `path` is a valid C string.
+    let path = unsafe { path_arg(path) };
+    path.exists()
+}
+
+/// Removes the file or empty directory at `path`.
+///
+/// # Safety
+///
+/// `path` must be a valid C string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_fs_remove(path: *const c_char) -> *const c_char {
+    // SAFETY: The caller guarantees `path` is a valid C string.
+    let path = unsafe { path_arg(path) };
+    status(&path, remove(&path))
+}
+
+/// Lists the entries of the directory at `path`, sorted by name and
+/// separated by newlines.
+///
+/// Returns the listing, or null with an error stored in `error`.
+///
+/// # Safety
+///
+/// `path` must be a valid C string and `error` valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_fs_list_dir(
+    path: *const c_char,
+    error: *mut *const c_char,
+) -> *const c_char {
+    // SAFETY: The caller guarantees `path` is a valid C string.
+    let path = unsafe { path_arg(path) };
+    // SAFETY: The caller guarantees `error` is valid for writes.
+    unsafe { string_result(&path, list_dir(&path), error) }
+}
+
+/// Creates the directory `path` and any missing parents.
+///
+/// # Safety
+///
+/// `path` must be a valid C string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_fs_create_dir(path: *const c_char) -> *const c_char {
+    // SAFETY: The caller guarantees `path` is a valid C string.
+    let path = unsafe { path_arg(path) };
+    status(&path, fs::create_dir_all(&path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_dir_is_sorted() {
+        let dir = std::env::temp_dir().join(format!("lak-fs-test-{}", std::process::id()));
+        fs::create_dir_all(dir.join("b_dir")).unwrap();
+        fs::write(dir.join("c.txt"), "").unwrap();
+        fs::write(dir.join("a.txt"), "").unwrap();
+        assert_eq!(list_dir(&dir).unwrap(), b"a.txt\nb_dir\nc.txt");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_list_dir_rejects_newline_in_name() {
+        let dir = std::env::temp_dir().join(format!("lak-fs-newline-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("a\nb"), "").unwrap();
+        let err = list_dir(&dir).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(err.to_string(), r#"entry name "a\nb" contains a newline"#);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_remove_non_empty_dir_fails() {
+        let dir = std::env::temp_dir().join(format!("lak-fs-remove-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("file"), "").unwrap();
+        assert!(remove(&dir).is_err());
+        remove(&dir.join("file")).unwrap();
+        remove(&dir).unwrap();
+        assert!(!dir.exists());
+    }
+}
@@ -8,16 +8,96 @@
 //!
 //! All exported functions use the C calling convention (`extern "C"`)
 //! to ensure compatibility with LLVM-generated code.
+//!
+//! # Modules
+//!
+//! - [`args`] - Command-line arguments read by `arg_count` and `arg`
+//! - [`backtrace`] - Shadow call stack printed on panic (`--backtrace` builds)
+//! - [`collections`] - Hash maps and sets behind `map<K, V>` and `set<T>`
+//! - [`format`] - Buffer-based formatting used by the `format` builtin
+//! - [`fs`] - File system functions of the `fs` module
+//! - [`parse`] - Number parsing used by the `parse_*` builtins
+//! - [`process`] - Environment variables and child processes of the `process` module
+//! - [`random`] - Seedable pseudo-random generators behind `rng` and the `random` module
+//! - [`rc`] - Reference-counted heap objects and leak checking
+//! - [`stdin`] - Standard input read by `read_line` and `read_all`
+//! - [`time`] - Clocks, sleeping and duration formatting of the `time` module
+
+mod args;
+mod backtrace;
+mod collections;
+mod format;
+mod fs;
+mod parse;
+mod process;
+mod random;
+mod rc;
+mod stdin;
+mod time;
 
 use std::cmp::Ordering;
 use std::ffi::CStr;
 use std::fmt::Display;
+use std::io::Write;
 use std::os::raw::c_char;
 
 fn print_display_line(value: impl Display) {
     println!("{value}");
 }
 
+/// Displays a float like Rust does, except that NaN is written `nan` to
+/// match Lak's `nan` literal (`inf` already matches).
+struct FloatDisplay<T>(T);
+
+impl<T: Display + Copy + Into<f64>> Display for FloatDisplay<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0.into().is_nan() {
+            f.write_str("nan")
+        } else {
+            self.0.fmt(f)
+        }
+    }
+}
+
+/// Displays a Lak `char`, which the compiler passes as its Unicode scalar
+/// value in a `u32`.
+struct CharDisplay(u32);
+
+impl Display for CharDisplay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Lak chars are always scalar values; the replacement character only
+        // guards against a corrupted value.
+        let c = char::from_u32(self.0).unwrap_or(char::REPLACEMENT_CHARACTER);
+        write!(f, "{c}")
+    }
+}
+
+/// Output destination and line ending used by a print builtin.
+#[derive(Clone, Copy)]
+enum PrintTarget {
+    /// `print`: stdout without a trailing newline.
+    Stdout,
+    /// `eprint`: stderr without a trailing newline.
+    Stderr,
+    /// `eprintln`: stderr followed by a newline.
+    StderrLine,
+}
+
+/// Writes a value to the given print target.
+///
+/// Stdout is flushed after partial-line writes so output that does not end
+/// in a newline is not lost when the program exits through the C runtime.
+fn write_display(target: PrintTarget, value: impl Display) {
+    match target {
+        PrintTarget::Stdout => {
+            print!("{value}");
+            let _ = std::io::stdout().flush();
+        }
+        PrintTarget::Stderr => eprint!("{value}"),
+        PrintTarget::StderrLine => eprintln!("{value}"),
+    }
+}
+
 /// Converts a nullable C string pointer to `Option<&CStr>`.
 ///
 /// # Safety
@@ -51,11 +131,11 @@ pub unsafe extern "C" fn lak_println(s: *const c_char) {
 }
 
 macro_rules! define_numeric_println {
-    ($(($fn_name:ident, $ty:ty)),* $(,)?) => {
+    ($(($fn_name:ident, $ty:ty $(, $wrap:ident)?)),* $(,)?) => {
         $(
             #[unsafe(no_mangle)]
             pub extern "C" fn $fn_name(value: $ty) {
-                print_display_line(value);
+                print_display_line($($wrap)?(value));
             }
         )*
     };
@@ -70,8 +150,9 @@ define_numeric_println!(
     (lak_println_u16, u16),
     (lak_println_u32, u32),
     (lak_println_u64, u64),
-    (lak_println_f32, f32),
-    (lak_println_f64, f64),
+    (lak_println_f32, f32, FloatDisplay),
+    (lak_println_f64, f64, FloatDisplay),
+    (lak_println_char, u32, CharDisplay),
 );
 
 /// Prints a boolean value followed by a newline to stdout.
@@ -86,6 +167,114 @@ pub extern "C" fn lak_println_bool(value: bool) {
     }
 }
 
+/// Defines the string, numeric, char and bool runtime functions for one print
+/// target.
+///
+/// Each family mirrors the `lak_println*` functions: a string printer taking a
+/// nullable C string (null prints nothing), one function per numeric type and
+/// for chars, and a bool printer. An entry may name a wrapper applied to the value before
+/// display, such as [`FloatDisplay`] or [`CharDisplay`].
+macro_rules! define_print_target {
+    (
+        $target:expr,
+        $builtin:literal,
+        $string_fn:ident,
+        $bool_fn:ident,
+        [$(($fn_name:ident, $ty:ty $(, $wrap:ident)?)),* $(,)?] $(,)?
+    ) => {
+        #[doc = concat!("Writes a string for Lak's `", $builtin, "` builtin.")]
+        ///
+        /// # Safety
+        ///
+        /// The caller must ensure that `s` is a valid null-terminated C string
+        /// (or a null pointer).
+        #[unsafe(no_mangle)]
+        pub unsafe extern "C" fn $string_fn(s: *const c_char) {
+            // SAFETY: The caller guarantees non-null pointers are valid C strings.
+            let text = unsafe { cstr_from_nullable_ptr(s) }
+                .map(cstr_to_lossy_str)
+                .unwrap_or_default();
+            write_display($target, text);
+        }
+
+        $(
+            #[doc = concat!(
+                "Writes a `", stringify!($ty), "` value for Lak's `", $builtin, "` builtin."
+            )]
+            #[unsafe(no_mangle)]
+            pub extern "C" fn $fn_name(value: $ty) {
+                write_display($target, $($wrap)?(value));
+            }
+        )*
+
+        #[doc = concat!("Writes a bool value for Lak's `", $builtin, "` builtin.")]
+        #[unsafe(no_mangle)]
+        pub extern "C" fn $bool_fn(value: bool) {
+            write_display($target, value);
+        }
+    };
+}
+
+define_print_target!(
+    PrintTarget::Stdout,
+    "print",
+    lak_print,
+    lak_print_bool,
+    [
+        (lak_print_i8, i8),
+        (lak_print_i16, i16),
+        (lak_print_i32, i32),
+        (lak_print_i64, i64),
+        (lak_print_u8, u8),
+        (lak_print_u16, u16),
+        (lak_print_u32, u32),
+        (lak_print_u64, u64),
+        (lak_print_f32, f32, FloatDisplay),
+        (lak_print_f64, f64, FloatDisplay),
+        (lak_print_char, u32, CharDisplay),
+    ],
+);
+
+define_print_target!(
+    PrintTarget::Stderr,
+    "eprint",
+    lak_eprint,
+    lak_eprint_bool,
+    [
+        (lak_eprint_i8, i8),
+        (lak_eprint_i16, i16),
+        (lak_eprint_i32, i32),
+        (lak_eprint_i64, i64),
+        (lak_eprint_u8, u8),
+        (lak_eprint_u16, u16),
+        (lak_eprint_u32, u32),
+        (lak_eprint_u64, u64),
+        (lak_eprint_f32, f32, FloatDisplay),
+        (lak_eprint_f64, f64, FloatDisplay),
+        (lak_eprint_char, u32, CharDisplay),
+    ],
+);
+
+define_print_target!(
+    PrintTarget::StderrLine,
+    "eprintln",
+    lak_eprintln,
+    lak_eprintln_bool,
+    [
+        (lak_eprintln_i8, i8),
+        (lak_eprintln_i16, i16),
+        (lak_eprintln_i32, i32),
+        (lak_eprintln_i64, i64),
+        (lak_eprintln_u8, u8),
+        (lak_eprintln_u16, u16),
+        (lak_eprintln_u32, u32),
+        (lak_eprintln_u64, u64),
+        (lak_eprintln_f32, f32, FloatDisplay),
+        (lak_eprintln_f64, f64, FloatDisplay),
+        (lak_eprintln_char, u32, CharDisplay),
+    ],
+);
+
 /// Compares two C strings for equality.
 ///
 /// Returns `true` if both strings have the same content, `false` otherwise.
@@ -152,38 +341,125 @@ pub unsafe extern "C" fn lak_strcmp(a: *const c_char, b: *const c_char) -> i32 {
     }
 }
 
-/// Prints an error message to stderr and terminates the program with exit code 1.
-///
-/// This function is called by Lak's `panic()` built-in function.
+/// Source location of a panic, as passed by compiled code.
+struct PanicLocation<'a> {
+    file: Option<&'a CStr>,
+    line: u32,
+    column: u32,
+}
+
+/// Writes a panic report: `panic: {message}` followed by the source
+/// location on its own line (`    at {file}:{line}:{column}`).
+fn write_panic_report(
+    out: &mut impl Write,
+    message: Option<&CStr>,
+    location: PanicLocation<'_>,
+) -> std::io::Result<()> {
+    match message {
+        Some(c_str) => writeln!(out, "panic: {}", cstr_to_lossy_str(c_str))?,
+        None => writeln!(out, "panic: (no message)")?,
+    }
+    let file = location
+        .file
+        .map_or_else(|| "<unknown>".to_string(), cstr_to_lossy_str);
+    writeln!(out, "    at {}:{}:{}", file, location.line, location.column)
+}
+
+/// Prints an error message with its source location to stderr and
+/// terminates the program with exit code 1.
 ///
-/// # Behavior
+/// This function is called by Lak's `panic()`, `assert*()` built-in functions
+/// and by compiler-inserted runtime checks (integer overflow, division by
+/// zero). The output is:
 ///
-/// - Non-null, valid UTF-8: prints `panic: {message}\n`
-/// - Non-null, invalid UTF-8: prints `panic: {lossy_conversion}\n`
-/// - Null pointer: prints `panic: (no message)\n`
+/// ```text
+/// panic: {message}
+///     at {file}:{line}:{column}
+/// ```
 ///
-/// The function never returns. All code paths call `std::process::exit(1)`.
+/// A null `message` is reported as `(no message)` and a null `file` as
+/// `<unknown>`. In programs built with `--backtrace`, the active Lak calls
+/// are listed afterwards (see [`backtrace`]). Like [`lak_exit`], it prints
+/// no leak report. The function never returns.
 ///
 /// # Safety
 ///
-/// The caller must ensure that `message` is a valid null-terminated C string.
+/// The caller must ensure that `message` and `file` are valid null-terminated
+/// C strings or null.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn lak_panic(message: *const c_char) -> ! {
-    // SAFETY: `lak_panic` requires that non-null pointers are valid C strings.
-    if let Some(c_str) = unsafe { cstr_from_nullable_ptr(message) } {
-        eprintln!("panic: {}", cstr_to_lossy_str(c_str));
-    } else {
-        eprintln!("panic: (no message)");
-    }
+pub unsafe extern "C" fn lak_panic_at(
+    message: *const c_char,
+    file: *const c_char,
+    line: u32,
+    column: u32,
+) -> ! {
+    // SAFETY: `lak_panic_at` requires that non-null pointers are valid C strings.
+    let message = unsafe { cstr_from_nullable_ptr(message) };
+    let location = PanicLocation {
+        // SAFETY: Same as above.
+        file: unsafe { cstr_from_nullable_ptr(file) },
+        line,
+        column,
+    };
+    let mut stderr = std::io::stderr();
+    let _ = write_panic_report(&mut stderr, message, location);
+    // SAFETY: `file` is null or a valid C string, as required above.
+    let _ = unsafe { backtrace::write_backtrace(&mut stderr, file, line, column) };
 
+    rc::skip_leak_report();
     std::process::exit(1);
 }
 
+/// Terminates the program with the given exit status.
+///
+/// This function is called by Lak's `exit()` built-in function. Stdout is
+/// flushed before exiting; deferred bodies do not run and no leak report is
+/// printed. The function never returns.
+#[unsafe(no_mangle)]
+pub extern "C" fn lak_exit(code: i32) -> ! {
+    rc::skip_leak_report();
+    let _ = std::io::stdout().flush();
+
+    std::process::exit(code);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::ffi::CString;
 
+    fn panic_report(message: Option<&CStr>, location: PanicLocation<'_>) -> String {
+        let mut out = Vec::new();
+        write_panic_report(&mut out, message, location).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_panic_report_with_location() {
+        let location = PanicLocation {
+            file: Some(c"lib/utils.lak"),
+            line: 3,
+            column: 14,
+        };
+        assert_eq!(
+            panic_report(Some(c"integer overflow"), location),
+            "panic: integer overflow\n    at lib/utils.lak:3:14\n"
+        );
+    }
+
+    #[test]
+    fn test_panic_report_without_file_or_message() {
+        let location = PanicLocation {
+            file: None,
+            line: 1,
+            column: 1,
+        };
+        assert_eq!(
+            panic_report(None, location),
+            "panic: (no message)\n    at <unknown>:1:1\n"
+        );
+    }
+
     #[test]
     fn test_null_pointer() {
         // Should print empty line, not crash
@@ -286,6 +562,46 @@ mod tests {
         lak_println_f64(-0.25);
     }
 
+    #[test]
+    fn test_float_display_writes_nan_lowercase() {
+        assert_eq!(FloatDisplay(f64::NAN).to_string(), "nan");
+        assert_eq!(FloatDisplay(-f32::NAN).to_string(), "nan");
+        assert_eq!(FloatDisplay(f64::INFINITY).to_string(), "inf");
+        assert_eq!(FloatDisplay(f64::NEG_INFINITY).to_string(), "-inf");
+        assert_eq!(FloatDisplay(2.5_f32).to_string(), "2.5");
+    }
+
+    #[test]
+    fn test_char_display() {
+        assert_eq!(CharDisplay('a' as u32).to_string(), "a");
+        assert_eq!(CharDisplay('😀' as u32).to_string(), "😀");
+        assert_eq!(CharDisplay(0xD800).to_string(), "\u{FFFD}");
+    }
+
+    #[test]
+    fn test_print_variants() {
+        let s = CString::new("partial").unwrap();
+        unsafe { lak_print(s.as_ptr()) };
+        unsafe { lak_print(std::ptr::null()) };
+        lak_print_i64(-7);
+        lak_print_u8(u8::MAX);
+        lak_print_f64(0.5);
+        lak_print_bool(true);
+        lak_print_char('λ' as u32);
+    }
+
+    #[test]
+    fn test_eprint_variants() {
+        let s = CString::new("diagnostic").unwrap();
+        unsafe { lak_eprint(s.as_ptr()) };
+        unsafe { lak_eprintln(s.as_ptr()) };
+        unsafe { lak_eprintln(std::ptr::null()) };
+        lak_eprint_i32(i32::MIN);
+        lak_eprintln_u64(u64::MAX);
+        lak_eprintln_f32(-0.25);
+        lak_eprintln_bool(false);
+    }
+
     // lak_streq tests
 
     #[test]
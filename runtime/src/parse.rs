@@ -0,0 +1,203 @@
+//! Number parsing for the `parse_*` prelude functions.
+//!
+//! Each function returns the parsed value and stores an owned error message
+//! through its `error` out-parameter, or null on success. On error the
+//! value is zero. Leading or trailing whitespace is not accepted.
+//!
+//! Messages have the form `cannot parse "<text>" as <type>: <reason>`, where
+//! the reason is one of `empty string`, `invalid digit`, `invalid float` or
+//! `number out of range`.
+
+use std::ffi::CStr;
+use std::num::IntErrorKind;
+use std::os::raw::c_char;
+use std::str::FromStr;
+
+use crate::rc::new_string;
+
+/// Parses `text` as an integer of type `T` named `type_name`.
+fn parse_int<T>(text: &[u8], type_name: &str) -> Result<T, String>
+where
+    T: FromStr<Err = std::num::ParseIntError>,
+{
+    let reason = match std::str::from_utf8(text) {
+        Ok(s) => match s.parse::<T>() {
+            Ok(value) => return Ok(value),
+            Err(e) => match e.kind() {
+                IntErrorKind::Empty => "empty string",
+                IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => "number out of range",
+                _ => "invalid digit",
+            },
+        },
+        Err(_) => "invalid digit",
+    };
+    Err(parse_error(text, type_name, reason))
+}
+
+/// Parses `text` as a float of type `T` named `type_name`.
+///
+/// A finite number too large for `T` is out of range, like an integer
+/// overflow; only an explicit `inf` or `infinity` parses as infinity.
+fn parse_float<T>(text: &[u8], type_name: &str) -> Result<T, String>
+where
+    T: FromStr<Err = std::num::ParseFloatError> + Copy + Into<f64>,
+{
+    let reason = match std::str::from_utf8(text) {
+        Ok("") => "empty string",
+        Ok(s) => match s.parse::<T>() {
+            Ok(value) if value.into().is_infinite() && !is_infinity_literal(s) => {
+                "number out of range"
+            }
+            Ok(value) => return Ok(value),
+            Err(_) => "invalid float",
+        },
+        Err(_) => "invalid float",
+    };
+    Err(parse_error(text, type_name, reason))
+}
+
+/// Returns true when `text` spells infinity rather than a finite number.
+fn is_infinity_literal(text: &str) -> bool {
+    let unsigned = text.strip_prefix(['+', '-']).unwrap_or(text);
+    unsigned.eq_ignore_ascii_case("inf") || unsigned.eq_ignore_ascii_case("infinity")
+}
+
+fn parse_error(text: &[u8], type_name: &str, reason: &str) -> String {
+    format!(
+        "cannot parse {:?} as {}: {}",
+        String::from_utf8_lossy(text),
+        type_name,
+        reason
+    )
+}
+
+/// Stores the outcome of a parse in `error` and returns the value, or
+/// zero on error.
+///
+/// # Safety
+///
+/// `error` must be valid for writes.
+unsafe fn finish<T: Default>(result: Result<T, String>, error: *mut *const c_char) -> T {
+    let (value, message) = match result {
+        Ok(value) => (value, std::ptr::null()),
+        Err(message) => (T::default(), new_string(message.as_bytes())),
+    };
+    // SAFETY: The caller guarantees `error` is valid for writes.
+    unsafe { error.write(message) };
+    value
+}
+
+/// Defines `lak_parse_<type>` for each listed type with the given parser.
+macro_rules! parse_functions {
+    ($parse:ident: $($symbol:ident => $ty:ident),* $(,)?) => {$(
+        #[doc = concat!("Parses `text` as `", stringify!($ty), "`.")]
+        ///
+        /// # Safety
+        ///
+        /// `text` must be a valid C string and `error` must be valid for
+        /// writes.
+        #[unsafe(no_mangle)]
+        pub unsafe extern "C" fn $symbol(text: *const c_char, error: *mut *const c_char) -> $ty {
+            // SAFETY: The caller guarantees `text` is a valid C string.
+            let text = unsafe { CStr::from_ptr(text) }.to_bytes();
+            // SAFETY: The caller guarantees `error` is valid for writes.
+            unsafe { finish($parse::<$ty>(text, stringify!($ty)), error) }
+        }
+    )*};
+}
+
+parse_functions!(parse_int:
+    lak_parse_i8 => i8,
+    lak_parse_i16 => i16,
+    lak_parse_i32 => i32,
+    lak_parse_i64 => i64,
+    lak_parse_u8 => u8,
+    lak_parse_u16 => u16,
+    lak_parse_u32 => u32,
+    lak_parse_u64 => u64,
+);
+
+parse_functions!(parse_float:
+    lak_parse_f32 => f32,
+    lak_parse_f64 => f64,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_int() {
+        assert_eq!(parse_int::<i64>(b"42", "i64"), Ok(42));
+        assert_eq!(parse_int::<i8>(b"-128", "i8"), Ok(-128));
+        assert_eq!(parse_int::<u8>(b"+7", "u8"), Ok(7));
+    }
+
+    #[test]
+    fn test_parse_int_errors() {
+        assert_eq!(
+            parse_int::<i64>(b"", "i64"),
+            Err("cannot parse \"\" as i64: empty string".to_string())
+        );
+        assert_eq!(
+            parse_int::<i64>(b"12x", "i64"),
+            Err("cannot parse \"12x\" as i64: invalid digit".to_string())
+        );
+        assert_eq!(
+            parse_int::<i64>(b" 1", "i64"),
+            Err("cannot parse \" 1\" as i64: invalid digit".to_string())
+        );
+        assert_eq!(
+            parse_int::<u8>(b"256", "u8"),
+            Err("cannot parse \"256\" as u8: number out of range".to_string())
+        );
+        assert_eq!(
+            parse_int::<i8>(b"-129", "i8"),
+            Err("cannot parse \"-129\" as i8: number out of range".to_string())
+        );
+        assert_eq!(
+            parse_int::<u32>(b"-1", "u32"),
+            Err("cannot parse \"-1\" as u32: invalid digit".to_string())
+        );
+        assert_eq!(
+            parse_int::<i32>(b"\xff", "i32"),
+            Err("cannot parse \"\u{fffd}\" as i32: invalid digit".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_float() {
+        assert_eq!(parse_float::<f64>(b"1.5", "f64"), Ok(1.5));
+        assert_eq!(parse_float::<f64>(b"-2e3", "f64"), Ok(-2000.0));
+        assert_eq!(parse_float::<f32>(b"inf", "f32"), Ok(f32::INFINITY));
+        assert_eq!(
+            parse_float::<f64>(b"-Infinity", "f64"),
+            Ok(f64::NEG_INFINITY)
+        );
+        assert_eq!(
+            parse_float::<f64>(b"", "f64"),
+            Err("cannot parse \"\" as f64: empty string".to_string())
+        );
+        assert_eq!(
+            parse_float::<f64>(b"1.5.2", "f64"),
+            Err("cannot parse \"1.5.2\" as f64: invalid float".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_float_out_of_range() {
+        assert_eq!(
+            parse_float::<f64>(b"1e999", "f64"),
+            Err("cannot parse \"1e999\" as f64: number out of range".to_string())
+        );
+        assert_eq!(
+            parse_float::<f64>(b"-1e999", "f64"),
+            Err("cannot parse \"-1e999\" as f64: number out of range".to_string())
+        );
+        assert_eq!(
+            parse_float::<f32>(b"1e39", "f32"),
+            Err("cannot parse \"1e39\" as f32: number out of range".to_string())
+        );
+        assert_eq!(parse_float::<f32>(b"3.4e38", "f32"), Ok(3.4e38));
+    }
+}
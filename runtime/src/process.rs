@@ -0,0 +1,257 @@
+//! Environment variables and child processes for the `process` standard
+//! module.
+//!
+//! Strings returned to Lak code are owned (see `rc::new_string`). Failures
+//! are reported as error strings like in [`crate::fs`], except for `run`,
+//! which reports a command that cannot be started through its exit code and
+//! stderr.
+
+use std::ffi::{CStr, OsStr, OsString};
+use std::io;
+use std::os::raw::c_char;
+use std::process::Command;
+
+use crate::rc::{check_string_bytes, new_string};
+
+/// Converts a C string argument to an `OsString`.
+///
+/// # Safety
+///
+/// `text` must be a valid C string.
+unsafe fn os_string_arg(text: *const c_char) -> OsString {
+    // SAFETY: The caller guarantees `text` is a valid C string.
+    let bytes = unsafe { CStr::from_ptr(text) }.to_bytes();
+    os_string_from_bytes(bytes)
+}
+
+fn os_string_from_bytes(bytes: &[u8]) -> OsString {
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt;
+        OsStr::from_bytes(bytes).to_os_string()
+    }
+    #[cfg(not(unix))]
+    {
+        OsString::from(String::from_utf8_lossy(bytes).into_owned())
+    }
+}
+
+fn os_str_bytes(text: &OsStr) -> Vec<u8> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt;
+        text.as_bytes().to_vec()
+    }
+    #[cfg(not(unix))]
+    {
+        text.to_string_lossy().into_owned().into_bytes()
+    }
+}
+
+/// Splits the `args` of `run` into one argument per line.
+///
+/// An empty string has no arguments. Otherwise a trailing newline ends the
+/// last line instead of starting an empty one, so `"\n"` is one empty
+/// argument. An argument cannot contain a newline.
+fn split_args(args: &[u8]) -> Vec<OsString> {
+    if args.is_empty() {
+        return Vec::new();
+    }
+    let args = args.strip_suffix(b"\n").unwrap_or(args);
+    args.split(|&byte| byte == b'\n')
+        .map(os_string_from_bytes)
+        .collect()
+}
+
+/// Checks that `name` can be used as an environment variable name.
+fn check_env_name(name: &OsStr) -> Result<(), String> {
+    let bytes = os_str_bytes(name);
+    if bytes.is_empty() || bytes.contains(&b'=') {
+        return Err(format!(
+            "invalid environment variable name '{}'",
+            name.to_string_lossy()
+        ));
+    }
+    Ok(())
+}
+
+/// Returns the value of the environment variable `name` as a new string,
+/// or null if it is not set.
+///
+/// # Safety
+///
+/// `name` must be a valid C string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_process_get_env(name: *const c_char) -> *const c_char {
+    // SAFETY: The caller guarantees `name` is a valid C string.
+    let name = unsafe { os_string_arg(name) };
+    if check_env_name(&name).is_err() {
+        return std::ptr::null();
+    }
+    match std::env::var_os(&name) {
+        Some(value) => new_string(&os_str_bytes(&value)),
+        None => std::ptr::null(),
+    }
+}
+
+/// Sets the environment variable `name` to `value`.
+///
+/// Returns null on success or an owned error string if `name` is empty or
+/// contains `=`.
+///
+/// # Safety
+///
+/// `name` and `value` must be valid C strings.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_process_set_env(
+    name: *const c_char,
+    value: *const c_char,
+) -> *const c_char {
+    // SAFETY: The caller guarantees both arguments are valid C strings.
+    let (name, value) = unsafe { (os_string_arg(name), os_string_arg(value)) };
+    if let Err(message) = check_env_name(&name) {
+        return new_string(message.as_bytes());
+    }
+    // SAFETY: Lak programs are single-threaded, so no other thread reads
+    // the environment concurrently.
+    unsafe { std::env::set_var(&name, &value) };
+    std::ptr::null()
+}
+
+/// Returns the current working directory, or null with an error stored in
+/// `error`.
+///
+/// # Safety
+///
+/// `error` must be valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_process_current_dir(error: *mut *const c_char) -> *const c_char {
+    let (value, message) = match std::env::current_dir() {
+        Ok(dir) => (new_string(&os_str_bytes(dir.as_os_str())), std::ptr::null()),
+        Err(e) => (
+            std::ptr::null(),
+            new_string(format!("current directory: {}", e).as_bytes()),
+        ),
+    };
+    // SAFETY: The caller guarantees `error` is valid for writes.
+    unsafe { error.write(message) };
+    value
+}
+
+/// The result of running a child process.
+struct RunOutput {
+    code: i32,
+    stdout: Vec<u8>,
+    stderr: Vec<u8>,
+}
+
+/// Runs `command` with `args` and captures its output. Stdin is closed.
+///
+/// Output containing a NUL byte cannot be a Lak string, so it is reported
+/// like a command that cannot be started.
+fn run(command: &OsStr, args: &[OsString]) -> RunOutput {
+    let output = Command::new(command)
+        .args(args)
+        .output()
+        .and_then(|output| {
+            for (name, bytes) in [("stdout", &output.stdout), ("stderr", &output.stderr)] {
+                check_string_bytes(bytes)
+                    .map_err(|e| io::Error::new(e.kind(), format!("{} {}", name, e)))?;
+            }
+            Ok(output)
+        });
+    match output {
+        Ok(output) => RunOutput {
+            code: output.status.code().unwrap_or(-1),
+            stdout: output.stdout,
+            stderr: output.stderr,
+        },
+        Err(error) => RunOutput {
+            code: -1,
+            stdout: Vec::new(),
+            stderr: spawn_error(command, &error).into_bytes(),
+        },
+    }
+}
+
+fn spawn_error(command: &OsStr, error: &io::Error) -> String {
+    format!("{}: {}", command.to_string_lossy(), error)
+}
+
+/// Runs `command` with `args`, one argument per line, and waits for it to
+/// exit. See [`split_args`] for how `args` is split.
+///
+/// Returns the exit code and stores the captured stdout and stderr as owned
+/// strings. The exit code is -1 if the command could not be started or its
+/// output contains a NUL byte (stderr then holds the reason), or if it was
+/// terminated by a signal.
+///
+/// # Safety
+///
+/// `command` and `args` must be valid C strings; `stdout` and `stderr` must
+/// be valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_process_run(
+    command: *const c_char,
+    args: *const c_char,
+    stdout: *mut *const c_char,
+    stderr: *mut *const c_char,
+) -> i32 {
+    // SAFETY: The caller guarantees both arguments are valid C strings.
+    let (command, args) = unsafe { (os_string_arg(command), CStr::from_ptr(args)) };
+    let output = run(&command, &split_args(args.to_bytes()));
+    // SAFETY: The caller guarantees both out-parameters are valid for writes.
+    unsafe {
+        stdout.write(new_string(&output.stdout));
+        stderr.write(new_string(&output.stderr));
+    }
+    output.code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_args() {
+        assert!(split_args(b"").is_empty());
+        assert_eq!(split_args(b"\n"), vec![""]);
+        assert_eq!(split_args(b"a"), vec!["a"]);
+        assert_eq!(split_args(b"a b\nc\n"), vec!["a b", "c"]);
+        assert_eq!(split_args(b"a\n\nb"), vec!["a", "", "b"]);
+        assert_eq!(split_args(b"a\n\n"), vec!["a", ""]);
+    }
+
+    #[test]
+    fn test_check_env_name() {
+        assert!(check_env_name(OsStr::new("HOME")).is_ok());
+        assert!(check_env_name(OsStr::new("")).is_err());
+        assert!(check_env_name(OsStr::new("A=B")).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_output_with_nul() {
+        let args = [OsString::from("-c"), OsString::from("printf 'a\\0b'")];
+        let output = run(OsStr::new("sh"), &args);
+        assert_eq!(output.code, -1);
+        assert!(output.stdout.is_empty());
+        assert_eq!(
+            String::from_utf8_lossy(&output.stderr),
+            "sh: stdout contains a NUL byte at offset 1"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_missing_command() {
+        let output = run(OsStr::new("lak-no-such-command"), &[]);
+        assert_eq!(output.code, -1);
+        assert!(output.stdout.is_empty());
+        assert!(
+            String::from_utf8_lossy(&output.stderr).starts_with("lak-no-such-command: "),
+            "{:?}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+}
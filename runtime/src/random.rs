@@ -0,0 +1,285 @@
+//! Seedable pseudo-random numbers behind Lak's `rng` type.
+//!
+//! The generator is xoshiro256** with its state expanded from a 64-bit seed
+//! by SplitMix64. A generator is a reference-counted object (see
+//! [`crate::rc`]) whose payload is its [`Rng`] state, created by the
+//! `random` module's `seeded` or `from_entropy`. There is no global
+//! generator: the same seed yields the same numbers on every run, and only
+//! `from_entropy` uses OS entropy.
+//!
+//! Generators are values like maps and sets: compiled code calls
+//! `lak_rng_unique` before drawing a number, which copies the state first
+//! if another variable still refers to the generator, so a copy continues
+//! the sequence independently.
+//!
+//! The numbers are not suitable for cryptography.
+
+use std::ffi::CString;
+use std::hash::{BuildHasher, RandomState};
+use std::os::raw::c_char;
+
+use crate::lak_panic_at;
+use crate::rc::{is_shared, lak_rc_alloc, lak_release};
+
+/// xoshiro256** generator state.
+#[derive(Clone)]
+pub(crate) struct Rng {
+    state: [u64; 4],
+}
+
+impl Rng {
+    /// Creates a generator whose state is expanded from `seed` with
+    /// SplitMix64, which never yields the all-zero state.
+    const fn from_seed(seed: u64) -> Rng {
+        let mut x = seed;
+        let mut state = [0; 4];
+        let mut i = 0;
+        while i < 4 {
+            x = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = x;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            state[i] = z ^ (z >> 31);
+            i += 1;
+        }
+        Rng { state }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let s = &mut self.state;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+
+    /// Returns a uniformly distributed number in `0..bound`.
+    ///
+    /// Uses Lemire's multiply-and-reject method, so there is no modulo
+    /// bias. `bound` must not be zero.
+    pub(crate) fn below(&mut self, bound: u64) -> u64 {
+        let threshold = bound.wrapping_neg() % bound;
+        loop {
+            let product = u128::from(self.next_u64()) * u128::from(bound);
+            if product as u64 >= threshold {
+                return (product >> 64) as u64;
+            }
+        }
+    }
+
+    /// Returns a uniformly distributed float in `[0, 1)` with 53 random bits.
+    fn float(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+}
+
+/// Allocates a generator object owning `rng`.
+fn new_object(rng: Rng) -> *mut u8 {
+    let ptr = lak_rc_alloc(size_of::<Rng>() as u64);
+    // SAFETY: The payload has room for the state and is suitably aligned.
+    unsafe { (ptr as *mut Rng).write(rng) };
+    ptr
+}
+
+/// Returns the state of a generator object.
+///
+/// # Safety
+///
+/// `ptr` must be a live generator object.
+pub(crate) unsafe fn generator<'a>(ptr: *mut u8) -> &'a mut Rng {
+    // SAFETY: The payload of a generator object is its state.
+    unsafe { &mut *(ptr as *mut Rng) }
+}
+
+/// Panics with `message` at the given call site.
+///
+/// # Safety
+///
+/// `file` must be null or a valid C string.
+unsafe fn panic_at(message: String, file: *const c_char, line: u32, column: u32) -> ! {
+    let message = CString::new(message).expect("message has no NUL bytes");
+    // SAFETY: `message` is a valid C string; `file` is forwarded from the caller.
+    unsafe { lak_panic_at(message.as_ptr(), file, line, column) }
+}
+
+/// Creates a generator seeded with `seed`, owned by the caller.
+#[unsafe(no_mangle)]
+pub extern "C" fn lak_random_seeded(seed: u64) -> *mut u8 {
+    new_object(Rng::from_seed(seed))
+}
+
+/// Creates a generator seeded from OS entropy, owned by the caller.
+#[unsafe(no_mangle)]
+pub extern "C" fn lak_random_from_entropy() -> *mut u8 {
+    // `RandomState` keys are drawn from the OS entropy source.
+    let seed = RandomState::new().hash_one(0u64);
+    new_object(Rng::from_seed(seed))
+}
+
+/// Prepares a generator owned by the caller for drawing a number.
+///
+/// Returns `rng` itself if the caller is its only owner. Otherwise releases
+/// the caller's reference and returns a copy owned by the caller, so the
+/// other owners keep their place in the sequence.
+///
+/// # Safety
+///
+/// `rng` must be a live generator owned by the caller. It must not be used
+/// by the caller afterwards; the returned generator replaces it.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_rng_unique(rng: *mut u8) -> *mut u8 {
+    // SAFETY: Guaranteed by the caller.
+    unsafe {
+        if !is_shared(rng) {
+            return rng;
+        }
+        let copy = new_object(generator(rng).clone());
+        lak_release(rng);
+        copy
+    }
+}
+
+/// Returns the next 64 random bits.
+///
+/// # Safety
+///
+/// `rng` must be a live generator.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_rng_next_u64(rng: *mut u8) -> u64 {
+    // SAFETY: Guaranteed by the caller.
+    unsafe { generator(rng) }.next_u64()
+}
+
+/// Returns a uniformly distributed integer in `low..high`.
+///
+/// Panics with the call-site location if the range is empty.
+///
+/// # Safety
+///
+/// `rng` must be a live generator, and `file` must be null or a valid C
+/// string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_rng_int_range(
+    rng: *mut u8,
+    low: i64,
+    high: i64,
+    file: *const c_char,
+    line: u32,
+    column: u32,
+) -> i64 {
+    if low >= high {
+        let message = format!("int_range called with empty range {}..{}", low, high);
+        // SAFETY: `file` is forwarded from the caller.
+        unsafe { panic_at(message, file, line, column) }
+    }
+    let span = high.wrapping_sub(low) as u64;
+    // SAFETY: Guaranteed by the caller.
+    low.wrapping_add(unsafe { generator(rng) }.below(span) as i64)
+}
+
+/// Returns a uniformly distributed float in `[0, 1)`.
+///
+/// # Safety
+///
+/// `rng` must be a live generator.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_rng_float(rng: *mut u8) -> f64 {
+    // SAFETY: Guaranteed by the caller.
+    unsafe { generator(rng) }.float()
+}
+
+/// Returns a uniformly distributed float in `[low, high)`.
+///
+/// Panics with the call-site location unless `low < high` and the width
+/// of the range is finite.
+///
+/// # Safety
+///
+/// `rng` must be a live generator, and `file` must be null or a valid C
+/// string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_rng_float_range(
+    rng: *mut u8,
+    low: f64,
+    high: f64,
+    file: *const c_char,
+    line: u32,
+    column: u32,
+) -> f64 {
+    let width = high - low;
+    if !(low < high && width.is_finite()) {
+        let message = format!("float_range called with invalid range {}..{}", low, high);
+        // SAFETY: `file` is forwarded from the caller.
+        unsafe { panic_at(message, file, line, column) }
+    }
+    // SAFETY: Guaranteed by the caller.
+    let rng = unsafe { generator(rng) };
+    loop {
+        let value = low + width * rng.float();
+        // Rounding can produce `high` for ranges that are wide relative to
+        // their bounds.
+        if value < high {
+            return value;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_same_sequence() {
+        let mut a = Rng::from_seed(42);
+        let mut b = Rng::from_seed(42);
+        let mut c = Rng::from_seed(43);
+        let first: Vec<u64> = (0..4).map(|_| a.next_u64()).collect();
+        let second: Vec<u64> = (0..4).map(|_| b.next_u64()).collect();
+        let other: Vec<u64> = (0..4).map(|_| c.next_u64()).collect();
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+    }
+
+    #[test]
+    fn test_below_stays_in_bounds() {
+        let mut rng = Rng::from_seed(1);
+        for bound in [1, 2, 3, 10, u64::MAX] {
+            for _ in 0..100 {
+                assert!(rng.below(bound) < bound);
+            }
+        }
+        let mut seen = [false; 6];
+        for _ in 0..1000 {
+            seen[rng.below(6) as usize] = true;
+        }
+        assert!(seen.iter().all(|&hit| hit));
+    }
+
+    #[test]
+    fn test_float_in_unit_interval() {
+        let mut rng = Rng::from_seed(7);
+        for _ in 0..1000 {
+            let value = rng.float();
+            assert!((0.0..1.0).contains(&value));
+        }
+    }
+
+    #[test]
+    fn test_unique_copies_shared_generator() {
+        let rng = lak_random_seeded(9);
+        unsafe {
+            crate::rc::lak_retain(rng);
+            let copy = lak_rng_unique(rng);
+            assert_ne!(copy, rng);
+            assert_eq!(lak_rng_next_u64(copy), lak_rng_next_u64(rng));
+            assert_eq!(lak_rng_unique(copy), copy);
+            lak_release(copy);
+            lak_release(rng);
+        }
+    }
+}
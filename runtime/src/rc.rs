@@ -0,0 +1,395 @@
+//! Reference counting for heap-allocated Lak values.
+//!
+//! Every heap object starts with an [`RcHeader`] holding its reference count
+//! and payload size. Compiled code only ever sees a pointer to the payload,
+//! which for strings is a null-terminated C string, so heap objects can be
+//! passed to the other runtime functions unchanged.
+//!
+//! The compiler inserts the calls:
+//!
+//! - `lak_rc_alloc` (via runtime functions such as `lak_fmt_finish`) returns
+//!   a new object with a count of 1, owned by the caller
+//! - `lak_retain` is emitted when a value is copied into a variable, a
+//!   parameter, or a return value
+//! - `lak_release` is emitted when such an owner goes out of scope or is
+//!   overwritten, and when a temporary is no longer needed; maps and sets
+//!   use `lak_map_release` and `lak_set_release` instead, which also release
+//!   their contents
+//!
+//! String literals are emitted as static objects whose count is
+//! [`IMMORTAL`]; retain and release leave them untouched.
+//!
+//! # Leak checking
+//!
+//! When the `LAK_LEAK_CHECK` environment variable is set to a non-empty value
+//! other than `0`, the runtime records every live object and prints a report
+//! to stderr when the program exits. The generated `main` calls
+//! `lak_rc_init` first, so the report is printed even if nothing was
+//! allocated. An explicit `exit` or a panic skips the report,
+//! because the objects still owned by live variables are not leaks.
+
+use std::alloc::{Layout, alloc, dealloc, handle_alloc_error};
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::os::raw::{c_char, c_int};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+/// Reference count marking a static object that is never freed.
+pub const IMMORTAL: u64 = u64::MAX;
+
+/// Environment variable that enables leak checking.
+const LEAK_CHECK_ENV: &str = "LAK_LEAK_CHECK";
+
+/// Alignment of heap objects.
+const ALIGN: usize = 16;
+
+/// Header stored immediately before the payload of every object.
+///
+/// The compiler emits the same layout (`{ i64, i64, [n x i8] }`) for string
+/// literals, so both fields must stay 64-bit.
+#[repr(C)]
+struct RcHeader {
+    /// Number of owners, or [`IMMORTAL`] for static objects.
+    count: u64,
+    /// Payload size in bytes.
+    size: u64,
+}
+
+const HEADER_SIZE: usize = size_of::<RcHeader>();
+
+unsafe extern "C" {
+    fn atexit(callback: extern "C" fn()) -> c_int;
+}
+
+/// Objects that are currently alive, keyed by payload address.
+#[derive(Default)]
+struct LiveObjects {
+    objects: HashMap<usize, u64>,
+}
+
+impl LiveObjects {
+    fn insert(&mut self, ptr: *const u8, size: u64) {
+        self.objects.insert(ptr as usize, size);
+    }
+
+    fn remove(&mut self, ptr: *const u8) {
+        self.objects.remove(&(ptr as usize));
+    }
+
+    /// Writes the leak report for the objects that are still alive.
+    fn report(&self, out: &mut impl Write) -> std::io::Result<()> {
+        if self.objects.is_empty() {
+            return writeln!(out, "leak check: no leaked objects");
+        }
+
+        let bytes: u64 = self.objects.values().sum();
+        let noun = if self.objects.len() == 1 {
+            "object"
+        } else {
+            "objects"
+        };
+        writeln!(
+            out,
+            "leak check: {} leaked {} ({} bytes)",
+            self.objects.len(),
+            noun,
+            bytes
+        )?;
+
+        let mut objects: Vec<_> = self.objects.iter().collect();
+        objects.sort();
+        for (address, size) in objects {
+            writeln!(out, "  {:#x}: {} bytes", address, size)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the live object registry if leak checking is enabled.
+///
+/// The environment is read once; the exit report is registered at the same
+/// time.
+fn live_objects() -> Option<&'static Mutex<LiveObjects>> {
+    static LIVE: OnceLock<Option<Mutex<LiveObjects>>> = OnceLock::new();
+    LIVE.get_or_init(|| {
+        let enabled = std::env::var_os(LEAK_CHECK_ENV).is_some_and(|v| !v.is_empty() && v != "0");
+        if !enabled {
+            return None;
+        }
+        // SAFETY: `report_leaks` is a valid `extern "C"` function with no arguments.
+        unsafe { atexit(report_leaks) };
+        Some(Mutex::new(LiveObjects::default()))
+    })
+    .as_ref()
+}
+
+/// Reads the leak check setting and registers the exit report.
+///
+/// Called once on entry to the generated `main`.
+#[unsafe(no_mangle)]
+pub extern "C" fn lak_rc_init() {
+    live_objects();
+}
+
+/// Set by [`skip_leak_report`] when the program exits early.
+static SKIP_REPORT: AtomicBool = AtomicBool::new(false);
+
+/// Suppresses the leak report at exit.
+///
+/// Called by `lak_exit` and `lak_panic_at`, which terminate the program
+/// while variables are still in scope.
+pub(crate) fn skip_leak_report() {
+    SKIP_REPORT.store(true, Ordering::Relaxed);
+}
+
+extern "C" fn report_leaks() {
+    if SKIP_REPORT.load(Ordering::Relaxed) {
+        return;
+    }
+    if let Some(live) = live_objects()
+        && let Ok(live) = live.lock()
+    {
+        let _ = live.report(&mut std::io::stderr());
+    }
+}
+
+fn layout(size: u64) -> Layout {
+    Layout::from_size_align(HEADER_SIZE + size as usize, ALIGN).expect("object size overflows")
+}
+
+/// Returns the header of an object.
+///
+/// # Safety
+///
+/// `ptr` must be the payload pointer of a live object.
+unsafe fn header<'a>(ptr: *mut u8) -> &'a mut RcHeader {
+    // SAFETY: The caller guarantees a header precedes `ptr`.
+    unsafe { &mut *(ptr.sub(HEADER_SIZE) as *mut RcHeader) }
+}
+
+/// Allocates an object with room for `size` payload bytes and a count of 1.
+///
+/// Returns a pointer to the uninitialized payload.
+#[unsafe(no_mangle)]
+pub extern "C" fn lak_rc_alloc(size: u64) -> *mut u8 {
+    let layout = layout(size);
+    // SAFETY: `layout` always has a non-zero size because of the header.
+    let base = unsafe { alloc(layout) };
+    if base.is_null() {
+        handle_alloc_error(layout);
+    }
+    // SAFETY: `base` is a fresh allocation large enough for the header.
+    unsafe { (base as *mut RcHeader).write(RcHeader { count: 1, size }) };
+    // SAFETY: The payload starts right after the header, inside the allocation.
+    let ptr = unsafe { base.add(HEADER_SIZE) };
+
+    if let Some(live) = live_objects() {
+        live.lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(ptr, size);
+    }
+    ptr
+}
+
+/// Checks that external data, such as file contents or process output, can
+/// become a Lak string.
+///
+/// Lak strings never contain NUL bytes, since compiled code treats them as
+/// C strings; the lexer rejects NUL in literals. Runtime functions reading
+/// such data report this error through their usual error path instead of
+/// changing the data.
+pub(crate) fn check_string_bytes(bytes: &[u8]) -> io::Result<()> {
+    match bytes.iter().position(|&b| b == 0) {
+        Some(offset) => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("contains a NUL byte at offset {}", offset),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Allocates a string object holding `bytes` followed by a terminating NUL.
+///
+/// The caller owns the returned reference.
+///
+/// # Panics
+///
+/// Panics if `bytes` contains a NUL byte; data from outside the program is
+/// checked with [`check_string_bytes`] first.
+pub(crate) fn new_string(bytes: &[u8]) -> *const c_char {
+    assert!(!bytes.contains(&0), "Lak strings cannot contain NUL bytes");
+    let ptr = lak_rc_alloc(bytes.len() as u64 + 1);
+    // SAFETY: `ptr` has room for the bytes and the terminating NUL.
+    unsafe {
+        std::ptr::copy_nonoverlapping(bytes.as_ptr(), ptr, bytes.len());
+        ptr.add(bytes.len()).write(0);
+    }
+    ptr as *const c_char
+}
+
+/// Adds an owner to an object.
+///
+/// Null pointers and static objects are ignored.
+///
+/// # Safety
+///
+/// If non-null, `ptr` must be the payload pointer of a live object.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_retain(ptr: *mut u8) {
+    if ptr.is_null() {
+        return;
+    }
+    // SAFETY: The caller guarantees `ptr` is a live object.
+    let header = unsafe { header(ptr) };
+    if header.count != IMMORTAL {
+        header.count += 1;
+    }
+}
+
+/// Removes an owner from an object, freeing it when no owners remain.
+///
+/// Null pointers and static objects are ignored.
+///
+/// # Safety
+///
+/// If non-null, `ptr` must be the payload pointer of a live object owned by
+/// the caller. It must not be used by the caller afterwards.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_release(ptr: *mut u8) {
+    // SAFETY: Forwarded from the caller; string payloads own nothing.
+    unsafe { release_with(ptr, |_| {}) }
+}
+
+/// Returns whether an object has more than one owner.
+///
+/// Static objects count as shared, since they must never be changed.
+///
+/// # Safety
+///
+/// `ptr` must be the payload pointer of a live object.
+pub(crate) unsafe fn is_shared(ptr: *mut u8) -> bool {
+    // SAFETY: The caller guarantees `ptr` is a live object.
+    unsafe { header(ptr) }.count > 1
+}
+
+/// Removes an owner from an object like [`lak_release`], calling
+/// `drop_payload` with the payload right before the object is freed.
+///
+/// Used by objects whose payload owns other memory, such as maps and sets.
+///
+/// # Safety
+///
+/// Same as [`lak_release`].
+pub(crate) unsafe fn release_with(ptr: *mut u8, drop_payload: impl FnOnce(*mut u8)) {
+    if ptr.is_null() {
+        return;
+    }
+    // SAFETY: The caller guarantees `ptr` is a live object.
+    let header = unsafe { header(ptr) };
+    if header.count == IMMORTAL {
+        return;
+    }
+    header.count -= 1;
+    if header.count > 0 {
+        return;
+    }
+
+    let size = header.size;
+    drop_payload(ptr);
+    if let Some(live) = live_objects() {
+        live.lock().unwrap_or_else(|e| e.into_inner()).remove(ptr);
+    }
+    // SAFETY: The object was allocated by `lak_rc_alloc` with this layout.
+    unsafe { dealloc(ptr.sub(HEADER_SIZE), layout(size)) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count(ptr: *mut u8) -> u64 {
+        unsafe { header(ptr) }.count
+    }
+
+    #[test]
+    fn test_alloc_retain_release() {
+        let ptr = lak_rc_alloc(4);
+        assert_eq!(ptr as usize % ALIGN, 0);
+        assert_eq!(count(ptr), 1);
+        assert_eq!(unsafe { header(ptr) }.size, 4);
+
+        unsafe { lak_retain(ptr) };
+        assert_eq!(count(ptr), 2);
+        unsafe { lak_release(ptr) };
+        assert_eq!(count(ptr), 1);
+        unsafe { lak_release(ptr) };
+    }
+
+    #[test]
+    fn test_immortal_objects_are_ignored() {
+        #[repr(C)]
+        struct Literal {
+            header: RcHeader,
+            bytes: [u8; 3],
+        }
+        let mut literal = Literal {
+            header: RcHeader {
+                count: IMMORTAL,
+                size: 3,
+            },
+            bytes: *b"hi\0",
+        };
+        let ptr = literal.bytes.as_mut_ptr();
+
+        unsafe { lak_retain(ptr) };
+        unsafe { lak_release(ptr) };
+        unsafe { lak_release(ptr) };
+        assert_eq!(literal.header.count, IMMORTAL);
+    }
+
+    #[test]
+    fn test_new_string() {
+        let ptr = new_string(b"ab") as *mut u8;
+        let text = unsafe { std::ffi::CStr::from_ptr(ptr as *const c_char) };
+        assert_eq!(text.to_bytes(), b"ab");
+        assert_eq!(unsafe { header(ptr) }.size, 3);
+        unsafe { lak_release(ptr) };
+    }
+
+    #[test]
+    fn test_check_string_bytes() {
+        assert!(check_string_bytes(b"ab").is_ok());
+        let error = check_string_bytes(b"ab\0c").unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(error.to_string(), "contains a NUL byte at offset 2");
+    }
+
+    #[test]
+    fn test_null_is_ignored() {
+        unsafe { lak_retain(std::ptr::null_mut()) };
+        unsafe { lak_release(std::ptr::null_mut()) };
+    }
+
+    #[test]
+    fn test_leak_report() {
+        let mut live = LiveObjects::default();
+        let mut out = Vec::new();
+        live.report(&mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "leak check: no leaked objects\n"
+        );
+
+        live.insert(0x20 as *const u8, 6);
+        live.insert(0x10 as *const u8, 3);
+        live.insert(0x30 as *const u8, 1);
+        live.remove(0x30 as *const u8);
+        let mut out = Vec::new();
+        live.report(&mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "leak check: 2 leaked objects (9 bytes)\n  0x10: 3 bytes\n  0x20: 6 bytes\n"
+        );
+    }
+}
@@ -0,0 +1,135 @@
+//! Standard input read by the `read_line` and `read_all` builtins.
+//!
+//! Both builtins share the buffered process stdin, so they can be mixed
+//! freely. Read errors panic with the call-site location, and so does input
+//! containing a NUL byte, which cannot be a Lak string.
+
+use std::ffi::CString;
+use std::io::{self, BufRead, Read};
+use std::os::raw::c_char;
+
+use crate::lak_panic_at;
+use crate::rc::{check_string_bytes, new_string};
+
+/// Reads one line, without its trailing `\n` or `\r\n`.
+///
+/// Returns `None` at end of input. A final line without a newline is
+/// returned as is; a line containing a NUL byte is an error.
+fn read_line_from(reader: &mut impl BufRead) -> io::Result<Option<Vec<u8>>> {
+    let mut line = Vec::new();
+    if reader.read_until(b'\n', &mut line)? == 0 {
+        return Ok(None);
+    }
+    if line.last() == Some(&b'\n') {
+        line.pop();
+        if line.last() == Some(&b'\r') {
+            line.pop();
+        }
+    }
+    check_string_bytes(&line)?;
+    Ok(Some(line))
+}
+
+/// Panics with a read error at the given call site.
+///
+/// # Safety
+///
+/// `file` must be null or a valid C string.
+unsafe fn panic_read_error(error: io::Error, file: *const c_char, line: u32, column: u32) -> ! {
+    let message = CString::new(format!("failed to read from stdin: {}", error))
+        .unwrap_or_else(|_| c"failed to read from stdin".to_owned());
+    // SAFETY: `message` is a valid C string; `file` is forwarded from the caller.
+    unsafe { lak_panic_at(message.as_ptr(), file, line, column) }
+}
+
+/// Reads the next line from stdin as a new string.
+///
+/// Returns null at end of input; the generated code turns the result into a
+/// `(string, bool)` tuple. A non-null string is a reference-counted object
+/// owned by the caller.
+///
+/// # Safety
+///
+/// `file` must be null or a valid C string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_read_line(
+    file: *const c_char,
+    line: u32,
+    column: u32,
+) -> *const c_char {
+    match read_line_from(&mut io::stdin().lock()) {
+        Ok(Some(text)) => new_string(&text),
+        Ok(None) => std::ptr::null(),
+        // SAFETY: Forwarded from the caller.
+        Err(error) => unsafe { panic_read_error(error, file, line, column) },
+    }
+}
+
+/// Reads the rest of stdin as a new string.
+///
+/// Returns an empty string at end of input. The string is a
+/// reference-counted object owned by the caller.
+///
+/// # Safety
+///
+/// `file` must be null or a valid C string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_read_all(
+    file: *const c_char,
+    line: u32,
+    column: u32,
+) -> *const c_char {
+    let mut text = Vec::new();
+    let result = io::stdin()
+        .lock()
+        .read_to_end(&mut text)
+        .and_then(|_| check_string_bytes(&text));
+    match result {
+        Ok(()) => new_string(&text),
+        // SAFETY: Forwarded from the caller.
+        Err(error) => unsafe { panic_read_error(error, file, line, column) },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(input: &[u8]) -> Vec<Vec<u8>> {
+        let mut reader = input;
+        std::iter::from_fn(|| read_line_from(&mut reader).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_read_line_strips_line_endings() {
+        assert_eq!(
+            lines(b"a\nb\r\nc\n"),
+            vec![b"a".to_vec(), b"b".to_vec(), b"c".to_vec()]
+        );
+    }
+
+    #[test]
+    fn test_read_line_returns_final_line_without_newline() {
+        assert_eq!(lines(b"a\nlast"), vec![b"a".to_vec(), b"last".to_vec()]);
+    }
+
+    #[test]
+    fn test_read_line_keeps_empty_lines() {
+        assert_eq!(
+            lines(b"\n\nx\n"),
+            vec![Vec::new(), Vec::new(), b"x".to_vec()]
+        );
+    }
+
+    #[test]
+    fn test_read_line_at_eof() {
+        assert!(lines(b"").is_empty());
+    }
+
+    #[test]
+    fn test_read_line_rejects_nul() {
+        let mut reader: &[u8] = b"a\0b\nc\n";
+        let error = read_line_from(&mut reader).unwrap_err();
+        assert_eq!(error.to_string(), "contains a NUL byte at offset 1");
+    }
+}
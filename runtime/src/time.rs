@@ -0,0 +1,156 @@
+//! Clocks, sleeping and duration formatting for the `time` standard module.
+//!
+//! Durations are `i64` nanoseconds, so the monotonic clock and
+//! `format_duration` work with the same unit.
+
+use std::ffi::CString;
+use std::os::raw::c_char;
+use std::sync::OnceLock;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::lak_panic_at;
+use crate::rc::new_string;
+
+/// Reference point of the monotonic clock, fixed on first use.
+static START: OnceLock<Instant> = OnceLock::new();
+
+/// Returns monotonic nanoseconds since an unspecified starting point.
+///
+/// Only differences between two calls are meaningful.
+#[unsafe(no_mangle)]
+pub extern "C" fn lak_time_now_ns() -> i64 {
+    let start = *START.get_or_init(Instant::now);
+    i64::try_from(start.elapsed().as_nanos()).unwrap_or(i64::MAX)
+}
+
+/// Returns the wall-clock time in whole seconds since the Unix epoch.
+///
+/// Times before the epoch are negative.
+#[unsafe(no_mangle)]
+pub extern "C" fn lak_time_unix_seconds() -> i64 {
+    match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(since) => i64::try_from(since.as_secs()).unwrap_or(i64::MAX),
+        Err(before) => {
+            let before = before.duration();
+            let seconds = i64::try_from(before.as_secs()).unwrap_or(i64::MAX);
+            // Round towards negative infinity like the positive case.
+            if before.subsec_nanos() > 0 {
+                -seconds - 1
+            } else {
+                -seconds
+            }
+        }
+    }
+}
+
+/// Suspends the program for `ms` milliseconds.
+///
+/// Panics with the call-site location if `ms` is negative.
+///
+/// # Safety
+///
+/// `file` must be null or a valid C string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lak_time_sleep_ms(ms: i64, file: *const c_char, line: u32, column: u32) {
+    match u64::try_from(ms) {
+        Ok(ms) => std::thread::sleep(Duration::from_millis(ms)),
+        Err(_) => {
+            let message = CString::new(format!("sleep_ms called with negative duration {}", ms))
+                .expect("message has no NUL bytes");
+            // SAFETY: `message` is a valid C string; `file` is forwarded from the caller.
+            unsafe { lak_panic_at(message.as_ptr(), file, line, column) }
+        }
+    }
+}
+
+/// Formats `ns` nanoseconds as a new string (e.g. `1h2m3.5s`, `1.5ms`).
+#[unsafe(no_mangle)]
+pub extern "C" fn lak_time_format_duration(ns: i64) -> *const c_char {
+    new_string(format_duration(ns).as_bytes())
+}
+
+/// Formats a duration in nanoseconds.
+///
+/// Durations under a second use the largest of `ns`, `µs` and `ms` that
+/// keeps the integer part non-zero. Longer durations are written as hours,
+/// minutes and seconds, omitting leading zero units. Fractions have
+/// trailing zeros removed.
+fn format_duration(ns: i64) -> String {
+    let sign = if ns < 0 { "-" } else { "" };
+    let ns = ns.unsigned_abs();
+    if ns == 0 {
+        return "0s".to_string();
+    }
+    if ns < 1_000 {
+        return format!("{}{}ns", sign, ns);
+    }
+    if ns < 1_000_000 {
+        return format!("{}{}µs", sign, decimal(ns, 1_000));
+    }
+    if ns < 1_000_000_000 {
+        return format!("{}{}ms", sign, decimal(ns, 1_000_000));
+    }
+
+    let hours = ns / 3_600_000_000_000;
+    let minutes = ns / 60_000_000_000 % 60;
+    let seconds = decimal(ns % 60_000_000_000, 1_000_000_000);
+    if hours > 0 {
+        format!("{}{}h{}m{}s", sign, hours, minutes, seconds)
+    } else if minutes > 0 {
+        format!("{}{}m{}s", sign, minutes, seconds)
+    } else {
+        format!("{}{}s", sign, seconds)
+    }
+}
+
+/// Formats `value / unit` with the fraction's trailing zeros removed.
+fn decimal(value: u64, unit: u64) -> String {
+    let whole = value / unit;
+    let fraction = value % unit;
+    if fraction == 0 {
+        return whole.to_string();
+    }
+    let width = unit.ilog10() as usize;
+    let digits = format!("{:0width$}", fraction, width = width);
+    format!("{}.{}", whole, digits.trim_end_matches('0'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_duration_small_units() {
+        assert_eq!(format_duration(0), "0s");
+        assert_eq!(format_duration(999), "999ns");
+        assert_eq!(format_duration(1_000), "1µs");
+        assert_eq!(format_duration(1_500), "1.5µs");
+        assert_eq!(format_duration(1_500_000), "1.5ms");
+        assert_eq!(format_duration(999_999_999), "999.999999ms");
+    }
+
+    #[test]
+    fn test_format_duration_seconds_and_up() {
+        assert_eq!(format_duration(1_000_000_000), "1s");
+        assert_eq!(format_duration(2_250_000_000), "2.25s");
+        assert_eq!(format_duration(60_000_000_000), "1m0s");
+        assert_eq!(format_duration(3_723_500_000_000), "1h2m3.5s");
+        assert_eq!(format_duration(3_600_000_000_000), "1h0m0s");
+    }
+
+    #[test]
+    fn test_format_duration_negative_and_extremes() {
+        assert_eq!(format_duration(-1_500), "-1.5µs");
+        assert_eq!(format_duration(-90_000_000_000), "-1m30s");
+        assert_eq!(format_duration(i64::MIN), "-2562047h47m16.854775808s");
+        assert_eq!(format_duration(i64::MAX), "2562047h47m16.854775807s");
+    }
+
+    #[test]
+    fn test_now_ns_is_monotonic() {
+        let first = lak_time_now_ns();
+        let second = lak_time_now_ns();
+        assert!(first >= 0);
+        assert!(second >= first);
+    }
+}